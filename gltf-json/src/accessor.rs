@@ -192,7 +192,8 @@ pub mod sparse {
 pub struct Accessor {
     /// The parent buffer view this accessor reads from.
     ///
-    /// This field can be omitted in sparse accessors.
+    /// When omitted, the accessor is initialized with zeros, which sparse
+    /// storage may override.
     #[serde(rename = "bufferView")]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub buffer_view: Option<Index<buffer::View>>,
@@ -250,12 +251,6 @@ impl Validate for Accessor {
     fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
         where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
     {
-        if self.sparse.is_none() && self.buffer_view.is_none() {
-            // If sparse is missing, then bufferView must be present. Report that bufferView is
-            // missing since it is the more common one to require.
-            report(&|| path().field("bufferView"), Error::Missing);
-        }
-
         self.buffer_view.validate(root, || path().field("bufferView"), report);
         self.byte_offset.validate(root, || path().field("byteOffset"), report);
         self.count.validate(root, || path().field("count"), report);
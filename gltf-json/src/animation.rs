@@ -18,6 +18,8 @@ pub const VALID_PROPERTIES: &'static [&'static str] = &[
     "rotation",
     "scale",
     "weights",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "pointer",
 ];
 
 /// Specifies an interpolation algorithm.
@@ -62,6 +64,11 @@ pub enum Property {
 
     /// Weights of morph targets.
     MorphTargetWeights,
+
+    /// Any property referenced by the JSON pointer of the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    Pointer,
 }
 
 /// A keyframe animation.
@@ -115,7 +122,7 @@ pub struct Channel {
 }
 
 /// The index of the node and TRS property that an animation channel targets.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Target {
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -127,7 +134,16 @@ pub struct Target {
     pub extras: Extras,
     
     /// The index of the node to target.
+    #[cfg(not(feature = "KHR_animation_pointer"))]
     pub node: Index<scene::Node>,
+
+    /// The index of the node to target.
+    ///
+    /// This is only absent when the target is defined by the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node: Option<Index<scene::Node>>,
     
     /// The name of the node's property to modify or the 'weights' of the
     /// morph targets it instantiates.
@@ -169,6 +185,33 @@ impl Validate for Animation {
                 let path = || path().field("channels").index(index).field("sampler");
                 report(&path, Error::IndexOutOfBounds);
             }
+            channel.target.validate(root, || path().field("channels").index(index).field("target"), report);
+        }
+    }
+}
+
+impl Validate for Target {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+    where
+        P: Fn() -> Path,
+        R: FnMut(&dyn Fn() -> Path, Error),
+    {
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.node.validate(root, || path().field("node"), report);
+        self.path.validate(root, || path().field("path"), report);
+
+        #[cfg(feature = "KHR_animation_pointer")]
+        if self.path == Checked::Valid(Property::Pointer) {
+            let pointer = self.extensions.as_ref().and_then(|x| x.khr_animation_pointer.as_ref());
+            if pointer.is_none() {
+                report(&|| path().field("extensions").field("KHR_animation_pointer"), Error::Missing);
+            }
+            return;
+        }
+
+        #[cfg(feature = "KHR_animation_pointer")]
+        if self.node.is_none() {
+            report(&|| path().field("node"), Error::Missing);
         }
     }
 }
@@ -243,6 +286,8 @@ impl<'de> de::Deserialize<'de> for Checked<Property> {
                     "rotation" => Valid(Rotation),
                     "scale" => Valid(Scale),
                     "weights" => Valid(MorphTargetWeights),
+                    #[cfg(feature = "KHR_animation_pointer")]
+                    "pointer" => Valid(Pointer),
                     _ => Invalid,
                 })
             }
@@ -261,6 +306,8 @@ impl ser::Serialize for Property {
             Property::Rotation => "rotation",
             Property::Scale => "scale",
             Property::MorphTargetWeights => "weights",
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => "pointer",
         })
     }
 }
@@ -7,18 +7,42 @@ pub mod sparse {
 
     /// Indices of those attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Indices {}
+    pub struct Indices {
+        /// Extensions unknown to the crate, or whose feature is disabled,
+        /// keyed by extension name.
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 
     /// Sparse storage of attributes that deviate from their initialization value.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Sparse {}
+    pub struct Sparse {
+        /// Extensions unknown to the crate, or whose feature is disabled,
+        /// keyed by extension name.
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 
     /// Array of size `count * number_of_components` storing the displaced
     /// accessor attributes pointed by `accessor::sparse::Indices`.
     #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-    pub struct Values {}
+    pub struct Values {
+        /// Extensions unknown to the crate, or whose feature is disabled,
+        /// keyed by extension name.
+        #[cfg(feature = "extensions")]
+        #[serde(default, flatten)]
+        pub others: serde_json::Map<String, serde_json::Value>,
+    }
 }
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Accessor {}
+pub struct Accessor {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
@@ -3,16 +3,64 @@ use serde_derive::{Serialize, Deserialize};
 
 /// A keyframe animation.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Animation {}
+pub struct Animation {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Targets an animation's sampler at a node's property.
 #[derive(Clone, Debug, Default, Deserialize, Serialize)]
-pub struct Channel {}
+pub struct Channel {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The index of the node and TRS property that an animation channel targets.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Target {}
+pub struct Target {
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[serde(default, rename = "KHR_animation_pointer", skip_serializing_if = "Option::is_none")]
+    pub khr_animation_pointer: Option<khr_animation_pointer::KhrAnimationPointer>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "KHR_animation_pointer")]
+pub mod khr_animation_pointer {
+    use crate::Extras;
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Targets an arbitrary property of the asset with a JSON pointer.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct KhrAnimationPointer {
+        /// A JSON pointer to the animated property, for example
+        /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+        pub pointer: String,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+}
 
 /// Defines a keyframe graph but not its target.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+pub struct Sampler {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
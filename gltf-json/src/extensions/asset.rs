@@ -3,4 +3,10 @@ use serde_derive::{Serialize, Deserialize};
 
 /// Metadata about the glTF asset.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Asset {}
+pub struct Asset {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
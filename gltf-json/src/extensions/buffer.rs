@@ -3,8 +3,235 @@ use serde_derive::{Serialize, Deserialize};
 
 /// A buffer points to binary data representing geometry, animations, or skins.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Buffer {}
+pub struct Buffer {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(default, rename = "EXT_meshopt_compression", skip_serializing_if = "Option::is_none")]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::Buffer>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A view into a buffer generally representing a subset of the buffer.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct View {}
+pub struct View {
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[serde(default, rename = "EXT_meshopt_compression", skip_serializing_if = "Option::is_none")]
+    pub ext_meshopt_compression: Option<ext_meshopt_compression::View>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+pub mod ext_meshopt_compression {
+    use crate::validation::Checked;
+    use crate::{Extras, Index};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid compression modes.
+    pub const VALID_MODES: &[&str] = &[
+        "ATTRIBUTES",
+        "TRIANGLES",
+        "INDICES",
+    ];
+
+    /// All valid compression filters.
+    pub const VALID_FILTERS: &[&str] = &[
+        "NONE",
+        "OCTAHEDRAL",
+        "QUATERNION",
+        "EXPONENTIAL",
+    ];
+
+    /// Marks a buffer as fallback for compressed buffer views.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Buffer {
+        /// Whether the buffer only provides the uncompressed layout of the
+        /// compressed buffer views, in which case it may have no data.
+        #[serde(default)]
+        pub fallback: bool,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The compressed data of a buffer view.
+    ///
+    /// The buffer view itself describes the data after decompression.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct View {
+        /// The buffer storing the compressed data.
+        pub buffer: Index<crate::Buffer>,
+
+        /// Offset of the compressed data into the buffer in bytes.
+        #[serde(default)]
+        pub byte_offset: u32,
+
+        /// The length of the compressed data in bytes.
+        pub byte_length: u32,
+
+        /// The stride in bytes of the decompressed elements.
+        pub byte_stride: u32,
+
+        /// The number of decompressed elements.
+        pub count: u32,
+
+        /// The compression mode.
+        pub mode: Checked<Mode>,
+
+        /// The filter applied to the decompressed elements.
+        #[serde(default = "filter_default")]
+        pub filter: Checked<Filter>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn filter_default() -> Checked<Filter> {
+        Checked::Valid(Filter::None)
+    }
+
+    /// Specifies how the data of a buffer view is compressed.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Mode {
+        /// Vertex attributes or other interleaved data.
+        Attributes = 1,
+
+        /// Triangle list indices.
+        Triangles,
+
+        /// Arbitrary index sequences.
+        Indices,
+    }
+
+    /// Specifies the filter applied to the decompressed elements.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Filter {
+        /// No filter.
+        None = 1,
+
+        /// Octahedral encoding of unit vectors.
+        Octahedral,
+
+        /// Encoding of unit quaternions by their three smallest components.
+        Quaternion,
+
+        /// Encoding of floating point values by a mantissa and an exponent.
+        Exponential,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Mode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Mode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Mode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "ATTRIBUTES" => Valid(Attributes),
+                        "TRIANGLES" => Valid(Triangles),
+                        "INDICES" => Valid(Indices),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Mode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl Mode {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                Mode::Attributes => "ATTRIBUTES",
+                Mode::Triangles => "TRIANGLES",
+                Mode::Indices => "INDICES",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Filter> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Filter>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_FILTERS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Filter::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "NONE" => Valid(None),
+                        "OCTAHEDRAL" => Valid(Octahedral),
+                        "QUATERNION" => Valid(Quaternion),
+                        "EXPONENTIAL" => Valid(Exponential),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Filter {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl Filter {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                Filter::None => "NONE",
+                Filter::Octahedral => "OCTAHEDRAL",
+                Filter::Quaternion => "QUATERNION",
+                Filter::Exponential => "EXPONENTIAL",
+            }
+        }
+    }
+}
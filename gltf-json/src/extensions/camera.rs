@@ -6,12 +6,30 @@ use serde_derive::{Serialize, Deserialize};
 /// A node can reference a camera to apply a transform to place the camera in the
 /// scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Camera {}
+pub struct Camera {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for an orthographic camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Orthographic {}
+pub struct Orthographic {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Values for a perspective camera.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Perspective {}
+pub struct Perspective {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
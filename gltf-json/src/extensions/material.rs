@@ -1,11 +1,35 @@
 use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
-#[cfg(any(feature = "KHR_materials_pbrSpecularGlossiness", feature = "KHR_materials_transmission", feature = "KHR_materials_ior"))]
+#[cfg(any(
+    feature = "KHR_materials_pbrSpecularGlossiness",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_ior",
+    feature = "KHR_materials_volume",
+    feature = "KHR_materials_specular",
+    feature = "KHR_materials_sheen",
+    feature = "KHR_materials_clearcoat",
+    feature = "KHR_materials_iridescence",
+    feature = "KHR_materials_anisotropy",
+    feature = "KHR_materials_emissive_strength",
+    feature = "KHR_materials_diffuse_transmission",
+))]
 use crate::{Extras, validation::Validate};
-#[cfg(any(feature = "KHR_materials_pbrSpecularGlossiness", feature = "KHR_materials_transmission"))]
+#[cfg(any(
+    feature = "KHR_materials_pbrSpecularGlossiness",
+    feature = "KHR_materials_transmission",
+    feature = "KHR_materials_volume",
+    feature = "KHR_materials_specular",
+    feature = "KHR_materials_sheen",
+    feature = "KHR_materials_clearcoat",
+    feature = "KHR_materials_iridescence",
+    feature = "KHR_materials_anisotropy",
+    feature = "KHR_materials_diffuse_transmission",
+))]
 use crate::texture;
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
 use crate::material::StrengthFactor;
+#[cfg(feature = "KHR_materials_clearcoat")]
+use crate::material::NormalTexture as NormalTextureInfo;
 
 /// The material appearance of a primitive.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
@@ -33,12 +57,52 @@ pub struct Material {
     #[cfg(feature = "KHR_materials_ior")]
     #[serde(default, rename = "KHR_materials_ior", skip_serializing_if = "Option::is_none")]
     pub ior: Option<Ior>,
+
+    #[cfg(feature = "KHR_materials_sheen")]
+    #[serde(default, rename = "KHR_materials_sheen", skip_serializing_if = "Option::is_none")]
+    pub sheen: Option<Sheen>,
+
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    #[serde(default, rename = "KHR_materials_clearcoat", skip_serializing_if = "Option::is_none")]
+    pub clearcoat: Option<Clearcoat>,
+
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[serde(default, rename = "KHR_materials_iridescence", skip_serializing_if = "Option::is_none")]
+    pub iridescence: Option<Iridescence>,
+
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[serde(default, rename = "KHR_materials_anisotropy", skip_serializing_if = "Option::is_none")]
+    pub anisotropy: Option<Anisotropy>,
+
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[serde(default, rename = "KHR_materials_emissive_strength", skip_serializing_if = "Option::is_none")]
+    pub emissive_strength: Option<EmissiveStrength>,
+
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    #[serde(default, rename = "KHR_materials_diffuse_transmission", skip_serializing_if = "Option::is_none")]
+    pub diffuse_transmission: Option<DiffuseTransmission>,
+
+    #[cfg(feature = "VRM")]
+    #[serde(default, rename = "VRMC_materials_mtoon", skip_serializing_if = "Option::is_none")]
+    pub mtoon: Option<vrmc_materials_mtoon::MToon>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 /// A set of parameter values that are used to define the metallic-roughness
 /// material model from Physically-Based Rendering (PBR) methodology.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct PbrMetallicRoughness {}
+pub struct PbrMetallicRoughness {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A set of parameter values that are used to define the specular-glossiness
 /// material model from Physically-Based Rendering (PBR) methodology.
@@ -93,11 +157,23 @@ pub struct PbrSpecularGlossiness {
 
 /// Defines the normal texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct NormalTexture {}
+pub struct NormalTexture {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Defines the occlusion texture of a material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct OcclusionTexture {}
+pub struct OcclusionTexture {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// The diffuse factor of a material.
 #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
@@ -233,6 +309,15 @@ impl Default for AttenuationDistance {
     }
 }
 
+#[cfg(feature = "KHR_materials_volume")]
+impl AttenuationDistance {
+    /// Infinity cannot be represented in JSON, so the default is omitted when
+    /// serializing.
+    fn is_infinite(&self) -> bool {
+        self.0.is_infinite()
+    }
+}
+
 #[cfg(feature = "KHR_materials_volume")]
 impl Validate for AttenuationDistance {}
 
@@ -270,6 +355,7 @@ pub struct Volume {
     /// Density of the medium given as the average distance that light
     /// travels in the medium before interacting with a particle. The
     /// value is given in world space. Range is (0, +inf).
+    #[serde(skip_serializing_if = "AttenuationDistance::is_infinite")]
     pub attenuation_distance: AttenuationDistance,
 
     /// The color that white light turns into due to absorption when
@@ -337,3 +423,518 @@ pub struct Specular {
     #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
     pub extras: Extras,
 }
+
+/// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [0.0; 3].
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SheenColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenColorFactor {}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct SheenRoughnessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_sheen")]
+impl Validate for SheenRoughnessFactor {}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Sheen {
+    /// The sheen color in linear space.
+    pub sheen_color_factor: SheenColorFactor,
+
+    /// The sheen color (RGB) texture, stored in the `RGB` channels and
+    /// encoded in sRGB. This will be multiplied by `sheen_color_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_color_texture: Option<texture::Info>,
+
+    /// The sheen roughness.
+    pub sheen_roughness_factor: SheenRoughnessFactor,
+
+    /// The sheen roughness texture, stored in the alpha (`A`) channel.
+    /// This will be multiplied by `sheen_roughness_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sheen_roughness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct ClearcoatFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Validate for ClearcoatFactor {}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct ClearcoatRoughnessFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+impl Validate for ClearcoatRoughnessFactor {}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Clearcoat {
+    /// The clearcoat layer intensity.
+    pub clearcoat_factor: ClearcoatFactor,
+
+    /// The clearcoat layer intensity texture, stored in the `R` channel.
+    /// This will be multiplied by `clearcoat_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_texture: Option<texture::Info>,
+
+    /// The clearcoat layer roughness.
+    pub clearcoat_roughness_factor: ClearcoatRoughnessFactor,
+
+    /// The clearcoat layer roughness texture, stored in the `G` channel.
+    /// This will be multiplied by `clearcoat_roughness_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_roughness_texture: Option<texture::Info>,
+
+    /// The clearcoat normal map texture.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub clearcoat_normal_texture: Option<NormalTextureInfo>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct IridescenceFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceFactor {}
+
+/// A number in the inclusive range [1.0, +inf] with a default value of 1.3.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct IridescenceIor(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceIor {
+    fn default() -> Self {
+        IridescenceIor(1.3)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceIor {}
+
+/// A number in the inclusive range [0.0, +inf] with a default value of 100.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct IridescenceThicknessMinimum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMinimum {
+    fn default() -> Self {
+        IridescenceThicknessMinimum(100.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMinimum {}
+
+/// A number in the inclusive range [0.0, +inf] with a default value of 400.0.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct IridescenceThicknessMaximum(pub f32);
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Default for IridescenceThicknessMaximum {
+    fn default() -> Self {
+        IridescenceThicknessMaximum(400.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+impl Validate for IridescenceThicknessMaximum {}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Iridescence {
+    /// The iridescence intensity factor.
+    pub iridescence_factor: IridescenceFactor,
+
+    /// The iridescence intensity texture, stored in the `R` channel.
+    /// This will be multiplied by `iridescence_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_texture: Option<texture::Info>,
+
+    /// The index of refraction of the dielectric thin-film layer.
+    pub iridescence_ior: IridescenceIor,
+
+    /// The minimum thickness of the thin-film layer given in nanometers.
+    pub iridescence_thickness_minimum: IridescenceThicknessMinimum,
+
+    /// The maximum thickness of the thin-film layer given in nanometers.
+    pub iridescence_thickness_maximum: IridescenceThicknessMaximum,
+
+    /// The thickness texture of the thin-film layer, stored in the `G` channel.
+    /// This is used to interpolate between `iridescence_thickness_minimum`
+    /// and `iridescence_thickness_maximum`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub iridescence_thickness_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct AnisotropyStrength(pub f32);
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Validate for AnisotropyStrength {}
+
+/// An angle in radians with a default value of 0.0.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct AnisotropyRotation(pub f32);
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+impl Validate for AnisotropyRotation {}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Anisotropy {
+    /// The anisotropy strength. When the anisotropy texture is present, this
+    /// value is multiplied by the texture's blue channel.
+    pub anisotropy_strength: AnisotropyStrength,
+
+    /// The rotation of the anisotropy in tangent, bitangent space, measured in
+    /// radians counter-clockwise from the tangent. When the anisotropy texture
+    /// is present, this value provides additional rotation to the vectors in
+    /// the texture.
+    pub anisotropy_rotation: AnisotropyRotation,
+
+    /// The anisotropy texture. The red and green channels contain the direction
+    /// in `[-1, 1]` tangent, bitangent space, to be rotated by
+    /// `anisotropy_rotation`. The blue channel contains strength as `[0, 1]`
+    /// to be multiplied by `anisotropy_strength`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub anisotropy_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A positive number with a default value of 1.0.
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct EmissiveStrengthFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Default for EmissiveStrengthFactor {
+    fn default() -> Self {
+        EmissiveStrengthFactor(1.0)
+    }
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+impl Validate for EmissiveStrengthFactor {}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct EmissiveStrength {
+    /// The strength adjustment to be multiplied with the material's emissive value.
+    pub emissive_strength: EmissiveStrengthFactor,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// A number in the inclusive range [0.0, 1.0] with a default value of 0.0.
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Copy, Debug, Default, Deserialize, Serialize)]
+pub struct DiffuseTransmissionFactor(pub f32);
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Validate for DiffuseTransmissionFactor {}
+
+/// A colour in the inclusive range [[0.0; 3], [1.0; 3]] with a default value of [1.0; 3].
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+pub struct DiffuseTransmissionColorFactor(pub [f32; 3]);
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Default for DiffuseTransmissionColorFactor {
+    fn default() -> Self {
+        DiffuseTransmissionColorFactor([1.0, 1.0, 1.0])
+    }
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+impl Validate for DiffuseTransmissionColorFactor {}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct DiffuseTransmission {
+    /// The percentage of non-specularly reflected light that is transmitted
+    /// through the surface via the Lambertian diffuse transmission.
+    pub diffuse_transmission_factor: DiffuseTransmissionFactor,
+
+    /// A texture that defines the percentage of light transmitted through the
+    /// surface, stored in the alpha (`A`) channel. This will be multiplied by
+    /// `diffuse_transmission_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffuse_transmission_texture: Option<texture::Info>,
+
+    /// The color that modulates the transmitted light (linear RGB).
+    pub diffuse_transmission_color_factor: DiffuseTransmissionColorFactor,
+
+    /// A texture that defines the color of the transmitted light, stored in
+    /// the `RGB` channels and encoded in sRGB. This will be multiplied by
+    /// `diffuse_transmission_color_factor`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diffuse_transmission_color_texture: Option<texture::Info>,
+
+    /// Optional application specific data.
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+#[cfg(feature = "VRM")]
+pub mod vrmc_materials_mtoon {
+    use crate::{texture, Extras, Index};
+    use crate::validation::Checked;
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid outline width modes.
+    pub const VALID_OUTLINE_WIDTH_MODES: &[&str] = &[
+        "none",
+        "worldCoordinates",
+        "screenCoordinates",
+    ];
+
+    /// The parameters of the MToon toon shading model.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct MToon {
+        /// The version of the specification the material conforms to.
+        pub spec_version: String,
+
+        /// Whether depth is written for transparent materials.
+        pub transparent_with_z_write: bool,
+
+        /// The offset of the render queue within the alpha mode.
+        pub render_queue_offset_number: i32,
+
+        /// The color of shaded areas in linear space.
+        pub shade_color_factor: [f32; 3],
+
+        /// A texture multiplied with `shade_color_factor`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub shade_multiply_texture: Option<texture::Info>,
+
+        /// Shifts the boundary between lit and shaded areas.
+        pub shading_shift_factor: f32,
+
+        /// A texture added to `shading_shift_factor`, stored in the `R`
+        /// channel.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub shading_shift_texture: Option<ShadingShiftTexture>,
+
+        /// The sharpness of the boundary between lit and shaded areas.
+        pub shading_toony_factor: f32,
+
+        /// How much global illumination is made uniform.
+        pub gi_equalization_factor: f32,
+
+        /// The color multiplied with the matcap texture.
+        pub matcap_factor: [f32; 3],
+
+        /// A texture sampled by view-space normals.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub matcap_texture: Option<texture::Info>,
+
+        /// The color of the parametric rim light.
+        pub parametric_rim_color_factor: [f32; 3],
+
+        /// A texture multiplied with the rim lighting.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub rim_multiply_texture: Option<texture::Info>,
+
+        /// How much the rim lighting is affected by scene lighting.
+        pub rim_lighting_mix_factor: f32,
+
+        /// The fresnel exponent of the parametric rim light.
+        pub parametric_rim_fresnel_power_factor: f32,
+
+        /// The lift of the parametric rim light.
+        pub parametric_rim_lift_factor: f32,
+
+        /// How the outline width is measured.
+        pub outline_width_mode: Checked<OutlineWidthMode>,
+
+        /// The width of the outline.
+        pub outline_width_factor: f32,
+
+        /// A texture multiplied with `outline_width_factor`, stored in the
+        /// `G` channel.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub outline_width_multiply_texture: Option<texture::Info>,
+
+        /// The color of the outline in linear space.
+        pub outline_color_factor: [f32; 3],
+
+        /// How much the outline color is affected by scene lighting.
+        pub outline_lighting_mix_factor: f32,
+
+        /// A texture masking the UV animation, stored in the `B` channel.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub uv_animation_mask_texture: Option<texture::Info>,
+
+        /// The horizontal UV scroll speed in UV units per second.
+        pub uv_animation_scroll_x_speed_factor: f32,
+
+        /// The vertical UV scroll speed in UV units per second.
+        pub uv_animation_scroll_y_speed_factor: f32,
+
+        /// The UV rotation speed in radians per second.
+        pub uv_animation_rotation_speed_factor: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for MToon {
+        fn default() -> Self {
+            MToon {
+                spec_version: "1.0".to_string(),
+                transparent_with_z_write: false,
+                render_queue_offset_number: 0,
+                shade_color_factor: [1.0, 1.0, 1.0],
+                shade_multiply_texture: None,
+                shading_shift_factor: 0.0,
+                shading_shift_texture: None,
+                shading_toony_factor: 0.9,
+                gi_equalization_factor: 0.9,
+                matcap_factor: [1.0, 1.0, 1.0],
+                matcap_texture: None,
+                parametric_rim_color_factor: [0.0, 0.0, 0.0],
+                rim_multiply_texture: None,
+                rim_lighting_mix_factor: 1.0,
+                parametric_rim_fresnel_power_factor: 5.0,
+                parametric_rim_lift_factor: 0.0,
+                outline_width_mode: Checked::Valid(OutlineWidthMode::None),
+                outline_width_factor: 0.0,
+                outline_width_multiply_texture: None,
+                outline_color_factor: [0.0, 0.0, 0.0],
+                outline_lighting_mix_factor: 1.0,
+                uv_animation_mask_texture: None,
+                uv_animation_scroll_x_speed_factor: 0.0,
+                uv_animation_scroll_y_speed_factor: 0.0,
+                uv_animation_rotation_speed_factor: 0.0,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// A texture shifting the boundary between lit and shaded areas.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ShadingShiftTexture {
+        /// The index of the texture.
+        pub index: Index<texture::Texture>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        #[serde(default)]
+        pub tex_coord: u32,
+
+        /// The scalar multiplier applied to the texture values.
+        #[serde(default = "shading_shift_texture_scale_default")]
+        pub scale: f32,
+    }
+
+    fn shading_shift_texture_scale_default() -> f32 {
+        1.0
+    }
+
+    /// Specifies how the width of an outline is measured.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum OutlineWidthMode {
+        /// No outline is drawn.
+        None = 1,
+
+        /// The width is measured in world units.
+        WorldCoordinates,
+
+        /// The width is a fraction of the screen height.
+        ScreenCoordinates,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<OutlineWidthMode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<OutlineWidthMode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_OUTLINE_WIDTH_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::OutlineWidthMode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "none" => Valid(None),
+                        "worldCoordinates" => Valid(WorldCoordinates),
+                        "screenCoordinates" => Valid(ScreenCoordinates),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for OutlineWidthMode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl OutlineWidthMode {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                OutlineWidthMode::None => "none",
+                OutlineWidthMode::WorldCoordinates => "worldCoordinates",
+                OutlineWidthMode::ScreenCoordinates => "screenCoordinates",
+            }
+        }
+    }
+}
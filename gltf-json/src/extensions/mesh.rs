@@ -6,8 +6,231 @@ use serde_derive::{Serialize, Deserialize};
 /// A node can contain one or more meshes and its transform places the meshes in
 /// the scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Mesh {}
+pub struct Mesh {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// Geometry to be rendered with the given material.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Primitive {}
+pub struct Primitive {
+    #[cfg(feature = "KHR_materials_variants")]
+    #[serde(default, rename = "KHR_materials_variants", skip_serializing_if = "Option::is_none")]
+    pub khr_materials_variants: Option<khr_materials_variants::KhrMaterialsVariants>,
+
+    #[cfg(feature = "EXT_mesh_features")]
+    #[serde(default, rename = "EXT_mesh_features", skip_serializing_if = "Option::is_none")]
+    pub ext_mesh_features: Option<ext_mesh_features::ExtMeshFeatures>,
+
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[serde(default, rename = "EXT_structural_metadata", skip_serializing_if = "Option::is_none")]
+    pub ext_structural_metadata: Option<ext_structural_metadata::ExtStructuralMetadata>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "EXT_mesh_features")]
+pub mod ext_mesh_features {
+    use crate::{texture, Extras, Index, Path, Root};
+    use crate::validation::{Error, Validate};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// The feature IDs of a primitive.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ExtMeshFeatures {
+        /// The sets of feature IDs of the primitive.
+        pub feature_ids: Vec<FeatureId>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A set of feature IDs, stored in a vertex attribute, in a texture or
+    /// implicitly given by the vertex index.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FeatureId {
+        /// The number of distinct features.
+        pub feature_count: u32,
+
+        /// A value denoting that no feature is associated with the vertex or
+        /// texel.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub null_feature_id: Option<u32>,
+
+        /// A label identifying the set of feature IDs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub label: Option<String>,
+
+        /// The set index of the `_FEATURE_ID_n` attribute storing the feature
+        /// IDs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub attribute: Option<u32>,
+
+        /// The texture storing the feature IDs.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub texture: Option<FeatureIdTexture>,
+
+        /// The index of the `EXT_structural_metadata` property table
+        /// associated with the features.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub property_table: Option<u32>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for FeatureId {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.attribute.is_some() && self.texture.is_some() {
+                report(&|| path().field("texture"), Error::Invalid);
+            }
+
+            #[cfg(feature = "EXT_structural_metadata")]
+            if let Some(property_table) = self.property_table {
+                let count = root.extensions
+                    .as_ref()
+                    .and_then(|extensions| extensions.ext_structural_metadata.as_ref())
+                    .map_or(0, |metadata| metadata.property_tables.len());
+                if property_table as usize >= count {
+                    report(&|| path().field("propertyTable"), Error::IndexOutOfBounds);
+                }
+            }
+
+            self.texture.validate(root, || path().field("texture"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A texture storing feature IDs in some of its channels.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FeatureIdTexture {
+        /// The index of the texture.
+        pub index: Index<texture::Texture>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        #[serde(default)]
+        pub tex_coord: u32,
+
+        /// The texture channels storing the feature ID, in little-endian
+        /// order.
+        #[serde(default = "channels_default")]
+        pub channels: Vec<u32>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn channels_default() -> Vec<u32> {
+        vec![0]
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+pub mod ext_structural_metadata {
+    use crate::extensions::root::ext_structural_metadata::{PropertyAttribute, PropertyTexture};
+    use crate::{Extras, Index};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// The property textures and property attributes of a primitive.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct ExtStructuralMetadata {
+        /// The indices of the property textures of the primitive.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub property_textures: Vec<Index<PropertyTexture>>,
+
+        /// The indices of the property attributes of the primitive.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub property_attributes: Vec<Index<PropertyAttribute>>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+pub mod khr_materials_variants {
+    use crate::{Extras, Index, Material, Path, Root};
+    use crate::validation::{Error, Validate};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// A material variant that may be selected by the application.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct Variant {
+        /// The name of the material variant.
+        pub name: String,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The material variant mappings of a primitive.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct KhrMaterialsVariants {
+        /// Material mappings of the primitive.
+        pub mappings: Vec<Mapping>,
+    }
+
+    /// Maps a set of material variants to a material.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Mapping {
+        /// The material associated with the set of variants.
+        pub material: Index<Material>,
+
+        /// Indices of the root-level variants that select `material`.
+        pub variants: Vec<u32>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Mapping {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            let count = root.extensions
+                .as_ref()
+                .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+                .map_or(0, |variants| variants.variants.len());
+            for (i, variant) in self.variants.iter().enumerate() {
+                if *variant as usize >= count {
+                    report(&|| path().field("variants").index(i), Error::IndexOutOfBounds);
+                }
+            }
+
+            self.material.validate(root, || path().field("material"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+}
@@ -50,6 +50,64 @@ pub const ENABLED_EXTENSIONS: &'static [&'static str] = &[
     "KHR_materials_transmission",
     #[cfg(feature = "KHR_materials_ior")]
     "KHR_materials_ior",
+    #[cfg(feature = "KHR_materials_volume")]
+    "KHR_materials_volume",
+    #[cfg(feature = "KHR_materials_specular")]
+    "KHR_materials_specular",
+    #[cfg(feature = "KHR_materials_sheen")]
+    "KHR_materials_sheen",
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    "KHR_materials_clearcoat",
+    #[cfg(feature = "KHR_materials_iridescence")]
+    "KHR_materials_iridescence",
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    "KHR_materials_anisotropy",
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    "KHR_materials_emissive_strength",
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    "KHR_materials_diffuse_transmission",
+    #[cfg(feature = "KHR_materials_variants")]
+    "KHR_materials_variants",
+    #[cfg(feature = "KHR_texture_basisu")]
+    "KHR_texture_basisu",
+    #[cfg(feature = "EXT_texture_webp")]
+    "EXT_texture_webp",
+    #[cfg(feature = "KHR_mesh_quantization")]
+    "KHR_mesh_quantization",
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    "EXT_mesh_gpu_instancing",
+    #[cfg(feature = "EXT_meshopt_compression")]
+    "EXT_meshopt_compression",
+    #[cfg(feature = "KHR_animation_pointer")]
+    "KHR_animation_pointer",
+    #[cfg(feature = "EXT_lights_ies")]
+    "EXT_lights_ies",
+    #[cfg(feature = "MSFT_lod")]
+    "MSFT_lod",
+    #[cfg(feature = "MSFT_texture_dds")]
+    "MSFT_texture_dds",
+    #[cfg(feature = "KHR_audio")]
+    "KHR_audio",
+    #[cfg(feature = "KHR_node_visibility")]
+    "KHR_node_visibility",
+    #[cfg(feature = "KHR_interactivity")]
+    "KHR_interactivity",
+    #[cfg(feature = "KHR_implicit_shapes")]
+    "KHR_implicit_shapes",
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    "KHR_physics_rigid_bodies",
+    #[cfg(feature = "EXT_mesh_features")]
+    "EXT_mesh_features",
+    #[cfg(feature = "EXT_structural_metadata")]
+    "EXT_structural_metadata",
+    #[cfg(feature = "VRM")]
+    "VRMC_vrm",
+    #[cfg(feature = "VRM")]
+    "VRMC_springBone",
+    #[cfg(feature = "VRM")]
+    "VRMC_materials_mtoon",
+    #[cfg(feature = "CESIUM_RTC")]
+    "CESIUM_RTC",
 ];
 
 /// Names of glTF 2.0 extensions supported by the library.
@@ -60,4 +118,33 @@ pub const SUPPORTED_EXTENSIONS: &'static [&'static str] = &[
     "KHR_texture_transform",
     "KHR_materials_transmission",
     "KHR_materials_ior",
+    "KHR_materials_volume",
+    "KHR_materials_specular",
+    "KHR_materials_sheen",
+    "KHR_materials_clearcoat",
+    "KHR_materials_iridescence",
+    "KHR_materials_anisotropy",
+    "KHR_materials_emissive_strength",
+    "KHR_materials_diffuse_transmission",
+    "KHR_materials_variants",
+    "KHR_texture_basisu",
+    "EXT_texture_webp",
+    "KHR_mesh_quantization",
+    "EXT_mesh_gpu_instancing",
+    "EXT_meshopt_compression",
+    "KHR_animation_pointer",
+    "EXT_lights_ies",
+    "MSFT_lod",
+    "MSFT_texture_dds",
+    "KHR_audio",
+    "KHR_node_visibility",
+    "KHR_interactivity",
+    "KHR_implicit_shapes",
+    "KHR_physics_rigid_bodies",
+    "EXT_mesh_features",
+    "EXT_structural_metadata",
+    "VRMC_vrm",
+    "VRMC_springBone",
+    "VRMC_materials_mtoon",
+    "CESIUM_RTC",
 ];
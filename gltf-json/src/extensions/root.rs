@@ -7,6 +7,52 @@ pub struct Root {
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(default, rename = "KHR_lights_punctual", skip_serializing_if = "Option::is_none")]
     pub khr_lights_punctual: Option<KhrLightsPunctual>,
+
+    #[cfg(feature = "KHR_materials_variants")]
+    #[serde(default, rename = "KHR_materials_variants", skip_serializing_if = "Option::is_none")]
+    pub khr_materials_variants: Option<KhrMaterialsVariants>,
+
+    #[cfg(feature = "EXT_lights_ies")]
+    #[serde(default, rename = "EXT_lights_ies", skip_serializing_if = "Option::is_none")]
+    pub ext_lights_ies: Option<ExtLightsIes>,
+
+    #[cfg(feature = "KHR_audio")]
+    #[serde(default, rename = "KHR_audio", skip_serializing_if = "Option::is_none")]
+    pub khr_audio: Option<KhrAudio>,
+
+    #[cfg(feature = "KHR_interactivity")]
+    #[serde(default, rename = "KHR_interactivity", skip_serializing_if = "Option::is_none")]
+    pub khr_interactivity: Option<khr_interactivity::KhrInteractivity>,
+
+    #[cfg(feature = "KHR_implicit_shapes")]
+    #[serde(default, rename = "KHR_implicit_shapes", skip_serializing_if = "Option::is_none")]
+    pub khr_implicit_shapes: Option<KhrImplicitShapes>,
+
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[serde(default, rename = "KHR_physics_rigid_bodies", skip_serializing_if = "Option::is_none")]
+    pub khr_physics_rigid_bodies: Option<KhrPhysicsRigidBodies>,
+
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[serde(default, rename = "EXT_structural_metadata", skip_serializing_if = "Option::is_none")]
+    pub ext_structural_metadata: Option<ext_structural_metadata::ExtStructuralMetadata>,
+
+    #[cfg(feature = "VRM")]
+    #[serde(default, rename = "VRMC_vrm", skip_serializing_if = "Option::is_none")]
+    pub vrmc_vrm: Option<vrmc_vrm::VrmcVrm>,
+
+    #[cfg(feature = "VRM")]
+    #[serde(default, rename = "VRMC_springBone", skip_serializing_if = "Option::is_none")]
+    pub vrmc_spring_bone: Option<vrmc_spring_bone::VrmcSpringBone>,
+
+    #[cfg(feature = "CESIUM_RTC")]
+    #[serde(default, rename = "CESIUM_RTC", skip_serializing_if = "Option::is_none")]
+    pub cesium_rtc: Option<CesiumRtc>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -32,3 +78,2836 @@ impl crate::root::Get<crate::extensions::scene::khr_lights_punctual::Light> for
         }
     }
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrMaterialsVariants {
+    /// Material variants of the asset.
+    pub variants: Vec<crate::extensions::mesh::khr_materials_variants::Variant>,
+}
+
+#[cfg(feature = "EXT_lights_ies")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct ExtLightsIes {
+    /// IES light profiles of the asset.
+    pub lights: Vec<crate::extensions::scene::ext_lights_ies::LightProfile>,
+}
+
+#[cfg(feature = "EXT_lights_ies")]
+impl crate::root::Get<crate::extensions::scene::ext_lights_ies::LightProfile> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::ext_lights_ies::LightProfile>)
+        -> Option<&crate::extensions::scene::ext_lights_ies::LightProfile>
+    {
+        self.extensions.as_ref()?.ext_lights_ies.as_ref()?.lights.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrAudio {
+    /// Audio data of the asset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub audio: Vec<crate::extensions::scene::khr_audio::Audio>,
+
+    /// Audio sources of the asset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sources: Vec<crate::extensions::scene::khr_audio::AudioSource>,
+
+    /// Audio emitters of the asset.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub emitters: Vec<crate::extensions::scene::khr_audio::Emitter>,
+}
+
+#[cfg(feature = "KHR_audio")]
+impl crate::root::Get<crate::extensions::scene::khr_audio::Audio> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_audio::Audio>)
+        -> Option<&crate::extensions::scene::khr_audio::Audio>
+    {
+        self.extensions.as_ref()?.khr_audio.as_ref()?.audio.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+impl crate::root::Get<crate::extensions::scene::khr_audio::AudioSource> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_audio::AudioSource>)
+        -> Option<&crate::extensions::scene::khr_audio::AudioSource>
+    {
+        self.extensions.as_ref()?.khr_audio.as_ref()?.sources.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+impl crate::root::Get<crate::extensions::scene::khr_audio::Emitter> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_audio::Emitter>)
+        -> Option<&crate::extensions::scene::khr_audio::Emitter>
+    {
+        self.extensions.as_ref()?.khr_audio.as_ref()?.emitters.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_implicit_shapes")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+pub struct KhrImplicitShapes {
+    /// Implicit shapes of the asset.
+    pub shapes: Vec<crate::extensions::scene::khr_implicit_shapes::Shape>,
+}
+
+#[cfg(feature = "KHR_implicit_shapes")]
+impl crate::root::Get<crate::extensions::scene::khr_implicit_shapes::Shape> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_implicit_shapes::Shape>)
+        -> Option<&crate::extensions::scene::khr_implicit_shapes::Shape>
+    {
+        self.extensions.as_ref()?.khr_implicit_shapes.as_ref()?.shapes.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+#[serde(default, rename_all = "camelCase")]
+pub struct KhrPhysicsRigidBodies {
+    /// Physics materials of the asset.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub physics_materials: Vec<crate::extensions::scene::khr_physics_rigid_bodies::Material>,
+
+    /// Collision filters of the asset.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub collision_filters: Vec<crate::extensions::scene::khr_physics_rigid_bodies::CollisionFilter>,
+
+    /// Joint descriptions of the asset.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub physics_joints: Vec<crate::extensions::scene::khr_physics_rigid_bodies::Joint>,
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl crate::root::Get<crate::extensions::scene::khr_physics_rigid_bodies::Material> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_physics_rigid_bodies::Material>)
+        -> Option<&crate::extensions::scene::khr_physics_rigid_bodies::Material>
+    {
+        self.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?.physics_materials.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl crate::root::Get<crate::extensions::scene::khr_physics_rigid_bodies::CollisionFilter> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_physics_rigid_bodies::CollisionFilter>)
+        -> Option<&crate::extensions::scene::khr_physics_rigid_bodies::CollisionFilter>
+    {
+        self.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?.collision_filters.get(id.value())
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl crate::root::Get<crate::extensions::scene::khr_physics_rigid_bodies::Joint> for crate::Root {
+    fn get(&self, id: crate::Index<crate::extensions::scene::khr_physics_rigid_bodies::Joint>)
+        -> Option<&crate::extensions::scene::khr_physics_rigid_bodies::Joint>
+    {
+        self.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?.physics_joints.get(id.value())
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+impl crate::root::Get<ext_structural_metadata::PropertyTexture> for crate::Root {
+    fn get(&self, id: crate::Index<ext_structural_metadata::PropertyTexture>)
+        -> Option<&ext_structural_metadata::PropertyTexture>
+    {
+        self.extensions.as_ref()?.ext_structural_metadata.as_ref()?.property_textures.get(id.value())
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+impl crate::root::Get<ext_structural_metadata::PropertyAttribute> for crate::Root {
+    fn get(&self, id: crate::Index<ext_structural_metadata::PropertyAttribute>)
+        -> Option<&ext_structural_metadata::PropertyAttribute>
+    {
+        self.extensions.as_ref()?.ext_structural_metadata.as_ref()?.property_attributes.get(id.value())
+    }
+}
+
+/// The relative-to-center origin of the asset.
+#[cfg(feature = "CESIUM_RTC")]
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct CesiumRtc {
+    /// The center of the asset in the target coordinate system, such as
+    /// Earth-centered, Earth-fixed coordinates, which all node positions are
+    /// relative to.
+    pub center: [f64; 3],
+}
+
+#[cfg(feature = "CESIUM_RTC")]
+impl crate::validation::Validate for CesiumRtc {}
+
+#[cfg(feature = "VRM")]
+impl crate::root::Get<vrmc_spring_bone::Collider> for crate::Root {
+    fn get(&self, id: crate::Index<vrmc_spring_bone::Collider>)
+        -> Option<&vrmc_spring_bone::Collider>
+    {
+        self.extensions.as_ref()?.vrmc_spring_bone.as_ref()?.colliders.get(id.value())
+    }
+}
+
+#[cfg(feature = "VRM")]
+impl crate::root::Get<vrmc_spring_bone::ColliderGroup> for crate::Root {
+    fn get(&self, id: crate::Index<vrmc_spring_bone::ColliderGroup>)
+        -> Option<&vrmc_spring_bone::ColliderGroup>
+    {
+        self.extensions.as_ref()?.vrmc_spring_bone.as_ref()?.collider_groups.get(id.value())
+    }
+}
+
+#[cfg(feature = "EXT_structural_metadata")]
+pub mod ext_structural_metadata {
+    use crate::{buffer, texture, Extras, Index, Path, Root, Value};
+    use crate::validation::{Checked, Error, Validate};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::fmt;
+
+    /// All valid property types.
+    pub const VALID_TYPES: &[&str] = &[
+        "SCALAR",
+        "VEC2",
+        "VEC3",
+        "VEC4",
+        "MAT2",
+        "MAT3",
+        "MAT4",
+        "STRING",
+        "BOOLEAN",
+        "ENUM",
+    ];
+
+    /// All valid property component types.
+    pub const VALID_COMPONENT_TYPES: &[&str] = &[
+        "INT8",
+        "UINT8",
+        "INT16",
+        "UINT16",
+        "INT32",
+        "UINT32",
+        "INT64",
+        "UINT64",
+        "FLOAT32",
+        "FLOAT64",
+    ];
+
+    /// Structured metadata of the asset.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct ExtStructuralMetadata {
+        /// The schema embedded in the asset.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub schema: Option<Schema>,
+
+        /// The URI of an external schema. Use this instead of `schema`.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub schema_uri: Option<String>,
+
+        /// Property tables storing metadata per feature.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub property_tables: Vec<PropertyTable>,
+
+        /// Property textures storing metadata per texel.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub property_textures: Vec<PropertyTexture>,
+
+        /// Property attributes storing metadata per vertex.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub property_attributes: Vec<PropertyAttribute>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for ExtStructuralMetadata {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.schema.is_some() == self.schema_uri.is_some() {
+                report(&|| path().field("schema"), Error::Missing);
+            }
+
+            // Classes of an external schema cannot be checked.
+            if let Some(schema) = self.schema.as_ref() {
+                let check = |class: &str, properties: Vec<&String>, path: &dyn Fn() -> Path, report: &mut R| {
+                    match schema.classes.get(class) {
+                        Some(class) => {
+                            for property in properties {
+                                if !class.properties.contains_key(property) {
+                                    report(&|| path().field("properties").key(property), Error::Invalid);
+                                }
+                            }
+                        }
+                        None => report(&|| path().field("class"), Error::Invalid),
+                    }
+                };
+                for (i, table) in self.property_tables.iter().enumerate() {
+                    let path = || path().field("propertyTables").index(i);
+                    check(&table.class, table.properties.keys().collect(), &path, report);
+                }
+                for (i, texture) in self.property_textures.iter().enumerate() {
+                    let path = || path().field("propertyTextures").index(i);
+                    check(&texture.class, texture.properties.keys().collect(), &path, report);
+                }
+                for (i, attribute) in self.property_attributes.iter().enumerate() {
+                    let path = || path().field("propertyAttributes").index(i);
+                    check(&attribute.class, attribute.properties.keys().collect(), &path, report);
+                }
+            }
+
+            self.schema.validate(root, || path().field("schema"), report);
+            self.property_tables.validate(root, || path().field("propertyTables"), report);
+            self.property_textures.validate(root, || path().field("propertyTextures"), report);
+            self.property_attributes.validate(root, || path().field("propertyAttributes"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Describes the classes and enums of the metadata.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Schema {
+        /// The unique identifier of the schema.
+        pub id: String,
+
+        /// The name of the schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The description of the schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The application specific version of the schema.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub version: Option<String>,
+
+        /// The classes of the schema, keyed by identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub classes: HashMap<String, Class>,
+
+        /// The enums of the schema, keyed by identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub enums: HashMap<String, Enum>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Schema {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            for (class_id, class) in &self.classes {
+                for (id, property) in &class.properties {
+                    let path = || path().field("classes").key(class_id).field("properties").key(id);
+                    match property.type_ {
+                        Checked::Valid(Type::Enum) => match property.enum_type.as_ref() {
+                            Some(enum_type) if self.enums.contains_key(enum_type) => {}
+                            _ => report(&|| path().field("enumType"), Error::Invalid),
+                        },
+                        Checked::Valid(Type::String) | Checked::Valid(Type::Boolean) => {}
+                        _ => {
+                            if property.component_type.is_none() {
+                                report(&|| path().field("componentType"), Error::Missing);
+                            }
+                        }
+                    }
+                    property.validate(root, path, report);
+                }
+            }
+
+            for (id, enum_) in &self.enums {
+                enum_.validate(root, || path().field("enums").key(id), report);
+            }
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A class describing the properties of entities.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(default)]
+    pub struct Class {
+        /// The name of the class.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The description of the class.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The properties of the class, keyed by identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", skip_serializing_if = "HashMap::is_empty")]
+        pub properties: HashMap<String, ClassProperty>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A property of a class.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ClassProperty {
+        /// The name of the property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The description of the property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The element type.
+        #[serde(rename = "type")]
+        pub type_: Checked<Type>,
+
+        /// The component type of numeric elements.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub component_type: Option<Checked<ComponentType>>,
+
+        /// The identifier of the enum of enum elements.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub enum_type: Option<String>,
+
+        /// Whether the property is an array of elements.
+        #[serde(default)]
+        pub array: bool,
+
+        /// The number of elements of fixed-length arrays.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub count: Option<u32>,
+
+        /// Whether integer values are normalized to `[0, 1]` or `[-1, 1]`.
+        #[serde(default)]
+        pub normalized: bool,
+
+        /// An offset added to the values after normalization.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// A factor applied to the values after normalization.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// The maximum value after transformation.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// The minimum value after transformation.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Whether every entity must provide a value for the property.
+        #[serde(default)]
+        pub required: bool,
+
+        /// The raw value denoting that no value is available.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub no_data: Option<Value>,
+
+        /// The value to use in place of missing values.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub default: Option<Value>,
+
+        /// An identifier describing how the property should be interpreted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub semantic: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A set of named integer values.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Enum {
+        /// The name of the enum.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The description of the enum.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The integer type used to store the values.
+        #[serde(default = "enum_value_type_default")]
+        pub value_type: Checked<ComponentType>,
+
+        /// The values of the enum.
+        pub values: Vec<EnumValue>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn enum_value_type_default() -> Checked<ComponentType> {
+        Checked::Valid(ComponentType::U16)
+    }
+
+    /// A named value of an enum.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct EnumValue {
+        /// The name of the value.
+        pub name: String,
+
+        /// The description of the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// The integer value.
+        pub value: i64,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for EnumValue {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Stores the property values of a class for a number of features in
+    /// buffer views.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyTable {
+        /// The name of the property table.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The identifier of the class of the property values.
+        pub class: String,
+
+        /// The number of features, i.e. rows of the table.
+        pub count: u32,
+
+        /// The property columns, keyed by class property identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub properties: HashMap<String, PropertyTableProperty>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A column of a property table.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PropertyTableProperty {
+        /// The index of the buffer view containing the values.
+        pub values: Index<buffer::View>,
+
+        /// The index of the buffer view containing the element offsets of
+        /// variable-length arrays.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub array_offsets: Option<Index<buffer::View>>,
+
+        /// The index of the buffer view containing the byte offsets of
+        /// strings.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub string_offsets: Option<Index<buffer::View>>,
+
+        /// The unsigned integer type of the array offsets.
+        #[serde(default = "offset_type_default")]
+        pub array_offset_type: Checked<ComponentType>,
+
+        /// The unsigned integer type of the string offsets.
+        #[serde(default = "offset_type_default")]
+        pub string_offset_type: Checked<ComponentType>,
+
+        /// Overrides the offset of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// Overrides the scale of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// Overrides the maximum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// Overrides the minimum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn offset_type_default() -> Checked<ComponentType> {
+        Checked::Valid(ComponentType::U32)
+    }
+
+    impl Validate for PropertyTableProperty {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            use self::ComponentType::*;
+            for (offset_type, field) in [
+                (self.array_offset_type, "arrayOffsetType"),
+                (self.string_offset_type, "stringOffsetType"),
+            ] {
+                if let Checked::Valid(I8 | I16 | I32 | I64 | F32 | F64) = offset_type {
+                    report(&|| path().field(field), Error::Invalid);
+                }
+            }
+
+            self.values.validate(root, || path().field("values"), report);
+            self.array_offsets.validate(root, || path().field("arrayOffsets"), report);
+            self.string_offsets.validate(root, || path().field("stringOffsets"), report);
+            self.array_offset_type.validate(root, || path().field("arrayOffsetType"), report);
+            self.string_offset_type.validate(root, || path().field("stringOffsetType"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Stores the property values of a class in the channels of textures.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyTexture {
+        /// The name of the property texture.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The identifier of the class of the property values.
+        pub class: String,
+
+        /// The property textures, keyed by class property identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub properties: HashMap<String, PropertyTextureProperty>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The texture channels storing a property of a property texture.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PropertyTextureProperty {
+        /// The index of the texture.
+        pub index: Index<texture::Texture>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        #[serde(default)]
+        pub tex_coord: u32,
+
+        /// The texture channels storing the value, in little-endian order.
+        #[serde(default = "channels_default")]
+        pub channels: Vec<u32>,
+
+        /// Overrides the offset of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// Overrides the scale of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// Overrides the maximum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// Overrides the minimum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn channels_default() -> Vec<u32> {
+        vec![0]
+    }
+
+    /// Stores the property values of a class in vertex attributes.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyAttribute {
+        /// The name of the property attribute.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub name: Option<String>,
+
+        /// The identifier of the class of the property values.
+        pub class: String,
+
+        /// The vertex attributes, keyed by class property identifier.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub properties: HashMap<String, PropertyAttributeProperty>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The vertex attribute storing a property of a property attribute.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct PropertyAttributeProperty {
+        /// The name of the vertex attribute, for example `_TEMPERATURE`.
+        pub attribute: String,
+
+        /// Overrides the offset of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset: Option<Value>,
+
+        /// Overrides the scale of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub scale: Option<Value>,
+
+        /// Overrides the maximum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max: Option<Value>,
+
+        /// Overrides the minimum of the class property.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub min: Option<Value>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The element type of a class property.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Type {
+        /// A single numeric component.
+        Scalar = 1,
+
+        /// Two numeric components.
+        Vec2,
+
+        /// Three numeric components.
+        Vec3,
+
+        /// Four numeric components.
+        Vec4,
+
+        /// A 2x2 matrix of numeric components in column-major order.
+        Mat2,
+
+        /// A 3x3 matrix of numeric components in column-major order.
+        Mat3,
+
+        /// A 4x4 matrix of numeric components in column-major order.
+        Mat4,
+
+        /// A UTF-8 string.
+        String,
+
+        /// A boolean stored as a single bit.
+        Boolean,
+
+        /// A value of an enum.
+        Enum,
+    }
+
+    impl Type {
+        /// Returns the number of components of numeric element types, or 1 for
+        /// other types.
+        pub fn multiplicity(&self) -> usize {
+            match *self {
+                Type::Vec2 => 2,
+                Type::Vec3 => 3,
+                Type::Vec4 | Type::Mat2 => 4,
+                Type::Mat3 => 9,
+                Type::Mat4 => 16,
+                _ => 1,
+            }
+        }
+    }
+
+    /// The component type of numeric class properties.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum ComponentType {
+        /// Corresponds to `INT8`.
+        I8 = 1,
+
+        /// Corresponds to `UINT8`.
+        U8,
+
+        /// Corresponds to `INT16`.
+        I16,
+
+        /// Corresponds to `UINT16`.
+        U16,
+
+        /// Corresponds to `INT32`.
+        I32,
+
+        /// Corresponds to `UINT32`.
+        U32,
+
+        /// Corresponds to `INT64`.
+        I64,
+
+        /// Corresponds to `UINT64`.
+        U64,
+
+        /// Corresponds to `FLOAT32`.
+        F32,
+
+        /// Corresponds to `FLOAT64`.
+        F64,
+    }
+
+    impl ComponentType {
+        /// Returns the number of bytes this value represents.
+        pub fn size(&self) -> usize {
+            use self::ComponentType::*;
+            match *self {
+                I8 | U8 => 1,
+                I16 | U16 => 2,
+                I32 | U32 | F32 => 4,
+                I64 | U64 | F64 => 8,
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Type> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Type>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Type::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "SCALAR" => Valid(Scalar),
+                        "VEC2" => Valid(Vec2),
+                        "VEC3" => Valid(Vec3),
+                        "VEC4" => Valid(Vec4),
+                        "MAT2" => Valid(Mat2),
+                        "MAT3" => Valid(Mat3),
+                        "MAT4" => Valid(Mat4),
+                        "STRING" => Valid(String),
+                        "BOOLEAN" => Valid(Boolean),
+                        "ENUM" => Valid(Enum),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                Type::Scalar => "SCALAR",
+                Type::Vec2 => "VEC2",
+                Type::Vec3 => "VEC3",
+                Type::Vec4 => "VEC4",
+                Type::Mat2 => "MAT2",
+                Type::Mat3 => "MAT3",
+                Type::Mat4 => "MAT4",
+                Type::String => "STRING",
+                Type::Boolean => "BOOLEAN",
+                Type::Enum => "ENUM",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<ComponentType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<ComponentType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_COMPONENT_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::ComponentType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "INT8" => Valid(I8),
+                        "UINT8" => Valid(U8),
+                        "INT16" => Valid(I16),
+                        "UINT16" => Valid(U16),
+                        "INT32" => Valid(I32),
+                        "UINT32" => Valid(U32),
+                        "INT64" => Valid(I64),
+                        "UINT64" => Valid(U64),
+                        "FLOAT32" => Valid(F32),
+                        "FLOAT64" => Valid(F64),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for ComponentType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                ComponentType::I8 => "INT8",
+                ComponentType::U8 => "UINT8",
+                ComponentType::I16 => "INT16",
+                ComponentType::U16 => "UINT16",
+                ComponentType::I32 => "INT32",
+                ComponentType::U32 => "UINT32",
+                ComponentType::I64 => "INT64",
+                ComponentType::U64 => "UINT64",
+                ComponentType::F32 => "FLOAT32",
+                ComponentType::F64 => "FLOAT64",
+            })
+        }
+    }
+}
+
+#[cfg(feature = "KHR_interactivity")]
+pub mod khr_interactivity {
+    use crate::{Extras, Path, Root};
+    use crate::validation::{Error, Validate};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// Behavior graphs of the asset.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    pub struct KhrInteractivity {
+        /// The behavior graphs of the asset.
+        #[serde(default)]
+        pub graphs: Vec<Graph>,
+
+        /// The index of the behavior graph to execute when the asset is
+        /// loaded. Defaults to the first graph.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub graph: Option<u32>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for KhrInteractivity {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if let Some(graph) = self.graph {
+                if graph as usize >= self.graphs.len() {
+                    report(&|| path().field("graph"), Error::IndexOutOfBounds);
+                }
+            }
+
+            self.graphs.validate(root, || path().field("graphs"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A behavior graph.
+    ///
+    /// Indices within a graph refer to the arrays of the same graph.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(default)]
+    pub struct Graph {
+        /// The value types used by the graph.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub types: Vec<Type>,
+
+        /// The variables of the graph.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub variables: Vec<Variable>,
+
+        /// The custom events of the graph.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub events: Vec<Event>,
+
+        /// The operations that nodes of the graph may perform.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub declarations: Vec<Declaration>,
+
+        /// The nodes of the graph.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub nodes: Vec<Node>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Graph {
+        fn validate<P, R>(&self, _root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            let types = self.types.len();
+            let check = |index: u32, len: usize, path: &dyn Fn() -> Path, report: &mut R| {
+                if index as usize >= len {
+                    report(path, Error::IndexOutOfBounds);
+                }
+            };
+
+            for (i, variable) in self.variables.iter().enumerate() {
+                check(variable.type_, types, &|| path().field("variables").index(i).field("type"), report);
+            }
+            for (i, event) in self.events.iter().enumerate() {
+                for (name, value) in &event.values {
+                    let path = || path().field("events").index(i).field("values").key(name).field("type");
+                    check(value.type_, types, &path, report);
+                }
+            }
+            for (i, declaration) in self.declarations.iter().enumerate() {
+                let sockets = declaration.input_value_sockets.iter()
+                    .map(|(name, socket)| ("inputValueSockets", name, socket))
+                    .chain(declaration.output_value_sockets.iter()
+                        .map(|(name, socket)| ("outputValueSockets", name, socket)));
+                for (field, name, socket) in sockets {
+                    let path = || path().field("declarations").index(i).field(field).key(name).field("type");
+                    check(socket.type_, types, &path, report);
+                }
+            }
+            for (i, node) in self.nodes.iter().enumerate() {
+                let node_path = || path().field("nodes").index(i);
+                check(node.declaration, self.declarations.len(), &|| node_path().field("declaration"), report);
+                for (name, value) in &node.values {
+                    let value_path = || node_path().field("values").key(name);
+                    match (value.value.is_some(), value.node) {
+                        (true, None) => {
+                            if let Some(type_) = value.type_ {
+                                check(type_, types, &|| value_path().field("type"), report);
+                            } else {
+                                report(&|| value_path().field("type"), Error::Missing);
+                            }
+                        }
+                        (false, Some(node)) => {
+                            check(node, self.nodes.len(), &|| value_path().field("node"), report);
+                            if value.socket.is_none() {
+                                report(&|| value_path().field("socket"), Error::Missing);
+                            }
+                        }
+                        _ => report(&|| value_path().field("value"), Error::Invalid),
+                    }
+                }
+                for (name, flow) in &node.flows {
+                    check(flow.node, self.nodes.len(), &|| node_path().field("flows").key(name).field("node"), report);
+                }
+            }
+        }
+    }
+
+    /// A literal component of a value.
+    #[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+    #[serde(untagged)]
+    pub enum Literal {
+        /// A boolean component.
+        Bool(bool),
+
+        /// A numeric component, used for both integer and floating-point types.
+        Number(f64),
+    }
+
+    /// A value type, identified by its signature.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Type {
+        /// The type signature, for example `float3` or `bool`.
+        pub signature: String,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A variable of a behavior graph.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Variable {
+        /// The index of the variable type.
+        #[serde(rename = "type")]
+        pub type_: u32,
+
+        /// The initial value of the variable.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub value: Option<Vec<Literal>>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A custom event of a behavior graph.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Event {
+        /// The external identifier of the event.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub id: Option<String>,
+
+        /// The values carried by the event.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub values: HashMap<String, TypedValue>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A value of a given type, with an optional initial value.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct TypedValue {
+        /// The index of the value type.
+        #[serde(rename = "type")]
+        pub type_: u32,
+
+        /// The initial value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub value: Option<Vec<Literal>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// An operation that nodes may perform, for example `math/add`.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Declaration {
+        /// The operation identifier.
+        pub op: String,
+
+        /// The extension defining the operation, if any.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub extension: Option<String>,
+
+        /// The input value sockets of operations defined by extensions.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub input_value_sockets: HashMap<String, TypedValue>,
+
+        /// The output value sockets of operations defined by extensions.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub output_value_sockets: HashMap<String, TypedValue>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A node of a behavior graph.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Node {
+        /// The index of the declaration of the operation performed by the node.
+        pub declaration: u32,
+
+        /// The input values of the node.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub values: HashMap<String, ValueInput>,
+
+        /// The output flows of the node.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub flows: HashMap<String, Flow>,
+
+        /// The configuration of the node.
+        #[serde(serialize_with = "crate::serialize::sorted_map", default, skip_serializing_if = "HashMap::is_empty")]
+        pub configuration: HashMap<String, Configuration>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// An input value of a node, either a literal or the output of another
+    /// node.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct ValueInput {
+        /// The literal value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub value: Option<Vec<Literal>>,
+
+        /// The index of the type of the literal value.
+        #[serde(rename = "type")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub type_: Option<u32>,
+
+        /// The index of the node providing the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub node: Option<u32>,
+
+        /// The output value socket of the node providing the value.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub socket: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// An output flow of a node, activating an input flow socket of another
+    /// node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct Flow {
+        /// The index of the node to activate.
+        pub node: u32,
+
+        /// The input flow socket of the node to activate.
+        #[serde(default = "flow_socket_default")]
+        pub socket: String,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn flow_socket_default() -> String {
+        "in".to_string()
+    }
+
+    /// A configuration value of a node.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct Configuration {
+        /// The configuration value.
+        pub value: Vec<Literal>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Literal {}
+}
+
+#[cfg(feature = "VRM")]
+pub mod vrmc_vrm {
+    use crate::{image, material, Extras, Index, Path, Root};
+    use crate::validation::{Checked, Error, Validate};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use std::fmt;
+
+    /// All valid humanoid bone names.
+    pub const VALID_HUMAN_BONES: &[&str] = &[
+        "hips",
+        "spine",
+        "chest",
+        "upperChest",
+        "neck",
+        "head",
+        "leftEye",
+        "rightEye",
+        "jaw",
+        "leftUpperLeg",
+        "leftLowerLeg",
+        "leftFoot",
+        "leftToes",
+        "rightUpperLeg",
+        "rightLowerLeg",
+        "rightFoot",
+        "rightToes",
+        "leftShoulder",
+        "leftUpperArm",
+        "leftLowerArm",
+        "leftHand",
+        "rightShoulder",
+        "rightUpperArm",
+        "rightLowerArm",
+        "rightHand",
+        "leftThumbMetacarpal",
+        "leftThumbProximal",
+        "leftThumbDistal",
+        "leftIndexProximal",
+        "leftIndexIntermediate",
+        "leftIndexDistal",
+        "leftMiddleProximal",
+        "leftMiddleIntermediate",
+        "leftMiddleDistal",
+        "leftRingProximal",
+        "leftRingIntermediate",
+        "leftRingDistal",
+        "leftLittleProximal",
+        "leftLittleIntermediate",
+        "leftLittleDistal",
+        "rightThumbMetacarpal",
+        "rightThumbProximal",
+        "rightThumbDistal",
+        "rightIndexProximal",
+        "rightIndexIntermediate",
+        "rightIndexDistal",
+        "rightMiddleProximal",
+        "rightMiddleIntermediate",
+        "rightMiddleDistal",
+        "rightRingProximal",
+        "rightRingIntermediate",
+        "rightRingDistal",
+        "rightLittleProximal",
+        "rightLittleIntermediate",
+        "rightLittleDistal",
+    ];
+
+    /// The humanoid bones every VRM model must map.
+    pub const REQUIRED_HUMAN_BONES: &[&str] = &[
+        "hips",
+        "spine",
+        "head",
+        "leftUpperLeg",
+        "leftLowerLeg",
+        "leftFoot",
+        "rightUpperLeg",
+        "rightLowerLeg",
+        "rightFoot",
+        "leftUpperArm",
+        "leftLowerArm",
+        "leftHand",
+        "rightUpperArm",
+        "rightLowerArm",
+        "rightHand",
+    ];
+
+    /// All valid preset expression names.
+    pub const VALID_EXPRESSION_PRESETS: &[&str] = &[
+        "happy",
+        "angry",
+        "sad",
+        "relaxed",
+        "surprised",
+        "aa",
+        "ih",
+        "ou",
+        "ee",
+        "oh",
+        "blink",
+        "blinkLeft",
+        "blinkRight",
+        "lookUp",
+        "lookDown",
+        "lookLeft",
+        "lookRight",
+        "neutral",
+    ];
+
+    /// All valid avatar permissions.
+    pub const VALID_AVATAR_PERMISSIONS: &[&str] = &[
+        "onlyAuthor",
+        "onlySeparatelyLicensedPerson",
+        "everyone",
+    ];
+
+    /// All valid commercial usages.
+    pub const VALID_COMMERCIAL_USAGES: &[&str] = &[
+        "personalNonProfit",
+        "personalProfit",
+        "corporation",
+    ];
+
+    /// All valid credit notations.
+    pub const VALID_CREDIT_NOTATIONS: &[&str] = &[
+        "required",
+        "unnecessary",
+    ];
+
+    /// All valid modification permissions.
+    pub const VALID_MODIFICATIONS: &[&str] = &[
+        "prohibited",
+        "allowModification",
+        "allowModificationRedistribution",
+    ];
+
+    /// All valid first-person mesh annotation types.
+    pub const VALID_FIRST_PERSON_TYPES: &[&str] = &[
+        "auto",
+        "both",
+        "thirdPersonOnly",
+        "firstPersonOnly",
+    ];
+
+    /// All valid look-at types.
+    pub const VALID_LOOK_AT_TYPES: &[&str] = &[
+        "bone",
+        "expression",
+    ];
+
+    /// All valid material color bind types.
+    pub const VALID_MATERIAL_COLOR_TYPES: &[&str] = &[
+        "color",
+        "emissionColor",
+        "shadeColor",
+        "matcapColor",
+        "rimColor",
+        "outlineColor",
+    ];
+
+    /// All valid expression override types.
+    pub const VALID_EXPRESSION_OVERRIDES: &[&str] = &[
+        "none",
+        "block",
+        "blend",
+    ];
+
+    /// The humanoid avatar description of the asset.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct VrmcVrm {
+        /// The version of the VRM specification the asset conforms to.
+        pub spec_version: String,
+
+        /// The license and authorship information of the avatar.
+        pub meta: Meta,
+
+        /// The mapping of humanoid bones to nodes.
+        pub humanoid: Humanoid,
+
+        /// The visibility of meshes in first-person views.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub first_person: Option<FirstPerson>,
+
+        /// How the eyes of the avatar are controlled.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub look_at: Option<LookAt>,
+
+        /// Facial expressions of the avatar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub expressions: Option<Expressions>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The license and authorship information of an avatar.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Meta {
+        /// The name of the avatar.
+        pub name: String,
+
+        /// The version of the avatar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub version: Option<String>,
+
+        /// The authors of the avatar.
+        pub authors: Vec<String>,
+
+        /// The copyright information of the avatar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub copyright_information: Option<String>,
+
+        /// Contact information for the authors.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub contact_information: Option<String>,
+
+        /// References to the works the avatar is based on.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub references: Vec<String>,
+
+        /// The licenses of third-party works used by the avatar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub third_party_licenses: Option<String>,
+
+        /// The index of the thumbnail image of the avatar.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub thumbnail_image: Option<Index<image::Image>>,
+
+        /// The URL of the license document of the avatar.
+        pub license_url: String,
+
+        /// Who may perform as the avatar.
+        #[serde(default = "meta_avatar_permission_default")]
+        pub avatar_permission: Checked<AvatarPermission>,
+
+        /// Whether the avatar may be used in excessively violent content.
+        #[serde(default)]
+        pub allow_excessively_violent_usage: bool,
+
+        /// Whether the avatar may be used in excessively sexual content.
+        #[serde(default)]
+        pub allow_excessively_sexual_usage: bool,
+
+        /// Who may use the avatar commercially.
+        #[serde(default = "meta_commercial_usage_default")]
+        pub commercial_usage: Checked<CommercialUsage>,
+
+        /// Whether the avatar may be used for political or religious
+        /// purposes.
+        #[serde(default)]
+        pub allow_political_or_religious_usage: bool,
+
+        /// Whether the avatar may be used for antisocial or hateful purposes.
+        #[serde(default)]
+        pub allow_antisocial_or_hate_usage: bool,
+
+        /// Whether the authors must be credited.
+        #[serde(default = "meta_credit_notation_default")]
+        pub credit_notation: Checked<CreditNotation>,
+
+        /// Whether the avatar may be redistributed.
+        #[serde(default)]
+        pub allow_redistribution: bool,
+
+        /// Whether and how the avatar may be modified.
+        #[serde(default = "meta_modification_default")]
+        pub modification: Checked<Modification>,
+
+        /// The URL of a license document overriding the above terms.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub other_license_url: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn meta_avatar_permission_default() -> Checked<AvatarPermission> {
+        Checked::Valid(AvatarPermission::OnlyAuthor)
+    }
+
+    fn meta_commercial_usage_default() -> Checked<CommercialUsage> {
+        Checked::Valid(CommercialUsage::PersonalNonProfit)
+    }
+
+    fn meta_credit_notation_default() -> Checked<CreditNotation> {
+        Checked::Valid(CreditNotation::Required)
+    }
+
+    fn meta_modification_default() -> Checked<Modification> {
+        Checked::Valid(Modification::Prohibited)
+    }
+
+    /// Specifies who may perform as an avatar.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum AvatarPermission {
+        /// Only the author.
+        OnlyAuthor = 1,
+
+        /// Only persons licensed separately by the author.
+        OnlySeparatelyLicensedPerson,
+
+        /// Anyone.
+        Everyone,
+    }
+
+    /// Specifies who may use an avatar commercially.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum CommercialUsage {
+        /// Individuals, for non-profit purposes only.
+        PersonalNonProfit = 1,
+
+        /// Individuals, including for profit.
+        PersonalProfit,
+
+        /// Individuals and corporations.
+        Corporation,
+    }
+
+    /// Specifies whether the authors of an avatar must be credited.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum CreditNotation {
+        /// The authors must be credited.
+        Required = 1,
+
+        /// Crediting the authors is optional.
+        Unnecessary,
+    }
+
+    /// Specifies whether and how an avatar may be modified.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Modification {
+        /// The avatar may not be modified.
+        Prohibited = 1,
+
+        /// The avatar may be modified but not redistributed.
+        AllowModification,
+
+        /// The avatar may be modified and redistributed.
+        AllowModificationRedistribution,
+    }
+
+    /// The mapping of humanoid bones to nodes.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Humanoid {
+        /// The node of each mapped bone.
+        #[serde(serialize_with = "crate::serialize::sorted_map")]
+        pub human_bones: HashMap<Checked<HumanBoneName>, HumanBone>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Humanoid {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            for name in REQUIRED_HUMAN_BONES {
+                let bone = HumanBoneName::checked(name);
+                if !self.human_bones.contains_key(&bone) {
+                    report(&|| path().field("humanBones").key(name), Error::Missing);
+                }
+            }
+
+            self.human_bones.validate(root, || path().field("humanBones"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A node mapped to a humanoid bone.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct HumanBone {
+        /// The index of the node.
+        pub node: Index<crate::Node>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Identifies a bone of the humanoid skeleton.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum HumanBoneName {
+        /// The hips bone.
+        Hips = 1,
+
+        /// The spine bone.
+        Spine,
+
+        /// The chest bone.
+        Chest,
+
+        /// The upper chest bone.
+        UpperChest,
+
+        /// The neck bone.
+        Neck,
+
+        /// The head bone.
+        Head,
+
+        /// The left eye bone.
+        LeftEye,
+
+        /// The right eye bone.
+        RightEye,
+
+        /// The jaw bone.
+        Jaw,
+
+        /// The left upper leg bone.
+        LeftUpperLeg,
+
+        /// The left lower leg bone.
+        LeftLowerLeg,
+
+        /// The left foot bone.
+        LeftFoot,
+
+        /// The left toes bone.
+        LeftToes,
+
+        /// The right upper leg bone.
+        RightUpperLeg,
+
+        /// The right lower leg bone.
+        RightLowerLeg,
+
+        /// The right foot bone.
+        RightFoot,
+
+        /// The right toes bone.
+        RightToes,
+
+        /// The left shoulder bone.
+        LeftShoulder,
+
+        /// The left upper arm bone.
+        LeftUpperArm,
+
+        /// The left lower arm bone.
+        LeftLowerArm,
+
+        /// The left hand bone.
+        LeftHand,
+
+        /// The right shoulder bone.
+        RightShoulder,
+
+        /// The right upper arm bone.
+        RightUpperArm,
+
+        /// The right lower arm bone.
+        RightLowerArm,
+
+        /// The right hand bone.
+        RightHand,
+
+        /// The left thumb metacarpal bone.
+        LeftThumbMetacarpal,
+
+        /// The left thumb proximal bone.
+        LeftThumbProximal,
+
+        /// The left thumb distal bone.
+        LeftThumbDistal,
+
+        /// The left index proximal bone.
+        LeftIndexProximal,
+
+        /// The left index intermediate bone.
+        LeftIndexIntermediate,
+
+        /// The left index distal bone.
+        LeftIndexDistal,
+
+        /// The left middle proximal bone.
+        LeftMiddleProximal,
+
+        /// The left middle intermediate bone.
+        LeftMiddleIntermediate,
+
+        /// The left middle distal bone.
+        LeftMiddleDistal,
+
+        /// The left ring proximal bone.
+        LeftRingProximal,
+
+        /// The left ring intermediate bone.
+        LeftRingIntermediate,
+
+        /// The left ring distal bone.
+        LeftRingDistal,
+
+        /// The left little proximal bone.
+        LeftLittleProximal,
+
+        /// The left little intermediate bone.
+        LeftLittleIntermediate,
+
+        /// The left little distal bone.
+        LeftLittleDistal,
+
+        /// The right thumb metacarpal bone.
+        RightThumbMetacarpal,
+
+        /// The right thumb proximal bone.
+        RightThumbProximal,
+
+        /// The right thumb distal bone.
+        RightThumbDistal,
+
+        /// The right index proximal bone.
+        RightIndexProximal,
+
+        /// The right index intermediate bone.
+        RightIndexIntermediate,
+
+        /// The right index distal bone.
+        RightIndexDistal,
+
+        /// The right middle proximal bone.
+        RightMiddleProximal,
+
+        /// The right middle intermediate bone.
+        RightMiddleIntermediate,
+
+        /// The right middle distal bone.
+        RightMiddleDistal,
+
+        /// The right ring proximal bone.
+        RightRingProximal,
+
+        /// The right ring intermediate bone.
+        RightRingIntermediate,
+
+        /// The right ring distal bone.
+        RightRingDistal,
+
+        /// The right little proximal bone.
+        RightLittleProximal,
+
+        /// The right little intermediate bone.
+        RightLittleIntermediate,
+
+        /// The right little distal bone.
+        RightLittleDistal,
+    }
+
+    impl HumanBoneName {
+        /// Parses a bone name, returning `Checked::Invalid` for unknown names.
+        pub fn checked(name: &str) -> Checked<Self> {
+            use self::HumanBoneName::*;
+            use crate::validation::Checked::*;
+            match name {
+                "hips" => Valid(Hips),
+                "spine" => Valid(Spine),
+                "chest" => Valid(Chest),
+                "upperChest" => Valid(UpperChest),
+                "neck" => Valid(Neck),
+                "head" => Valid(Head),
+                "leftEye" => Valid(LeftEye),
+                "rightEye" => Valid(RightEye),
+                "jaw" => Valid(Jaw),
+                "leftUpperLeg" => Valid(LeftUpperLeg),
+                "leftLowerLeg" => Valid(LeftLowerLeg),
+                "leftFoot" => Valid(LeftFoot),
+                "leftToes" => Valid(LeftToes),
+                "rightUpperLeg" => Valid(RightUpperLeg),
+                "rightLowerLeg" => Valid(RightLowerLeg),
+                "rightFoot" => Valid(RightFoot),
+                "rightToes" => Valid(RightToes),
+                "leftShoulder" => Valid(LeftShoulder),
+                "leftUpperArm" => Valid(LeftUpperArm),
+                "leftLowerArm" => Valid(LeftLowerArm),
+                "leftHand" => Valid(LeftHand),
+                "rightShoulder" => Valid(RightShoulder),
+                "rightUpperArm" => Valid(RightUpperArm),
+                "rightLowerArm" => Valid(RightLowerArm),
+                "rightHand" => Valid(RightHand),
+                "leftThumbMetacarpal" => Valid(LeftThumbMetacarpal),
+                "leftThumbProximal" => Valid(LeftThumbProximal),
+                "leftThumbDistal" => Valid(LeftThumbDistal),
+                "leftIndexProximal" => Valid(LeftIndexProximal),
+                "leftIndexIntermediate" => Valid(LeftIndexIntermediate),
+                "leftIndexDistal" => Valid(LeftIndexDistal),
+                "leftMiddleProximal" => Valid(LeftMiddleProximal),
+                "leftMiddleIntermediate" => Valid(LeftMiddleIntermediate),
+                "leftMiddleDistal" => Valid(LeftMiddleDistal),
+                "leftRingProximal" => Valid(LeftRingProximal),
+                "leftRingIntermediate" => Valid(LeftRingIntermediate),
+                "leftRingDistal" => Valid(LeftRingDistal),
+                "leftLittleProximal" => Valid(LeftLittleProximal),
+                "leftLittleIntermediate" => Valid(LeftLittleIntermediate),
+                "leftLittleDistal" => Valid(LeftLittleDistal),
+                "rightThumbMetacarpal" => Valid(RightThumbMetacarpal),
+                "rightThumbProximal" => Valid(RightThumbProximal),
+                "rightThumbDistal" => Valid(RightThumbDistal),
+                "rightIndexProximal" => Valid(RightIndexProximal),
+                "rightIndexIntermediate" => Valid(RightIndexIntermediate),
+                "rightIndexDistal" => Valid(RightIndexDistal),
+                "rightMiddleProximal" => Valid(RightMiddleProximal),
+                "rightMiddleIntermediate" => Valid(RightMiddleIntermediate),
+                "rightMiddleDistal" => Valid(RightMiddleDistal),
+                "rightRingProximal" => Valid(RightRingProximal),
+                "rightRingIntermediate" => Valid(RightRingIntermediate),
+                "rightRingDistal" => Valid(RightRingDistal),
+                "rightLittleProximal" => Valid(RightLittleProximal),
+                "rightLittleIntermediate" => Valid(RightLittleIntermediate),
+                "rightLittleDistal" => Valid(RightLittleDistal),
+                _ => Invalid,
+            }
+        }
+
+        /// Returns the name of the bone as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                HumanBoneName::Hips => "hips",
+                HumanBoneName::Spine => "spine",
+                HumanBoneName::Chest => "chest",
+                HumanBoneName::UpperChest => "upperChest",
+                HumanBoneName::Neck => "neck",
+                HumanBoneName::Head => "head",
+                HumanBoneName::LeftEye => "leftEye",
+                HumanBoneName::RightEye => "rightEye",
+                HumanBoneName::Jaw => "jaw",
+                HumanBoneName::LeftUpperLeg => "leftUpperLeg",
+                HumanBoneName::LeftLowerLeg => "leftLowerLeg",
+                HumanBoneName::LeftFoot => "leftFoot",
+                HumanBoneName::LeftToes => "leftToes",
+                HumanBoneName::RightUpperLeg => "rightUpperLeg",
+                HumanBoneName::RightLowerLeg => "rightLowerLeg",
+                HumanBoneName::RightFoot => "rightFoot",
+                HumanBoneName::RightToes => "rightToes",
+                HumanBoneName::LeftShoulder => "leftShoulder",
+                HumanBoneName::LeftUpperArm => "leftUpperArm",
+                HumanBoneName::LeftLowerArm => "leftLowerArm",
+                HumanBoneName::LeftHand => "leftHand",
+                HumanBoneName::RightShoulder => "rightShoulder",
+                HumanBoneName::RightUpperArm => "rightUpperArm",
+                HumanBoneName::RightLowerArm => "rightLowerArm",
+                HumanBoneName::RightHand => "rightHand",
+                HumanBoneName::LeftThumbMetacarpal => "leftThumbMetacarpal",
+                HumanBoneName::LeftThumbProximal => "leftThumbProximal",
+                HumanBoneName::LeftThumbDistal => "leftThumbDistal",
+                HumanBoneName::LeftIndexProximal => "leftIndexProximal",
+                HumanBoneName::LeftIndexIntermediate => "leftIndexIntermediate",
+                HumanBoneName::LeftIndexDistal => "leftIndexDistal",
+                HumanBoneName::LeftMiddleProximal => "leftMiddleProximal",
+                HumanBoneName::LeftMiddleIntermediate => "leftMiddleIntermediate",
+                HumanBoneName::LeftMiddleDistal => "leftMiddleDistal",
+                HumanBoneName::LeftRingProximal => "leftRingProximal",
+                HumanBoneName::LeftRingIntermediate => "leftRingIntermediate",
+                HumanBoneName::LeftRingDistal => "leftRingDistal",
+                HumanBoneName::LeftLittleProximal => "leftLittleProximal",
+                HumanBoneName::LeftLittleIntermediate => "leftLittleIntermediate",
+                HumanBoneName::LeftLittleDistal => "leftLittleDistal",
+                HumanBoneName::RightThumbMetacarpal => "rightThumbMetacarpal",
+                HumanBoneName::RightThumbProximal => "rightThumbProximal",
+                HumanBoneName::RightThumbDistal => "rightThumbDistal",
+                HumanBoneName::RightIndexProximal => "rightIndexProximal",
+                HumanBoneName::RightIndexIntermediate => "rightIndexIntermediate",
+                HumanBoneName::RightIndexDistal => "rightIndexDistal",
+                HumanBoneName::RightMiddleProximal => "rightMiddleProximal",
+                HumanBoneName::RightMiddleIntermediate => "rightMiddleIntermediate",
+                HumanBoneName::RightMiddleDistal => "rightMiddleDistal",
+                HumanBoneName::RightRingProximal => "rightRingProximal",
+                HumanBoneName::RightRingIntermediate => "rightRingIntermediate",
+                HumanBoneName::RightRingDistal => "rightRingDistal",
+                HumanBoneName::RightLittleProximal => "rightLittleProximal",
+                HumanBoneName::RightLittleIntermediate => "rightLittleIntermediate",
+                HumanBoneName::RightLittleDistal => "rightLittleDistal",
+            }
+        }
+    }
+
+    impl fmt::Display for Checked<HumanBoneName> {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            match *self {
+                Checked::Valid(ref bone) => f.write_str(bone.as_str()),
+                Checked::Invalid => f.write_str("<invalid bone name>"),
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<HumanBoneName> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<HumanBoneName>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_HUMAN_BONES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    Ok(HumanBoneName::checked(value))
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for HumanBoneName {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    /// The visibility of meshes in first-person views.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct FirstPerson {
+        /// The visibility of individual meshes.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub mesh_annotations: Vec<MeshAnnotation>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The first-person visibility of the mesh of a node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct MeshAnnotation {
+        /// The index of the node whose mesh is annotated.
+        pub node: Index<crate::Node>,
+
+        /// In which views the mesh is visible.
+        #[serde(rename = "type")]
+        pub type_: Checked<FirstPersonType>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Specifies in which views a mesh is visible.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum FirstPersonType {
+        /// Parts of the mesh skinned to the head are hidden in first-person views.
+        Auto = 1,
+
+        /// Visible in all views.
+        Both,
+
+        /// Visible in third-person views only.
+        ThirdPersonOnly,
+
+        /// Visible in first-person views only.
+        FirstPersonOnly,
+    }
+
+    /// How the eyes of an avatar are controlled.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LookAt {
+        /// The position of the eyes relative to the head bone.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub offset_from_head_bone: Option<[f32; 3]>,
+
+        /// Whether the eyes are moved by bones or by expressions.
+        #[serde(rename = "type")]
+        pub type_: Checked<LookAtType>,
+
+        /// The mapping of inward horizontal gaze angles.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub range_map_horizontal_inner: Option<RangeMap>,
+
+        /// The mapping of outward horizontal gaze angles.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub range_map_horizontal_outer: Option<RangeMap>,
+
+        /// The mapping of downward gaze angles.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub range_map_vertical_down: Option<RangeMap>,
+
+        /// The mapping of upward gaze angles.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub range_map_vertical_up: Option<RangeMap>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Specifies how the eyes of an avatar are moved.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum LookAtType {
+        /// The eye bones are rotated.
+        Bone = 1,
+
+        /// The `look*` expressions are applied.
+        Expression,
+    }
+
+    /// Maps gaze angles to bone rotations or expression weights.
+    #[derive(Clone, Copy, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RangeMap {
+        /// The gaze angle in degrees at which the output reaches its scale.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub input_max_value: Option<f32>,
+
+        /// The bone angle in degrees or the expression weight at the maximum
+        /// input.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub output_scale: Option<f32>,
+    }
+
+    /// Facial expressions of an avatar.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(default)]
+    pub struct Expressions {
+        /// Expressions with a predefined meaning, keyed by preset name.
+        #[serde(serialize_with = "crate::serialize::sorted_map", skip_serializing_if = "HashMap::is_empty")]
+        pub preset: HashMap<String, Expression>,
+
+        /// Expressions defined by the author, keyed by name.
+        #[serde(serialize_with = "crate::serialize::sorted_map", skip_serializing_if = "HashMap::is_empty")]
+        pub custom: HashMap<String, Expression>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Expressions {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            for name in self.preset.keys() {
+                if !VALID_EXPRESSION_PRESETS.contains(&name.as_str()) {
+                    report(&|| path().field("preset").key(name), Error::Invalid);
+                }
+            }
+            for name in self.custom.keys() {
+                if VALID_EXPRESSION_PRESETS.contains(&name.as_str()) {
+                    report(&|| path().field("custom").key(name), Error::Invalid);
+                }
+            }
+
+            self.preset.validate(root, || path().field("preset"), report);
+            self.custom.validate(root, || path().field("custom"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A facial expression, combining morph target weights and material
+    /// property changes.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Expression {
+        /// The morph target weights applied by the expression.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub morph_target_binds: Vec<MorphTargetBind>,
+
+        /// The material colors applied by the expression.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub material_color_binds: Vec<MaterialColorBind>,
+
+        /// The texture transforms applied by the expression.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub texture_transform_binds: Vec<TextureTransformBind>,
+
+        /// Whether the weight of the expression is rounded to 0 or 1.
+        pub is_binary: bool,
+
+        /// How the expression affects blink expressions.
+        pub override_blink: Checked<ExpressionOverride>,
+
+        /// How the expression affects look-at expressions.
+        pub override_look_at: Checked<ExpressionOverride>,
+
+        /// How the expression affects mouth expressions.
+        pub override_mouth: Checked<ExpressionOverride>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Expression {
+        fn default() -> Self {
+            Expression {
+                morph_target_binds: Vec::new(),
+                material_color_binds: Vec::new(),
+                texture_transform_binds: Vec::new(),
+                is_binary: false,
+                override_blink: Checked::Valid(ExpressionOverride::None),
+                override_look_at: Checked::Valid(ExpressionOverride::None),
+                override_mouth: Checked::Valid(ExpressionOverride::None),
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// Sets the weight of a morph target of the mesh of a node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct MorphTargetBind {
+        /// The index of the node whose mesh is affected.
+        pub node: Index<crate::Node>,
+
+        /// The index of the morph target.
+        pub index: u32,
+
+        /// The weight of the morph target at full expression weight.
+        pub weight: f32,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Sets a color property of a material.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct MaterialColorBind {
+        /// The index of the material.
+        pub material: Index<material::Material>,
+
+        /// The color property that is set.
+        #[serde(rename = "type")]
+        pub type_: Checked<MaterialColorType>,
+
+        /// The color at full expression weight.
+        pub target_value: [f32; 4],
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Specifies the material color property set by an expression.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum MaterialColorType {
+        /// The base color factor.
+        Color = 1,
+
+        /// The emissive factor.
+        EmissionColor,
+
+        /// The MToon shade color factor.
+        ShadeColor,
+
+        /// The MToon matcap factor.
+        MatcapColor,
+
+        /// The MToon parametric rim color factor.
+        RimColor,
+
+        /// The MToon outline color factor.
+        OutlineColor,
+    }
+
+    /// Sets the texture transform of a material.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct TextureTransformBind {
+        /// The index of the material.
+        pub material: Index<material::Material>,
+
+        /// The texture coordinate scale at full expression weight.
+        #[serde(default = "texture_transform_bind_scale_default")]
+        pub scale: [f32; 2],
+
+        /// The texture coordinate offset at full expression weight.
+        #[serde(default)]
+        pub offset: [f32; 2],
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn texture_transform_bind_scale_default() -> [f32; 2] {
+        [1.0, 1.0]
+    }
+
+    /// Specifies how an expression affects other expressions.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum ExpressionOverride {
+        /// Other expressions are unaffected.
+        None = 1,
+
+        /// Other expressions are disabled while this one is active.
+        Block,
+
+        /// Other expressions are attenuated by the weight of this one.
+        Blend,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<AvatarPermission> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<AvatarPermission>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_AVATAR_PERMISSIONS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::AvatarPermission::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "onlyAuthor" => Valid(OnlyAuthor),
+                        "onlySeparatelyLicensedPerson" => Valid(OnlySeparatelyLicensedPerson),
+                        "everyone" => Valid(Everyone),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for AvatarPermission {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl AvatarPermission {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                AvatarPermission::OnlyAuthor => "onlyAuthor",
+                AvatarPermission::OnlySeparatelyLicensedPerson => "onlySeparatelyLicensedPerson",
+                AvatarPermission::Everyone => "everyone",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<CommercialUsage> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<CommercialUsage>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_COMMERCIAL_USAGES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::CommercialUsage::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "personalNonProfit" => Valid(PersonalNonProfit),
+                        "personalProfit" => Valid(PersonalProfit),
+                        "corporation" => Valid(Corporation),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for CommercialUsage {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl CommercialUsage {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                CommercialUsage::PersonalNonProfit => "personalNonProfit",
+                CommercialUsage::PersonalProfit => "personalProfit",
+                CommercialUsage::Corporation => "corporation",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<CreditNotation> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<CreditNotation>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_CREDIT_NOTATIONS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::CreditNotation::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "required" => Valid(Required),
+                        "unnecessary" => Valid(Unnecessary),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for CreditNotation {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl CreditNotation {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                CreditNotation::Required => "required",
+                CreditNotation::Unnecessary => "unnecessary",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Modification> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Modification>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MODIFICATIONS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Modification::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "prohibited" => Valid(Prohibited),
+                        "allowModification" => Valid(AllowModification),
+                        "allowModificationRedistribution" => Valid(AllowModificationRedistribution),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Modification {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl Modification {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                Modification::Prohibited => "prohibited",
+                Modification::AllowModification => "allowModification",
+                Modification::AllowModificationRedistribution => "allowModificationRedistribution",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<FirstPersonType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<FirstPersonType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_FIRST_PERSON_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::FirstPersonType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "auto" => Valid(Auto),
+                        "both" => Valid(Both),
+                        "thirdPersonOnly" => Valid(ThirdPersonOnly),
+                        "firstPersonOnly" => Valid(FirstPersonOnly),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for FirstPersonType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl FirstPersonType {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                FirstPersonType::Auto => "auto",
+                FirstPersonType::Both => "both",
+                FirstPersonType::ThirdPersonOnly => "thirdPersonOnly",
+                FirstPersonType::FirstPersonOnly => "firstPersonOnly",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<LookAtType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<LookAtType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_LOOK_AT_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::LookAtType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "bone" => Valid(Bone),
+                        "expression" => Valid(Expression),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for LookAtType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl LookAtType {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                LookAtType::Bone => "bone",
+                LookAtType::Expression => "expression",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<MaterialColorType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<MaterialColorType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_MATERIAL_COLOR_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::MaterialColorType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "color" => Valid(Color),
+                        "emissionColor" => Valid(EmissionColor),
+                        "shadeColor" => Valid(ShadeColor),
+                        "matcapColor" => Valid(MatcapColor),
+                        "rimColor" => Valid(RimColor),
+                        "outlineColor" => Valid(OutlineColor),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for MaterialColorType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl MaterialColorType {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                MaterialColorType::Color => "color",
+                MaterialColorType::EmissionColor => "emissionColor",
+                MaterialColorType::ShadeColor => "shadeColor",
+                MaterialColorType::MatcapColor => "matcapColor",
+                MaterialColorType::RimColor => "rimColor",
+                MaterialColorType::OutlineColor => "outlineColor",
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<ExpressionOverride> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<ExpressionOverride>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_EXPRESSION_OVERRIDES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::ExpressionOverride::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "none" => Valid(None),
+                        "block" => Valid(Block),
+                        "blend" => Valid(Blend),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for ExpressionOverride {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(self.as_str())
+        }
+    }
+
+    impl ExpressionOverride {
+        /// Returns the name of the value as it appears in the JSON.
+        pub fn as_str(&self) -> &'static str {
+            match *self {
+                ExpressionOverride::None => "none",
+                ExpressionOverride::Block => "block",
+                ExpressionOverride::Blend => "blend",
+            }
+        }
+    }
+}
+
+#[cfg(feature = "VRM")]
+pub mod vrmc_spring_bone {
+    use crate::{Extras, Index, Path, Root};
+    use crate::validation::{Error, Validate};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// The spring bone simulation of the asset.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct VrmcSpringBone {
+        /// The version of the specification the asset conforms to.
+        pub spec_version: String,
+
+        /// Shapes the spring bones collide with.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub colliders: Vec<Collider>,
+
+        /// Named sets of colliders.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub collider_groups: Vec<ColliderGroup>,
+
+        /// Chains of joints swaying under inertia and gravity.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub springs: Vec<Spring>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A collision shape attached to a node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct Collider {
+        /// The index of the node the shape is attached to.
+        pub node: Index<crate::Node>,
+
+        /// The shape of the collider.
+        pub shape: Shape,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The shape of a collider, of which exactly one must be defined.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Shape {
+        /// A sphere.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub sphere: Option<Sphere>,
+
+        /// A capsule.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub capsule: Option<Capsule>,
+    }
+
+    impl Validate for Shape {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.sphere.is_some() == self.capsule.is_some() {
+                report(&|| path().field("sphere"), Error::Missing);
+            }
+
+            self.sphere.validate(root, || path().field("sphere"), report);
+            self.capsule.validate(root, || path().field("capsule"), report);
+        }
+    }
+
+    /// A sphere collider.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default)]
+    pub struct Sphere {
+        /// The center of the sphere relative to the node.
+        pub offset: [f32; 3],
+
+        /// The radius of the sphere.
+        pub radius: f32,
+    }
+
+    /// A capsule collider.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default)]
+    pub struct Capsule {
+        /// The center of the first cap relative to the node.
+        pub offset: [f32; 3],
+
+        /// The radius of the capsule.
+        pub radius: f32,
+
+        /// The center of the second cap relative to the node.
+        pub tail: [f32; 3],
+    }
+
+    /// A named set of colliders.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct ColliderGroup {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The indices of the colliders of the group.
+        pub colliders: Vec<Index<Collider>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A chain of joints swaying under inertia and gravity.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Spring {
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The joints of the chain, from root to tip.
+        pub joints: Vec<Joint>,
+
+        /// The indices of the collider groups the joints collide with.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub collider_groups: Vec<Index<ColliderGroup>>,
+
+        /// The index of the node whose space the simulation runs in.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub center: Option<Index<crate::Node>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A joint of a spring.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Joint {
+        /// The index of the node moved by the joint.
+        pub node: Index<crate::Node>,
+
+        /// The radius of the joint for collision detection.
+        #[serde(default)]
+        pub hit_radius: f32,
+
+        /// The force returning the joint to its initial pose.
+        #[serde(default = "joint_stiffness_default")]
+        pub stiffness: f32,
+
+        /// The strength of gravity on the joint.
+        #[serde(default)]
+        pub gravity_power: f32,
+
+        /// The direction of gravity in world space.
+        #[serde(default = "joint_gravity_dir_default")]
+        pub gravity_dir: [f32; 3],
+
+        /// The damping of the motion of the joint.
+        #[serde(default = "joint_drag_force_default")]
+        pub drag_force: f32,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    fn joint_stiffness_default() -> f32 {
+        1.0
+    }
+
+    fn joint_gravity_dir_default() -> [f32; 3] {
+        [0.0, -1.0, 0.0]
+    }
+
+    fn joint_drag_force_default() -> f32 {
+        0.5
+    }
+}
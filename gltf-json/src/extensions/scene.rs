@@ -16,6 +16,1349 @@ pub struct Node {
     #[cfg(feature = "KHR_lights_punctual")]
     #[serde(default, rename = "KHR_lights_punctual", skip_serializing_if = "Option::is_none")]
     pub khr_lights_punctual: Option<khr_lights_punctual::KhrLightsPunctual>,
+
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[serde(default, rename = "EXT_mesh_gpu_instancing", skip_serializing_if = "Option::is_none")]
+    pub ext_mesh_gpu_instancing: Option<ext_mesh_gpu_instancing::ExtMeshGpuInstancing>,
+
+    #[cfg(feature = "EXT_lights_ies")]
+    #[serde(default, rename = "EXT_lights_ies", skip_serializing_if = "Option::is_none")]
+    pub ext_lights_ies: Option<ext_lights_ies::ExtLightsIes>,
+
+    #[cfg(feature = "MSFT_lod")]
+    #[serde(default, rename = "MSFT_lod", skip_serializing_if = "Option::is_none")]
+    pub msft_lod: Option<msft_lod::MsftLod>,
+
+    #[cfg(feature = "KHR_audio")]
+    #[serde(default, rename = "KHR_audio", skip_serializing_if = "Option::is_none")]
+    pub khr_audio: Option<khr_audio::NodeEmitter>,
+
+    #[cfg(feature = "KHR_node_visibility")]
+    #[serde(default, rename = "KHR_node_visibility", skip_serializing_if = "Option::is_none")]
+    pub khr_node_visibility: Option<khr_node_visibility::KhrNodeVisibility>,
+
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[serde(default, rename = "KHR_physics_rigid_bodies", skip_serializing_if = "Option::is_none")]
+    pub khr_physics_rigid_bodies: Option<khr_physics_rigid_bodies::KhrPhysicsRigidBodies>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(feature = "KHR_node_visibility")]
+pub mod khr_node_visibility {
+    use crate::Extras;
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Specifies whether a node and its descendants are visible.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct KhrNodeVisibility {
+        /// Whether the node and its descendants are visible.
+        #[serde(default = "visible_default")]
+        pub visible: bool,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for KhrNodeVisibility {
+        fn default() -> Self {
+            KhrNodeVisibility {
+                visible: true,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    fn visible_default() -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "KHR_implicit_shapes")]
+pub mod khr_implicit_shapes {
+    use crate::{Extras, Path, Root};
+    use crate::validation::{Checked, Error, Validate};
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid shape types.
+    pub const VALID_TYPES: &[&str] = &[
+        "sphere",
+        "box",
+        "capsule",
+        "cylinder",
+    ];
+
+    /// A parametric shape, typically used as the geometry of a physics collider.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Shape {
+        /// Specifies the shape type.
+        #[serde(rename = "type")]
+        pub type_: Checked<Type>,
+
+        /// Sphere parameters, used when the type is `sphere`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub sphere: Option<Sphere>,
+
+        /// Box parameters, used when the type is `box`.
+        #[serde(default, rename = "box", skip_serializing_if = "Option::is_none")]
+        pub box_: Option<Box>,
+
+        /// Capsule parameters, used when the type is `capsule`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub capsule: Option<Capsule>,
+
+        /// Cylinder parameters, used when the type is `cylinder`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub cylinder: Option<Cylinder>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Shape {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            let other = match self.type_ {
+                Checked::Valid(Type::Sphere) => {
+                    self.box_.is_some() || self.capsule.is_some() || self.cylinder.is_some()
+                }
+                Checked::Valid(Type::Box) => {
+                    self.sphere.is_some() || self.capsule.is_some() || self.cylinder.is_some()
+                }
+                Checked::Valid(Type::Capsule) => {
+                    self.sphere.is_some() || self.box_.is_some() || self.cylinder.is_some()
+                }
+                Checked::Valid(Type::Cylinder) => {
+                    self.sphere.is_some() || self.box_.is_some() || self.capsule.is_some()
+                }
+                Checked::Invalid => false,
+            };
+            if other {
+                report(&|| path().field("type"), Error::Invalid);
+            }
+
+            self.type_.validate(root, || path().field("type"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Specifies the shape type.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Type {
+        /// A sphere centered at the origin.
+        Sphere = 1,
+
+        /// An axis-aligned box centered at the origin.
+        Box,
+
+        /// A capsule centered at the origin and aligned with the Y axis.
+        Capsule,
+
+        /// A cylinder centered at the origin and aligned with the Y axis.
+        Cylinder,
+    }
+
+    /// Sphere parameters.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(default)]
+    pub struct Sphere {
+        /// The radius of the sphere.
+        pub radius: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Sphere {
+        fn default() -> Self {
+            Sphere {
+                radius: 0.5,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// Box parameters.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(default)]
+    pub struct Box {
+        /// The extents of the box along the X, Y and Z axes.
+        pub size: [f32; 3],
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Box {
+        fn default() -> Self {
+            Box {
+                size: [1.0, 1.0, 1.0],
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// Capsule parameters.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Capsule {
+        /// The distance between the centers of the two hemispherical caps.
+        pub height: f32,
+
+        /// The radius of the cap at the positive Y end.
+        pub radius_top: f32,
+
+        /// The radius of the cap at the negative Y end.
+        pub radius_bottom: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Capsule {
+        fn default() -> Self {
+            Capsule {
+                height: 0.5,
+                radius_top: 0.25,
+                radius_bottom: 0.25,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// Cylinder parameters.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Cylinder {
+        /// The distance between the two flat ends.
+        pub height: f32,
+
+        /// The radius of the end at the positive Y end.
+        pub radius_top: f32,
+
+        /// The radius of the end at the negative Y end.
+        pub radius_bottom: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Cylinder {
+        fn default() -> Self {
+            Cylinder {
+                height: 0.5,
+                radius_top: 0.25,
+                radius_bottom: 0.25,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<Type> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Type>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Type::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "sphere" => Valid(Sphere),
+                        "box" => Valid(Box),
+                        "capsule" => Valid(Capsule),
+                        "cylinder" => Valid(Cylinder),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                Type::Sphere => "sphere",
+                Type::Box => "box",
+                Type::Capsule => "capsule",
+                Type::Cylinder => "cylinder",
+            })
+        }
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+pub mod khr_physics_rigid_bodies {
+    use crate::{Extras, Index, Path, Root};
+    use crate::extensions::scene::khr_implicit_shapes::Shape;
+    use crate::validation::{Checked, Error, Validate};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid friction and restitution combine modes.
+    pub const VALID_COMBINE_MODES: &[&str] = &[
+        "average",
+        "minimum",
+        "maximum",
+        "multiply",
+    ];
+
+    /// All valid joint drive types.
+    pub const VALID_DRIVE_TYPES: &[&str] = &[
+        "linear",
+        "angular",
+    ];
+
+    /// All valid joint drive modes.
+    pub const VALID_DRIVE_MODES: &[&str] = &[
+        "force",
+        "acceleration",
+    ];
+
+    /// The physics properties of a node.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct KhrPhysicsRigidBodies {
+        /// Makes the node and its descendants a rigid body.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub motion: Option<Motion>,
+
+        /// Attaches a collider to the node.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub collider: Option<Collider>,
+
+        /// Attaches a trigger volume to the node.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub trigger: Option<Trigger>,
+
+        /// Constrains the node relative to another node.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub joint: Option<NodeJoint>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The motion properties of a rigid body.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Motion {
+        /// Whether the body is moved by animation rather than by the simulation.
+        pub is_kinematic: bool,
+
+        /// The mass of the body, computed from the colliders if undefined.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub mass: Option<f32>,
+
+        /// The center of mass relative to the node origin.
+        pub center_of_mass: [f32; 3],
+
+        /// The principal moments of inertia, computed from the colliders if
+        /// undefined.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inertia_diagonal: Option<[f32; 3]>,
+
+        /// The orientation of the inertia tensor as a unit quaternion.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub inertia_orientation: Option<[f32; 4]>,
+
+        /// The initial linear velocity in meters per second.
+        pub linear_velocity: [f32; 3],
+
+        /// The initial angular velocity in radians per second.
+        pub angular_velocity: [f32; 3],
+
+        /// Multiplier applied to the acceleration due to gravity.
+        pub gravity_factor: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Motion {
+        fn default() -> Self {
+            Motion {
+                is_kinematic: false,
+                mass: None,
+                center_of_mass: [0.0, 0.0, 0.0],
+                inertia_diagonal: None,
+                inertia_orientation: None,
+                linear_velocity: [0.0, 0.0, 0.0],
+                angular_velocity: [0.0, 0.0, 0.0],
+                gravity_factor: 1.0,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// The geometry of a collider or trigger.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Geometry {
+        /// The index of an implicit shape.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub shape: Option<Index<Shape>>,
+
+        /// The index of a node whose mesh is used as the geometry.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub node: Option<Index<crate::Node>>,
+
+        /// Whether the convex hull of the mesh is used instead of the mesh
+        /// itself.
+        #[serde(default)]
+        pub convex_hull: bool,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Geometry {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.shape.is_some() == self.node.is_some() {
+                report(&|| path().field("shape"), Error::Missing);
+            }
+
+            self.shape.validate(root, || path().field("shape"), report);
+            self.node.validate(root, || path().field("node"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A solid collider.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Collider {
+        /// The geometry of the collider.
+        pub geometry: Geometry,
+
+        /// The index of the physics material of the collider.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub physics_material: Option<Index<Material>>,
+
+        /// The index of the collision filter of the collider.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub collision_filter: Option<Index<CollisionFilter>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// A trigger volume that reports overlaps without a collision response.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Trigger {
+        /// The geometry of a simple trigger.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub geometry: Option<Geometry>,
+
+        /// The indices of the nodes forming a compound trigger.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub nodes: Vec<Index<crate::Node>>,
+
+        /// The index of the collision filter of the trigger.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub collision_filter: Option<Index<CollisionFilter>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Trigger {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.geometry.is_none() == self.nodes.is_empty() {
+                report(&|| path().field("geometry"), Error::Missing);
+            }
+
+            self.geometry.validate(root, || path().field("geometry"), report);
+            self.nodes.validate(root, || path().field("nodes"), report);
+            self.collision_filter.validate(root, || path().field("collisionFilter"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Constrains a node relative to another node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(rename_all = "camelCase")]
+    pub struct NodeJoint {
+        /// The index of the node the joint connects to.
+        pub connected_node: Index<crate::Node>,
+
+        /// The index of the joint description.
+        pub joint: Index<Joint>,
+
+        /// Whether the connected bodies collide with each other.
+        #[serde(default)]
+        pub enable_collision: bool,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Surface properties of a collider.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Material {
+        /// The friction coefficient when the surfaces are at rest.
+        pub static_friction: f32,
+
+        /// The friction coefficient when the surfaces are sliding.
+        pub dynamic_friction: f32,
+
+        /// The coefficient of restitution.
+        pub restitution: f32,
+
+        /// How the friction coefficients of two colliders are combined.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub friction_combine: Option<Checked<CombineMode>>,
+
+        /// How the restitution coefficients of two colliders are combined.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub restitution_combine: Option<Checked<CombineMode>>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Default for Material {
+        fn default() -> Self {
+            Material {
+                static_friction: 0.6,
+                dynamic_friction: 0.6,
+                restitution: 0.0,
+                friction_combine: None,
+                restitution_combine: None,
+                extras: Default::default(),
+            }
+        }
+    }
+
+    /// Specifies how the coefficients of two colliders are combined.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum CombineMode {
+        /// The mean of the two coefficients.
+        Average = 1,
+
+        /// The smaller of the two coefficients.
+        Minimum,
+
+        /// The larger of the two coefficients.
+        Maximum,
+
+        /// The product of the two coefficients.
+        Multiply,
+    }
+
+    /// Restricts which colliders may collide with each other.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct CollisionFilter {
+        /// The collision systems the collider belongs to.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub collision_systems: Vec<String>,
+
+        /// The only collision systems the collider collides with.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub collide_with_systems: Vec<String>,
+
+        /// The collision systems the collider does not collide with.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub not_collide_with_systems: Vec<String>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The degrees of freedom of a joint.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default)]
+    pub struct Joint {
+        /// The limits restricting the motion of the connected bodies.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub limits: Vec<JointLimit>,
+
+        /// The drives applying forces to the connected bodies.
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub drives: Vec<JointDrive>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Restricts the relative motion of the connected bodies along or around
+    /// some axes.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct JointLimit {
+        /// The translation axes restricted by the limit, from 0 (X) to 2 (Z).
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub linear_axes: Vec<u8>,
+
+        /// The rotation axes restricted by the limit, from 0 (X) to 2 (Z).
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        pub angular_axes: Vec<u8>,
+
+        /// The minimum distance or angle.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min: Option<f32>,
+
+        /// The maximum distance or angle.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub max: Option<f32>,
+
+        /// The stiffness of a soft limit, or `None` for a hard limit.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub stiffness: Option<f32>,
+
+        /// The damping of a soft limit.
+        pub damping: f32,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Applies a force to the connected bodies along or around one axis.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    #[serde(rename_all = "camelCase")]
+    pub struct JointDrive {
+        /// Whether the drive applies a linear or an angular force.
+        #[serde(rename = "type")]
+        pub type_: Checked<DriveType>,
+
+        /// Whether the drive output is a force or an acceleration.
+        pub mode: Checked<DriveMode>,
+
+        /// The axis of the drive, from 0 (X) to 2 (Z).
+        pub axis: u8,
+
+        /// The maximum force the drive may apply.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub max_force: Option<f32>,
+
+        /// The target distance or angle.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub position_target: Option<f32>,
+
+        /// The target linear or angular velocity.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub velocity_target: Option<f32>,
+
+        /// The stiffness of the drive towards the position target.
+        #[serde(default)]
+        pub stiffness: f32,
+
+        /// The damping of the drive towards the velocity target.
+        #[serde(default)]
+        pub damping: f32,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for JointLimit {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            for (i, axis) in self.linear_axes.iter().enumerate() {
+                if *axis > 2 {
+                    report(&|| path().field("linearAxes").index(i), Error::Invalid);
+                }
+            }
+            for (i, axis) in self.angular_axes.iter().enumerate() {
+                if *axis > 2 {
+                    report(&|| path().field("angularAxes").index(i), Error::Invalid);
+                }
+            }
+
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    impl Validate for JointDrive {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.axis > 2 {
+                report(&|| path().field("axis"), Error::Invalid);
+            }
+
+            self.type_.validate(root, || path().field("type"), report);
+            self.mode.validate(root, || path().field("mode"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Specifies whether a joint drive applies a linear or an angular force.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum DriveType {
+        /// The drive moves the bodies along the axis.
+        Linear = 1,
+
+        /// The drive rotates the bodies around the axis.
+        Angular,
+    }
+
+    /// Specifies whether a joint drive output is a force or an acceleration.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum DriveMode {
+        /// The drive output is a force, so its effect depends on the mass.
+        Force = 1,
+
+        /// The drive output is an acceleration, regardless of the mass.
+        Acceleration,
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<CombineMode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<CombineMode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_COMBINE_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::CombineMode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "average" => Valid(Average),
+                        "minimum" => Valid(Minimum),
+                        "maximum" => Valid(Maximum),
+                        "multiply" => Valid(Multiply),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for CombineMode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                CombineMode::Average => "average",
+                CombineMode::Minimum => "minimum",
+                CombineMode::Maximum => "maximum",
+                CombineMode::Multiply => "multiply",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<DriveType> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<DriveType>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_DRIVE_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::DriveType::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "linear" => Valid(Linear),
+                        "angular" => Valid(Angular),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for DriveType {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                DriveType::Linear => "linear",
+                DriveType::Angular => "angular",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<DriveMode> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<DriveMode>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_DRIVE_MODES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::DriveMode::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "force" => Valid(Force),
+                        "acceleration" => Valid(Acceleration),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for DriveMode {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                DriveMode::Force => "force",
+                DriveMode::Acceleration => "acceleration",
+            })
+        }
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+pub mod khr_audio {
+    use crate::{buffer, Extras, Index, Path, Root};
+    use crate::validation::{Checked, Error, Validate};
+    use gltf_derive::Validate;
+    use serde::{de, ser};
+    use serde_derive::{Deserialize, Serialize};
+    use std::fmt;
+
+    /// All valid emitter types.
+    pub const VALID_TYPES: &[&str] = &[
+        "global",
+        "positional",
+    ];
+
+    /// All valid distance models.
+    pub const VALID_DISTANCE_MODELS: &[&str] = &[
+        "linear",
+        "inverse",
+        "exponential",
+    ];
+
+    /// The positional audio emitter attached to a node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct NodeEmitter {
+        /// The index of the positional emitter.
+        pub emitter: Index<Emitter>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// The global audio emitters attached to a scene.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct SceneEmitters {
+        /// The indices of the global emitters.
+        pub emitters: Vec<Index<Emitter>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// Audio data, stored either in a buffer view or at a URI.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Audio {
+        /// The index of the buffer view that contains the audio data. Use this
+        /// instead of the audio's uri property.
+        #[serde(rename = "bufferView")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub buffer_view: Option<Index<buffer::View>>,
+
+        /// The audio's MIME type. Required if `buffer_view` is defined.
+        #[serde(rename = "mimeType")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub mime_type: Option<String>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The uri of the audio data. Relative paths are relative to the .gltf
+        /// file. Instead of referencing an external file, the uri can also be a
+        /// data-uri.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub uri: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Audio {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.buffer_view.is_some() == self.uri.is_some() {
+                report(&|| path().field("uri"), Error::Missing);
+            }
+            if self.buffer_view.is_some() && self.mime_type.is_none() {
+                report(&|| path().field("mimeType"), Error::Missing);
+            }
+
+            self.buffer_view.validate(root, || path().field("bufferView"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// A playable audio source referencing audio data.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct AudioSource {
+        /// The index of the audio data played by this source.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub audio: Option<Index<Audio>>,
+
+        /// Whether the source starts playing when the scene is loaded.
+        pub auto_play: bool,
+
+        /// Unitless multiplier applied to the audio volume.
+        pub gain: Gain,
+
+        /// Whether the audio restarts when it reaches its end.
+        #[serde(rename = "loop")]
+        pub loop_: bool,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Optional application specific data.
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// An audio emitter playing one or more audio sources.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct Emitter {
+        /// Unitless multiplier applied to the volume of all sources.
+        #[serde(default)]
+        pub gain: Gain,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// Positional emitter parameters.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub positional: Option<Positional>,
+
+        /// The indices of the audio sources played by this emitter.
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub sources: Vec<Index<AudioSource>>,
+
+        /// Specifies the emitter type.
+        #[serde(rename = "type")]
+        pub type_: Checked<Type>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for Emitter {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.type_ == Checked::Valid(Type::Global) && self.positional.is_some() {
+                report(&|| path().field("positional"), Error::Invalid);
+            }
+
+            self.positional.validate(root, || path().field("positional"), report);
+            self.sources.validate(root, || path().field("sources"), report);
+            self.type_.validate(root, || path().field("type"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Specifies the emitter type.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum Type {
+        /// Global emitters play their sources without spatialization.
+        Global = 1,
+
+        /// Positional emitters are attached to nodes and spatialized according
+        /// to the node's position and orientation.
+        Positional,
+    }
+
+    /// Specifies how the volume of a positional emitter decreases with distance.
+    #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+    pub enum DistanceModel {
+        /// Linear attenuation.
+        Linear = 1,
+
+        /// Inverse distance attenuation.
+        Inverse,
+
+        /// Exponential attenuation.
+        Exponential,
+    }
+
+    /// Positional emitter parameters.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    #[serde(default, rename_all = "camelCase")]
+    pub struct Positional {
+        /// The angle in radians of the cone inside of which there is no volume
+        /// reduction.
+        pub cone_inner_angle: f32,
+
+        /// The angle in radians of the cone outside of which the volume is
+        /// reduced by `cone_outer_gain`.
+        pub cone_outer_angle: f32,
+
+        /// The gain of the audio emitter outside of the outer cone.
+        pub cone_outer_gain: f32,
+
+        /// Specifies how the volume decreases with distance.
+        pub distance_model: Checked<DistanceModel>,
+
+        /// The maximum distance between the emitter and the listener, after
+        /// which the volume is no longer reduced.
+        pub max_distance: f32,
+
+        /// The reference distance for reducing the volume.
+        pub ref_distance: f32,
+
+        /// How quickly the volume is reduced as the distance increases.
+        pub rolloff_factor: f32,
+    }
+
+    impl Default for Positional {
+        fn default() -> Self {
+            Positional {
+                cone_inner_angle: 2.0 * std::f32::consts::PI,
+                cone_outer_angle: 2.0 * std::f32::consts::PI,
+                cone_outer_gain: 0.0,
+                distance_model: Checked::Valid(DistanceModel::Inverse),
+                max_distance: 10000.0,
+                ref_distance: 1.0,
+                rolloff_factor: 1.0,
+            }
+        }
+    }
+
+    /// Unitless multiplier applied to the audio volume.
+    #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+    pub struct Gain(pub f32);
+
+    impl Default for Gain {
+        fn default() -> Self {
+            Gain(1.0)
+        }
+    }
+
+    impl Validate for Gain {}
+
+    impl<'de> de::Deserialize<'de> for Checked<Type> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<Type>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_TYPES)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::Type::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "global" => Valid(Global),
+                        "positional" => Valid(Positional),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for Type {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                Type::Global => "global",
+                Type::Positional => "positional",
+            })
+        }
+    }
+
+    impl<'de> de::Deserialize<'de> for Checked<DistanceModel> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where D: de::Deserializer<'de>
+        {
+            struct Visitor;
+            impl<'de> de::Visitor<'de> for Visitor {
+                type Value = Checked<DistanceModel>;
+
+                fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write!(f, "any of: {:?}", VALID_DISTANCE_MODELS)
+                }
+
+                fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+                    where E: de::Error
+                {
+                    use self::DistanceModel::*;
+                    use crate::validation::Checked::*;
+                    Ok(match value {
+                        "linear" => Valid(Linear),
+                        "inverse" => Valid(Inverse),
+                        "exponential" => Valid(Exponential),
+                        _ => Invalid,
+                    })
+                }
+            }
+            deserializer.deserialize_str(Visitor)
+        }
+    }
+
+    impl ser::Serialize for DistanceModel {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+            S: ser::Serializer
+        {
+            serializer.serialize_str(match *self {
+                DistanceModel::Linear => "linear",
+                DistanceModel::Inverse => "inverse",
+                DistanceModel::Exponential => "exponential",
+            })
+        }
+    }
+}
+
+#[cfg(feature = "MSFT_lod")]
+pub mod msft_lod {
+    use crate::{scene, Extras, Index};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// Lower levels of detail of a node.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct MsftLod {
+        /// The indices of the nodes replacing this node at successively lower
+        /// levels of detail.
+        pub ids: Vec<Index<scene::Node>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+}
+
+#[cfg(feature = "EXT_lights_ies")]
+pub mod ext_lights_ies {
+    use crate::{buffer, Extras, Index, Path, Root};
+    use crate::validation::{Error, Validate};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+
+    /// All valid light profile MIME types.
+    pub const VALID_MIME_TYPES: &[&str] = &[
+        "application/x-ies-lm-63",
+    ];
+
+    /// An IES light profile instantiated by a node.
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+    pub struct ExtLightsIes {
+        /// The index of the light profile.
+        pub light: Index<LightProfile>,
+
+        /// Non-negative factor to scale the light intensity.
+        #[serde(default)]
+        pub multiplier: Multiplier,
+
+        /// RGB value for the light's color in linear space.
+        #[serde(default = "color_default")]
+        pub color: [f32; 3],
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    /// An IES light profile, stored either in a buffer view or at a URI.
+    #[derive(Clone, Debug, Deserialize, Serialize)]
+    pub struct LightProfile {
+        /// The index of the buffer view that contains the light profile. Use
+        /// this instead of the profile's uri property.
+        #[serde(rename = "bufferView")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub buffer_view: Option<Index<buffer::View>>,
+
+        /// The light profile's MIME type. Required if `buffer_view` is defined.
+        #[serde(rename = "mimeType")]
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub mime_type: Option<String>,
+
+        /// Optional user-defined name for this object.
+        #[cfg(feature = "names")]
+        #[cfg_attr(feature = "names", serde(skip_serializing_if = "Option::is_none"))]
+        pub name: Option<String>,
+
+        /// The uri of the light profile. Relative paths are relative to the
+        /// .gltf file. Instead of referencing an external file, the uri can
+        /// also be a data-uri.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub uri: Option<String>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
+
+    impl Validate for LightProfile {
+        fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+            where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+        {
+            if self.buffer_view.is_some() == self.uri.is_some() {
+                report(&|| path().field("uri"), Error::Missing);
+            }
+            if self.buffer_view.is_some() && self.mime_type.is_none() {
+                report(&|| path().field("mimeType"), Error::Missing);
+            }
+            if let Some(mime_type) = self.mime_type.as_ref() {
+                if !VALID_MIME_TYPES.contains(&mime_type.as_str()) {
+                    report(&|| path().field("mimeType"), Error::Invalid);
+                }
+            }
+
+            self.buffer_view.validate(root, || path().field("bufferView"), report);
+            self.extras.validate(root, || path().field("extras"), report);
+        }
+    }
+
+    /// Non-negative factor to scale the light intensity.
+    #[derive(Clone, Copy, Debug, Deserialize, Serialize)]
+    pub struct Multiplier(pub f32);
+
+    impl Default for Multiplier {
+        fn default() -> Self {
+            Multiplier(1.0)
+        }
+    }
+
+    impl Validate for Multiplier {}
+
+    fn color_default() -> [f32; 3] {
+        [1.0, 1.0, 1.0]
+    }
+}
+
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+pub mod ext_mesh_gpu_instancing {
+    use crate::{accessor, Extras, Index};
+    use gltf_derive::Validate;
+    use serde_derive::{Deserialize, Serialize};
+    use std::collections::HashMap;
+
+    /// Per-instance attributes used to draw the mesh of a node many times.
+    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    pub struct ExtMeshGpuInstancing {
+        /// Maps instance attribute names to the accessors containing their
+        /// per-instance values.
+        ///
+        /// Valid names are `TRANSLATION`, `ROTATION`, `SCALE` and application
+        /// specific names prefixed with an underscore.
+        #[serde(serialize_with = "crate::serialize::sorted_map")]
+        pub attributes: HashMap<String, Index<accessor::Accessor>>,
+
+        /// Optional application specific data.
+        #[serde(default)]
+        #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+        pub extras: Extras,
+    }
 }
 
 #[cfg(feature = "KHR_lights_punctual")]
@@ -134,7 +1477,7 @@ pub mod khr_lights_punctual {
     }
 
     /// Spot light parameters.
-    #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
+    #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
     #[serde(rename_all = "camelCase")]
     pub struct Spot {
         /// Angle in radians from centre of spotlight where falloff begins.
@@ -146,8 +1489,17 @@ pub mod khr_lights_punctual {
         pub outer_cone_angle: f32,
     }
 
+    impl Default for Spot {
+        fn default() -> Self {
+            Spot {
+                inner_cone_angle: 0.0,
+                outer_cone_angle: outer_cone_angle_default(),
+            }
+        }
+    }
+
     fn outer_cone_angle_default() -> f32 {
-        0.7853981633974483
+        std::f32::consts::FRAC_PI_4
     }
 
     impl<'de> de::Deserialize<'de> for Checked<Type> {
@@ -195,4 +1547,14 @@ pub mod khr_lights_punctual {
 
 /// The root `Node`s of a scene.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Scene {}
+pub struct Scene {
+    #[cfg(feature = "KHR_audio")]
+    #[serde(default, rename = "KHR_audio", skip_serializing_if = "Option::is_none")]
+    pub khr_audio: Option<khr_audio::SceneEmitters>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
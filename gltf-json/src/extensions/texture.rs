@@ -1,15 +1,116 @@
+#[cfg(any(
+    feature = "KHR_texture_transform",
+    feature = "KHR_texture_basisu",
+    feature = "EXT_texture_webp",
+    feature = "MSFT_texture_dds",
+))]
+use crate::extras::Extras;
 #[cfg(feature = "KHR_texture_transform")]
-use crate::{extras::Extras, validation::Validate};
+use crate::validation::Validate;
+#[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+use crate::{image::Image, Index};
 use gltf_derive::Validate;
 use serde_derive::{Deserialize, Serialize};
 
 /// Texture sampler properties for filtering and wrapping modes.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Sampler {}
+pub struct Sampler {
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
 
 /// A texture and its sampler.
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
-pub struct Texture {}
+pub struct Texture {
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[serde(default, rename = "KHR_texture_basisu", skip_serializing_if = "Option::is_none")]
+    pub texture_basisu: Option<TextureBasisu>,
+
+    #[cfg(feature = "EXT_texture_webp")]
+    #[serde(default, rename = "EXT_texture_webp", skip_serializing_if = "Option::is_none")]
+    pub texture_webp: Option<TextureWebp>,
+
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[serde(default, rename = "MSFT_texture_dds", skip_serializing_if = "Option::is_none")]
+    pub texture_dds: Option<TextureDds>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Texture {
+    /// Returns `true` if an extension provides the image used by the texture.
+    #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+    pub(crate) fn has_source(&self) -> bool {
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if self.texture_basisu.is_some() {
+                return true;
+            }
+        }
+
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            if self.texture_webp.is_some() {
+                return true;
+            }
+        }
+
+        #[cfg(feature = "MSFT_texture_dds")]
+        {
+            if self.texture_dds.is_some() {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+/// Provides a KTX2 image with Basis Universal supercompression as the texture source.
+#[cfg(feature = "KHR_texture_basisu")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureBasisu {
+    /// The index of the KTX2 image used by the texture.
+    pub source: Index<Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// Provides a WebP image as the texture source.
+#[cfg(feature = "EXT_texture_webp")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureWebp {
+    /// The index of the WebP image used by the texture.
+    pub source: Index<Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
+
+/// Provides a DDS image as the texture source.
+#[cfg(feature = "MSFT_texture_dds")]
+#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+pub struct TextureDds {
+    /// The index of the DDS image used by the texture.
+    pub source: Index<Image>,
+
+    /// Optional application specific data.
+    #[serde(default)]
+    #[cfg_attr(feature = "extras", serde(skip_serializing_if = "Option::is_none"))]
+    pub extras: Extras,
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
@@ -17,6 +118,12 @@ pub struct Info {
     #[cfg(feature = "KHR_texture_transform")]
     #[serde(default, rename = "KHR_texture_transform", skip_serializing_if = "Option::is_none")]
     pub texture_transform: Option<TextureTransform>,
+
+    /// Extensions unknown to the crate, or whose feature is disabled,
+    /// keyed by extension name.
+    #[cfg(feature = "extensions")]
+    #[serde(default, flatten)]
+    pub others: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Many techniques can be used to optimize resource usage for a 3d scene.
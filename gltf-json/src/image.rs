@@ -7,6 +7,10 @@ use crate::{buffer, extensions, Extras, Index};
 pub const VALID_MIME_TYPES: &'static [&'static str] = &[
     "image/jpeg",
     "image/png",
+    #[cfg(feature = "EXT_texture_webp")]
+    "image/webp",
+    #[cfg(feature = "MSFT_texture_dds")]
+    "image/vnd-ms.dds",
 ];
 
 /// Image data used to create a texture.
@@ -30,7 +34,8 @@ pub struct Image {
 
     /// The uri of the image.  Relative paths are relative to the .gltf file.
     /// Instead of referencing an external file, the uri can also be a data-uri.
-    /// The image format must be jpg or png.
+    /// The image format must be jpg or png, or webp when `EXT_texture_webp` is
+    /// enabled, or dds when `MSFT_texture_dds` is enabled.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub uri: Option<String>,
 
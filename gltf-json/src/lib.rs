@@ -102,4 +102,17 @@ pub mod deserialize {
 /// being bound to a specific version of `serde_json`.
 pub mod serialize {
     pub use serde_json::{to_string, to_string_pretty, to_value, to_vec, to_vec_pretty, to_writer, to_writer_pretty};
+
+    use serde::ser::{Serialize, Serializer};
+    use std::collections::HashMap;
+
+    /// Serializes a map with its entries sorted by key, so that the output
+    /// does not depend on the iteration order of the map.
+    pub(crate) fn sorted_map<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+        where K: Serialize + ToString, V: Serialize, S: Serializer
+    {
+        let mut entries = map.iter().map(|(key, value)| (key.to_string(), key, value)).collect::<Vec<_>>();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        serializer.collect_map(entries.into_iter().map(|(_, key, value)| (key, value)))
+    }
 }
@@ -122,6 +122,22 @@ impl Root {
         (self as &dyn Get<T>).get(index)
     }
 
+    /// Returns a single mutable item from the root object.
+    pub fn get_mut<T>(&mut self, index: Index<T>) -> Option<&mut T>
+        where Self: AsMut<Vec<T>>
+    {
+        self.as_mut().get_mut(index.value())
+    }
+
+    /// Appends an item to the root object, returning its index.
+    pub fn push<T>(&mut self, value: T) -> Index<T>
+        where Self: AsMut<Vec<T>>
+    {
+        let items = self.as_mut();
+        items.push(value);
+        Index::new(items.len() as u32 - 1)
+    }
+
     /// Deserialize from a JSON string slice.
     pub fn from_str(str_: &str) -> Result<Self, Error> {
         serde_json::from_str(str_)
@@ -263,6 +279,12 @@ macro_rules! impl_get {
                 self.$field.get(index.value())
             }
         }
+
+        impl AsMut<Vec<$ty>> for Root {
+            fn as_mut(&mut self) -> &mut Vec<$ty> {
+                &mut self.$field
+            }
+        }
     }
 }
 
@@ -2,8 +2,8 @@ use gltf_derive::Validate;
 use serde_derive::{Serialize, Deserialize};
 use serde::{de, ser};
 use std::fmt;
-use crate::validation::Checked;
-use crate::{extensions, image, Extras, Index};
+use crate::validation::{Checked, Error, Validate};
+use crate::{extensions, image, Extras, Index, Path, Root};
 
 /// Corresponds to `GL_NEAREST`.
 pub const NEAREST: u32 = 9728;
@@ -173,7 +173,7 @@ pub struct Sampler {
 }
 
 /// A texture and its sampler.
-#[derive(Clone, Debug, Deserialize, Serialize, Validate)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Texture {
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
@@ -185,8 +185,17 @@ pub struct Texture {
     pub sampler: Option<Index<Sampler>>,
 
     /// The index of the image used by this texture.
+    #[cfg(not(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds")))]
     pub source: Index<image::Image>,
 
+    /// The index of the image used by this texture.
+    ///
+    /// May be omitted when an extension such as `KHR_texture_basisu` provides
+    /// the image instead.
+    #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Index<image::Image>>,
+
     /// Extension specific data.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extensions: Option<extensions::texture::Texture>,
@@ -197,6 +206,27 @@ pub struct Texture {
     pub extras: Extras,
 }
 
+impl Validate for Texture {
+    fn validate<P, R>(&self, root: &Root, path: P, report: &mut R)
+        where P: Fn() -> Path, R: FnMut(&dyn Fn() -> Path, Error)
+    {
+        #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+        {
+            let has_extension_source = self.extensions
+                .as_ref()
+                .is_some_and(extensions::texture::Texture::has_source);
+            if self.source.is_none() && !has_extension_source {
+                report(&|| path().field("source"), Error::Missing);
+            }
+        }
+
+        self.sampler.validate(root, || path().field("sampler"), report);
+        self.source.validate(root, || path().field("source"), report);
+        self.extensions.validate(root, || path().field("extensions"), report);
+        self.extras.validate(root, || path().field("extras"), report);
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, Validate)]
 /// Reference to a `Texture`.
 pub struct Info {
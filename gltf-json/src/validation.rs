@@ -156,9 +156,11 @@ impl Validate for bool {}
 impl Validate for u32 {}
 impl Validate for i32 {}
 impl Validate for f32 {}
+impl Validate for [f32; 2] {}
 impl Validate for [f32; 3] {}
 impl Validate for [f32; 4] {}
 impl Validate for [f32; 16] {}
 impl Validate for () {}
 impl Validate for String {}
 impl Validate for serde_json::Value {}
+impl Validate for serde_json::Map<String, serde_json::Value> {}
@@ -33,6 +33,6 @@ fn test_non_sparse_accessor_without_buffer_view_validate() {
         gltf_json::Path::new,
         &mut |path, err| errs.push((path(), err)),
     );
-    assert_eq!(errs,
-        [(Path("accessors[0].bufferView".into()), Error::Missing)]);
+    // Accessors without a buffer view are initialized with zeros.
+    assert_eq!(errs, []);
 }
@@ -40,7 +40,7 @@
 //! for accessor in gltf.accessors() {
 //!     match (accessor.data_type(), accessor.dimensions()) {
 //!         (DataType::F32, Dimensions::Vec3) => {
-//!             let iter = Iter::<[f32; 3]>::new(accessor, get_buffer_data);
+//!             let iter = Iter::<[f32; 3]>::new(accessor, get_buffer_data)?;
 //!             for item in iter {
 //!                 println!("{:?}", item);
 //!             }
@@ -70,7 +70,7 @@ pub mod sparse;
 
 #[cfg(feature = "utils")]
 #[doc(inline)]
-pub use self::util::{Item, Iter};
+pub use self::util::{Analysis, DynElement, DynIter, Element, ElementIter, Error, Get, Item, Iter, NormalizedIter};
 
 /// A typed view into a buffer view.
 #[derive(Clone, Debug)]
@@ -111,7 +111,8 @@ impl<'a> Accessor<'a> {
 
     /// Returns the buffer view this accessor reads from.
     ///
-    /// This may be `None` if the corresponding accessor is sparse.
+    /// This may be `None`, in which case the accessor is initialized with
+    /// zeros, which sparse storage may override.
     pub fn view(&self) -> Option<buffer::View<'a>> {
         self.json.buffer_view.map(|view| self.document.views().nth(view.value()).unwrap())
     }
@@ -171,4 +172,213 @@ impl<'a> Accessor<'a> {
             sparse::Sparse::new(self.document, json)
         })
     }
+
+    /// Visits the elements of this accessor, whatever their dimensions and
+    /// component type.
+    ///
+    /// Each element is tagged with its component type and dimensions, so that
+    /// any accessor may be inspected without matching on them beforehand.
+    /// Sparse values are substituted, and normalized components are returned
+    /// as they are stored.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf::accessor::{DynElement, Element};
+    ///
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// for accessor in document.accessors() {
+    ///     for element in accessor.read_dyn(|buffer| Some(&buffers[buffer.index()]))? {
+    ///         match element {
+    ///             DynElement::F32(Element::Vec3(vec)) => println!("{:?}", vec),
+    ///             DynElement::U16(Element::Scalar(index)) => println!("{}", index),
+    ///             other => println!("{:?}", other),
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn read_dyn<'s, F>(&self, get_buffer_data: F) -> Result<util::DynIter<'s>, util::Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        util::DynIter::new(self.clone(), get_buffer_data)
+    }
+
+    /// Computes statistics of the data of this accessor, such as the range of
+    /// each component and the number of NaN, infinite, and subnormal
+    /// components.
+    ///
+    /// Unlike the `min` and `max` properties, which exporters may get wrong,
+    /// the statistics are computed from the data.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// for accessor in document.accessors() {
+    ///     let analysis = accessor.analyze(|buffer| Some(&buffers[buffer.index()]))?;
+    ///     assert!(!analysis.has_invalid_values());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn analyze<'s, F>(&self, get_buffer_data: F) -> Result<util::Analysis, util::Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Ok(util::Analysis::new(self.read_dyn(get_buffer_data)?))
+    }
+
+    /// Visits the elements of this accessor as `f32`, whatever their
+    /// component type, for elements of `N` components.
+    ///
+    /// Components of normalized accessors, such as colors, texture
+    /// co-ordinates, weights, and positions quantized with
+    /// `KHR_mesh_quantization`, are mapped to `[0.0, 1.0]` (unsigned) or
+    /// `[-1.0, 1.0]` (signed) as defined by the glTF specification. Other
+    /// components are converted as they are.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    /// for position in positions.read_normalized::<3, _>(|buffer| Some(&buffers[buffer.index()]))? {
+    ///     println!("{:?}", position);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn read_normalized<'s, const N: usize, F>(
+        &self,
+        get_buffer_data: F,
+    ) -> Result<util::NormalizedIter<'s, N>, util::Error>
+    where
+        F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        util::NormalizedIter::new(self.clone(), get_buffer_data)
+    }
+
+    /// Copies the elements of this accessor to the front of `out` in one
+    /// pass, and returns the number of elements copied, which is the smaller
+    /// of the number of elements and the length of `out`.
+    ///
+    /// Sparse values are substituted after the base values are copied, which
+    /// avoids the per-element overhead of [`Iter`] for large accessors.
+    ///
+    /// [`Iter`]: enum.Iter.html
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    /// let mut out = [[0.0; 3]; 24];
+    /// let count = positions.read_into(|buffer| Some(&buffers[buffer.index()]), &mut out)?;
+    /// assert_eq!(count, 24);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn read_into<'s, T, F>(&self, get_buffer_data: F, out: &mut [T]) -> Result<usize, util::Error>
+    where
+        T: util::Item,
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        util::read_into(self, &get_buffer_data, out)
+    }
+
+    /// Copies the elements of this accessor to a new vector in one pass.
+    ///
+    /// This is equivalent to collecting [`Iter`], but faster for large
+    /// accessors. See [`read_into`].
+    ///
+    /// [`Iter`]: enum.Iter.html
+    /// [`read_into`]: #method.read_into
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let indices = primitive.indices().unwrap();
+    /// let indices: Vec<u16> = indices.read_into_vec(|buffer| Some(&buffers[buffer.index()]))?;
+    /// assert_eq!(indices.len(), 36);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn read_into_vec<'s, T, F>(&self, get_buffer_data: F) -> Result<Vec<T>, util::Error>
+    where
+        T: util::Item,
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        util::read_into_vec(self, &get_buffer_data)
+    }
+
+    /// Returns the elements of this accessor as a slice of the buffer data,
+    /// without copying them.
+    ///
+    /// `T` must be a type with the layout of the elements, e.g. `[f32; 3]` for
+    /// `VEC3` accessors of `f32` components. Returns `None` if the elements
+    /// cannot be borrowed as they are, i.e. if the accessor is sparse, its
+    /// elements are interleaved with other data or not aligned for `T`, the
+    /// size of `T` differs from that of the elements, or the target is big
+    /// endian. The elements may be read with [`Iter`] instead.
+    ///
+    /// [`Iter`]: struct.Iter.html
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let indices = primitive.indices().unwrap();
+    /// let indices: &[u16] = indices.as_slice(|buffer| Some(&buffers[buffer.index()])).unwrap();
+    /// assert_eq!(indices.len(), 36);
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(all(feature = "utils", feature = "bytemuck"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "utils", feature = "bytemuck"))))]
+    pub fn as_slice<'s, T, F>(&self, get_buffer_data: F) -> Option<&'s [T]>
+    where
+        T: bytemuck::Pod,
+        F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let size = self.size();
+        if cfg!(target_endian = "big") || self.sparse().is_some() || std::mem::size_of::<T>() != size {
+            return None;
+        }
+        let view = self.view()?;
+        if view.stride().is_some_and(|stride| stride != size) {
+            return None;
+        }
+        let (data, _) = util::element_slice(view, self.offset(), self.count(), size, &get_buffer_data).ok()?;
+        bytemuck::try_cast_slice(data).ok()
+    }
 }
@@ -1,20 +1,145 @@
-use std::{iter, mem};
+use std::{fmt, mem};
 use byteorder::{LE, ByteOrder};
 use std::marker::PhantomData;
 
-use crate::{accessor, buffer};
+use crate::{accessor, buffer, Normalize};
+
+/// The reason the data of an accessor could not be read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The data of a buffer was not provided.
+    MissingBufferData {
+        /// The index of the buffer.
+        buffer: usize,
+    },
+
+    /// The size of the items read does not match the size of the elements
+    /// of the accessor.
+    SizeMismatch {
+        /// The size of the elements of the accessor in bytes.
+        expected: usize,
+
+        /// The size of the items read in bytes.
+        actual: usize,
+    },
+
+    /// The byte stride of a buffer view is smaller than the elements read
+    /// from it.
+    Stride {
+        /// The index of the buffer view.
+        view: usize,
+
+        /// The byte stride of the buffer view.
+        stride: usize,
+
+        /// The size of the elements in bytes.
+        size: usize,
+    },
+
+    /// A buffer view extends past the end of the data of its buffer.
+    ViewOutOfBounds {
+        /// The index of the buffer view.
+        view: usize,
+
+        /// The end of the buffer view in bytes.
+        end: usize,
+
+        /// The length of the buffer data in bytes.
+        length: usize,
+    },
+
+    /// The elements read extend past the end of their buffer view.
+    OutOfBounds {
+        /// The index of the buffer view.
+        view: usize,
+
+        /// The end of the elements relative to the buffer view in bytes.
+        end: usize,
+
+        /// The length of the buffer view in bytes.
+        length: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::MissingBufferData { buffer } => write!(f, "data of buffer {} is missing", buffer),
+            Error::SizeMismatch { expected, actual } => write!(
+                f,
+                "accessor elements are {} bytes but {} bytes were requested",
+                expected, actual,
+            ),
+            Error::Stride { view, stride, size } => write!(
+                f,
+                "byte stride of buffer view {} is {} but elements are {} bytes",
+                view, stride, size,
+            ),
+            Error::ViewOutOfBounds { view, end, length } => write!(
+                f,
+                "buffer view {} ends at byte {} but its buffer is {} bytes",
+                view, end, length,
+            ),
+            Error::OutOfBounds { view, end, length } => write!(
+                f,
+                "elements end at byte {} but buffer view {} is {} bytes",
+                end, view, length,
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
 
 fn buffer_view_slice<'a, 's>(
-    view: buffer::View<'a>,
+    view: &buffer::View<'a>,
     get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
-) -> Option<&'s [u8]> {
+) -> Result<&'s [u8], Error> {
+    let buffer = view.buffer().index();
+    let data = get_buffer_data(view.buffer()).ok_or(Error::MissingBufferData { buffer })?;
     let start = view.offset();
-    let end = start + view.length();
-    get_buffer_data(view.buffer())
-        .map(|slice| &slice[start..end])
+    let end = start.saturating_add(view.length());
+    data.get(start..end).ok_or(Error::ViewOutOfBounds {
+        view: view.index(),
+        end,
+        length: data.len(),
+    })
+}
+
+/// Returns the data of `count` elements of `size` bytes starting at `offset`
+/// in a buffer view, along with their stride.
+pub(crate) fn element_slice<'a, 's>(
+    view: buffer::View<'a>,
+    offset: usize,
+    count: usize,
+    size: usize,
+    get_buffer_data: &dyn Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+) -> Result<(&'s [u8], usize), Error> {
+    let stride = view.stride().unwrap_or(size);
+    if stride < size {
+        return Err(Error::Stride { view: view.index(), stride, size });
+    }
+    let data = buffer_view_slice(&view, get_buffer_data)?;
+    let end = match count {
+        0 => offset,
+        _ => stride
+            .checked_mul(count - 1)
+            .and_then(|length| length.checked_add(size))
+            .and_then(|length| length.checked_add(offset))
+            .unwrap_or(usize::MAX),
+    };
+    let slice = data.get(offset..end).ok_or(Error::OutOfBounds {
+        view: view.index(),
+        end,
+        length: data.len(),
+    })?;
+    Ok((slice, stride))
 }
 
 /// General iterator for an accessor.
+///
+/// Values substituted by sparse storage are applied over the base values,
+/// which are zero for accessors without a buffer view.
 #[derive(Clone, Debug)]
 pub enum Iter<'a, T: Item> {
     /// Standard accessor iterator.
@@ -65,6 +190,22 @@ impl<'a, T: Item> Iterator for Iter<'a, T> {
 
 impl<'a, T: Item> ExactSizeIterator for Iter<'a, T> {}
 
+impl<'a, T: Item> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            &mut Iter::Standard(ref mut iter) => iter.next_back(),
+            &mut Iter::Sparse(ref mut iter) => iter.next_back(),
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        match self {
+            &mut Iter::Standard(ref mut iter) => iter.nth_back(n),
+            &mut Iter::Sparse(ref mut iter) => iter.nth_back(n),
+        }
+    }
+}
+
 /// Iterator over indices of sparse accessor.
 #[derive(Clone, Debug)]
 pub enum SparseIndicesIter<'a> {
@@ -76,6 +217,30 @@ pub enum SparseIndicesIter<'a> {
     U32(ItemIter<'a, u32>),
 }
 
+impl<'a> SparseIndicesIter<'a> {
+    /// Returns the index at `index` relative to the next index, without
+    /// advancing the iterator.
+    pub fn get(&self, index: usize) -> Option<u32> {
+        match *self {
+            SparseIndicesIter::U8(ref iter) => iter.get(index).map(|x| x as u32),
+            SparseIndicesIter::U16(ref iter) => iter.get(index).map(|x| x as u32),
+            SparseIndicesIter::U32(ref iter) => iter.get(index),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for SparseIndicesIter<'a> {}
+
+impl<'a> DoubleEndedIterator for SparseIndicesIter<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match *self {
+            SparseIndicesIter::U8(ref mut iter) => iter.next_back().map(|x| x as u32),
+            SparseIndicesIter::U16(ref mut iter) => iter.next_back().map(|x| x as u32),
+            SparseIndicesIter::U32(ref mut iter) => iter.next_back(),
+        }
+    }
+}
+
 impl<'a> Iterator for SparseIndicesIter<'a> {
     type Item = u32;
     fn next(&mut self) -> Option<Self::Item> {
@@ -85,40 +250,90 @@ impl<'a> Iterator for SparseIndicesIter<'a> {
             SparseIndicesIter::U32(ref mut iter) => iter.next(),
         }
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match *self {
+            SparseIndicesIter::U8(ref mut iter) => iter.nth(n).map(|x| x as u32),
+            SparseIndicesIter::U16(ref mut iter) => iter.nth(n).map(|x| x as u32),
+            SparseIndicesIter::U32(ref mut iter) => iter.nth(n),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match *self {
+            SparseIndicesIter::U8(ref iter) => iter.size_hint(),
+            SparseIndicesIter::U16(ref iter) => iter.size_hint(),
+            SparseIndicesIter::U32(ref iter) => iter.size_hint(),
+        }
+    }
 }
 
 /// Iterates over a sparse accessor.
 #[derive(Clone, Debug)]
 pub struct SparseIter<'a, T: Item> {
-    /// Base value iterator.
+    /// Base values, read by index.
     ///
     /// This can be `None` if the base buffer view is not set. In this case the base values are all zero.
     base: Option<ItemIter<'a, T>>,
 
-    /// Sparse indices iterator.
-    indices: iter::Peekable<SparseIndicesIter<'a>>,
+    /// Sparse indices not yet visited from either end.
+    indices: SparseIndicesIter<'a>,
 
-    /// Sparse values iterator.
+    /// Sparse values not yet visited from either end.
     values: ItemIter<'a, T>,
 
-    /// Iterator counter.
-    counter: u32,
+    /// The index of the next element from the front.
+    front: usize,
+
+    /// One past the index of the next element from the back.
+    back: usize,
 }
 
 impl<'a, T: Item> SparseIter<'a, T> {
     /// Constructor.
     /// 
     /// Here `base` is allowed to be `None` when the base buffer view is not explicitly specified.
+    /// `count` is the number of elements of the accessor.
     pub fn new(
         base: Option<ItemIter<'a, T>>,
         indices: SparseIndicesIter<'a>,
         values: ItemIter<'a, T>,
+        count: usize,
     ) -> Self {
         SparseIter {
             base,
-            indices: indices.peekable(),
-            values: values,
-            counter: 0,
+            indices,
+            values,
+            front: 0,
+            back: count,
+        }
+    }
+
+    /// Returns the element at `index`, given the sparse value substituted for
+    /// it, if any.
+    fn element(&self, index: usize, value: Option<T>) -> Option<T> {
+        match (value, &self.base) {
+            (Some(value), _) => Some(value),
+            (None, Some(base)) => base.get(index),
+            (None, None) => Some(T::zero()),
+        }
+    }
+
+    /// Skips the sparse values of elements before the next element from the
+    /// front.
+    fn skip_front(&mut self) {
+        // Sparse indices are strictly increasing.
+        let (mut low, mut high) = (0, self.indices.len());
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.indices.get(middle) {
+                Some(index) if (index as usize) < self.front => low = middle + 1,
+                _ => high = middle,
+            }
+        }
+        if low > 0 {
+            self.indices.nth(low - 1);
+            self.values.nth(low - 1);
         }
     }
 }
@@ -126,38 +341,121 @@ impl<'a, T: Item> SparseIter<'a, T> {
 impl<'a, T: Item> Iterator for SparseIter<'a, T> {
     type Item = T;
     fn next(&mut self) -> Option<Self::Item> {
-        let next_base_value = self.base.as_mut().map(|iter| iter.next()).unwrap_or(Some(T::zero()));
-        if next_base_value.is_none() {
+        if self.front >= self.back {
             return None;
         }
-
-        let mut next_value = next_base_value.unwrap();
-        let next_sparse_index = self.indices.peek().clone();
-        if let Some(index) = next_sparse_index {
-            if *index == self.counter {
-                self.indices.next(); // advance
-                next_value = self.values.next().unwrap();
+        self.skip_front();
+        let index = self.front;
+        self.front += 1;
+        let value = match self.indices.get(0) {
+            Some(sparse_index) if sparse_index as usize == index => {
+                self.indices.next();
+                self.values.next()
             }
-        }
+            _ => None,
+        };
+        self.element(index, value)
+    }
 
-        self.counter += 1;
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
 
-        Some(next_value)
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let hint = self.values.len() - self.counter as usize;
+        let hint = self.back.saturating_sub(self.front);
         (hint, Some(hint))
     }
 }
 
+impl<'a, T: Item> DoubleEndedIterator for SparseIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let index = self.back;
+        let last_index = |indices: &SparseIndicesIter| indices.len().checked_sub(1).and_then(|last| indices.get(last));
+        // Drop sparse values of elements after this one, which are only
+        // left if they were skipped by `nth_back` or are out of range.
+        while matches!(last_index(&self.indices), Some(sparse_index) if sparse_index as usize > index) {
+            self.indices.next_back();
+            self.values.next_back();
+        }
+        let value = match last_index(&self.indices) {
+            Some(sparse_index) if sparse_index as usize == index => {
+                self.indices.next_back();
+                self.values.next_back()
+            }
+            _ => None,
+        };
+        self.element(index, value)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
 impl<'a, T: Item> ExactSizeIterator for SparseIter<'a, T> {}
 
 /// Represents items that can be read by an [`Accessor`].
 ///
-/// [`Accessor`]: struct.Accessor.html
-pub trait Item {
+/// The trait may be implemented for other types, such as fixed-point or half
+/// precision numbers, to decode elements into them with [`Iter`], [`Get`],
+/// or [`Accessor::read_into`]. Elements are only read if their size in bytes
+/// is [`SIZE`].
+///
+/// [`Accessor`]: ../struct.Accessor.html
+/// [`Accessor::read_into`]: ../struct.Accessor.html#method.read_into
+/// [`Iter`]: enum.Iter.html
+/// [`Get`]: struct.Get.html
+/// [`SIZE`]: #associatedconstant.SIZE
+///
+/// # Examples
+///
+/// Decoding `SHORT` components as signed 8.8 fixed-point numbers:
+///
+/// ```
+/// use gltf::accessor::Item;
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Fixed(f32);
+///
+/// impl Item for Fixed {
+///     const SIZE: usize = 2;
+///
+///     fn from_slice(slice: &[u8]) -> Self {
+///         Fixed(i16::from_le_bytes([slice[0], slice[1]]) as f32 / 256.0)
+///     }
+///
+///     fn zero() -> Self {
+///         Fixed(0.0)
+///     }
+/// }
+///
+/// assert_eq!(Fixed::from_slice(&[0x80, 0x01]), Fixed(1.5));
+/// assert_eq!(<[Fixed; 2]>::from_slice(&[0x80, 0x01, 0x00, 0xff]), [Fixed(1.5), Fixed(-1.0)]);
+/// ```
+pub trait Item: Sized {
+    /// The size of the encoded item in bytes, which defaults to the size of
+    /// the type.
+    const SIZE: usize = mem::size_of::<Self>();
+
     /// Create an object of this type from a byte slice.
+    ///
+    /// The slice holds at least [`SIZE`] bytes.
+    ///
+    /// [`SIZE`]: #associatedconstant.SIZE
     fn from_slice(slice: &[u8]) -> Self;
     /// Create an object of this type that represents a zero value.
     fn zero() -> Self;
@@ -227,39 +525,15 @@ impl Item for f32 {
     }
 }
 
-impl<T: Item + Copy> Item for [T; 2] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 2 * mem::size_of::<T>());
-        [T::from_slice(slice),
-         T::from_slice(&slice[mem::size_of::<T>() ..])]
-    }
-    fn zero() -> Self {
-        [T::zero(); 2]
-    }
-}
-
-impl<T: Item + Copy> Item for [T; 3] {
-    fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 3 * mem::size_of::<T>());
-        [T::from_slice(slice),
-         T::from_slice(&slice[1 * mem::size_of::<T>() ..]),
-         T::from_slice(&slice[2 * mem::size_of::<T>() ..])]
-    }
-    fn zero() -> Self {
-        [T::zero(); 3]
-    }
-}
+impl<T: Item + Copy, const N: usize> Item for [T; N] {
+    const SIZE: usize = N * T::SIZE;
 
-impl<T: Item + Copy> Item for [T; 4] {
     fn from_slice(slice: &[u8]) -> Self {
-        assert!(slice.len() >= 4 * mem::size_of::<T>());
-        [T::from_slice(slice),
-         T::from_slice(&slice[1 * mem::size_of::<T>() ..]),
-         T::from_slice(&slice[2 * mem::size_of::<T>() ..]),
-         T::from_slice(&slice[3 * mem::size_of::<T>() ..])]
+        assert!(slice.len() >= Self::SIZE);
+        std::array::from_fn(|i| T::from_slice(&slice[i * T::SIZE ..]))
     }
     fn zero() -> Self {
-        [T::zero(); 4]
+        [T::zero(); N]
     }
 }
 
@@ -272,98 +546,250 @@ impl<'a, T: Item> ItemIter<'a, T> {
             _phantom: PhantomData,
         }
     }
+
+    /// Returns the item at `index` relative to the next item, without
+    /// advancing the iterator.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let data = self.data.get(index.checked_mul(self.stride)?..)?;
+        if data.len() >= T::SIZE {
+            Some(T::from_slice(data))
+        } else {
+            None
+        }
+    }
+}
+
+/// The data of an accessor, split into base values and sparse substitutions.
+struct Parts<'a, T: Item> {
+    /// Base values, if the accessor has a buffer view.
+    base: Option<ItemIter<'a, T>>,
+
+    /// Indices and values substituted by sparse storage, if any.
+    sparse: Option<(SparseIndicesIter<'a>, ItemIter<'a, T>)>,
+}
+
+impl<'a, 's, T: Item> Parts<'s, T> {
+    fn new<F>(
+        accessor: &super::Accessor<'a>,
+        get_buffer_data: &F,
+    ) -> Result<Parts<'s, T>, Error>
+        where F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let size = T::SIZE;
+        if size != accessor.size() {
+            return Err(Error::SizeMismatch { expected: accessor.size(), actual: size });
+        }
+        let base = match accessor.view() {
+            Some(view) => {
+                let (subslice, stride) = element_slice(view, accessor.offset(), accessor.count(), size, get_buffer_data)?;
+                Some(ItemIter::new(subslice, stride))
+            }
+            None => None,
+        };
+        let sparse = match accessor.sparse() {
+            Some(sparse) => sparse,
+            None => return Ok(Parts { base, sparse: None }),
+        };
+        let indices = sparse.indices();
+        let values = sparse.values();
+        let sparse_count = sparse.count() as usize;
+        let index_iter = {
+            let index_size = indices.index_type().size();
+            let offset = indices.offset() as usize;
+            let (subslice, stride) = element_slice(indices.view(), offset, sparse_count, index_size, get_buffer_data)?;
+            match indices.index_type() {
+                accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
+                accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
+                accessor::sparse::IndexType::U32 => SparseIndicesIter::U32(ItemIter::new(subslice, stride)),
+            }
+        };
+        let value_iter = {
+            let offset = values.offset() as usize;
+            let (subslice, stride) = element_slice(values.view(), offset, sparse_count, size, get_buffer_data)?;
+            ItemIter::new(subslice, stride)
+        };
+        Ok(Parts { base, sparse: Some((index_iter, value_iter)) })
+    }
+
+    /// Writes the elements to the front of `out`, and returns the number of
+    /// elements written.
+    fn copy_into(self, count: usize, out: &mut [T]) -> usize {
+        let count = count.min(out.len());
+        let out = &mut out[..count];
+        match self.base {
+            // Contiguous elements are converted in one tight loop, without
+            // the bookkeeping of the iterator.
+            Some(base) if base.stride == T::SIZE => {
+                for (value, data) in out.iter_mut().zip(base.data.chunks_exact(base.stride)) {
+                    *value = T::from_slice(data);
+                }
+            }
+            Some(base) => {
+                for (value, data) in out.iter_mut().zip(base.data.chunks(base.stride)) {
+                    *value = T::from_slice(data);
+                }
+            }
+            None => out.iter_mut().for_each(|value| *value = T::zero()),
+        }
+        if let Some((indices, values)) = self.sparse {
+            for (index, value) in indices.zip(values) {
+                if let Some(element) = out.get_mut(index as usize) {
+                    *element = value;
+                }
+            }
+        }
+        count
+    }
 }
 
 impl<'a, 's, T: Item> Iter<'s, T> {
     /// Constructor.
+    ///
+    /// Fails if the data of the accessor cannot be read as items of type `T`,
+    /// e.g. because buffer data is missing or the accessor is out of bounds.
     pub fn new<F>(
         accessor: super::Accessor<'a>,
         get_buffer_data: F,
-    ) -> Option<Iter<'s, T>>
+    ) -> Result<Iter<'s, T>, Error>
         where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
     {
-        let is_sparse = accessor.sparse().is_some();
-        if is_sparse {
-            let sparse = accessor.sparse();
-            let indices = sparse.as_ref().unwrap().indices();
-            let values = sparse.as_ref().unwrap().values();
-            let base_iter = {
-                // Using `if let` here instead of map to preserve the early return behavior.
-                if let Some(view) = accessor.view() {
-                    let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                    let start = accessor.offset();
-                    let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                    let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                        &slice[start..end]
-                    } else {
-                        return None
-                    };
-                    Some(ItemIter::new(subslice, stride))
-                } else {
-                    None
-                }
-            };
-            let sparse_count = sparse.as_ref().unwrap().count() as usize;
-            let index_iter = {
-                let view = indices.view();
-                let index_size = indices.index_type().size();
-                let stride = view.stride().unwrap_or(index_size);
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = indices.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + index_size;
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                match indices.index_type() {
-                    accessor::sparse::IndexType::U8 => SparseIndicesIter::U8(ItemIter::new(subslice, stride)),
-                    accessor::sparse::IndexType::U16 => SparseIndicesIter::U16(ItemIter::new(subslice, stride)),
-                    accessor::sparse::IndexType::U32 => SparseIndicesIter::U32(ItemIter::new(subslice, stride)),
+        match Parts::new(&accessor, &get_buffer_data)? {
+            Parts { base, sparse: Some((indices, values)) } => {
+                Ok(Iter::Sparse(SparseIter::new(base, indices, values, accessor.count())))
+            }
+            Parts { base: Some(base), sparse: None } => Ok(Iter::Standard(base)),
+            Parts { base: None, sparse: None } => {
+                // Accessors without a buffer view are initialized with zeros.
+                let indices = SparseIndicesIter::U8(ItemIter::new(&[], 1));
+                let values = ItemIter::new(&[], T::SIZE);
+                Ok(Iter::Sparse(SparseIter::new(None, indices, values, accessor.count())))
+            }
+        }
+    }
+}
+
+/// Copies the elements of an accessor to the front of `out`, and returns the
+/// number of elements copied.
+pub(crate) fn read_into<'a, 's, T, F>(
+    accessor: &super::Accessor<'a>,
+    get_buffer_data: &F,
+    out: &mut [T],
+) -> Result<usize, Error>
+    where T: Item, F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    Ok(Parts::new(accessor, get_buffer_data)?.copy_into(accessor.count(), out))
+}
+
+/// Copies the elements of an accessor to a new vector.
+pub(crate) fn read_into_vec<'a, 's, T, F>(
+    accessor: &super::Accessor<'a>,
+    get_buffer_data: &F,
+) -> Result<Vec<T>, Error>
+    where T: Item, F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+{
+    // The data is validated before allocating for the elements.
+    let parts = Parts::new(accessor, get_buffer_data)?;
+    let mut out = (0..accessor.count()).map(|_| T::zero()).collect::<Vec<_>>();
+    parts.copy_into(accessor.count(), &mut out);
+    Ok(out)
+}
+
+/// Reads the elements of an accessor by index.
+///
+/// Unlike [`Iter`], elements may be read in any order without visiting the
+/// elements before them. Byte strides are honored and values substituted by
+/// sparse storage are returned in place of the base values.
+///
+/// [`Iter`]: enum.Iter.html
+#[derive(Clone, Debug)]
+pub struct Get<'a, T: Item> {
+    /// Base values, or `None` if they are all zero.
+    base: Option<ItemIter<'a, T>>,
+
+    /// Indices and values substituted by sparse storage, if any.
+    sparse: Option<(SparseIndicesIter<'a>, ItemIter<'a, T>)>,
+
+    /// The number of elements.
+    count: usize,
+}
+
+impl<'a, 's, T: Item> Get<'s, T> {
+    /// Constructor.
+    ///
+    /// Fails if the data of the accessor cannot be read as items of type `T`,
+    /// as with [`Iter::new`].
+    ///
+    /// [`Iter::new`]: enum.Iter.html#method.new
+    pub fn new<F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Result<Get<'s, T>, Error>
+        where F: Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let Parts { base, sparse } = Parts::new(&accessor, &get_buffer_data)?;
+        Ok(Get { base, sparse, count: accessor.count() })
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns whether the accessor has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.count {
+            return None;
+        }
+        if let Some((ref indices, ref values)) = self.sparse {
+            // Sparse indices are strictly increasing.
+            let (mut low, mut high) = (0, indices.len());
+            while low < high {
+                let middle = low + (high - low) / 2;
+                match (indices.get(middle)? as usize).cmp(&index) {
+                    std::cmp::Ordering::Less => low = middle + 1,
+                    std::cmp::Ordering::Greater => high = middle,
+                    std::cmp::Ordering::Equal => return values.get(middle),
                 }
-            };
-            let value_iter = {
-                let view = values.view();
-                let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    let start = values.offset() as usize;
-                    let end = start + stride * (sparse_count - 1) + mem::size_of::<T>();
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                ItemIter::new(subslice, stride)
-            };
-            Some(Iter::Sparse(SparseIter::new(base_iter, index_iter, value_iter)))
-        } else {
-            debug_assert_eq!(mem::size_of::<T>(), accessor.size());
-            debug_assert!(mem::size_of::<T>() > 0);
-            if let Some(view) = accessor.view() {
-                let stride = view.stride().unwrap_or(mem::size_of::<T>());
-                debug_assert!(stride >= mem::size_of::<T>(), "Mismatch in stride, expected at least {} stride but found {}", mem::size_of::<T>(), stride);
-                let start = accessor.offset();
-                let end = start + stride * (accessor.count() - 1) + mem::size_of::<T>();
-                let subslice = if let Some(slice) = buffer_view_slice(view, &get_buffer_data) {
-                    &slice[start..end]
-                } else {
-                    return None
-                };
-                Some(Iter::Standard(ItemIter { stride, data: subslice, _phantom: PhantomData }))
-            } else {
-                None
             }
         }
+        match self.base {
+            Some(ref base) => base.get(index),
+            None => Some(T::zero()),
+        }
     }
 }
 
 impl<'a, T: Item> ExactSizeIterator for ItemIter<'a, T> {}
+impl<'a, T: Item> DoubleEndedIterator for ItemIter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let len = self.len();
+        if n >= len {
+            self.data = &self.data[..0];
+            return None;
+        }
+        let start = (len - 1 - n) * self.stride;
+        let val = T::from_slice(&self.data[start ..]);
+        self.data = &self.data[..start];
+        Some(val)
+    }
+}
 impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
         let stride = if self.data.len() >= self.stride {
             Some(self.stride)
-        } else if self.data.len() >= mem::size_of::<T>() {
-            Some(mem::size_of::<T>())
+        } else if self.data.len() >= T::SIZE {
+            Some(T::SIZE)
         } else {
             None
         };
@@ -378,21 +804,18 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
     }
 
     fn nth(&mut self, nth: usize) -> Option<Self::Item> {
-        if let Some(val_data) = self.data.get(nth * self.stride ..) {
-            if val_data.len() >= mem::size_of::<T>() {
-                let val = T::from_slice(val_data);
-                self.data = &val_data[self.stride.min(val_data.len()) ..];
-                Some(val)
-            } else {
-                None
-            }
-        } else {
-            None
+        if nth >= self.len() {
+            self.data = &self.data[..0];
+            return None;
         }
+        let val_data = &self.data[nth * self.stride ..];
+        let val = T::from_slice(val_data);
+        self.data = &val_data[self.stride.min(val_data.len()) ..];
+        Some(val)
     }
 
     fn last(self) -> Option<Self::Item> {
-        if self.data.len() >= mem::size_of::<T>() {
+        if self.data.len() >= T::SIZE {
             self.data
                 .get((self.data.len() - 1) / self.stride * self.stride ..)
                 .map(T::from_slice)
@@ -407,7 +830,417 @@ impl<'a, T: Item> Iterator for ItemIter<'a, T> {
 
     fn size_hint(&self) -> (usize, Option<usize>) {
         let hint = self.data.len() / self.stride
-            + (self.data.len() % self.stride >= mem::size_of::<T>()) as usize;
+            + (self.data.len() % self.stride >= T::SIZE) as usize;
         (hint, Some(hint))
     }
 }
+
+/// An element of an accessor with components of type `T`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Element<T> {
+    /// A scalar.
+    Scalar(T),
+
+    /// A vector of two components.
+    Vec2([T; 2]),
+
+    /// A vector of three components.
+    Vec3([T; 3]),
+
+    /// A vector of four components.
+    Vec4([T; 4]),
+
+    /// A 2x2 matrix in column-major order.
+    Mat2([[T; 2]; 2]),
+
+    /// A 3x3 matrix in column-major order.
+    Mat3([[T; 3]; 3]),
+
+    /// A 4x4 matrix in column-major order.
+    Mat4([[T; 4]; 4]),
+}
+
+/// An element of an accessor of any component type.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DynElement {
+    /// Signed 8-bit components.
+    I8(Element<i8>),
+
+    /// Unsigned 8-bit components.
+    U8(Element<u8>),
+
+    /// Signed 16-bit components.
+    I16(Element<i16>),
+
+    /// Unsigned 16-bit components.
+    U16(Element<u16>),
+
+    /// Unsigned 32-bit components.
+    U32(Element<u32>),
+
+    /// Floating point components.
+    F32(Element<f32>),
+}
+
+impl<T: Copy> Element<T> {
+    /// Returns the components of the element in order.
+    fn components(&self) -> Vec<T> {
+        match *self {
+            Element::Scalar(x) => vec![x],
+            Element::Vec2(v) => v.to_vec(),
+            Element::Vec3(v) => v.to_vec(),
+            Element::Vec4(v) => v.to_vec(),
+            Element::Mat2(m) => m.iter().flatten().copied().collect(),
+            Element::Mat3(m) => m.iter().flatten().copied().collect(),
+            Element::Mat4(m) => m.iter().flatten().copied().collect(),
+        }
+    }
+}
+
+impl DynElement {
+    /// Returns the components of the element as `f64`, along with the number
+    /// of subnormal components.
+    fn components(&self) -> (Vec<f64>, usize) {
+        fn convert<T: Copy + Into<f64>>(element: &Element<T>) -> (Vec<f64>, usize) {
+            (element.components().into_iter().map(Into::into).collect(), 0)
+        }
+        match self {
+            DynElement::I8(element) => convert(element),
+            DynElement::U8(element) => convert(element),
+            DynElement::I16(element) => convert(element),
+            DynElement::U16(element) => convert(element),
+            DynElement::U32(element) => convert(element),
+            DynElement::F32(element) => {
+                let components = element.components();
+                let subnormal = components.iter().filter(|x| x.is_subnormal()).count();
+                (components.into_iter().map(f64::from).collect(), subnormal)
+            }
+        }
+    }
+}
+
+/// Statistics of the data of an accessor, for diagnosing broken exports.
+///
+/// Components are taken as they are stored, i.e. normalized components are
+/// not mapped to `[0.0, 1.0]`, and sparse values are substituted.
+///
+/// Created by [`Accessor::analyze`].
+///
+/// [`Accessor::analyze`]: struct.Accessor.html#method.analyze
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Analysis {
+    /// The number of elements.
+    pub count: usize,
+
+    /// The minimum of each component, ignoring NaN.
+    ///
+    /// Components that are NaN in all elements have a NaN minimum. Empty if
+    /// the accessor has no elements.
+    pub min: Vec<f64>,
+
+    /// The maximum of each component, ignoring NaN.
+    ///
+    /// Components that are NaN in all elements have a NaN maximum. Empty if
+    /// the accessor has no elements.
+    pub max: Vec<f64>,
+
+    /// The number of NaN components.
+    pub nan: usize,
+
+    /// The number of infinite components.
+    pub infinite: usize,
+
+    /// The number of subnormal floating point components, which are rarely
+    /// intended and often a sign of uninitialized data.
+    pub subnormal: usize,
+
+    /// Whether all elements are bitwise equal, as for attributes exported
+    /// with a placeholder value.
+    pub constant: bool,
+}
+
+impl Analysis {
+    /// Analyzes the elements of an accessor.
+    pub(crate) fn new(elements: DynIter) -> Self {
+        let mut analysis = Analysis { constant: true, ..Default::default() };
+        let mut first = Vec::new();
+        for element in elements {
+            let (components, subnormal) = element.components();
+            if analysis.count == 0 {
+                analysis.min = vec![f64::NAN; components.len()];
+                analysis.max = vec![f64::NAN; components.len()];
+                first = components.iter().map(|x| x.to_bits()).collect();
+            }
+            for (i, &x) in components.iter().enumerate() {
+                analysis.min[i] = analysis.min[i].min(x);
+                analysis.max[i] = analysis.max[i].max(x);
+                analysis.nan += x.is_nan() as usize;
+                analysis.infinite += x.is_infinite() as usize;
+                analysis.constant &= x.to_bits() == first[i];
+            }
+            analysis.subnormal += subnormal;
+            analysis.count += 1;
+        }
+        analysis
+    }
+
+    /// Returns whether any component is NaN, infinite, or subnormal.
+    pub fn has_invalid_values(&self) -> bool {
+        self.nan > 0 || self.infinite > 0 || self.subnormal > 0
+    }
+}
+
+/// Visits the elements of an accessor with components of type `T`.
+#[derive(Clone, Debug)]
+pub enum ElementIter<'a, T: Item + Copy> {
+    /// Scalars.
+    Scalar(Iter<'a, T>),
+
+    /// Vectors of two components.
+    Vec2(Iter<'a, [T; 2]>),
+
+    /// Vectors of three components.
+    Vec3(Iter<'a, [T; 3]>),
+
+    /// Vectors of four components.
+    Vec4(Iter<'a, [T; 4]>),
+
+    /// 2x2 matrices.
+    Mat2(Iter<'a, [[T; 2]; 2]>),
+
+    /// 3x3 matrices.
+    Mat3(Iter<'a, [[T; 3]; 3]>),
+
+    /// 4x4 matrices.
+    Mat4(Iter<'a, [[T; 4]; 4]>),
+}
+
+/// Visits the elements of an accessor of any component type.
+///
+/// Created by [`Accessor::read_dyn`].
+///
+/// [`Accessor::read_dyn`]: struct.Accessor.html#method.read_dyn
+#[derive(Clone, Debug)]
+pub enum DynIter<'a> {
+    /// Signed 8-bit components.
+    I8(ElementIter<'a, i8>),
+
+    /// Unsigned 8-bit components.
+    U8(ElementIter<'a, u8>),
+
+    /// Signed 16-bit components.
+    I16(ElementIter<'a, i16>),
+
+    /// Unsigned 16-bit components.
+    U16(ElementIter<'a, u16>),
+
+    /// Unsigned 32-bit components.
+    U32(ElementIter<'a, u32>),
+
+    /// Floating point components.
+    F32(ElementIter<'a, f32>),
+}
+
+impl<'s, T: Item + Copy> ElementIter<'s, T> {
+    /// Constructor.
+    ///
+    /// The dimensions of the elements are those of the accessor.
+    pub fn new<'a, F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Result<ElementIter<'s, T>, Error>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use super::Dimensions;
+        Ok(match accessor.dimensions() {
+            Dimensions::Scalar => ElementIter::Scalar(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Vec2 => ElementIter::Vec2(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Vec3 => ElementIter::Vec3(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Vec4 => ElementIter::Vec4(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Mat2 => ElementIter::Mat2(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Mat3 => ElementIter::Mat3(Iter::new(accessor, get_buffer_data)?),
+            Dimensions::Mat4 => ElementIter::Mat4(Iter::new(accessor, get_buffer_data)?),
+        })
+    }
+}
+
+impl<'a, T: Item + Copy> Iterator for ElementIter<'a, T> {
+    type Item = Element<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            ElementIter::Scalar(iter) => iter.next().map(Element::Scalar),
+            ElementIter::Vec2(iter) => iter.next().map(Element::Vec2),
+            ElementIter::Vec3(iter) => iter.next().map(Element::Vec3),
+            ElementIter::Vec4(iter) => iter.next().map(Element::Vec4),
+            ElementIter::Mat2(iter) => iter.next().map(Element::Mat2),
+            ElementIter::Mat3(iter) => iter.next().map(Element::Mat3),
+            ElementIter::Mat4(iter) => iter.next().map(Element::Mat4),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ElementIter::Scalar(iter) => iter.size_hint(),
+            ElementIter::Vec2(iter) => iter.size_hint(),
+            ElementIter::Vec3(iter) => iter.size_hint(),
+            ElementIter::Vec4(iter) => iter.size_hint(),
+            ElementIter::Mat2(iter) => iter.size_hint(),
+            ElementIter::Mat3(iter) => iter.size_hint(),
+            ElementIter::Mat4(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a, T: Item + Copy> ExactSizeIterator for ElementIter<'a, T> {}
+
+impl<'s> DynIter<'s> {
+    /// Constructor.
+    pub fn new<'a, F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Result<DynIter<'s>, Error>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use super::DataType;
+        Ok(match accessor.data_type() {
+            DataType::I8 => DynIter::I8(ElementIter::new(accessor, get_buffer_data)?),
+            DataType::U8 => DynIter::U8(ElementIter::new(accessor, get_buffer_data)?),
+            DataType::I16 => DynIter::I16(ElementIter::new(accessor, get_buffer_data)?),
+            DataType::U16 => DynIter::U16(ElementIter::new(accessor, get_buffer_data)?),
+            DataType::U32 => DynIter::U32(ElementIter::new(accessor, get_buffer_data)?),
+            DataType::F32 => DynIter::F32(ElementIter::new(accessor, get_buffer_data)?),
+        })
+    }
+}
+
+impl<'a> Iterator for DynIter<'a> {
+    type Item = DynElement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            DynIter::I8(iter) => iter.next().map(DynElement::I8),
+            DynIter::U8(iter) => iter.next().map(DynElement::U8),
+            DynIter::I16(iter) => iter.next().map(DynElement::I16),
+            DynIter::U16(iter) => iter.next().map(DynElement::U16),
+            DynIter::U32(iter) => iter.next().map(DynElement::U32),
+            DynIter::F32(iter) => iter.next().map(DynElement::F32),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            DynIter::I8(iter) => iter.size_hint(),
+            DynIter::U8(iter) => iter.size_hint(),
+            DynIter::I16(iter) => iter.size_hint(),
+            DynIter::U16(iter) => iter.size_hint(),
+            DynIter::U32(iter) => iter.size_hint(),
+            DynIter::F32(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for DynIter<'a> {}
+
+/// Visits the elements of an accessor of `N` components as `f32`.
+///
+/// Components of normalized accessors are mapped to `[0.0, 1.0]` (unsigned)
+/// or `[-1.0, 1.0]` (signed) as defined by the glTF specification. Other
+/// components are converted as they are.
+///
+/// Created by [`Accessor::read_normalized`].
+///
+/// [`Accessor::read_normalized`]: struct.Accessor.html#method.read_normalized
+#[derive(Clone, Debug)]
+pub struct NormalizedIter<'a, const N: usize> {
+    /// The components as stored.
+    components: Components<'a, N>,
+
+    /// Whether the components are normalized.
+    normalized: bool,
+}
+
+/// Visits the elements of an accessor of `N` components of any type.
+#[derive(Clone, Debug)]
+enum Components<'a, const N: usize> {
+    I8(Iter<'a, [i8; N]>),
+    U8(Iter<'a, [u8; N]>),
+    I16(Iter<'a, [i16; N]>),
+    U16(Iter<'a, [u16; N]>),
+    U32(Iter<'a, [u32; N]>),
+    F32(Iter<'a, [f32; N]>),
+}
+
+impl<'s, const N: usize> NormalizedIter<'s, N> {
+    /// Constructor.
+    ///
+    /// Fails if the elements of the accessor do not have `N` components.
+    pub fn new<'a, F>(
+        accessor: super::Accessor<'a>,
+        get_buffer_data: F,
+    ) -> Result<NormalizedIter<'s, N>, Error>
+        where F: Clone + Fn(buffer::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        use super::DataType;
+        let normalized = accessor.normalized();
+        let components = match accessor.data_type() {
+            DataType::I8 => Components::I8(Iter::new(accessor, get_buffer_data)?),
+            DataType::U8 => Components::U8(Iter::new(accessor, get_buffer_data)?),
+            DataType::I16 => Components::I16(Iter::new(accessor, get_buffer_data)?),
+            DataType::U16 => Components::U16(Iter::new(accessor, get_buffer_data)?),
+            DataType::U32 => Components::U32(Iter::new(accessor, get_buffer_data)?),
+            DataType::F32 => Components::F32(Iter::new(accessor, get_buffer_data)?),
+        };
+        Ok(NormalizedIter { components, normalized })
+    }
+
+    /// Converts an element to `f32`.
+    fn convert<T>(&self, element: [T; N]) -> [f32; N]
+        where T: Normalize<f32> + Into<f64>,
+    {
+        if self.normalized {
+            element.map(Normalize::normalize)
+        } else {
+            element.map(|component| component.into() as f32)
+        }
+    }
+}
+
+impl<'a, const N: usize> Iterator for NormalizedIter<'a, N> {
+    type Item = [f32; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.components {
+            Components::I8(ref mut iter) => iter.next().map(|x| self.convert(x)),
+            Components::U8(ref mut iter) => iter.next().map(|x| self.convert(x)),
+            Components::I16(ref mut iter) => iter.next().map(|x| self.convert(x)),
+            Components::U16(ref mut iter) => iter.next().map(|x| self.convert(x)),
+            Components::U32(ref mut iter) => iter.next().map(|x| x.map(|component| component as f32)),
+            Components::F32(ref mut iter) => iter.next(),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.components {
+            Components::I8(ref mut iter) => iter.nth(n).map(|x| self.convert(x)),
+            Components::U8(ref mut iter) => iter.nth(n).map(|x| self.convert(x)),
+            Components::I16(ref mut iter) => iter.nth(n).map(|x| self.convert(x)),
+            Components::U16(ref mut iter) => iter.nth(n).map(|x| self.convert(x)),
+            Components::U32(ref mut iter) => iter.nth(n).map(|x| x.map(|component| component as f32)),
+            Components::F32(ref mut iter) => iter.nth(n),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.components {
+            Components::I8(ref iter) => iter.size_hint(),
+            Components::U8(ref iter) => iter.size_hint(),
+            Components::I16(ref iter) => iter.size_hint(),
+            Components::U16(ref iter) => iter.size_hint(),
+            Components::U32(ref iter) => iter.size_hint(),
+            Components::F32(ref iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for NormalizedIter<'a, N> {}
@@ -181,10 +181,36 @@ impl<'a> Target<'a> {
     }
 
     /// Returns the target node.
+    #[cfg(not(feature = "KHR_animation_pointer"))]
     pub fn node(&self) -> scene::Node<'a> {
         self.anim.document.nodes().nth(self.json.node.value()).unwrap()
     }
 
+    /// Returns the target node.
+    ///
+    /// This is only `None` when the target is defined by the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn node(&self) -> Option<scene::Node<'a>> {
+        self.json
+            .node
+            .map(|index| self.anim.document.nodes().nth(index.value()).unwrap())
+    }
+
+    /// Returns the JSON pointer to the targeted property as defined by the
+    /// `KHR_animation_pointer` extension.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    pub fn pointer(&self) -> Option<crate::khr_animation_pointer::Pointer<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .khr_animation_pointer
+            .as_ref()
+            .map(|json| crate::khr_animation_pointer::Pointer::new(self.anim.document, json))
+    }
+
     /// Returns the node's property to modify or the 'weights' of the morph
     /// targets it instantiates.
     pub fn property(&self) -> Property {
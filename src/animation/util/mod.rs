@@ -58,6 +58,21 @@ pub enum MorphTargetWeights<'a> {
     F32(accessor::Iter<'a, f32>),
 }
 
+/// Values of a property targeted with `KHR_animation_pointer`.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+#[derive(Clone, Debug)]
+pub enum PointerValues<'a> {
+    /// Scalar values of type `f32`.
+    Scalar(accessor::Iter<'a, f32>),
+    /// Vector values of type `[f32; 2]`.
+    Vec2(accessor::Iter<'a, [f32; 2]>),
+    /// Vector values of type `[f32; 3]`.
+    Vec3(accessor::Iter<'a, [f32; 3]>),
+    /// Vector values of type `[f32; 4]`.
+    Vec4(accessor::Iter<'a, [f32; 4]>),
+}
+
 /// Animation output sampler values.
 pub enum ReadOutputs<'a> {
     /// XYZ translations of type `[f32; 3]`.
@@ -71,6 +86,11 @@ pub enum ReadOutputs<'a> {
 
     /// Morph target animations.
     MorphTargetWeights(MorphTargetWeights<'a>),
+
+    /// Animations of the property targeted with `KHR_animation_pointer`.
+    #[cfg(feature = "KHR_animation_pointer")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+    Pointer(PointerValues<'a>),
 }
 
 impl<'a> Rotations<'a> {
@@ -141,7 +161,7 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the input samples of a channel.
     pub fn read_inputs(&self) -> Option<ReadInputs<'s>> {
-        accessor::Iter::new(self.channel.sampler().input(), self.get_buffer_data.clone())
+        accessor::Iter::new(self.channel.sampler().input(), self.get_buffer_data.clone()).ok()
     }
 
     /// Visits the output samples of a channel.
@@ -150,28 +170,42 @@ where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
         use crate::animation::Property;
         let output = self.channel.sampler().output();
         match self.channel.target().property() {
-            Property::Translation => Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Translations),
+            Property::Translation => Iter::new(output, self.get_buffer_data.clone()).ok().map(ReadOutputs::Translations),
             Property::Rotation => {
                 match output.data_type() {
-                    DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::I8(x))),
-                    DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::U8(x))),
-                    DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::I16(x))),
-                    DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::U16(x))),
-                    DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::Rotations(Rotations::F32(x))),
+                    DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Rotations(Rotations::I8(x))),
+                    DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Rotations(Rotations::U8(x))),
+                    DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Rotations(Rotations::I16(x))),
+                    DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Rotations(Rotations::U16(x))),
+                    DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Rotations(Rotations::F32(x))),
                     _ => unreachable!()
                 }
             },
-            Property::Scale => Iter::new(output, self.get_buffer_data.clone()).map(ReadOutputs::Scales),
+            Property::Scale => Iter::new(output, self.get_buffer_data.clone()).ok().map(ReadOutputs::Scales),
             Property::MorphTargetWeights => {
                 match output.data_type() {
-                    DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I8(x))),
-                    DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U8(x))),
-                    DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I16(x))),
-                    DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U16(x))),
-                    DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::F32(x))),
+                    DataType::I8 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I8(x))),
+                    DataType::U8 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U8(x))),
+                    DataType::I16 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::I16(x))),
+                    DataType::U16 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::U16(x))),
+                    DataType::F32 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::MorphTargetWeights(MorphTargetWeights::F32(x))),
                     _ => unreachable!()
                 }
             },
+            #[cfg(feature = "KHR_animation_pointer")]
+            Property::Pointer => {
+                use accessor::Dimensions;
+                if output.data_type() != DataType::F32 {
+                    return None;
+                }
+                match output.dimensions() {
+                    Dimensions::Scalar => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Pointer(PointerValues::Scalar(x))),
+                    Dimensions::Vec2 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Pointer(PointerValues::Vec2(x))),
+                    Dimensions::Vec3 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Pointer(PointerValues::Vec3(x))),
+                    Dimensions::Vec4 => Iter::new(output, self.get_buffer_data.clone()).ok().map(|x| ReadOutputs::Pointer(PointerValues::Vec4(x))),
+                    _ => None,
+                }
+            },
         }
     }
 }
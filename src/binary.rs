@@ -112,7 +112,7 @@ impl ChunkHeader {
     }
 }
 
-fn align_to_multiple_of_four(n: &mut usize) {
+pub(crate) fn align_to_multiple_of_four(n: &mut usize) {
     *n = (*n + 3) & !3;
 }
 
@@ -283,6 +283,204 @@ impl<'a> Glb<'a> {
             x => Err(crate::Error::Binary(Error::Version(x)))
         }
     }
+
+    /// Reads the `JSON` chunk of binary glTF from a stream of data, and
+    /// locates the `BIN` chunk without reading it.
+    ///
+    /// Returns the `JSON` chunk, along with the range in bytes of the `BIN`
+    /// chunk in the stream, if any.
+    #[cfg(feature = "import")]
+    pub(crate) fn locate_bin<R: io::Read>(reader: R) -> Result<(Vec<u8>, Option<std::ops::Range<u64>>), crate::Error> {
+        let mut glb = GlbReader::new(reader)?;
+        let json = match glb.next_chunk()? {
+            Some(chunk) => chunk.to_vec()?,
+            None => Vec::new(),
+        };
+        let bin = glb.next_chunk()?.map(|chunk| chunk.offset()..chunk.offset() + chunk.len() as u64);
+        Ok((json, bin))
+    }
+}
+
+/// Reads binary glTF chunk by chunk from a stream of data, without reading
+/// the whole stream up front.
+///
+/// The header of the stream and of each chunk is validated as it is read,
+/// so that the JSON may be processed while the `BIN` chunk is still being
+/// received, e.g. over the network.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use std::io::Read;
+/// use gltf::binary::GlbReader;
+///
+/// let file = std::fs::File::open("examples/Box.glb")?;
+/// let mut glb = GlbReader::new(std::io::BufReader::new(file))?;
+/// let json = glb.next_chunk()?.unwrap().to_vec()?;
+/// let document = gltf::Document::from_json(gltf::json::deserialize::from_slice(&json)?)?;
+///
+/// // The document is available before the `BIN` chunk is read.
+/// let mut bin = glb.next_chunk()?.unwrap();
+/// let mut data = Vec::new();
+/// bin.read_to_end(&mut data)?;
+/// assert_eq!(data.len(), document.buffers().next().unwrap().length());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("runtime error");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct GlbReader<R> {
+    /// The stream of data.
+    reader: R,
+
+    /// The header of the binary glTF.
+    header: Header,
+
+    /// The number of bytes read from the stream so far.
+    position: u64,
+
+    /// The number of bytes of the current chunk not read yet.
+    unread: u64,
+
+    /// The number of chunks encountered so far.
+    chunks: usize,
+}
+
+/// A chunk of binary glTF being read by a [`GlbReader`].
+///
+/// The data of the chunk is read through the `io::Read` implementation.
+///
+/// [`GlbReader`]: struct.GlbReader.html
+#[derive(Debug)]
+pub struct Chunk<'a, R> {
+    /// The reader of the binary glTF.
+    glb: &'a mut GlbReader<R>,
+
+    /// The type of the chunk.
+    ty: ChunkType,
+
+    /// The offset in bytes of the chunk data from the start of the stream.
+    offset: u64,
+
+    /// The length of the chunk data in bytes.
+    length: u32,
+}
+
+impl<R: io::Read> GlbReader<R> {
+    /// Reads and validates the header of binary glTF.
+    pub fn new(mut reader: R) -> Result<Self, crate::Error> {
+        let header = Header::from_reader(&mut reader)?;
+        if header.version != 2 {
+            return Err(crate::Error::Binary(Error::Version(header.version)));
+        }
+        Ok(Self {
+            reader,
+            header,
+            position: Header::size_of() as u64,
+            unread: 0,
+            chunks: 0,
+        })
+    }
+
+    /// Returns the header of the binary glTF.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
+
+    /// Reads and validates the header of the next chunk, skipping the rest of
+    /// the current chunk.
+    ///
+    /// Returns `None` at the end of the binary glTF, as given by the length in
+    /// its header. The first chunk must be the `JSON` chunk, which may be
+    /// followed by a `BIN` chunk.
+    pub fn next_chunk(&mut self) -> Result<Option<Chunk<'_, R>>, crate::Error> {
+        if self.unread > 0 {
+            let unread = self.unread;
+            let skipped = io::copy(&mut io::Read::take(&mut self.reader, unread), &mut io::sink())?;
+            self.position += skipped;
+            self.unread = 0;
+            if skipped < unread {
+                return Err(crate::Error::Io(io::ErrorKind::UnexpectedEof.into()));
+            }
+        }
+        if self.position >= self.header.length as u64 {
+            return Ok(None);
+        }
+
+        let chunk_h = ChunkHeader::from_reader(&mut self.reader)?;
+        self.position += mem::size_of::<ChunkHeader>() as u64;
+        match (self.chunks, chunk_h.ty) {
+            (0, ChunkType::Json) | (1, ChunkType::Bin) => {}
+            (_, ty) => return Err(crate::Error::Binary(Error::ChunkType(ty))),
+        }
+        let remaining = (self.header.length as u64).saturating_sub(self.position);
+        if chunk_h.length as u64 > remaining {
+            return Err(crate::Error::Binary(Error::ChunkLength {
+                ty: chunk_h.ty,
+                length: chunk_h.length,
+                length_read: remaining as usize,
+            }));
+        }
+        self.chunks += 1;
+        self.unread = chunk_h.length as u64;
+        Ok(Some(Chunk {
+            ty: chunk_h.ty,
+            offset: self.position,
+            length: chunk_h.length,
+            glb: self,
+        }))
+    }
+
+    /// Returns the underlying stream of data.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<'a, R: io::Read> Chunk<'a, R> {
+    /// Returns the type of the chunk.
+    pub fn ty(&self) -> ChunkType {
+        self.ty
+    }
+
+    /// Returns the offset in bytes of the chunk data from the start of the
+    /// stream.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// Returns the length of the chunk data in bytes.
+    pub fn len(&self) -> usize {
+        self.length as usize
+    }
+
+    /// Returns whether the chunk has no data.
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Reads the rest of the chunk data into a vector.
+    pub fn to_vec(mut self) -> io::Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(self.glb.unread as usize);
+        io::Read::read_to_end(&mut self, &mut data)?;
+        if self.glb.unread > 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(data)
+    }
+}
+
+impl<'a, R: io::Read> io::Read for Chunk<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let length = self.glb.unread.min(buf.len() as u64) as usize;
+        let read = self.glb.reader.read(&mut buf[..length])?;
+        self.glb.unread -= read as u64;
+        self.glb.position += read as u64;
+        Ok(read)
+    }
 }
 
 impl fmt::Display for Error {
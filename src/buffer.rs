@@ -1,5 +1,7 @@
 #[cfg(feature = "import")]
 use std::ops;
+#[cfg(feature = "mmap")]
+use std::{fs, path::Path, sync::Arc};
 
 use crate::Document;
 
@@ -59,6 +61,87 @@ impl ops::Deref for Data {
     }
 }
 
+/// Buffer data belonging to an imported glTF asset, memory-mapped from the
+/// file it is stored in where possible.
+///
+/// Clones share the same mapping, which is unmapped once the last clone is
+/// dropped.
+#[cfg(feature = "mmap")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mmap")))]
+#[derive(Clone, Debug)]
+pub struct MappedData {
+    /// The mapped file or the loaded data.
+    storage: Arc<Storage>,
+
+    /// The range of the storage holding the buffer data.
+    range: ops::Range<usize>,
+}
+
+/// Memory backing `MappedData`.
+#[cfg(feature = "mmap")]
+#[derive(Debug)]
+enum Storage {
+    /// A memory-mapped file.
+    Map(memmap2::Mmap),
+
+    /// Data loaded into memory, e.g. from a data URI.
+    Owned(Vec<u8>),
+}
+
+#[cfg(feature = "mmap")]
+impl MappedData {
+    /// Memory-maps a whole file.
+    pub(crate) fn map<P>(path: P) -> crate::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = fs::File::open(path)?;
+        // Safety: the mapping is read-only, and the file must not be
+        // modified while mapped, as documented by `import_mapped`.
+        let map = unsafe { memmap2::Mmap::map(&file)? };
+        let range = 0..map.len();
+        Ok(Self {
+            storage: Arc::new(Storage::Map(map)),
+            range,
+        })
+    }
+
+    /// Wraps data loaded into memory.
+    pub(crate) fn owned(data: Vec<u8>) -> Self {
+        let range = 0..data.len();
+        Self {
+            storage: Arc::new(Storage::Owned(data)),
+            range,
+        }
+    }
+
+    /// Returns the data of a range of this data, sharing its storage.
+    pub(crate) fn slice(&self, range: ops::Range<usize>) -> Self {
+        Self {
+            storage: self.storage.clone(),
+            range: self.range.start + range.start..self.range.start + range.end,
+        }
+    }
+
+    /// Returns whether the data is memory-mapped rather than loaded into
+    /// memory.
+    pub fn is_mapped(&self) -> bool {
+        matches!(*self.storage, Storage::Map(_))
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl ops::Deref for MappedData {
+    type Target = [u8];
+    fn deref(&self) -> &Self::Target {
+        let data = match *self.storage {
+            Storage::Map(ref map) => &map[..],
+            Storage::Owned(ref data) => data.as_slice(),
+        };
+        &data[self.range.clone()]
+    }
+}
+
 impl<'a> Buffer<'a> {
     /// Constructs a `Buffer`.
     pub(crate) fn new(
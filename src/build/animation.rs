@@ -0,0 +1,233 @@
+use json::animation::{Interpolation, Property};
+use json::validation::Checked::Valid;
+use json::Index;
+
+use super::{BufferBuilder, Error};
+use crate::DocumentMut;
+
+/// Keyframe values of an animated property.
+#[derive(Clone, Debug)]
+enum Values<'a> {
+    /// XYZ translations.
+    Translations(&'a [[f32; 3]]),
+
+    /// XYZW rotation quaternions.
+    Rotations(&'a [[f32; 4]]),
+
+    /// XYZ scales.
+    Scales(&'a [[f32; 3]]),
+
+    /// Morph target weights, with the given number of morph targets.
+    MorphTargetWeights(&'a [f32], usize),
+}
+
+/// The keyframes of a single animated property of a node.
+#[derive(Clone, Debug)]
+struct Channel<'a> {
+    node: Index<json::Node>,
+    times: &'a [f32],
+    values: Values<'a>,
+    interpolation: Interpolation,
+}
+
+/// Builds a keyframe animation from per-node keyframe lists.
+///
+/// Each animated property gets its own sampler and channel. Keyframe times
+/// are written once and shared by all samplers using the same times.
+///
+/// With `CubicSpline` interpolation, each keyframe stores three values: an
+/// in-tangent, a spline vertex, and an out-tangent.
+#[derive(Clone, Debug, Default)]
+pub struct AnimationBuilder<'a> {
+    #[cfg(feature = "names")]
+    name: Option<String>,
+    channels: Vec<Channel<'a>>,
+}
+
+impl<'a> Values<'a> {
+    /// Returns the number of items of the values.
+    fn len(&self) -> usize {
+        match *self {
+            Values::Translations(values) => values.len(),
+            Values::Rotations(values) => values.len(),
+            Values::Scales(values) => values.len(),
+            Values::MorphTargetWeights(values, _) => values.len(),
+        }
+    }
+
+    /// Returns the number of items per keyframe value.
+    fn items_per_value(&self) -> usize {
+        match *self {
+            Values::MorphTargetWeights(_, targets) => targets,
+            _ => 1,
+        }
+    }
+
+    /// Returns the animated property.
+    fn property(&self) -> Property {
+        match *self {
+            Values::Translations(_) => Property::Translation,
+            Values::Rotations(_) => Property::Rotation,
+            Values::Scales(_) => Property::Scale,
+            Values::MorphTargetWeights(..) => Property::MorphTargetWeights,
+        }
+    }
+}
+
+impl<'a> Channel<'a> {
+    /// Checks that the keyframe times and values are consistent.
+    fn validate(&self) -> Result<(), Error> {
+        let increasing = self.times.windows(2).all(|pair| pair[0] < pair[1]);
+        let minimum = if let Interpolation::CubicSpline = self.interpolation { 2 } else { 1 };
+        if !increasing || self.times.len() < minimum || self.times[0] < 0.0 {
+            return Err(Error::KeyframeTimes);
+        }
+        let mut expected = self.times.len() * self.values.items_per_value();
+        if let Interpolation::CubicSpline = self.interpolation {
+            expected *= 3;
+        }
+        let actual = self.values.len();
+        if expected == 0 || actual != expected {
+            return Err(Error::KeyframeCount { expected, actual });
+        }
+        Ok(())
+    }
+}
+
+impl<'a> AnimationBuilder<'a> {
+    /// Constructs an animation without channels.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the animation.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Animates the translation of a node.
+    pub fn translations(
+        self,
+        node: Index<json::Node>,
+        times: &'a [f32],
+        translations: &'a [[f32; 3]],
+        interpolation: Interpolation,
+    ) -> Self {
+        self.channel(node, times, Values::Translations(translations), interpolation)
+    }
+
+    /// Animates the rotation of a node, given as XYZW quaternions.
+    pub fn rotations(
+        self,
+        node: Index<json::Node>,
+        times: &'a [f32],
+        rotations: &'a [[f32; 4]],
+        interpolation: Interpolation,
+    ) -> Self {
+        self.channel(node, times, Values::Rotations(rotations), interpolation)
+    }
+
+    /// Animates the scale of a node.
+    pub fn scales(
+        self,
+        node: Index<json::Node>,
+        times: &'a [f32],
+        scales: &'a [[f32; 3]],
+        interpolation: Interpolation,
+    ) -> Self {
+        self.channel(node, times, Values::Scales(scales), interpolation)
+    }
+
+    /// Animates the morph target weights of the mesh of a node.
+    ///
+    /// The weights of all `targets` morph targets are given for each keyframe
+    /// value in turn.
+    pub fn morph_target_weights(
+        self,
+        node: Index<json::Node>,
+        times: &'a [f32],
+        weights: &'a [f32],
+        targets: usize,
+        interpolation: Interpolation,
+    ) -> Self {
+        self.channel(node, times, Values::MorphTargetWeights(weights, targets), interpolation)
+    }
+
+    fn channel(
+        mut self,
+        node: Index<json::Node>,
+        times: &'a [f32],
+        values: Values<'a>,
+        interpolation: Interpolation,
+    ) -> Self {
+        self.channels.push(Channel { node, times, values, interpolation });
+        self
+    }
+
+    /// Writes the keyframes and adds the animation to the document.
+    ///
+    /// Nothing is added if the keyframes of any of the channels are
+    /// inconsistent.
+    pub fn build(
+        &self,
+        document: &mut DocumentMut,
+        buffer: &mut BufferBuilder,
+    ) -> Result<Index<json::Animation>, Error> {
+        for channel in &self.channels {
+            channel.validate()?;
+        }
+
+        let mut inputs: Vec<(&[f32], Index<json::Accessor>)> = Vec::new();
+        let mut samplers = Vec::with_capacity(self.channels.len());
+        let mut channels = Vec::with_capacity(self.channels.len());
+        for channel in &self.channels {
+            let input = match inputs.iter().find(|(times, _)| *times == channel.times) {
+                Some(&(_, input)) => input,
+                None => {
+                    let input = buffer.push(document, channel.times, None);
+                    inputs.push((channel.times, input));
+                    input
+                }
+            };
+            let output = match channel.values {
+                Values::Translations(values) => buffer.push(document, values, None),
+                Values::Rotations(values) => buffer.push(document, values, None),
+                Values::Scales(values) => buffer.push(document, values, None),
+                Values::MorphTargetWeights(values, _) => buffer.push(document, values, None),
+            };
+            channels.push(json::animation::Channel {
+                sampler: Index::new(samplers.len() as u32),
+                target: json::animation::Target {
+                    extensions: None,
+                    extras: Default::default(),
+                    #[cfg(not(feature = "KHR_animation_pointer"))]
+                    node: channel.node,
+                    #[cfg(feature = "KHR_animation_pointer")]
+                    node: Some(channel.node),
+                    path: Valid(channel.values.property()),
+                },
+                extensions: None,
+                extras: Default::default(),
+            });
+            samplers.push(json::animation::Sampler {
+                extensions: None,
+                extras: Default::default(),
+                input,
+                interpolation: Valid(channel.interpolation),
+                output,
+            });
+        }
+
+        Ok(document.add(json::Animation {
+            extensions: None,
+            extras: Default::default(),
+            channels,
+            #[cfg(feature = "names")]
+            name: self.name.clone(),
+            samplers,
+        }))
+    }
+}
@@ -0,0 +1,306 @@
+use byteorder::{LittleEndian, WriteBytesExt};
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::buffer::Target;
+use json::validation::Checked::Valid;
+use json::{Index, Value};
+
+use crate::DocumentMut;
+
+/// Represents the components of items that can be written by a
+/// [`BufferBuilder`].
+///
+/// [`BufferBuilder`]: struct.BufferBuilder.html
+pub trait Component: Copy + PartialOrd {
+    /// The corresponding accessor component type.
+    const COMPONENT_TYPE: ComponentType;
+
+    /// Appends the little-endian representation of the component.
+    fn write(self, data: &mut Vec<u8>);
+
+    /// Converts the component to a JSON number, as used by accessor bounds.
+    fn to_value(self) -> Value;
+}
+
+/// Represents items that can be written by a [`BufferBuilder`].
+///
+/// Matrices are represented as arrays of columns.
+///
+/// [`BufferBuilder`]: struct.BufferBuilder.html
+pub trait Element: Copy {
+    /// The type of the components of the item.
+    type Component: Component;
+
+    /// The corresponding accessor type.
+    const TYPE: Type;
+
+    /// Returns the component with the given index, in the order it is stored.
+    fn component(&self, index: usize) -> Self::Component;
+}
+
+/// Packs typed data into a buffer, adding a buffer view and an accessor to
+/// the document for each slice written.
+///
+/// Buffer views start at multiples of four bytes. Vertex attributes whose
+/// items are not a multiple of four bytes in size are padded, with the byte
+/// stride of the buffer view set accordingly. The bounds of each accessor are
+/// computed from the data.
+#[derive(Clone, Debug)]
+pub struct BufferBuilder {
+    /// The index of the buffer being built.
+    buffer: Index<json::Buffer>,
+
+    /// The packed data.
+    data: Vec<u8>,
+}
+
+impl BufferBuilder {
+    /// Adds an empty buffer to the document and returns a builder for its
+    /// data.
+    pub fn new(document: &mut DocumentMut) -> Self {
+        let buffer = document.add(json::Buffer {
+            byte_length: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        Self {
+            buffer,
+            data: Vec::new(),
+        }
+    }
+
+    /// Returns the index of the buffer being built.
+    pub fn index(&self) -> Index<json::Buffer> {
+        self.buffer
+    }
+
+    /// Returns the data packed so far.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Writes the items into a new buffer view and returns the accessor of the
+    /// written data.
+    pub fn push<T: Element>(
+        &mut self,
+        document: &mut DocumentMut,
+        items: &[T],
+        target: Option<Target>,
+    ) -> Index<json::Accessor> {
+        self.push_accessor(document, items, target, false)
+    }
+
+    /// Writes the items into a new buffer view and returns the normalized
+    /// accessor of the written data.
+    ///
+    /// Normalized accessors map integer components to the range `[0.0, 1.0]`
+    /// for unsigned, or `[-1.0, 1.0]` for signed components.
+    pub fn push_normalized<T: Element>(
+        &mut self,
+        document: &mut DocumentMut,
+        items: &[T],
+        target: Option<Target>,
+    ) -> Index<json::Accessor> {
+        self.push_accessor(document, items, target, true)
+    }
+
+    /// Writes raw bytes, e.g. an encoded image, into a new buffer view.
+    pub fn push_bytes(
+        &mut self,
+        document: &mut DocumentMut,
+        bytes: &[u8],
+    ) -> Index<json::buffer::View> {
+        self.align();
+        let offset = self.data.len();
+        self.data.extend_from_slice(bytes);
+        self.push_view(document, offset, None, None)
+    }
+
+    /// Sets the length of the buffer and returns its data.
+    pub fn finish(self, document: &mut DocumentMut) -> Vec<u8> {
+        if let Some(buffer) = document.get_mut(self.buffer) {
+            buffer.byte_length = self.data.len() as u32;
+        }
+        self.data
+    }
+
+    /// Pads the data with zeros to a multiple of four bytes.
+    fn align(&mut self) {
+        let mut length = self.data.len();
+        crate::binary::align_to_multiple_of_four(&mut length);
+        self.data.resize(length, 0);
+    }
+
+    /// Adds a buffer view of the data written since `offset`.
+    fn push_view(
+        &mut self,
+        document: &mut DocumentMut,
+        offset: usize,
+        stride: Option<usize>,
+        target: Option<Target>,
+    ) -> Index<json::buffer::View> {
+        document.add(json::buffer::View {
+            buffer: self.buffer,
+            byte_length: (self.data.len() - offset) as u32,
+            byte_offset: Some(offset as u32),
+            byte_stride: stride.map(|stride| stride as u32),
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Valid),
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+
+    fn push_accessor<T: Element>(
+        &mut self,
+        document: &mut DocumentMut,
+        items: &[T],
+        target: Option<Target>,
+        normalized: bool,
+    ) -> Index<json::Accessor> {
+        let multiplicity = T::TYPE.multiplicity();
+        let size = T::Component::COMPONENT_TYPE.size() * multiplicity;
+        let mut stride = size;
+        if let Some(Target::ArrayBuffer) = target {
+            crate::binary::align_to_multiple_of_four(&mut stride);
+        }
+
+        self.align();
+        let offset = self.data.len();
+        let mut min = Vec::with_capacity(multiplicity);
+        let mut max = Vec::with_capacity(multiplicity);
+        for item in items {
+            let start = self.data.len();
+            for index in 0..multiplicity {
+                let component = item.component(index);
+                component.write(&mut self.data);
+                if min.len() <= index {
+                    min.push(component);
+                    max.push(component);
+                } else if component < min[index] {
+                    min[index] = component;
+                } else if component > max[index] {
+                    max[index] = component;
+                }
+            }
+            self.data.resize(start + stride, 0);
+        }
+
+        let stride = if stride != size { Some(stride) } else { None };
+        let view = self.push_view(document, offset, stride, target);
+        let bounds = |values: Vec<T::Component>| {
+            if values.is_empty() {
+                None
+            } else {
+                Some(Value::Array(values.into_iter().map(Component::to_value).collect()))
+            }
+        };
+        document.add(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset: 0,
+            count: items.len() as u32,
+            component_type: Valid(GenericComponentType(T::Component::COMPONENT_TYPE)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Valid(T::TYPE),
+            min: bounds(min),
+            max: bounds(max),
+            #[cfg(feature = "names")]
+            name: None,
+            normalized,
+            sparse: None,
+        })
+    }
+}
+
+macro_rules! impl_component {
+    ($ty:ty, $component_type:ident, $write:expr) => {
+        impl Component for $ty {
+            const COMPONENT_TYPE: ComponentType = ComponentType::$component_type;
+
+            fn write(self, data: &mut Vec<u8>) {
+                $write(data, self).unwrap()
+            }
+
+            fn to_value(self) -> Value {
+                Value::from(self)
+            }
+        }
+
+        impl Element for $ty {
+            type Component = $ty;
+            const TYPE: Type = Type::Scalar;
+
+            fn component(&self, _: usize) -> $ty {
+                *self
+            }
+        }
+    };
+}
+
+impl_component!(i8, I8, Vec::<u8>::write_i8);
+impl_component!(u8, U8, Vec::<u8>::write_u8);
+impl_component!(i16, I16, Vec::<u8>::write_i16::<LittleEndian>);
+impl_component!(u16, U16, Vec::<u8>::write_u16::<LittleEndian>);
+impl_component!(u32, U32, Vec::<u8>::write_u32::<LittleEndian>);
+
+impl Component for f32 {
+    const COMPONENT_TYPE: ComponentType = ComponentType::F32;
+
+    fn write(self, data: &mut Vec<u8>) {
+        data.write_f32::<LittleEndian>(self).unwrap()
+    }
+
+    fn to_value(self) -> Value {
+        // Round-trip through the shortest decimal representation of the
+        // `f32` so bounds such as `0.1` aren't widened to `0.10000000149...`.
+        Value::from(self.to_string().parse::<f64>().unwrap())
+    }
+}
+
+impl Element for f32 {
+    type Component = f32;
+    const TYPE: Type = Type::Scalar;
+
+    fn component(&self, _: usize) -> f32 {
+        *self
+    }
+}
+
+macro_rules! impl_vector {
+    ($n:expr, $ty:ident) => {
+        impl<T: Component> Element for [T; $n] {
+            type Component = T;
+            const TYPE: Type = Type::$ty;
+
+            fn component(&self, index: usize) -> T {
+                self[index]
+            }
+        }
+    };
+}
+
+impl_vector!(2, Vec2);
+impl_vector!(3, Vec3);
+impl_vector!(4, Vec4);
+
+macro_rules! impl_matrix {
+    ($n:expr, $ty:ident) => {
+        impl Element for [[f32; $n]; $n] {
+            type Component = f32;
+            const TYPE: Type = Type::$ty;
+
+            fn component(&self, index: usize) -> f32 {
+                self[index / $n][index % $n]
+            }
+        }
+    };
+}
+
+impl_matrix!(2, Mat2);
+impl_matrix!(3, Mat3);
+impl_matrix!(4, Mat4);
@@ -0,0 +1,168 @@
+use json::material::{
+    AlphaCutoff, AlphaMode, EmissiveFactor, NormalTexture, OcclusionTexture, PbrBaseColorFactor,
+    StrengthFactor,
+};
+use json::validation::Checked::Valid;
+use json::Index;
+
+use crate::DocumentMut;
+
+/// Builds a PBR metallic-roughness material.
+///
+/// Extensions set on the material are added to the `extensionsUsed` list of
+/// the document when the material is built.
+#[derive(Clone, Debug, Default)]
+pub struct MaterialBuilder {
+    json: json::Material,
+}
+
+impl MaterialBuilder {
+    /// Constructs a material with default properties, i.e. an opaque,
+    /// single-sided, fully metallic and fully rough white material.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the material.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.json.name = Some(name.into());
+        self
+    }
+
+    /// Sets the linear RGBA base color factor.
+    pub fn base_color_factor(mut self, factor: [f32; 4]) -> Self {
+        self.json.pbr_metallic_roughness.base_color_factor = PbrBaseColorFactor(factor);
+        self
+    }
+
+    /// Sets the base color texture and the set index of its `TEXCOORD`
+    /// attribute.
+    pub fn base_color_texture(mut self, texture: Index<json::Texture>, tex_coord: u32) -> Self {
+        self.json.pbr_metallic_roughness.base_color_texture = Some(info(texture, tex_coord));
+        self
+    }
+
+    /// Sets the metalness factor.
+    pub fn metallic_factor(mut self, factor: f32) -> Self {
+        self.json.pbr_metallic_roughness.metallic_factor = StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the roughness factor.
+    pub fn roughness_factor(mut self, factor: f32) -> Self {
+        self.json.pbr_metallic_roughness.roughness_factor = StrengthFactor(factor);
+        self
+    }
+
+    /// Sets the metallic-roughness texture and the set index of its
+    /// `TEXCOORD` attribute.
+    pub fn metallic_roughness_texture(
+        mut self,
+        texture: Index<json::Texture>,
+        tex_coord: u32,
+    ) -> Self {
+        self.json.pbr_metallic_roughness.metallic_roughness_texture =
+            Some(info(texture, tex_coord));
+        self
+    }
+
+    /// Sets the tangent space normal texture, the set index of its `TEXCOORD`
+    /// attribute, and the scalar multiplier applied to its normal vectors.
+    pub fn normal_texture(
+        mut self,
+        texture: Index<json::Texture>,
+        tex_coord: u32,
+        scale: f32,
+    ) -> Self {
+        self.json.normal_texture = Some(NormalTexture {
+            index: texture,
+            scale,
+            tex_coord,
+            extensions: None,
+            extras: Default::default(),
+        });
+        self
+    }
+
+    /// Sets the occlusion texture, the set index of its `TEXCOORD` attribute,
+    /// and the amount of occlusion applied.
+    pub fn occlusion_texture(
+        mut self,
+        texture: Index<json::Texture>,
+        tex_coord: u32,
+        strength: f32,
+    ) -> Self {
+        self.json.occlusion_texture = Some(OcclusionTexture {
+            index: texture,
+            strength: StrengthFactor(strength),
+            tex_coord,
+            extensions: None,
+            extras: Default::default(),
+        });
+        self
+    }
+
+    /// Sets the linear RGB emissive factor.
+    pub fn emissive_factor(mut self, factor: [f32; 3]) -> Self {
+        self.json.emissive_factor = EmissiveFactor(factor);
+        self
+    }
+
+    /// Sets the emissive texture and the set index of its `TEXCOORD`
+    /// attribute.
+    pub fn emissive_texture(mut self, texture: Index<json::Texture>, tex_coord: u32) -> Self {
+        self.json.emissive_texture = Some(info(texture, tex_coord));
+        self
+    }
+
+    /// Sets the alpha rendering mode.
+    pub fn alpha_mode(mut self, mode: AlphaMode) -> Self {
+        self.json.alpha_mode = Valid(mode);
+        self
+    }
+
+    /// Sets the alpha cutoff value, selecting the `Mask` alpha rendering mode.
+    pub fn alpha_cutoff(mut self, cutoff: f32) -> Self {
+        self.json.alpha_mode = Valid(AlphaMode::Mask);
+        self.json.alpha_cutoff = Some(AlphaCutoff(cutoff));
+        self
+    }
+
+    /// Sets whether the material is double-sided.
+    pub fn double_sided(mut self, double_sided: bool) -> Self {
+        self.json.double_sided = double_sided;
+        self
+    }
+
+    /// Sets the extension blocks of the material.
+    pub fn extensions(mut self, extensions: json::extensions::material::Material) -> Self {
+        self.json.extensions = Some(extensions);
+        self
+    }
+
+    /// Returns the material JSON built so far, e.g. to set extension blocks
+    /// of texture references.
+    pub fn as_json_mut(&mut self) -> &mut json::Material {
+        &mut self.json
+    }
+
+    /// Adds the material to the document.
+    pub fn build(&self, document: &mut DocumentMut) -> Index<json::Material> {
+        if let Ok(value) = json::serialize::to_value(&self.json) {
+            super::use_extensions(document, &value);
+        }
+        document.add(self.json.clone())
+    }
+}
+
+/// Constructs a reference to a texture.
+fn info(texture: Index<json::Texture>, tex_coord: u32) -> json::texture::Info {
+    json::texture::Info {
+        index: texture,
+        tex_coord,
+        extensions: None,
+        extras: Default::default(),
+    }
+}
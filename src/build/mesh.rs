@@ -0,0 +1,248 @@
+use std::collections::HashMap;
+
+use json::buffer::Target;
+use json::mesh::{Mode, Semantic};
+use json::validation::Checked::Valid;
+use json::Index;
+
+use super::{BufferBuilder, Error};
+use crate::DocumentMut;
+
+/// Builds a mesh primitive from vertex data.
+///
+/// All vertex attributes must have as many items as there are vertex
+/// positions.
+#[derive(Clone, Debug)]
+pub struct PrimitiveBuilder<'a> {
+    positions: &'a [[f32; 3]],
+    normals: Option<&'a [[f32; 3]]>,
+    tangents: Option<&'a [[f32; 4]]>,
+    tex_coords: Vec<&'a [[f32; 2]]>,
+    colors: Vec<&'a [[f32; 4]]>,
+    joints: Vec<&'a [[u16; 4]]>,
+    weights: Vec<&'a [[f32; 4]]>,
+    indices: Option<&'a [u32]>,
+    material: Option<Index<json::Material>>,
+    mode: Mode,
+}
+
+/// Builds a mesh from its primitives.
+#[derive(Clone, Debug, Default)]
+pub struct MeshBuilder<'a> {
+    #[cfg(feature = "names")]
+    name: Option<String>,
+    primitives: Vec<PrimitiveBuilder<'a>>,
+    weights: Option<Vec<f32>>,
+}
+
+impl<'a> PrimitiveBuilder<'a> {
+    /// Constructs a triangle list primitive with the given vertex positions.
+    pub fn new(positions: &'a [[f32; 3]]) -> Self {
+        Self {
+            positions,
+            normals: None,
+            tangents: None,
+            tex_coords: Vec::new(),
+            colors: Vec::new(),
+            joints: Vec::new(),
+            weights: Vec::new(),
+            indices: None,
+            material: None,
+            mode: Mode::Triangles,
+        }
+    }
+
+    /// Sets the XYZ vertex normals.
+    pub fn normals(mut self, normals: &'a [[f32; 3]]) -> Self {
+        self.normals = Some(normals);
+        self
+    }
+
+    /// Sets the XYZW vertex tangents.
+    pub fn tangents(mut self, tangents: &'a [[f32; 4]]) -> Self {
+        self.tangents = Some(tangents);
+        self
+    }
+
+    /// Adds a set of UV texture co-ordinates, written as `TEXCOORD_n` in the
+    /// order added.
+    pub fn tex_coords(mut self, tex_coords: &'a [[f32; 2]]) -> Self {
+        self.tex_coords.push(tex_coords);
+        self
+    }
+
+    /// Adds a set of RGBA vertex colors, written as `COLOR_n` in the order
+    /// added.
+    pub fn colors(mut self, colors: &'a [[f32; 4]]) -> Self {
+        self.colors.push(colors);
+        self
+    }
+
+    /// Adds a set of joint indices and their weights, written as `JOINTS_n`
+    /// and `WEIGHTS_n` in the order added.
+    pub fn joints(mut self, joints: &'a [[u16; 4]], weights: &'a [[f32; 4]]) -> Self {
+        self.joints.push(joints);
+        self.weights.push(weights);
+        self
+    }
+
+    /// Sets the vertex indices.
+    ///
+    /// Indices are stored as unsigned shorts where possible, and as unsigned
+    /// integers otherwise.
+    pub fn indices(mut self, indices: &'a [u32]) -> Self {
+        self.indices = Some(indices);
+        self
+    }
+
+    /// Sets the material of the primitive.
+    pub fn material(mut self, material: Index<json::Material>) -> Self {
+        self.material = Some(material);
+        self
+    }
+
+    /// Sets the type of primitives to render.
+    pub fn mode(mut self, mode: Mode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Checks that the vertex attributes and indices are consistent.
+    pub fn validate(&self) -> Result<(), Error> {
+        let expected = self.positions.len();
+        let check = |semantic: Semantic, actual: usize| {
+            if actual == expected {
+                Ok(())
+            } else {
+                Err(Error::AttributeCount { semantic, expected, actual })
+            }
+        };
+        if let Some(normals) = self.normals {
+            check(Semantic::Normals, normals.len())?;
+        }
+        if let Some(tangents) = self.tangents {
+            check(Semantic::Tangents, tangents.len())?;
+        }
+        for (set, tex_coords) in self.tex_coords.iter().enumerate() {
+            check(Semantic::TexCoords(set as u32), tex_coords.len())?;
+        }
+        for (set, colors) in self.colors.iter().enumerate() {
+            check(Semantic::Colors(set as u32), colors.len())?;
+        }
+        for (set, joints) in self.joints.iter().enumerate() {
+            check(Semantic::Joints(set as u32), joints.len())?;
+        }
+        for (set, weights) in self.weights.iter().enumerate() {
+            check(Semantic::Weights(set as u32), weights.len())?;
+        }
+        if let Some(&index) = self.indices.unwrap_or(&[]).iter().find(|&&i| i as usize >= expected) {
+            return Err(Error::IndexOutOfBounds { index, count: expected });
+        }
+        Ok(())
+    }
+
+    /// Writes the vertex data and returns the primitive.
+    pub fn build(
+        &self,
+        document: &mut DocumentMut,
+        buffer: &mut BufferBuilder,
+    ) -> Result<json::mesh::Primitive, Error> {
+        self.validate()?;
+
+        let target = Some(Target::ArrayBuffer);
+        let mut attributes = HashMap::new();
+        let mut insert = |semantic, accessor| attributes.insert(Valid(semantic), accessor);
+        insert(Semantic::Positions, buffer.push(document, self.positions, target));
+        if let Some(normals) = self.normals {
+            insert(Semantic::Normals, buffer.push(document, normals, target));
+        }
+        if let Some(tangents) = self.tangents {
+            insert(Semantic::Tangents, buffer.push(document, tangents, target));
+        }
+        for (set, tex_coords) in self.tex_coords.iter().enumerate() {
+            insert(Semantic::TexCoords(set as u32), buffer.push(document, tex_coords, target));
+        }
+        for (set, colors) in self.colors.iter().enumerate() {
+            insert(Semantic::Colors(set as u32), buffer.push(document, colors, target));
+        }
+        for (set, joints) in self.joints.iter().enumerate() {
+            insert(Semantic::Joints(set as u32), buffer.push(document, joints, target));
+        }
+        for (set, weights) in self.weights.iter().enumerate() {
+            insert(Semantic::Weights(set as u32), buffer.push(document, weights, target));
+        }
+
+        let target = Some(Target::ElementArrayBuffer);
+        let indices = self.indices.map(|indices| {
+            if self.positions.len() <= u16::MAX as usize {
+                let indices = indices.iter().map(|&i| i as u16).collect::<Vec<_>>();
+                buffer.push(document, &indices, target)
+            } else {
+                buffer.push(document, indices, target)
+            }
+        });
+
+        Ok(json::mesh::Primitive {
+            attributes,
+            extensions: None,
+            extras: Default::default(),
+            indices,
+            material: self.material,
+            mode: Valid(self.mode),
+            targets: None,
+        })
+    }
+}
+
+impl<'a> MeshBuilder<'a> {
+    /// Constructs a mesh without primitives.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the mesh.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds a primitive.
+    pub fn primitive(mut self, primitive: PrimitiveBuilder<'a>) -> Self {
+        self.primitives.push(primitive);
+        self
+    }
+
+    /// Sets the default morph target weights.
+    pub fn weights(mut self, weights: Vec<f32>) -> Self {
+        self.weights = Some(weights);
+        self
+    }
+
+    /// Writes the vertex data of all primitives and adds the mesh to the
+    /// document.
+    ///
+    /// Nothing is added if any of the primitives fails validation.
+    pub fn build(
+        &self,
+        document: &mut DocumentMut,
+        buffer: &mut BufferBuilder,
+    ) -> Result<Index<json::Mesh>, Error> {
+        for primitive in &self.primitives {
+            primitive.validate()?;
+        }
+        let mut primitives = Vec::with_capacity(self.primitives.len());
+        for primitive in &self.primitives {
+            primitives.push(primitive.build(document, buffer)?);
+        }
+        Ok(document.add(json::Mesh {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: self.name.clone(),
+            primitives,
+            weights: self.weights.clone(),
+        }))
+    }
+}
@@ -0,0 +1,153 @@
+//! # Basic usage
+//!
+//! Building a triangle and exporting it as binary glTF.
+//!
+//! ```
+//! # fn run() -> Result<(), gltf::Error> {
+//! use gltf::build::BufferBuilder;
+//! use gltf::json::buffer::Target;
+//!
+//! let mut document = gltf::DocumentMut::new();
+//! let mut buffer = BufferBuilder::new(&mut document);
+//! let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0f32]];
+//! let accessor = buffer.push(&mut document, &positions, Some(Target::ArrayBuffer));
+//! let data = buffer.finish(&mut document);
+//! assert_eq!(document.get(accessor).unwrap().count, 3);
+//!
+//! let glb = gltf::export::Export::from_json(document.into_json(), &[data]).to_vec()?;
+//! # let _ = glb;
+//! # Ok(())
+//! # }
+//! # fn main() {
+//! #    let _ = run().expect("runtime error");
+//! # }
+//! ```
+
+use std::fmt;
+
+use json::mesh::Semantic;
+use json::Value;
+
+use crate::DocumentMut;
+
+/// Keyframe animations.
+mod animation;
+
+/// Packing of buffer data.
+mod buffer;
+
+/// Materials.
+mod material;
+
+/// Meshes and their primitives.
+mod mesh;
+
+/// Nodes and scenes.
+mod scene;
+
+/// Skins.
+mod skin;
+
+pub use self::animation::AnimationBuilder;
+pub use self::buffer::{BufferBuilder, Component, Element};
+pub use self::material::MaterialBuilder;
+pub use self::mesh::{MeshBuilder, PrimitiveBuilder};
+pub use self::scene::{NodeBuilder, SceneBuilder};
+pub use self::skin::SkinBuilder;
+
+/// Represents an error encountered while building glTF objects.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The number of items of a vertex attribute does not match the number of
+    /// vertex positions.
+    AttributeCount {
+        /// The semantic of the offending attribute.
+        semantic: Semantic,
+
+        /// The number of vertex positions.
+        expected: usize,
+
+        /// The number of items of the attribute.
+        actual: usize,
+    },
+
+    /// A vertex index refers to a vertex that does not exist.
+    IndexOutOfBounds {
+        /// The offending index.
+        index: u32,
+
+        /// The number of vertices.
+        count: usize,
+    },
+
+    /// The number of keyframe values does not match the number of keyframe
+    /// times.
+    KeyframeCount {
+        /// The number of values required by the keyframe times.
+        expected: usize,
+
+        /// The number of values given.
+        actual: usize,
+    },
+
+    /// Keyframe times are missing, negative, or not strictly increasing.
+    KeyframeTimes,
+
+    /// The bind-pose transform of a joint is not invertible.
+    SingularBindPose {
+        /// The index of the offending joint in the joints of the skin.
+        joint: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::AttributeCount { ref semantic, expected, actual } => write!(
+                f,
+                "attribute {:?}: expected {} items but received {} items",
+                semantic, expected, actual,
+            ),
+            Error::IndexOutOfBounds { index, count } => {
+                write!(f, "index {} out of bounds for {} vertices", index, count)
+            }
+            Error::KeyframeCount { expected, actual } => write!(
+                f,
+                "expected {} keyframe values but received {} values",
+                expected, actual,
+            ),
+            Error::KeyframeTimes => write!(f, "invalid keyframe times"),
+            Error::SingularBindPose { joint } => {
+                write!(f, "joint {}: bind pose is not invertible", joint)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Adds the names of the extensions used by an object, given as JSON, to the
+/// `extensionsUsed` list of the document.
+fn use_extensions(document: &mut DocumentMut, value: &Value) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(extensions)) = object.get("extensions") {
+                let used = &mut document.as_json_mut().extensions_used;
+                for name in extensions.keys() {
+                    if !used.contains(name) {
+                        used.push(name.clone());
+                    }
+                }
+            }
+            for value in object.values() {
+                use_extensions(document, value);
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                use_extensions(document, value);
+            }
+        }
+        _ => {}
+    }
+}
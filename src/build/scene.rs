@@ -0,0 +1,177 @@
+use json::scene::UnitQuaternion;
+use json::Index;
+
+use crate::scene::Transform;
+use crate::DocumentMut;
+
+/// Builds a node of the scene graph.
+///
+/// Hierarchies can be built bottom-up by adding the indices of built nodes as
+/// children, or top-down by setting the parent of each node before building
+/// it. Either way, the index of each node is known as soon as it is built and
+/// does not change as further nodes are added.
+#[derive(Clone, Debug, Default)]
+pub struct NodeBuilder {
+    json: json::Node,
+    parent: Option<Index<json::Node>>,
+}
+
+/// Builds a scene from its root nodes.
+#[derive(Clone, Debug, Default)]
+pub struct SceneBuilder {
+    #[cfg(feature = "names")]
+    name: Option<String>,
+    nodes: Vec<Index<json::Node>>,
+}
+
+impl NodeBuilder {
+    /// Constructs a node with the identity transform.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the node.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.json.name = Some(name.into());
+        self
+    }
+
+    /// Sets the translation, replacing any transformation matrix.
+    pub fn translation(mut self, translation: [f32; 3]) -> Self {
+        self.json.matrix = None;
+        self.json.translation = Some(translation);
+        self
+    }
+
+    /// Sets the rotation as an XYZW quaternion, replacing any transformation
+    /// matrix.
+    pub fn rotation(mut self, rotation: [f32; 4]) -> Self {
+        self.json.matrix = None;
+        self.json.rotation = Some(UnitQuaternion(rotation));
+        self
+    }
+
+    /// Sets the scale, replacing any transformation matrix.
+    pub fn scale(mut self, scale: [f32; 3]) -> Self {
+        self.json.matrix = None;
+        self.json.scale = Some(scale);
+        self
+    }
+
+    /// Sets the transformation matrix in column-major order, replacing any
+    /// translation, rotation, and scale.
+    pub fn matrix(mut self, matrix: [[f32; 4]; 4]) -> Self {
+        self.json.translation = None;
+        self.json.rotation = None;
+        self.json.scale = None;
+        let mut flat = [0.0; 16];
+        for (column, values) in matrix.iter().enumerate() {
+            flat[column * 4..column * 4 + 4].copy_from_slice(values);
+        }
+        self.json.matrix = Some(flat);
+        self
+    }
+
+    /// Sets the transform.
+    pub fn transform(self, transform: Transform) -> Self {
+        match transform {
+            Transform::Matrix { matrix } => self.matrix(matrix),
+            Transform::Decomposed { translation, rotation, scale } => {
+                self.translation(translation).rotation(rotation).scale(scale)
+            }
+        }
+    }
+
+    /// Attaches a mesh.
+    pub fn mesh(mut self, mesh: Index<json::Mesh>) -> Self {
+        self.json.mesh = Some(mesh);
+        self
+    }
+
+    /// Attaches a camera.
+    pub fn camera(mut self, camera: Index<json::Camera>) -> Self {
+        self.json.camera = Some(camera);
+        self
+    }
+
+    /// Attaches a skin, used to deform the attached mesh.
+    pub fn skin(mut self, skin: Index<json::Skin>) -> Self {
+        self.json.skin = Some(skin);
+        self
+    }
+
+    /// Sets the morph target weights of the attached mesh.
+    pub fn weights(mut self, weights: Vec<f32>) -> Self {
+        self.json.weights = Some(weights);
+        self
+    }
+
+    /// Adds a node that has already been built as a child.
+    pub fn child(mut self, child: Index<json::Node>) -> Self {
+        self.json.children.get_or_insert_with(Vec::new).push(child);
+        self
+    }
+
+    /// Sets the parent node, which the node is added to as a child when
+    /// built.
+    pub fn parent(mut self, parent: Index<json::Node>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// Adds the node to the document.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parent node does not exist.
+    pub fn build(&self, document: &mut DocumentMut) -> Index<json::Node> {
+        let node = document.add(self.json.clone());
+        if let Some(parent) = self.parent {
+            document
+                .get_mut(parent)
+                .expect("parent node does not exist")
+                .children
+                .get_or_insert_with(Vec::new)
+                .push(node);
+        }
+        node
+    }
+}
+
+impl SceneBuilder {
+    /// Constructs an empty scene.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the scene.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds a root node.
+    pub fn node(mut self, node: Index<json::Node>) -> Self {
+        self.nodes.push(node);
+        self
+    }
+
+    /// Adds the scene to the document.
+    ///
+    /// The scene becomes the default scene if the document has none yet.
+    pub fn build(&self, document: &mut DocumentMut) -> Index<json::Scene> {
+        let scene = document.add(json::Scene {
+            extensions: None,
+            extras: Default::default(),
+            #[cfg(feature = "names")]
+            name: self.name.clone(),
+            nodes: self.nodes.clone(),
+        });
+        document.as_json_mut().scene.get_or_insert(scene);
+        scene
+    }
+}
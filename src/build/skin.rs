@@ -0,0 +1,75 @@
+use json::Index;
+
+use super::{BufferBuilder, Error};
+use crate::math::Matrix4;
+use crate::DocumentMut;
+
+/// Builds a skin from its joints and their bind-pose transforms.
+///
+/// The inverse-bind matrices of the skin are computed from the world
+/// transforms of the joints in the bind pose.
+#[derive(Clone, Debug, Default)]
+pub struct SkinBuilder {
+    #[cfg(feature = "names")]
+    name: Option<String>,
+    joints: Vec<Index<json::Node>>,
+    bind_poses: Vec<[[f32; 4]; 4]>,
+    skeleton: Option<Index<json::Node>>,
+}
+
+impl SkinBuilder {
+    /// Constructs a skin without joints.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user-defined name of the skin.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Adds a joint, given its world transform in the bind pose as a
+    /// column-major matrix.
+    pub fn joint(mut self, node: Index<json::Node>, bind_pose: [[f32; 4]; 4]) -> Self {
+        self.joints.push(node);
+        self.bind_poses.push(bind_pose);
+        self
+    }
+
+    /// Sets the node used as the skeleton root.
+    pub fn skeleton(mut self, node: Index<json::Node>) -> Self {
+        self.skeleton = Some(node);
+        self
+    }
+
+    /// Writes the inverse-bind matrices and adds the skin to the document.
+    ///
+    /// Nothing is added if the bind pose of any of the joints is not
+    /// invertible.
+    pub fn build(
+        &self,
+        document: &mut DocumentMut,
+        buffer: &mut BufferBuilder,
+    ) -> Result<Index<json::Skin>, Error> {
+        let mut inverse_bind_matrices = Vec::with_capacity(self.bind_poses.len());
+        for (joint, bind_pose) in self.bind_poses.iter().enumerate() {
+            let inverse = Matrix4::from_array(*bind_pose)
+                .inverse()
+                .ok_or(Error::SingularBindPose { joint })?;
+            inverse_bind_matrices.push(inverse.as_array());
+        }
+        let inverse_bind_matrices = buffer.push(document, &inverse_bind_matrices, None);
+        Ok(document.add(json::Skin {
+            extensions: None,
+            extras: Default::default(),
+            inverse_bind_matrices: Some(inverse_bind_matrices),
+            joints: self.joints.clone(),
+            #[cfg(feature = "names")]
+            name: self.name.clone(),
+            skeleton: self.skeleton,
+        }))
+    }
+}
@@ -84,6 +84,73 @@ impl<'a> Camera<'a> {
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
     }
+
+    /// Returns the view matrix of the camera in column-major order, given
+    /// the world transform of the node referencing it.
+    ///
+    /// The view matrix is the inverse of the world transform with scaling
+    /// ignored, as defined by the glTF specification. The camera looks
+    /// towards the local -Z axis with +Y up.
+    pub fn view_matrix(&self, world_transform: [[f32; 4]; 4]) -> [[f32; 4]; 4] {
+        let [x, y, z, t] = world_transform;
+        let mut rotation = [[x[0], x[1], x[2]], [y[0], y[1], y[2]], [z[0], z[1], z[2]]];
+        for axis in &mut rotation {
+            let length = axis.iter().map(|value| value * value).sum::<f32>().sqrt();
+            if length > 0.0 {
+                axis.iter_mut().for_each(|value| *value /= length);
+            }
+        }
+        // The inverse of a rotation is its transpose.
+        let mut view = [[0.0; 4]; 4];
+        view[3][3] = 1.0;
+        for (row, axis) in rotation.iter().enumerate() {
+            for (column, value) in axis.iter().enumerate() {
+                view[column][row] = *value;
+            }
+            view[3][row] = -(axis[0] * t[0] + axis[1] * t[1] + axis[2] * t[2]);
+        }
+        view
+    }
+
+    /// Returns the six planes of the view frustum of the camera in world
+    /// space, given the world transform of the node referencing it.
+    ///
+    /// `aspect_fallback` is used as with [`Perspective::matrix`]. The planes
+    /// are `[a, b, c, d]` such that `a * x + b * y + c * z + d` is the signed
+    /// distance of a point from the plane, positive inside the frustum. They
+    /// are in the order left, right, bottom, top, near, far. The far plane of
+    /// a perspective camera without `zfar` has a zero normal and a positive
+    /// distance, so that every point is inside it.
+    ///
+    /// [`Perspective::matrix`]: struct.Perspective.html#method.matrix
+    pub fn frustum(&self, world_transform: [[f32; 4]; 4], aspect_fallback: f32) -> [[f32; 4]; 6] {
+        let projection = match self.projection() {
+            Projection::Orthographic(orthographic) => orthographic.matrix(),
+            Projection::Perspective(perspective) => perspective.matrix(aspect_fallback),
+        };
+        let view = self.view_matrix(world_transform);
+        let mut clip = [[0.0; 4]; 4];
+        for (column, view) in clip.iter_mut().zip(&view) {
+            for (row, value) in column.iter_mut().enumerate() {
+                *value = (0..4).map(|k| projection[k][row] * view[k]).sum();
+            }
+        }
+        let row = |i: usize| [clip[0][i], clip[1][i], clip[2][i], clip[3][i]];
+        let w = row(3);
+        let mut planes = [[0.0; 4]; 6];
+        for (i, plane) in planes.iter_mut().enumerate() {
+            let axis = row(i / 2);
+            let sign = if i % 2 == 0 { 1.0 } else { -1.0 };
+            for (value, (w, axis)) in plane.iter_mut().zip(w.iter().zip(&axis)) {
+                *value = w + sign * axis;
+            }
+            let length = plane[..3].iter().map(|value| value * value).sum::<f32>().sqrt();
+            if length > 0.0 {
+                plane.iter_mut().for_each(|value| *value /= length);
+            }
+        }
+        planes
+    }
 }
 
 impl<'a> Orthographic<'a> {
@@ -115,6 +182,19 @@ impl<'a> Orthographic<'a> {
         self.json.znear
     }
 
+    /// Returns the projection matrix in column-major order, as defined by
+    /// the glTF specification.
+    pub fn matrix(&self) -> [[f32; 4]; 4] {
+        let (r, t) = (self.xmag(), self.ymag());
+        let (n, f) = (self.znear(), self.zfar());
+        [
+            [1.0 / r, 0.0, 0.0, 0.0],
+            [0.0, 1.0 / t, 0.0, 0.0],
+            [0.0, 0.0, 2.0 / (n - f), 0.0],
+            [0.0, 0.0, (f + n) / (n - f), 1.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
@@ -150,6 +230,28 @@ impl<'a> Perspective<'a> {
         self.json.znear
     }
 
+    /// Returns the projection matrix in column-major order, as defined by
+    /// the glTF specification.
+    ///
+    /// `aspect_fallback` is used if the camera does not define an aspect
+    /// ratio, and is usually that of the viewport. The far clipping plane is
+    /// at infinity if the camera does not define one.
+    pub fn matrix(&self, aspect_fallback: f32) -> [[f32; 4]; 4] {
+        let a = self.aspect_ratio().unwrap_or(aspect_fallback);
+        let y = (0.5 * self.yfov()).tan();
+        let n = self.znear();
+        let (z, w) = match self.zfar() {
+            Some(f) => ((f + n) / (n - f), 2.0 * f * n / (n - f)),
+            None => (-1.0, -2.0 * n),
+        };
+        [
+            [1.0 / (a * y), 0.0, 0.0, 0.0],
+            [0.0, 1.0 / y, 0.0, 0.0],
+            [0.0, 0.0, z, -1.0],
+            [0.0, 0.0, w, 0.0],
+        ]
+    }
+
     ///  Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
         &self.json.extras
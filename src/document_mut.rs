@@ -0,0 +1,328 @@
+use std::cmp::Ordering;
+
+use json::Index;
+
+use crate::{Document, Result};
+
+/// Owned, mutable glTF JSON wrapper.
+///
+/// Unlike `Document`, which only provides read-only views, `DocumentMut` allows
+/// objects to be added, edited, and removed. When an object is removed, the
+/// indices of the objects following it are shifted down by one and every
+/// reference to them is updated accordingly, while references to the removed
+/// object itself are dropped.
+///
+/// Only references made by the core glTF specification are updated on removal.
+/// References made by extensions are left as they are.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let mut document = gltf::DocumentMut::new();
+/// let mesh = document.add(gltf::json::Mesh::default());
+/// let node = document.add(gltf::json::Node {
+///     mesh: Some(mesh),
+///     ..Default::default()
+/// });
+/// document.get_mut(node).unwrap().translation = Some([1.0, 2.0, 3.0]);
+/// document.remove_mesh(mesh);
+/// assert!(document.get(node).unwrap().mesh.is_none());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     let _ = run().expect("runtime error");
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct DocumentMut(json::Root);
+
+impl DocumentMut {
+    /// Creates an empty glTF 2.0 document.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps pre-deserialized JSON without performing validation checks.
+    pub fn from_json(json: json::Root) -> Self {
+        DocumentMut(json)
+    }
+
+    /// Returns the underlying glTF JSON.
+    pub fn as_json(&self) -> &json::Root {
+        &self.0
+    }
+
+    /// Returns the underlying glTF JSON for direct modification.
+    ///
+    /// No index fix-ups are performed for changes made through this reference.
+    pub fn as_json_mut(&mut self) -> &mut json::Root {
+        &mut self.0
+    }
+
+    /// Unwraps the glTF JSON.
+    pub fn into_json(self) -> json::Root {
+        self.0
+    }
+
+    /// Converts into a read-only `Document`, performing validation checks.
+    pub fn into_document(self) -> Result<Document> {
+        Document::from_json(self.0)
+    }
+
+    /// Converts into a read-only `Document` without performing validation
+    /// checks.
+    pub fn into_document_without_validation(self) -> Document {
+        Document::from_json_without_validation(self.0)
+    }
+
+    /// Returns a top-level object.
+    pub fn get<T>(&self, index: Index<T>) -> Option<&T>
+    where
+        json::Root: json::root::Get<T>,
+    {
+        self.0.get(index)
+    }
+
+    /// Returns a top-level object for modification.
+    pub fn get_mut<T>(&mut self, index: Index<T>) -> Option<&mut T>
+    where
+        json::Root: AsMut<Vec<T>>,
+    {
+        self.0.get_mut(index)
+    }
+
+    /// Appends a top-level object, returning its index.
+    pub fn add<T>(&mut self, value: T) -> Index<T>
+    where
+        json::Root: AsMut<Vec<T>>,
+    {
+        self.0.push(value)
+    }
+
+    /// Removes an accessor.
+    ///
+    /// Primitive attributes referencing the accessor are removed, as are
+    /// animation samplers along with the channels that use them. Optional
+    /// references such as primitive indices, morph targets, and inverse bind
+    /// matrices are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_accessor(&mut self, index: Index<json::Accessor>) -> json::Accessor {
+        let removed = index.value();
+        let accessor = self.0.accessors.remove(removed);
+        for mesh in &mut self.0.meshes {
+            for primitive in &mut mesh.primitives {
+                primitive.attributes.retain(|_, index| index.value() != removed);
+                for index in primitive.attributes.values_mut() {
+                    shift(index, removed);
+                }
+                shift_option(&mut primitive.indices, removed);
+                for target in primitive.targets.iter_mut().flatten() {
+                    shift_option(&mut target.positions, removed);
+                    shift_option(&mut target.normals, removed);
+                    shift_option(&mut target.tangents, removed);
+                }
+            }
+        }
+        for skin in &mut self.0.skins {
+            shift_option(&mut skin.inverse_bind_matrices, removed);
+        }
+        for animation in &mut self.0.animations {
+            for sampler in (0..animation.samplers.len()).rev() {
+                let json = &mut animation.samplers[sampler];
+                let input = shift(&mut json.input, removed);
+                let output = shift(&mut json.output, removed);
+                if !(input && output) {
+                    animation.samplers.remove(sampler);
+                    animation.channels.retain(|channel| channel.sampler.value() != sampler);
+                    for channel in &mut animation.channels {
+                        shift(&mut channel.sampler, sampler);
+                    }
+                }
+            }
+        }
+        accessor
+    }
+
+    /// Removes a material.
+    ///
+    /// Primitives referencing the material fall back to the default material.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_material(&mut self, index: Index<json::Material>) -> json::Material {
+        let removed = index.value();
+        let material = self.0.materials.remove(removed);
+        for mesh in &mut self.0.meshes {
+            for primitive in &mut mesh.primitives {
+                shift_option(&mut primitive.material, removed);
+            }
+        }
+        material
+    }
+
+    /// Removes a mesh.
+    ///
+    /// Nodes referencing the mesh no longer instantiate a mesh.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_mesh(&mut self, index: Index<json::Mesh>) -> json::Mesh {
+        let removed = index.value();
+        let mesh = self.0.meshes.remove(removed);
+        for node in &mut self.0.nodes {
+            shift_option(&mut node.mesh, removed);
+        }
+        mesh
+    }
+
+    /// Removes a node.
+    ///
+    /// The node is removed from the children of its parent and the root nodes
+    /// of scenes, and skins using it as their skeleton root no longer have one.
+    /// Animation channels targeting the node are removed. The children of the
+    /// node are not removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds, or if the node is a joint of a
+    /// skin, since removing a joint would invalidate the inverse bind matrices
+    /// and the joint indices of skinned vertices.
+    pub fn remove_node(&mut self, index: Index<json::Node>) -> json::Node {
+        let removed = index.value();
+        let is_joint = self.0.skins.iter().flat_map(|skin| &skin.joints).any(|joint| joint.value() == removed);
+        assert!(!is_joint, "node {} is a skin joint", removed);
+        let node = self.0.nodes.remove(removed);
+        for node in &mut self.0.nodes {
+            if let Some(children) = node.children.as_mut() {
+                shift_vec(children, removed);
+            }
+        }
+        for scene in &mut self.0.scenes {
+            shift_vec(&mut scene.nodes, removed);
+        }
+        for skin in &mut self.0.skins {
+            for joint in &mut skin.joints {
+                shift(joint, removed);
+            }
+            shift_option(&mut skin.skeleton, removed);
+        }
+        for animation in &mut self.0.animations {
+            #[cfg(feature = "KHR_animation_pointer")]
+            {
+                animation.channels.retain(|channel| {
+                    !matches!(channel.target.node, Some(node) if node.value() == removed)
+                });
+                for channel in &mut animation.channels {
+                    shift_option(&mut channel.target.node, removed);
+                }
+            }
+            #[cfg(not(feature = "KHR_animation_pointer"))]
+            {
+                animation.channels.retain(|channel| channel.target.node.value() != removed);
+                for channel in &mut animation.channels {
+                    shift(&mut channel.target.node, removed);
+                }
+            }
+        }
+        node
+    }
+
+    /// Removes a texture sampler.
+    ///
+    /// Textures referencing the sampler fall back to the default sampler.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_sampler(
+        &mut self,
+        index: Index<json::texture::Sampler>,
+    ) -> json::texture::Sampler {
+        let removed = index.value();
+        let sampler = self.0.samplers.remove(removed);
+        for texture in &mut self.0.textures {
+            shift_option(&mut texture.sampler, removed);
+        }
+        sampler
+    }
+
+    /// Removes a texture.
+    ///
+    /// Material texture slots referencing the texture are cleared.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn remove_texture(&mut self, index: Index<json::Texture>) -> json::Texture {
+        let removed = index.value();
+        let texture = self.0.textures.remove(removed);
+        for material in &mut self.0.materials {
+            let pbr = &mut material.pbr_metallic_roughness;
+            shift_texture(&mut pbr.base_color_texture, |info| &mut info.index, removed);
+            shift_texture(&mut pbr.metallic_roughness_texture, |info| &mut info.index, removed);
+            shift_texture(&mut material.normal_texture, |info| &mut info.index, removed);
+            shift_texture(&mut material.occlusion_texture, |info| &mut info.index, removed);
+            shift_texture(&mut material.emissive_texture, |info| &mut info.index, removed);
+        }
+        texture
+    }
+}
+
+impl From<Document> for DocumentMut {
+    fn from(document: Document) -> Self {
+        DocumentMut(document.into_json())
+    }
+}
+
+/// Updates an index after the object at `removed` has been removed.
+///
+/// Returns `false` if the index referred to the removed object.
+fn shift<T>(index: &mut Index<T>, removed: usize) -> bool {
+    match index.value().cmp(&removed) {
+        Ordering::Less => true,
+        Ordering::Equal => false,
+        Ordering::Greater => {
+            *index = Index::new(index.value() as u32 - 1);
+            true
+        }
+    }
+}
+
+/// Updates an optional index, clearing it if it referred to the removed
+/// object.
+fn shift_option<T>(index: &mut Option<Index<T>>, removed: usize) {
+    if let Some(value) = index.as_mut() {
+        if !shift(value, removed) {
+            *index = None;
+        }
+    }
+}
+
+/// Updates a list of indices, dropping those that referred to the removed
+/// object.
+fn shift_vec<T>(indices: &mut Vec<Index<T>>, removed: usize) {
+    indices.retain(|index| index.value() != removed);
+    for index in indices {
+        shift(index, removed);
+    }
+}
+
+/// Updates the texture index of an optional texture reference, clearing the
+/// reference if it referred to the removed texture.
+fn shift_texture<T>(
+    slot: &mut Option<T>,
+    index: fn(&mut T) -> &mut Index<json::Texture>,
+    removed: usize,
+) {
+    if let Some(info) = slot.as_mut() {
+        if !shift(index(info), removed) {
+            *slot = None;
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::borrow::Cow;
+
+use image_crate::codecs::jpeg::JpegEncoder;
+use image_crate::codecs::png::CompressionType;
+use image_crate::imageops::FilterType;
+use image_crate::{ColorType, DynamicImage, GenericImageView};
+
+use super::{encode_png, EncodedImage};
+use crate::{image, Result};
+
+/// Options for re-encoding attached images on export.
+///
+/// Only PNG and JPEG images are re-encoded; images of other formats are
+/// exported as attached.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ImageEncoding {
+    /// The format to re-encode images in, or `None` to keep the format of
+    /// each image.
+    pub format: Option<ImageFormat>,
+
+    /// The quality of JPEG images, from 1 to 100.
+    pub jpeg_quality: u8,
+
+    /// The compression level of PNG images.
+    pub png_compression: PngCompression,
+
+    /// The maximum width and height of images in pixels, if any.
+    ///
+    /// Larger images are downscaled to fit, preserving their aspect ratio.
+    pub max_dimension: Option<u32>,
+}
+
+/// Specifies the format of re-encoded images.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageFormat {
+    /// PNG, `image/png`.
+    Png,
+
+    /// JPEG, `image/jpeg`. Any alpha channel is discarded.
+    Jpeg,
+}
+
+/// Specifies the compression level of re-encoded PNG images.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PngCompression {
+    /// Fast compression, as used for images attached as pixel data.
+    Fast,
+
+    /// Balanced compression.
+    #[default]
+    Default,
+
+    /// The best compression, at the cost of encoding time.
+    Best,
+}
+
+impl Default for ImageEncoding {
+    fn default() -> Self {
+        Self {
+            format: None,
+            jpeg_quality: 90,
+            png_compression: PngCompression::Default,
+            max_dimension: None,
+        }
+    }
+}
+
+/// Re-encodes an attached image with the given options.
+pub(crate) fn reencode<'a>(image: &'a EncodedImage, options: &ImageEncoding) -> Result<EncodedImage<'a>> {
+    let source = match image.mime_type {
+        "image/png" => ImageFormat::Png,
+        "image/jpeg" => ImageFormat::Jpeg,
+        _ => {
+            return Ok(EncodedImage {
+                data: Cow::Borrowed(&image.data),
+                mime_type: image.mime_type,
+            })
+        }
+    };
+    let mut decoded = image_crate::load_from_memory(&image.data)?;
+    if let Some(max) = options.max_dimension {
+        if decoded.width() > max || decoded.height() > max {
+            decoded = decoded.resize(max, max, FilterType::Triangle);
+        }
+    }
+
+    let (width, height) = (decoded.width(), decoded.height());
+    let mut data = Vec::new();
+    let mime_type = match options.format.unwrap_or(source) {
+        ImageFormat::Png => {
+            let compression = match options.png_compression {
+                PngCompression::Fast => CompressionType::Fast,
+                PngCompression::Default => CompressionType::Default,
+                PngCompression::Best => CompressionType::Best,
+            };
+            let decoded = image::Data::new(decoded);
+            data = encode_png(&decoded.pixels, width, height, decoded.format, compression)?;
+            "image/png"
+        }
+        ImageFormat::Jpeg => {
+            let mut encoder = JpegEncoder::new_with_quality(&mut data, options.jpeg_quality.clamp(1, 100));
+            match decoded {
+                DynamicImage::ImageLuma8(pixels) => encoder.encode(&pixels, width, height, ColorType::L8)?,
+                decoded => encoder.encode(&decoded.to_rgb8(), width, height, ColorType::Rgb8)?,
+            }
+            "image/jpeg"
+        }
+    };
+    Ok(EncodedImage {
+        data: Cow::Owned(data),
+        mime_type,
+    })
+}
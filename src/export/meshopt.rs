@@ -0,0 +1,736 @@
+use std::collections::HashMap;
+use std::f32::consts::SQRT_2;
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::animation::Property;
+use json::extensions::buffer::ext_meshopt_compression as ext;
+use json::mesh::Semantic;
+use json::validation::Checked::Valid;
+use json::Index;
+
+use super::pad;
+use crate::binary;
+use crate::build::Component;
+
+/// The header byte of encoded vertex data.
+const VERTEX_HEADER: u8 = 0xa0;
+
+/// The header byte of encoded index sequences.
+const SEQUENCE_HEADER: u8 = 0xd1;
+
+/// The maximum size in bytes of the vertices of a block.
+const VERTEX_BLOCK_SIZE_BYTES: usize = 8192;
+
+/// The maximum number of vertices of a block.
+const VERTEX_BLOCK_MAX_SIZE: usize = 256;
+
+/// The number of bytes encoded together.
+const BYTE_GROUP_SIZE: usize = 16;
+
+/// The minimum size of the tail of encoded vertex data.
+const TAIL_MAX_SIZE: usize = 32;
+
+/// A filter applied to floating point data before compression.
+///
+/// Filters are lossy, trading precision for better compression.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Filter {
+    /// No filter. The data is compressed losslessly.
+    #[default]
+    None,
+
+    /// Octahedral encoding of unit vectors, with the given number of bits per
+    /// component from 2 to 16. Filtered data is stored as normalized bytes
+    /// or shorts, which requires `KHR_mesh_quantization`.
+    Octahedral(u8),
+
+    /// Encoding of unit quaternions by their three smallest components, with
+    /// the given number of bits per component from 4 to 16. Filtered data is
+    /// stored as normalized shorts.
+    Quaternion(u8),
+
+    /// Encoding of floating point values by a mantissa with the given number
+    /// of bits from 1 to 24 and an exponent shared by the components of each
+    /// element. Filtered data is stored as floats.
+    Exponential(u8),
+}
+
+/// Options for compression with `EXT_meshopt_compression`.
+///
+/// Vertex attributes, keyframes, and other data of accessors are compressed
+/// losslessly, and vertex indices are compressed as index sequences. Filters
+/// are applied on top to buffer views holding the data of a single floating
+/// point accessor.
+///
+/// The octahedral and quaternion filters assume unit vectors and unit
+/// quaternions respectively, so they are only applied to the data selected
+/// by `normals` and `rotations`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    /// The filter applied to vertex normals and tangents, either
+    /// `Octahedral` or `Exponential`.
+    pub normals: Filter,
+
+    /// The filter applied to rotation keyframes, either `Quaternion` or
+    /// `Exponential`.
+    pub rotations: Filter,
+
+    /// The filter applied to other vertex attributes and keyframe values,
+    /// which must be `Exponential` if any.
+    pub other: Filter,
+}
+
+/// Describes how the data of a buffer view is compressed.
+#[derive(Clone, Copy, Debug)]
+enum Plan {
+    /// The data is copied uncompressed.
+    Copy,
+
+    /// The data is compressed as vertex attributes with the given stride.
+    Attributes(usize),
+
+    /// The data is compressed as indices of the given size.
+    Indices(usize),
+
+    /// The data of the given accessor is filtered and compressed as vertex
+    /// attributes.
+    Filtered(usize, Filter),
+}
+
+/// A compressed buffer view.
+#[derive(Clone, Debug)]
+struct Encoded {
+    data: Vec<u8>,
+    count: usize,
+    stride: usize,
+    padded: bool,
+    mode: ext::Mode,
+    filter: ext::Filter,
+}
+
+impl Filter {
+    /// Returns `true` if the number of bits is in the range allowed by the
+    /// filter.
+    fn is_valid(&self) -> bool {
+        match *self {
+            Filter::None => true,
+            Filter::Octahedral(bits) => (2..=16).contains(&bits),
+            Filter::Quaternion(bits) => (4..=16).contains(&bits),
+            Filter::Exponential(bits) => (1..=24).contains(&bits),
+        }
+    }
+
+    /// Returns the stride, component type, and normalization of the filtered
+    /// data of an accessor, or `None` if the filter does not suit it.
+    fn output(&self, accessor: &json::Accessor) -> Option<(usize, ComponentType, bool)> {
+        let type_ = match (&accessor.component_type, &accessor.type_) {
+            (&Valid(GenericComponentType(ComponentType::F32)), &Valid(type_)) => type_,
+            _ => return None,
+        };
+        if accessor.normalized || accessor.sparse.is_some() {
+            return None;
+        }
+        match (*self, type_) {
+            (Filter::Octahedral(bits), Type::Vec3) | (Filter::Octahedral(bits), Type::Vec4) => {
+                if bits <= 8 {
+                    Some((4, ComponentType::I8, true))
+                } else {
+                    Some((8, ComponentType::I16, true))
+                }
+            }
+            (Filter::Quaternion(_), Type::Vec4) => Some((8, ComponentType::I16, true)),
+            (Filter::Exponential(_), _) => Some((4 * type_.multiplicity(), ComponentType::F32, false)),
+            _ => None,
+        }
+    }
+
+    /// Returns the filter as it appears in the JSON.
+    fn to_json(self) -> ext::Filter {
+        match self {
+            Filter::None => ext::Filter::None,
+            Filter::Octahedral(_) => ext::Filter::Octahedral,
+            Filter::Quaternion(_) => ext::Filter::Quaternion,
+            Filter::Exponential(_) => ext::Filter::Exponential,
+        }
+    }
+}
+
+impl Options {
+    /// Returns `true` if the number of bits of all filters is in range.
+    pub(crate) fn is_valid(&self) -> bool {
+        self.normals.is_valid() && self.rotations.is_valid() && self.other.is_valid()
+    }
+}
+
+/// Compresses the buffer views of a document whose data has been merged into
+/// its only buffer, returning the new data of the buffer.
+///
+/// The compressed buffer views are moved to a fallback buffer without data,
+/// describing their layout after decompression. Buffer views that were
+/// already compressed keep their compressed data.
+pub(crate) fn compress(json: &mut json::Root, bin: &[u8], options: &Options) -> Vec<u8> {
+    let plans = plan(json, options);
+    let fallback_buffer = Index::new(json.buffers.len() as u32);
+    let mut data = Vec::with_capacity(bin.len());
+    let mut fallback_length = 0usize;
+    let mut compressed = false;
+    let mut quantized = false;
+
+    for (index, plan) in plans.into_iter().enumerate() {
+        if copy_compressed(&mut json.buffer_views[index], bin, &mut data) {
+            continue;
+        }
+        let view = &json.buffer_views[index];
+        let start = view.byte_offset.unwrap_or(0) as usize;
+        let source = match bin.get(start..start + view.byte_length as usize) {
+            Some(source) if view.buffer.value() == 0 => source,
+            _ => continue,
+        };
+        let encoded = match plan {
+            Plan::Copy => None,
+            Plan::Attributes(stride) => Some(Encoded {
+                data: encode_vertices(source, stride),
+                count: source.len() / stride,
+                stride,
+                padded: false,
+                mode: ext::Mode::Attributes,
+                filter: ext::Filter::None,
+            }),
+            Plan::Indices(size) => Some(Encoded {
+                data: encode_indices(source, size),
+                count: source.len() / size,
+                stride: size,
+                padded: false,
+                mode: ext::Mode::Indices,
+                filter: ext::Filter::None,
+            }),
+            Plan::Filtered(accessor, filter) => {
+                quantized |= matches!(filter, Filter::Octahedral(_));
+                Some(encode_filtered(source, &mut json.accessors[accessor], filter))
+            }
+        };
+        // Unfiltered data is only compressed if that reduces its size.
+        let encoded = encoded.filter(|encoded| {
+            encoded.filter != ext::Filter::None || encoded.data.len() < source.len()
+        });
+
+        pad(&mut data);
+        let view = &mut json.buffer_views[index];
+        if let Some(encoded) = encoded {
+            let extension = ext::View {
+                buffer: Index::new(0),
+                byte_offset: data.len() as u32,
+                byte_length: encoded.data.len() as u32,
+                byte_stride: encoded.stride as u32,
+                count: encoded.count as u32,
+                mode: Valid(encoded.mode),
+                filter: Valid(encoded.filter),
+                extras: Default::default(),
+            };
+            data.extend_from_slice(&encoded.data);
+            binary::align_to_multiple_of_four(&mut fallback_length);
+            view.buffer = fallback_buffer;
+            view.byte_offset = Some(fallback_length as u32);
+            view.byte_length = (encoded.count * encoded.stride) as u32;
+            if encoded.padded {
+                view.byte_stride = Some(encoded.stride as u32);
+            }
+            view.extensions.get_or_insert_with(Default::default).ext_meshopt_compression =
+                Some(extension);
+            fallback_length += view.byte_length as usize;
+            compressed = true;
+        } else {
+            // Keep the alignment of accessors relative to the buffer.
+            data.resize(data.len() + start % 4, 0);
+            view.byte_offset = Some(data.len() as u32);
+            data.extend_from_slice(source);
+        }
+    }
+
+    json.buffers[0].byte_length = data.len() as u32;
+    if compressed {
+        json.buffers.push(json::Buffer {
+            byte_length: fallback_length as u32,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: Some(json::extensions::buffer::Buffer {
+                ext_meshopt_compression: Some(ext::Buffer {
+                    fallback: true,
+                    extras: Default::default(),
+                }),
+                #[cfg(feature = "extensions")]
+                others: Default::default(),
+            }),
+            extras: Default::default(),
+        });
+        require_extension(json, "EXT_meshopt_compression");
+    }
+    if quantized {
+        require_extension(json, "KHR_mesh_quantization");
+    }
+    data
+}
+
+/// Decides how to compress each buffer view.
+fn plan(json: &json::Root, options: &Options) -> Vec<Plan> {
+    let normals = match options.normals {
+        Filter::Octahedral(_) | Filter::Exponential(_) => options.normals,
+        _ => Filter::None,
+    };
+    let rotations = match options.rotations {
+        Filter::Quaternion(_) | Filter::Exponential(_) => options.rotations,
+        _ => Filter::None,
+    };
+    let other = match options.other {
+        Filter::Exponential(_) => options.other,
+        _ => Filter::None,
+    };
+
+    // Accessors used in several ways are not filtered.
+    let mut filters = HashMap::new();
+    let mut indices = Vec::new();
+    let mut filter = |accessor: Index<json::Accessor>, filter: Filter| {
+        let entry = filters.entry(accessor.value()).or_insert(filter);
+        if *entry != filter {
+            *entry = Filter::None;
+        }
+    };
+    for mesh in &json.meshes {
+        for primitive in &mesh.primitives {
+            for (semantic, &accessor) in &primitive.attributes {
+                match *semantic {
+                    Valid(Semantic::Normals) | Valid(Semantic::Tangents) => filter(accessor, normals),
+                    _ => filter(accessor, other),
+                }
+            }
+            for target in primitive.targets.iter().flatten() {
+                let accessors = [target.positions, target.normals, target.tangents];
+                for &accessor in accessors.iter().flatten() {
+                    filter(accessor, other);
+                }
+            }
+            if let Some(accessor) = primitive.indices {
+                filter(accessor, Filter::None);
+                indices.push(accessor.value());
+            }
+        }
+    }
+    for animation in &json.animations {
+        for channel in &animation.channels {
+            if let Some(sampler) = animation.samplers.get(channel.sampler.value()) {
+                filter(sampler.input, Filter::None);
+                match channel.target.path {
+                    Valid(Property::Rotation) => filter(sampler.output, rotations),
+                    _ => filter(sampler.output, other),
+                }
+            }
+        }
+    }
+    for skin in &json.skins {
+        if let Some(accessor) = skin.inverse_bind_matrices {
+            filter(accessor, Filter::None);
+        }
+    }
+
+    let mut users = vec![Vec::new(); json.buffer_views.len()];
+    let mut sparse = vec![false; json.buffer_views.len()];
+    for (index, accessor) in json.accessors.iter().enumerate() {
+        if let Some(view) = users.get_mut(accessor.buffer_view.map_or(usize::MAX, |view| view.value())) {
+            view.push(index);
+        }
+        if let Some(ref data) = accessor.sparse {
+            for view in &[data.indices.buffer_view, data.values.buffer_view] {
+                if let Some(sparse) = sparse.get_mut(view.value()) {
+                    *sparse = true;
+                }
+            }
+        }
+    }
+
+    let mut plans = Vec::with_capacity(json.buffer_views.len());
+    for (index, view) in json.buffer_views.iter().enumerate() {
+        let users = &users[index];
+        let length = view.byte_length as usize;
+        let sizes = users
+            .iter()
+            .map(|&accessor| element_size(&json.accessors[accessor]))
+            .collect::<Option<Vec<_>>>()
+            .filter(|sizes| sizes.windows(2).all(|pair| pair[0] == pair[1]));
+        let plan = match (sizes.as_ref().and_then(|sizes| sizes.first()), users.first()) {
+            _ if sparse[index] || length == 0 => Plan::Copy,
+            (Some(&size), _) if users.iter().all(|accessor| indices.contains(accessor)) => {
+                if (size == 2 || size == 4) && length / size * size == length {
+                    Plan::Indices(size)
+                } else {
+                    Plan::Copy
+                }
+            }
+            _ if users.iter().any(|accessor| indices.contains(accessor)) => Plan::Copy,
+            (Some(&size), Some(&accessor)) => {
+                let json = &json.accessors[accessor];
+                let filter = filters.get(&accessor).copied().unwrap_or(Filter::None);
+                let stride = view.byte_stride.map_or(size, |stride| stride as usize);
+                let packed = users.len() == 1
+                    && json.byte_offset == 0
+                    && stride == size
+                    && length == json.count as usize * size;
+                if packed && filter.output(json).is_some() {
+                    Plan::Filtered(accessor, filter)
+                } else if stride % 4 == 0 && stride <= 256 && length / stride * stride == length {
+                    Plan::Attributes(stride)
+                } else {
+                    Plan::Copy
+                }
+            }
+            _ => Plan::Copy,
+        };
+        plans.push(plan);
+    }
+    plans
+}
+
+/// Returns the size in bytes of the elements of an accessor, or `None` if
+/// its elements are padded.
+fn element_size(accessor: &json::Accessor) -> Option<usize> {
+    match (&accessor.component_type, &accessor.type_) {
+        (&Valid(GenericComponentType(component)), &Valid(type_)) => {
+            let size = component.size();
+            match type_ {
+                Type::Mat2 | Type::Mat3 if size < 4 => None,
+                _ => Some(size * type_.multiplicity()),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Copies the compressed data of a buffer view already compressed with
+/// `EXT_meshopt_compression` from the old data of the only buffer to the new
+/// one, returning `false` if the buffer view is not compressed.
+pub(crate) fn copy_compressed(view: &mut json::buffer::View, bin: &[u8], data: &mut Vec<u8>) -> bool {
+    let extension = view
+        .extensions
+        .as_mut()
+        .and_then(|extensions| extensions.ext_meshopt_compression.as_mut());
+    let extension = match extension {
+        Some(extension) if extension.buffer.value() == 0 => extension,
+        _ => return false,
+    };
+    let start = extension.byte_offset as usize;
+    let compressed = bin.get(start..start + extension.byte_length as usize).unwrap_or_default();
+    pad(data);
+    extension.byte_offset = data.len() as u32;
+    data.extend_from_slice(compressed);
+    true
+}
+
+/// Returns `true` if a buffer only provides the fallback layout of buffer
+/// views compressed with `EXT_meshopt_compression`.
+pub(crate) fn is_fallback(buffer: &json::Buffer) -> bool {
+    let extension = buffer
+        .extensions
+        .as_ref()
+        .and_then(|extensions| extensions.ext_meshopt_compression.as_ref());
+    matches!(extension, Some(extension) if extension.fallback)
+}
+
+/// Adds an extension to the used and required extensions of a document.
+pub(crate) fn require_extension(json: &mut json::Root, name: &str) {
+    for extensions in &mut [&mut json.extensions_used, &mut json.extensions_required] {
+        if !extensions.iter().any(|extension| extension == name) {
+            extensions.push(name.to_owned());
+        }
+    }
+}
+
+/// Filters and compresses the tightly packed floating point data of an
+/// accessor, updating the accessor to describe the filtered data.
+fn encode_filtered(source: &[u8], accessor: &mut json::Accessor, filter: Filter) -> Encoded {
+    let (stride, component_type, normalized) = filter.output(accessor).unwrap();
+    let components = source.len() / accessor.count as usize / 4;
+    let values = source
+        .chunks_exact(4)
+        .map(|bytes| f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        .collect::<Vec<_>>();
+    let mut filtered = Vec::with_capacity(accessor.count as usize * stride);
+    for element in values.chunks_exact(components) {
+        match filter {
+            Filter::Octahedral(bits) => {
+                let w = element.get(3).copied().unwrap_or(0.0);
+                let encoded = encode_octahedral([element[0], element[1], element[2], w], bits);
+                for &value in &encoded {
+                    if stride == 4 {
+                        filtered.push(value as i8 as u8);
+                    } else {
+                        filtered.extend_from_slice(&(value as i16).to_le_bytes());
+                    }
+                }
+            }
+            Filter::Quaternion(bits) => {
+                let quaternion = [element[0], element[1], element[2], element[3]];
+                for &value in &encode_quaternion(quaternion, bits) {
+                    filtered.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            Filter::Exponential(bits) => {
+                for value in encode_exponential(element, bits) {
+                    filtered.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+            Filter::None => unreachable!(),
+        }
+    }
+
+    accessor.component_type = Valid(GenericComponentType(component_type));
+    accessor.normalized = normalized;
+    if let Filter::Exponential(_) = filter {
+        // The bounds of the filtered values may differ slightly.
+        if accessor.min.is_some() || accessor.max.is_some() {
+            let mut min = vec![f32::INFINITY; components];
+            let mut max = vec![f32::NEG_INFINITY; components];
+            for (i, bytes) in filtered.chunks_exact(4).enumerate() {
+                let value = decode_exponential(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+                min[i % components] = min[i % components].min(value);
+                max[i % components] = max[i % components].max(value);
+            }
+            accessor.min = Some(json::Value::from(min.into_iter().map(Component::to_value).collect::<Vec<_>>()));
+            accessor.max = Some(json::Value::from(max.into_iter().map(Component::to_value).collect::<Vec<_>>()));
+        }
+    } else {
+        accessor.min = None;
+        accessor.max = None;
+    }
+
+    Encoded {
+        data: encode_vertices(&filtered, stride),
+        count: accessor.count as usize,
+        stride,
+        padded: element_size(accessor) != Some(stride),
+        mode: ext::Mode::Attributes,
+        filter: filter.to_json(),
+    }
+}
+
+/// Quantizes a value in the range [-1, 1] to a signed normalized integer
+/// with the given number of bits.
+fn quantize_snorm(value: f32, bits: u8) -> i32 {
+    let scale = ((1 << (bits - 1)) - 1) as f32;
+    let round = if value >= 0.0 { 0.5 } else { -0.5 };
+    (value.clamp(-1.0, 1.0) * scale + round) as i32
+}
+
+/// Encodes a unit vector in octahedral form, passing the fourth component
+/// through.
+fn encode_octahedral(vector: [f32; 4], bits: u8) -> [i32; 4] {
+    let [mut x, mut y, z, w] = vector;
+    let length = x.abs() + y.abs() + z.abs();
+    let scale = if length == 0.0 { 0.0 } else { 1.0 / length };
+    x *= scale;
+    y *= scale;
+    let (u, v) = if z >= 0.0 {
+        (x, y)
+    } else {
+        ((1.0 - y.abs()) * x.signum(), (1.0 - x.abs()) * y.signum())
+    };
+    [
+        quantize_snorm(u, bits),
+        quantize_snorm(v, bits),
+        quantize_snorm(1.0, bits),
+        quantize_snorm(w, bits),
+    ]
+}
+
+/// Encodes a unit quaternion by its three smallest components and the index
+/// of its largest component.
+fn encode_quaternion(quaternion: [f32; 4], bits: u8) -> [i16; 4] {
+    let largest = (1..4).fold(0, |largest, i| {
+        if quaternion[i].abs() > quaternion[largest].abs() {
+            i
+        } else {
+            largest
+        }
+    });
+    // Quaternions q and -q represent the same rotation.
+    let sign = if quaternion[largest] < 0.0 { -1.0 } else { 1.0 };
+    let component = |i: usize| quantize_snorm(quaternion[(largest + i) & 3] * SQRT_2 * sign, bits) as i16;
+    [
+        component(1),
+        component(2),
+        component(3),
+        ((quantize_snorm(1.0, bits) & !3) | largest as i32) as i16,
+    ]
+}
+
+/// Encodes floating point values by their mantissas and a shared exponent.
+fn encode_exponential(values: &[f32], bits: u8) -> impl Iterator<Item = u32> + '_ {
+    let exponent = values
+        .iter()
+        .filter(|value| value.is_finite() && **value != 0.0)
+        .map(|&value| exponent(value))
+        .fold(-100, i32::max);
+    let exponent = (exponent - (i32::from(bits) - 1)).min(127);
+    let limit = f64::from((1 << 23) - 1);
+    values.iter().map(move |&value| {
+        let scaled = f64::from(value) * 2f64.powi(-exponent);
+        let rounded = if value >= 0.0 { scaled + 0.5 } else { scaled - 0.5 };
+        let mantissa = rounded.max(-limit).min(limit) as i32;
+        (mantissa as u32 & 0x00ff_ffff) | ((exponent as u32) << 24)
+    })
+}
+
+/// Decodes a value encoded by `encode_exponential`.
+fn decode_exponential(value: u32) -> f32 {
+    let mantissa = ((value << 8) as i32) >> 8;
+    let exponent = (value as i32) >> 24;
+    (f64::from(mantissa) * 2f64.powi(exponent)) as f32
+}
+
+/// Returns the exponent `e` of a finite non-zero value such that the value is
+/// `m * 2^e` with `0.5 <= |m| < 1`.
+fn exponent(value: f32) -> i32 {
+    let biased = ((value.to_bits() >> 23) & 0xff) as i32;
+    if biased == 0 {
+        // Subnormal values are scaled to normal values first.
+        exponent(value * 2f32.powi(32)) - 32
+    } else {
+        biased - 126
+    }
+}
+
+/// Encodes vertex data with the given stride, which must be a multiple of
+/// four.
+fn encode_vertices(data: &[u8], stride: usize) -> Vec<u8> {
+    let mut encoded = vec![VERTEX_HEADER];
+    let mut last = data[..stride].to_vec();
+    let block_size = ((VERTEX_BLOCK_SIZE_BYTES / stride) & !(BYTE_GROUP_SIZE - 1)).min(VERTEX_BLOCK_MAX_SIZE);
+    for block in data.chunks(block_size * stride) {
+        encode_vertex_block(&mut encoded, block, stride, &mut last);
+    }
+    // The first vertex is repeated in the tail, which is padded to a minimum
+    // size to simplify bounds checks of decoders.
+    encoded.resize(encoded.len() + TAIL_MAX_SIZE.saturating_sub(stride), 0);
+    encoded.extend_from_slice(&data[..stride]);
+    encoded
+}
+
+/// Encodes a block of vertices as the deltas of each of their bytes to the
+/// respective byte of the previous vertex.
+fn encode_vertex_block(encoded: &mut Vec<u8>, block: &[u8], stride: usize, last: &mut [u8]) {
+    let count = block.len() / stride;
+    let padded = (count + BYTE_GROUP_SIZE - 1) & !(BYTE_GROUP_SIZE - 1);
+    for (k, &first) in last.iter().enumerate() {
+        let mut deltas = [0; VERTEX_BLOCK_MAX_SIZE];
+        let mut previous = first;
+        for (delta, vertex) in deltas.iter_mut().zip(block.chunks_exact(stride)) {
+            *delta = zigzag(vertex[k].wrapping_sub(previous));
+            previous = vertex[k];
+        }
+        encode_bytes(encoded, &deltas[..padded]);
+    }
+    last.copy_from_slice(&block[block.len() - stride..]);
+}
+
+/// Maps small signed deltas to small unsigned values.
+fn zigzag(value: u8) -> u8 {
+    ((value as i8 >> 7) as u8) ^ (value << 1)
+}
+
+/// Encodes bytes in groups, each group using the bit width that encodes it
+/// most compactly.
+fn encode_bytes(encoded: &mut Vec<u8>, bytes: &[u8]) {
+    // Two bits per group select the bit width.
+    let header = encoded.len();
+    encoded.resize(header + bytes.chunks(4 * BYTE_GROUP_SIZE).len(), 0);
+    for (i, group) in bytes.chunks_exact(BYTE_GROUP_SIZE).enumerate() {
+        let mut best = (8, BYTE_GROUP_SIZE);
+        for &bits in &[1, 2, 4] {
+            let size = measure_bytes_group(group, bits);
+            if size < best.1 {
+                best = (bits, size);
+            }
+        }
+        let log2 = match best.0 {
+            1 => 0,
+            2 => 1,
+            4 => 2,
+            _ => 3,
+        };
+        encoded[header + i / 4] |= log2 << (i % 4 * 2);
+        encode_bytes_group(encoded, group, best.0);
+    }
+}
+
+/// Returns the encoded size of a group of bytes with the given bit width,
+/// where a bit width of one denotes a group of zeros.
+fn measure_bytes_group(group: &[u8], bits: u8) -> usize {
+    match bits {
+        1 if group.iter().all(|&byte| byte == 0) => 0,
+        1 => usize::MAX,
+        8 => BYTE_GROUP_SIZE,
+        _ => {
+            let sentinel = (1 << bits) - 1;
+            let outliers = group.iter().filter(|&&byte| byte >= sentinel).count();
+            BYTE_GROUP_SIZE * bits as usize / 8 + outliers
+        }
+    }
+}
+
+/// Encodes a group of bytes with the given bit width.
+///
+/// Bytes that do not fit the bit width are replaced by a sentinel and stored
+/// in full after the group.
+fn encode_bytes_group(encoded: &mut Vec<u8>, group: &[u8], bits: u8) {
+    match bits {
+        1 => {}
+        8 => encoded.extend_from_slice(group),
+        _ => {
+            let sentinel = (1 << bits) - 1;
+            for chunk in group.chunks_exact(8 / bits as usize) {
+                let byte = chunk.iter().fold(0, |byte, &value| (byte << bits) | value.min(sentinel));
+                encoded.push(byte);
+            }
+            encoded.extend(group.iter().filter(|&&byte| byte >= sentinel));
+        }
+    }
+}
+
+/// Encodes a sequence of indices of the given size as deltas to one of two
+/// previous indices.
+fn encode_indices(data: &[u8], size: usize) -> Vec<u8> {
+    let mut encoded = vec![SEQUENCE_HEADER];
+    let mut last = [0u32; 2];
+    let mut current = 0;
+    for bytes in data.chunks_exact(size) {
+        let index = if size == 2 {
+            u32::from(u16::from_le_bytes([bytes[0], bytes[1]]))
+        } else {
+            u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+        };
+        // Switch to the other baseline when the delta grows too large to fit
+        // into a single byte.
+        if (index.wrapping_sub(last[current]) as i32).unsigned_abs() >= 30 {
+            current ^= 1;
+        }
+        let delta = index.wrapping_sub(last[current]);
+        let value = (delta << 1) ^ ((delta as i32 >> 31) as u32);
+        encode_vbyte(&mut encoded, (value << 1) | current as u32);
+        last[current] = index;
+    }
+    encoded.extend_from_slice(&[0; 4]);
+    encoded
+}
+
+/// Encodes an integer in groups of seven bits, lowest first, with the high
+/// bit of each byte marking that more groups follow.
+fn encode_vbyte(encoded: &mut Vec<u8>, mut value: u32) {
+    loop {
+        let byte = (value & 127) as u8;
+        value >>= 7;
+        if value == 0 {
+            encoded.push(byte);
+            break;
+        }
+        encoded.push(byte | 128);
+    }
+}
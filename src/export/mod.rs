@@ -0,0 +1,814 @@
+/// Compression with the `EXT_meshopt_compression` extension.
+#[cfg(feature = "EXT_meshopt_compression")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+pub mod meshopt;
+
+/// Sanitization of the names of objects.
+#[cfg(feature = "names")]
+#[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+pub mod names;
+
+/// Quantization of vertex attributes with the `KHR_mesh_quantization`
+/// extension.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub mod quantize;
+
+#[cfg(feature = "import")]
+mod encode;
+mod stream;
+mod strip;
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::{fs, io, mem, ops};
+
+use json::Index;
+
+use crate::binary::{self, Glb};
+#[cfg(feature = "import")]
+use crate::image;
+#[cfg(feature = "import")]
+use image_crate::codecs::png::{CompressionType, FilterType, PngEncoder};
+use crate::{Document, Error, Result};
+#[cfg(feature = "EXT_meshopt_compression")]
+use meshopt::is_fallback;
+
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+pub use self::encode::{ImageEncoding, ImageFormat, PngCompression};
+pub use self::stream::GlbWriter;
+
+/// Encoded image data to be written on export.
+#[derive(Clone, Debug)]
+struct EncodedImage<'a> {
+    /// The encoded image, e.g. the contents of a PNG file.
+    data: Cow<'a, [u8]>,
+
+    /// The MIME type of the encoded image.
+    mime_type: &'a str,
+}
+
+/// Destination for the files written on export.
+///
+/// Implement this trait to export to targets other than the file system, e.g.
+/// archives or object storage.
+pub trait Sink {
+    /// Writes a file, given its URI relative to the exported .gltf file.
+    fn write(&mut self, uri: &str, data: &[u8]) -> io::Result<()>;
+}
+
+/// A `Sink` that writes files into a directory of the file system.
+#[derive(Clone, Debug)]
+pub struct Directory(pub PathBuf);
+
+/// Specifies where the data of attached images is stored on export.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ImageStorage {
+    /// Images are embedded in the buffer, i.e. the `BIN` chunk of binary glTF
+    /// or the external .bin file of text glTF.
+    Buffer,
+
+    /// Images are written to external files through a `Sink`.
+    External,
+}
+
+/// Specifies how the glTF JSON is formatted on export.
+///
+/// Either way, the output is deterministic: object members are written in a
+/// fixed order, with maps such as the attributes of primitives sorted by key.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum JsonFormat {
+    /// Indented, with each member and array element on its own line.
+    Pretty,
+
+    /// Without any whitespace.
+    Minified,
+}
+
+/// Exports a glTF asset together with its buffer and image data.
+///
+/// On export to binary glTF, the data of all buffers is merged into the `BIN`
+/// chunk and buffer views are updated to point into it. Images with attached
+/// data are embedded into the `BIN` chunk as well by default.
+///
+/// On export to text glTF, the data of all buffers is merged into a single
+/// external .bin file instead, and images with attached data are written to
+/// external files by default.
+///
+/// Use `image_storage` to choose where images are stored regardless of the
+/// output format. Likewise, the JSON is minified in binary glTF and
+/// pretty-printed in text glTF unless chosen otherwise with `json_format`.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let (document, buffers, _) = gltf::import("tests/box_sparse.gltf")?;
+/// let glb = gltf::export::Export::new(&document, &buffers).to_vec()?;
+/// let (document, _, _) = gltf::import_slice(&glb)?;
+/// assert_eq!(document.buffers().count(), 1);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     let _ = run().expect("runtime error");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Export<'a> {
+    /// The glTF JSON to export.
+    json: json::Root,
+
+    /// The data of each buffer.
+    buffers: Vec<&'a [u8]>,
+
+    /// Encoded image data to write, paired with the image index.
+    images: Vec<(usize, EncodedImage<'a>)>,
+
+    /// Where image data is stored, if not the default of the output format.
+    image_storage: Option<ImageStorage>,
+
+    /// How attached images are re-encoded, if at all.
+    #[cfg(feature = "import")]
+    image_encoding: Option<ImageEncoding>,
+
+    /// How the JSON is formatted, if not the default of the output format.
+    json_format: Option<JsonFormat>,
+
+    /// Names of the extensions to remove.
+    stripped_extensions: Vec<String>,
+
+    /// The `KHR_materials_variants` variant to bake, if any.
+    #[cfg(feature = "KHR_materials_variants")]
+    material_variant: Option<u32>,
+
+    /// Options for sanitizing names, if enabled.
+    #[cfg(feature = "names")]
+    names: Option<names::Options>,
+
+    /// Options for compression with `EXT_meshopt_compression`, if enabled.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    meshopt: Option<meshopt::Options>,
+
+    /// Options for quantization with `KHR_mesh_quantization`, if enabled.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    quantize: Option<quantize::Options>,
+}
+
+impl<'a> Export<'a> {
+    /// Constructs an `Export` from a document and the data of its buffers.
+    ///
+    /// The buffer data is expected in the same order as the buffers of the
+    /// document, as returned by `import`.
+    pub fn new<B>(document: &Document, buffers: &'a [B]) -> Self
+    where
+        B: ops::Deref<Target = [u8]>,
+    {
+        Self::from_json(document.0.clone(), buffers)
+    }
+
+    /// Constructs an `Export` from glTF JSON and the data of its buffers.
+    pub fn from_json<B>(json: json::Root, buffers: &'a [B]) -> Self
+    where
+        B: ops::Deref<Target = [u8]>,
+    {
+        Self {
+            json,
+            buffers: buffers.iter().map(|data| &**data).collect(),
+            images: Vec::new(),
+            image_storage: None,
+            #[cfg(feature = "import")]
+            image_encoding: None,
+            json_format: None,
+            stripped_extensions: Vec::new(),
+            #[cfg(feature = "KHR_materials_variants")]
+            material_variant: None,
+            #[cfg(feature = "names")]
+            names: None,
+            #[cfg(feature = "EXT_meshopt_compression")]
+            meshopt: None,
+            #[cfg(feature = "KHR_mesh_quantization")]
+            quantize: None,
+        }
+    }
+
+    /// Attaches encoded image data, e.g. the contents of a PNG or JPEG file,
+    /// to the image with the given index.
+    ///
+    /// The data is embedded on export, replacing the URI or buffer view the
+    /// image previously referred to.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn image(&mut self, index: usize, data: &'a [u8], mime_type: &'a str) -> &mut Self {
+        self.attach(index, Cow::Borrowed(data), mime_type)
+    }
+
+    /// Attaches encoded image data to the image with the given index,
+    /// detecting the MIME type from the signature of the data.
+    ///
+    /// PNG and JPEG images are supported, as are WebP, KTX2, and DDS images
+    /// for use by the respective extensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    pub fn encoded_image(&mut self, index: usize, data: &'a [u8]) -> Result<&mut Self> {
+        let mime_type = mime_type(data).ok_or(Error::UnsupportedImageEncoding)?;
+        Ok(self.attach(index, Cow::Borrowed(data), mime_type))
+    }
+
+    /// Attaches raw RGBA pixel data, 8 bits per channel, to the image with the
+    /// given index. The pixels are encoded as PNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn image_rgba(
+        &mut self,
+        index: usize,
+        width: u32,
+        height: u32,
+        pixels: &[u8],
+    ) -> Result<&mut Self> {
+        let data = encode_png(pixels, width, height, image::Format::R8G8B8A8, CompressionType::Fast)?;
+        Ok(self.attach(index, Cow::Owned(data), "image/png"))
+    }
+
+    /// Attaches imported image data to the image with the given index. The
+    /// pixels are encoded as PNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn image_data(&mut self, index: usize, data: &image::Data) -> Result<&mut Self> {
+        let data = encode_png(&data.pixels, data.width, data.height, data.format, CompressionType::Fast)?;
+        Ok(self.attach(index, Cow::Owned(data), "image/png"))
+    }
+
+    /// Sets where the data of attached images is stored.
+    pub fn image_storage(&mut self, storage: ImageStorage) -> &mut Self {
+        self.image_storage = Some(storage);
+        self
+    }
+
+    /// Re-encodes attached PNG and JPEG images on export, e.g. to export the
+    /// same asset at several quality levels.
+    ///
+    /// Images are decoded and re-encoded on each export.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn image_encoding(&mut self, encoding: ImageEncoding) -> &mut Self {
+        self.image_encoding = Some(encoding);
+        self
+    }
+
+    /// Sets how the JSON is formatted, regardless of the output format.
+    pub fn json_format(&mut self, format: JsonFormat) -> &mut Self {
+        self.json_format = Some(format);
+        self
+    }
+
+    /// Removes an extension from the exported asset.
+    ///
+    /// The extension is removed from all objects, as well as from the used and
+    /// required extensions. This applies to extensions that are not supported
+    /// by this crate as well. Their data is dropped on import, so they are only
+    /// listed in the used and required extensions, unless the `extensions`
+    /// feature keeps it, in which case it is removed from all objects too.
+    pub fn strip_extension<S: Into<String>>(&mut self, name: S) -> &mut Self {
+        let name = name.into();
+        if !self.stripped_extensions.contains(&name) {
+            self.stripped_extensions.push(name);
+        }
+        self
+    }
+
+    /// Removes all extensions that are not enabled in this build of the crate,
+    /// as with [`strip_extension`].
+    ///
+    /// [`strip_extension`]: #method.strip_extension
+    pub fn strip_unsupported_extensions(&mut self) -> &mut Self {
+        let unsupported = self
+            .json
+            .extensions_used
+            .iter()
+            .chain(&self.json.extensions_required)
+            .filter(|name| !json::extensions::ENABLED_EXTENSIONS.contains(&name.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        for name in unsupported {
+            self.strip_extension(name);
+        }
+        self
+    }
+
+    /// Bakes a variant of `KHR_materials_variants` into the exported asset.
+    ///
+    /// Each primitive with a mapping for the variant is assigned the mapped
+    /// material as its default material, after which the extension is
+    /// removed. Materials that are no longer referenced are kept.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the variant does not exist.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn bake_material_variant(&mut self, variant: usize) -> &mut Self {
+        let count = self
+            .json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+            .map_or(0, |variants| variants.variants.len());
+        assert!(variant < count, "variant index out of bounds");
+        self.material_variant = Some(variant as u32);
+        self.strip_extension("KHR_materials_variants")
+    }
+
+    /// Sanitizes the names of top-level objects, e.g. for engines that
+    /// require unique names.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn sanitize_names(&mut self, options: names::Options) -> &mut Self {
+        self.names = Some(options);
+        self
+    }
+
+    /// Compresses buffer views with `EXT_meshopt_compression`.
+    ///
+    /// The compressed data is stored in the exported buffer, while the layout
+    /// of the decompressed data is described by a fallback buffer without
+    /// data. The extension is therefore added to the required extensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of bits of a filter is out of range.
+    #[cfg(feature = "EXT_meshopt_compression")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_meshopt_compression")))]
+    pub fn meshopt_compression(&mut self, options: meshopt::Options) -> &mut Self {
+        assert!(options.is_valid(), "filter bits out of range");
+        self.meshopt = Some(options);
+        self
+    }
+
+    /// Quantizes vertex attributes with `KHR_mesh_quantization`.
+    ///
+    /// The extension is added to the required extensions if any positions,
+    /// normals, or tangents are quantized. Quantization happens before any
+    /// compression.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn quantize(&mut self, options: quantize::Options) -> &mut Self {
+        self.quantize = Some(options);
+        self
+    }
+
+    fn attach(&mut self, index: usize, data: Cow<'a, [u8]>, mime_type: &'a str) -> &mut Self {
+        assert!(index < self.json.images.len(), "image index out of bounds");
+        self.images.retain(|&(i, _)| i != index);
+        self.images.push((index, EncodedImage { data, mime_type }));
+        self
+    }
+
+    /// Returns the attached images, re-encoded if requested.
+    fn encoded_images(&self) -> Result<Vec<(usize, EncodedImage<'_>)>> {
+        self.images
+            .iter()
+            .map(|(index, image)| {
+                #[cfg(feature = "import")]
+                {
+                    if let Some(encoding) = self.image_encoding.as_ref() {
+                        return Ok((*index, encode::reencode(image, encoding)?));
+                    }
+                }
+                let data = Cow::Borrowed(&*image.data);
+                Ok((*index, EncodedImage { data, mime_type: image.mime_type }))
+            })
+            .collect()
+    }
+
+    /// Returns the glTF JSON to be exported.
+    pub fn as_json(&self) -> &json::Root {
+        &self.json
+    }
+
+    /// Merges the data of all buffers into one, optionally embedding the
+    /// attached image data as well.
+    ///
+    /// Returns the updated JSON along with the merged buffer data.
+    fn pack(&self, embed_images: bool) -> Result<(json::Root, Vec<u8>)> {
+        let mut json = self.json.clone();
+        let mut bin = Vec::new();
+
+        #[cfg(feature = "KHR_materials_variants")]
+        {
+            if let Some(variant) = self.material_variant {
+                strip::bake_material_variant(&mut json, variant);
+            }
+        }
+        if !self.stripped_extensions.is_empty() {
+            strip::strip(&mut json, &self.stripped_extensions)?;
+        }
+        #[cfg(feature = "names")]
+        {
+            if let Some(options) = self.names.as_ref() {
+                names::sanitize(&mut json, options);
+            }
+        }
+
+        // Buffers only providing the fallback layout of compressed buffer
+        // views have no data and are kept after the merged buffer.
+        let mut locations = Vec::with_capacity(json.buffers.len());
+        let mut fallbacks = 0;
+        for (index, buffer) in json.buffers.iter().enumerate() {
+            if is_fallback(buffer) {
+                fallbacks += 1;
+                locations.push((fallbacks, 0));
+                continue;
+            }
+            let data = self.buffers.get(index).ok_or(Error::MissingBufferData(index))?;
+            let length = buffer.byte_length as usize;
+            if data.len() < length {
+                return Err(Error::BufferLength {
+                    buffer: index,
+                    expected: length,
+                    actual: data.len(),
+                });
+            }
+            pad(&mut bin);
+            locations.push((0, to_u32(bin.len())?));
+            bin.extend_from_slice(&data[..length]);
+        }
+        for view in &mut json.buffer_views {
+            if let Some(&(buffer, offset)) = locations.get(view.buffer.value()) {
+                let byte_offset = view.byte_offset.unwrap_or(0).checked_add(offset);
+                view.byte_offset = Some(byte_offset.ok_or_else(too_large)?);
+                view.buffer = Index::new(buffer as u32);
+            }
+        }
+        #[cfg(feature = "EXT_meshopt_compression")]
+        for (index, view) in json.buffer_views.iter_mut().enumerate() {
+            let extension = view
+                .extensions
+                .as_mut()
+                .and_then(|extensions| extensions.ext_meshopt_compression.as_mut());
+            if let Some(extension) = extension {
+                // Compressed data can only be read from buffers with data.
+                let offset = match locations.get(extension.buffer.value()) {
+                    Some(&(0, offset)) => offset,
+                    location => {
+                        let path = json::Path::new()
+                            .field("bufferViews")
+                            .index(index)
+                            .field("extensions")
+                            .field("EXT_meshopt_compression")
+                            .field("buffer");
+                        let error = match location {
+                            Some(_) => json::validation::Error::Invalid,
+                            None => json::validation::Error::IndexOutOfBounds,
+                        };
+                        return Err(Error::Validation(vec![(path, error)]));
+                    }
+                };
+                let byte_offset = extension.byte_offset.checked_add(offset);
+                extension.byte_offset = byte_offset.ok_or_else(too_large)?;
+                extension.buffer = Index::new(0);
+            }
+        }
+
+        if embed_images {
+            for (index, image) in self.encoded_images()? {
+                pad(&mut bin);
+                let view = json.push(json::buffer::View {
+                    buffer: Index::new(0),
+                    byte_length: to_u32(image.data.len())?,
+                    byte_offset: Some(to_u32(bin.len())?),
+                    byte_stride: None,
+                    #[cfg(feature = "names")]
+                    name: None,
+                    target: None,
+                    extensions: None,
+                    extras: Default::default(),
+                });
+                bin.extend_from_slice(&image.data);
+                let json = &mut json.images[index];
+                json.buffer_view = Some(view);
+                json.mime_type = Some(json::image::MimeType(image.mime_type.to_owned()));
+                json.uri = None;
+            }
+        }
+
+        if !json.buffers.is_empty() || !bin.is_empty() {
+            let (fallbacks, buffers) = json.buffers.drain(..).partition::<Vec<_>, _>(is_fallback);
+            let mut buffer = buffers.into_iter().next().unwrap_or(json::Buffer {
+                byte_length: 0,
+                #[cfg(feature = "names")]
+                name: None,
+                uri: None,
+                extensions: None,
+                extras: Default::default(),
+            });
+            buffer.byte_length = to_u32(bin.len())?;
+            buffer.uri = None;
+            json.buffers.push(buffer);
+            for mut fallback in fallbacks {
+                fallback.uri = None;
+                json.buffers.push(fallback);
+            }
+            #[cfg(feature = "EXT_meshopt_compression")]
+            {
+                if json.buffers.len() > 1 {
+                    meshopt::require_extension(&mut json, "EXT_meshopt_compression");
+                }
+            }
+        }
+
+        #[cfg(feature = "KHR_mesh_quantization")]
+        {
+            if let (Some(options), false) = (self.quantize.as_ref(), json.buffers.is_empty()) {
+                bin = quantize::quantize(&mut json, &bin, options);
+                json.buffers[0].byte_length = to_u32(bin.len())?;
+            }
+        }
+
+        #[cfg(feature = "EXT_meshopt_compression")]
+        {
+            if let (Some(options), false) = (self.meshopt.as_ref(), json.buffers.is_empty()) {
+                bin = meshopt::compress(&mut json, &bin, options);
+                json.buffers[0].byte_length = to_u32(bin.len())?;
+            }
+        }
+
+        Ok((json, bin))
+    }
+
+    /// Converts to binary glTF.
+    ///
+    /// Images with attached data are always embedded. Use `write_glb` to store
+    /// them in external files.
+    pub fn to_glb(&self) -> Result<Glb<'static>> {
+        let (json, bin) = self.pack(true)?;
+        glb(&json, bin, self.json_format.unwrap_or(JsonFormat::Minified))
+    }
+
+    /// Writes binary glTF to a writer.
+    pub fn to_writer<W>(&self, writer: W) -> Result<()>
+    where
+        W: io::Write,
+    {
+        self.to_glb()?.to_writer(writer)
+    }
+
+    /// Writes binary glTF to a byte vector.
+    pub fn to_vec(&self) -> Result<Vec<u8>> {
+        self.to_glb()?.to_vec()
+    }
+
+    /// Writes binary glTF through a sink.
+    ///
+    /// The .glb file is written as `{name}.glb`. Images with attached data are
+    /// embedded, unless `ImageStorage::External` is selected, in which case
+    /// they are written as `{name}_{index}.{extension}`, where the extension
+    /// is derived from the MIME type of the image.
+    pub fn write_glb<S>(&self, name: &str, sink: &mut S) -> Result<()>
+    where
+        S: Sink,
+    {
+        let external = self.image_storage == Some(ImageStorage::External);
+        let (mut json, bin) = self.pack(!external)?;
+        if external {
+            self.write_images(&mut json, name, sink)?;
+        }
+        let glb = glb(&json, bin, self.json_format.unwrap_or(JsonFormat::Minified))?.to_vec()?;
+        sink.write(&format!("{}.glb", name), &glb)?;
+        Ok(())
+    }
+
+    /// Writes text glTF through a sink.
+    ///
+    /// The .gltf file is written as `{name}.gltf` and the buffer data as
+    /// `{name}.bin`. Images with attached data are written as
+    /// `{name}_{index}.{extension}`, where the extension is derived from the
+    /// MIME type of the image, unless `ImageStorage::Buffer` is selected. All
+    /// URIs are relative to the .gltf file.
+    pub fn to_gltf<S>(&self, name: &str, sink: &mut S) -> Result<()>
+    where
+        S: Sink,
+    {
+        let embedded = self.image_storage == Some(ImageStorage::Buffer);
+        let (mut json, bin) = self.pack(embedded)?;
+        if let Some(buffer) = json.buffers.first_mut() {
+            let uri = format!("{}.bin", name);
+            sink.write(&uri, &bin)?;
+            buffer.uri = Some(uri);
+        }
+        if !embedded {
+            self.write_images(&mut json, name, sink)?;
+        }
+        let json = serialize(&json, self.json_format.unwrap_or(JsonFormat::Pretty))?;
+        sink.write(&format!("{}.gltf", name), &json)?;
+        Ok(())
+    }
+
+    /// Converts to a self-contained text glTF file, returning its contents.
+    ///
+    /// The buffer data is embedded as a base64 data URI. Images with attached
+    /// data are embedded as data URIs of their own, unless
+    /// `ImageStorage::Buffer` is selected.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn to_embedded_gltf(&self) -> Result<Vec<u8>> {
+        let embedded = self.image_storage == Some(ImageStorage::Buffer);
+        let (mut json, bin) = self.pack(embedded)?;
+        if let Some(buffer) = json.buffers.first_mut() {
+            buffer.uri = Some(data_uri("application/octet-stream", &bin));
+        }
+        if !embedded {
+            for (index, image) in self.encoded_images()? {
+                let json = &mut json.images[index];
+                json.buffer_view = None;
+                json.mime_type = Some(json::image::MimeType(image.mime_type.to_owned()));
+                json.uri = Some(data_uri(image.mime_type, &image.data));
+            }
+        }
+        serialize(&json, self.json_format.unwrap_or(JsonFormat::Pretty))
+    }
+
+    /// Writes the attached image data to external files and updates the image
+    /// URIs accordingly.
+    fn write_images<S>(&self, json: &mut json::Root, name: &str, sink: &mut S) -> Result<()>
+    where
+        S: Sink,
+    {
+        for (index, image) in self.encoded_images()? {
+            let uri = format!("{}_{}.{}", name, index, extension(image.mime_type));
+            sink.write(&uri, &image.data)?;
+            let json = &mut json.images[index];
+            json.buffer_view = None;
+            json.mime_type = Some(json::image::MimeType(image.mime_type.to_owned()));
+            json.uri = Some(uri);
+        }
+        Ok(())
+    }
+}
+
+impl Sink for Directory {
+    fn write(&mut self, uri: &str, data: &[u8]) -> io::Result<()> {
+        fs::write(self.0.join(uri), data)
+    }
+}
+
+impl Sink for HashMap<String, Vec<u8>> {
+    fn write(&mut self, uri: &str, data: &[u8]) -> io::Result<()> {
+        self.insert(uri.to_owned(), data.to_vec());
+        Ok(())
+    }
+}
+
+/// Encodes data as a base64 data URI.
+#[cfg(feature = "import")]
+fn data_uri(mime_type: &str, data: &[u8]) -> String {
+    format!("data:{};base64,{}", mime_type, base64::encode(data))
+}
+
+/// Serializes glTF JSON in the given format.
+fn serialize(json: &json::Root, format: JsonFormat) -> Result<Vec<u8>> {
+    match format {
+        JsonFormat::Pretty => json.to_vec_pretty(),
+        JsonFormat::Minified => json.to_vec(),
+    }
+    .map_err(Error::Serialize)
+}
+
+/// Constructs binary glTF from JSON and the merged buffer data.
+fn glb(json: &json::Root, bin: Vec<u8>, format: JsonFormat) -> Result<Glb<'static>> {
+    let json = serialize(json, format)?;
+    let bin = if bin.is_empty() { None } else { Some(bin) };
+    let mut length = mem::size_of::<binary::Header>() + chunk_length(json.len());
+    if let Some(bin) = bin.as_ref() {
+        length += chunk_length(bin.len());
+    }
+    Ok(Glb {
+        header: binary::Header {
+            magic: *b"glTF",
+            version: 2,
+            length: to_u32(length)?,
+        },
+        json: Cow::Owned(json),
+        bin: bin.map(Cow::Owned),
+    })
+}
+
+/// Encodes pixel data as PNG.
+#[cfg(feature = "import")]
+fn encode_png(
+    pixels: &[u8],
+    width: u32,
+    height: u32,
+    format: image::Format,
+    compression: CompressionType,
+) -> Result<Vec<u8>> {
+    use image::Format;
+    use image_crate::ColorType;
+
+    let mut pixels = Cow::Borrowed(pixels);
+    let color_type = match format {
+        Format::R8 => ColorType::L8,
+        Format::R8G8 => ColorType::La8,
+        Format::R8G8B8 => ColorType::Rgb8,
+        Format::R8G8B8A8 => ColorType::Rgba8,
+        Format::B8G8R8 | Format::B8G8R8A8 => {
+            let channels = if let Format::B8G8R8 = format { 3 } else { 4 };
+            for pixel in pixels.to_mut().chunks_mut(channels) {
+                pixel.swap(0, 2);
+            }
+            if channels == 3 { ColorType::Rgb8 } else { ColorType::Rgba8 }
+        }
+        Format::R16 | Format::R16G16 | Format::R16G16B16 | Format::R16G16B16A16 => {
+            // PNG stores 16-bit samples in big-endian byte order.
+            for sample in pixels.to_mut().chunks_mut(2) {
+                let value = u16::from_ne_bytes([sample[0], sample[1]]);
+                sample.copy_from_slice(&value.to_be_bytes());
+            }
+            match format {
+                Format::R16 => ColorType::L16,
+                Format::R16G16 => ColorType::La16,
+                Format::R16G16B16 => ColorType::Rgb16,
+                _ => ColorType::Rgba16,
+            }
+        }
+    };
+    let mut data = Vec::new();
+    PngEncoder::new_with_quality(&mut data, compression, FilterType::Sub).encode(&pixels, width, height, color_type)?;
+    Ok(data)
+}
+
+/// Detects the MIME type of an encoded image from its signature.
+fn mime_type(data: &[u8]) -> Option<&'static str> {
+    if data.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if data.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        Some("image/jpeg")
+    } else if data.len() >= 12 && data.starts_with(b"RIFF") && &data[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if data.starts_with(b"\xABKTX 20\xBB\r\n\x1A\n") {
+        Some("image/ktx2")
+    } else if data.starts_with(b"DDS ") {
+        Some("image/vnd-ms.dds")
+    } else {
+        None
+    }
+}
+
+/// Returns the file extension for an image MIME type.
+fn extension(mime_type: &str) -> &str {
+    match mime_type {
+        "image/jpeg" => "jpg",
+        "image/png" => "png",
+        "image/webp" => "webp",
+        "image/ktx2" => "ktx2",
+        "image/vnd-ms.dds" => "dds",
+        _ => "bin",
+    }
+}
+
+/// Returns the length of a GLB chunk, including its header and padding.
+fn chunk_length(data_length: usize) -> usize {
+    let mut length = data_length;
+    binary::align_to_multiple_of_four(&mut length);
+    // Chunk header: `u32` length plus `u32` chunk type.
+    8 + length
+}
+
+/// Returns the error for binary glTF whose length does not fit in the
+/// 32-bit fields of the format.
+fn too_large() -> Error {
+    Error::Io(io::Error::new(io::ErrorKind::InvalidInput, "binary glTF exceeds 4 GiB"))
+}
+
+/// Converts a length or offset within binary glTF to `u32`.
+fn to_u32(value: usize) -> Result<u32> {
+    u32::try_from(value).map_err(|_| too_large())
+}
+
+/// Returns `true` if a buffer only provides the fallback layout of buffer
+/// views compressed with `EXT_meshopt_compression`.
+#[cfg(not(feature = "EXT_meshopt_compression"))]
+fn is_fallback(_: &json::Buffer) -> bool {
+    false
+}
+
+/// Pads binary data with zeros to a multiple of four bytes.
+fn pad(bin: &mut Vec<u8>) {
+    let mut length = bin.len();
+    binary::align_to_multiple_of_four(&mut length);
+    bin.resize(length, 0);
+}
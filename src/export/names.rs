@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+/// Options for sanitizing the names of objects on export.
+///
+/// Names are processed separately for each kind of top-level object, e.g.
+/// a node and a mesh may still share a name.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    /// Whether to trim leading and trailing whitespace from names.
+    ///
+    /// Names that are empty after trimming are removed.
+    pub trim: bool,
+
+    /// Whether to replace control characters in names by underscores.
+    pub replace_control: bool,
+
+    /// Whether to replace characters outside of ASCII by underscores.
+    pub ascii_only: bool,
+
+    /// Whether to make names unique by suffixing duplicates with numbers,
+    /// as in `Cube`, `Cube.001`, `Cube.002`.
+    ///
+    /// The first object with a name keeps it, and suffixes are chosen so as
+    /// not to collide with other names either.
+    pub deduplicate: bool,
+}
+
+/// Sanitizes the names of all top-level objects of a document.
+pub(crate) fn sanitize(json: &mut json::Root, options: &Options) {
+    apply(json.accessors.iter_mut().map(|object| &mut object.name), options);
+    apply(json.animations.iter_mut().map(|object| &mut object.name), options);
+    apply(json.buffers.iter_mut().map(|object| &mut object.name), options);
+    apply(json.buffer_views.iter_mut().map(|object| &mut object.name), options);
+    apply(json.cameras.iter_mut().map(|object| &mut object.name), options);
+    apply(json.images.iter_mut().map(|object| &mut object.name), options);
+    apply(json.materials.iter_mut().map(|object| &mut object.name), options);
+    apply(json.meshes.iter_mut().map(|object| &mut object.name), options);
+    apply(json.nodes.iter_mut().map(|object| &mut object.name), options);
+    apply(json.samplers.iter_mut().map(|object| &mut object.name), options);
+    apply(json.scenes.iter_mut().map(|object| &mut object.name), options);
+    apply(json.skins.iter_mut().map(|object| &mut object.name), options);
+    apply(json.textures.iter_mut().map(|object| &mut object.name), options);
+}
+
+/// Sanitizes the names of one kind of object.
+fn apply<'a, I>(names: I, options: &Options)
+where
+    I: Iterator<Item = &'a mut Option<String>>,
+{
+    let mut names = names.collect::<Vec<_>>();
+    for name in &mut names {
+        if let Some(sanitized) = name.as_deref().map(|name| clean(name, options)) {
+            **name = if sanitized.is_empty() && options.trim { None } else { Some(sanitized) };
+        }
+    }
+    if !options.deduplicate {
+        return;
+    }
+
+    let all = names.iter().filter_map(|name| (**name).clone()).collect::<HashSet<_>>();
+    let mut taken = HashSet::new();
+    for name in names {
+        let base = match name.as_ref() {
+            Some(base) if taken.contains(base) => base.clone(),
+            Some(base) => {
+                taken.insert(base.clone());
+                continue;
+            }
+            None => continue,
+        };
+        let unique = (1..)
+            .map(|suffix| format!("{}.{:03}", base, suffix))
+            .find(|candidate| !all.contains(candidate) && !taken.contains(candidate))
+            .unwrap();
+        taken.insert(unique.clone());
+        *name = Some(unique);
+    }
+}
+
+/// Trims and replaces characters of a single name.
+fn clean(name: &str, options: &Options) -> String {
+    let name = if options.trim { name.trim() } else { name };
+    name.chars()
+        .map(|c| {
+            if (options.replace_control && c.is_control()) || (options.ascii_only && !c.is_ascii()) {
+                '_'
+            } else {
+                c
+            }
+        })
+        .collect()
+}
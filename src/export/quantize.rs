@@ -0,0 +1,549 @@
+use std::collections::HashMap;
+
+use json::accessor::{ComponentType, GenericComponentType, Type};
+use json::animation::Property;
+use json::mesh::Semantic;
+use json::validation::Checked::Valid;
+use json::Index;
+
+use super::pad;
+use crate::binary;
+use crate::build::Component;
+
+/// Options for quantization of vertex attributes with `KHR_mesh_quantization`.
+///
+/// Only floating point attributes that are not sparse are quantized.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    /// Whether to quantize vertex positions to normalized unsigned shorts.
+    ///
+    /// The positions of a mesh are mapped to the cube enclosing its bounding
+    /// box. The translation and uniform scale mapping them back are applied
+    /// by a node inserted between each node and its mesh, which also takes
+    /// over the morph target weights of the node. Morph target displacements
+    /// are scaled accordingly.
+    ///
+    /// Meshes that are skinned, instanced, or not used by any node are not
+    /// quantized, since their positions cannot be compensated this way.
+    pub positions: bool,
+
+    /// Whether to quantize vertex normals and tangents to normalized bytes.
+    pub normals: bool,
+
+    /// Whether to quantize texture co-ordinates to normalized unsigned
+    /// shorts.
+    ///
+    /// Sets of texture co-ordinates outside the range [0, 1] are not
+    /// quantized.
+    pub tex_coords: bool,
+}
+
+/// Specifies how an accessor is used.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Usage {
+    /// Vertex positions of the given mesh.
+    Positions(usize),
+
+    /// Morph target position displacements of the given mesh.
+    Displacements(usize),
+
+    /// Vertex normals.
+    Normals,
+
+    /// Vertex tangents.
+    Tangents,
+
+    /// Texture co-ordinates.
+    TexCoords,
+
+    /// Any other or more than one use.
+    Other,
+}
+
+/// The quantized elements of an accessor.
+#[derive(Clone, Debug)]
+struct Quantized {
+    /// The tightly packed elements.
+    data: Vec<u8>,
+
+    /// The size of each element in bytes.
+    size: usize,
+
+    component_type: ComponentType,
+    normalized: bool,
+    min: Option<json::Value>,
+    max: Option<json::Value>,
+}
+
+/// The data of the buffer views of a document whose data has been merged
+/// into its only buffer, copied when modified.
+struct Views<'a> {
+    bin: &'a [u8],
+    modified: HashMap<usize, Vec<u8>>,
+
+    /// The number of accessors using each buffer view.
+    users: Vec<usize>,
+}
+
+impl<'a> Views<'a> {
+    fn new(json: &json::Root, bin: &'a [u8]) -> Self {
+        let mut users = vec![0; json.buffer_views.len()];
+        let mut add = |view: Index<json::buffer::View>| {
+            if let Some(users) = users.get_mut(view.value()) {
+                *users += 1;
+            }
+        };
+        for accessor in &json.accessors {
+            if let Some(view) = accessor.buffer_view {
+                add(view);
+            }
+            if let Some(ref sparse) = accessor.sparse {
+                add(sparse.indices.buffer_view);
+                add(sparse.values.buffer_view);
+            }
+        }
+        Self { bin, modified: HashMap::new(), users }
+    }
+
+    /// Returns the data of a buffer view.
+    fn get(&self, json: &json::Root, view: usize) -> Option<&[u8]> {
+        if let Some(data) = self.modified.get(&view) {
+            return Some(data);
+        }
+        let view = json.buffer_views.get(view)?;
+        let start = view.byte_offset.unwrap_or(0) as usize;
+        if view.buffer.value() == 0 {
+            self.bin.get(start..start + view.byte_length as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Reads the floating point components of an accessor with the given
+    /// type.
+    fn read(&self, json: &json::Root, accessor: usize, type_: Type) -> Option<Vec<f32>> {
+        let accessor = &json.accessors[accessor];
+        match (&accessor.component_type, &accessor.type_) {
+            (&Valid(GenericComponentType(ComponentType::F32)), &Valid(t)) if t == type_ => {}
+            _ => return None,
+        }
+        if accessor.normalized || accessor.sparse.is_some() {
+            return None;
+        }
+        let view = accessor.buffer_view?.value();
+        let data = self.get(json, view)?;
+        let components = type_.multiplicity();
+        let stride = json.buffer_views[view].byte_stride.map_or(4 * components, |stride| stride as usize);
+        let count = accessor.count as usize;
+        let mut values = Vec::with_capacity(count * components);
+        for i in 0..count {
+            let start = accessor.byte_offset as usize + i * stride;
+            let element = data.get(start..start + 4 * components)?;
+            values.extend(element.chunks_exact(4).map(|bytes| {
+                f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+            }));
+        }
+        Some(values)
+    }
+
+    /// Returns `true` if the elements of an accessor can be replaced by
+    /// elements of the given size.
+    fn is_writable(&self, json: &json::Root, accessor: usize, size: usize) -> bool {
+        let accessor = &json.accessors[accessor];
+        match accessor.buffer_view {
+            Some(view) if self.get(json, view.value()).is_some() => {
+                let view = view.value();
+                match json.buffer_views[view].byte_stride {
+                    _ if self.users[view] == 1 => true,
+                    Some(stride) => size <= stride as usize,
+                    None => false,
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// Replaces the elements of an accessor.
+    ///
+    /// If the accessor is the only one using its buffer view, the buffer view
+    /// is replaced by the new elements, each aligned to four bytes. Otherwise
+    /// the new elements are written over the old ones in place.
+    fn write(&mut self, json: &mut json::Root, accessor: usize, quantized: Quantized) {
+        let view = json.accessors[accessor].buffer_view.unwrap().value();
+        let offset = json.accessors[accessor].byte_offset as usize;
+        let old_size = 4 * match json.accessors[accessor].type_ {
+            Valid(type_) => type_.multiplicity(),
+            _ => unreachable!(),
+        };
+        if self.users[view] == 1 {
+            let mut stride = quantized.size;
+            binary::align_to_multiple_of_four(&mut stride);
+            let mut data = Vec::with_capacity(quantized.data.len() / quantized.size * stride);
+            for element in quantized.data.chunks_exact(quantized.size) {
+                data.extend_from_slice(element);
+                data.resize(data.len() + stride - quantized.size, 0);
+            }
+            let json = &mut json.buffer_views[view];
+            if stride != quantized.size || json.byte_stride.is_some() {
+                json.byte_stride = Some(stride as u32);
+            }
+            json.byte_length = data.len() as u32;
+            self.modified.insert(view, data);
+        } else {
+            let stride = json.buffer_views[view].byte_stride.unwrap() as usize;
+            let mut data = self.get(json, view).unwrap().to_vec();
+            for (i, element) in quantized.data.chunks_exact(quantized.size).enumerate() {
+                let start = offset + i * stride;
+                data[start..start + quantized.size].copy_from_slice(element);
+                for byte in &mut data[start + quantized.size..start + old_size] {
+                    *byte = 0;
+                }
+            }
+            self.modified.insert(view, data);
+        }
+
+        let json = &mut json.accessors[accessor];
+        if self.users[view] == 1 {
+            json.byte_offset = 0;
+        }
+        json.component_type = Valid(GenericComponentType(quantized.component_type));
+        json.normalized = quantized.normalized;
+        json.min = quantized.min;
+        json.max = quantized.max;
+    }
+
+    /// Returns the new buffer data, with the data of all buffer views in
+    /// order.
+    fn finish(self, json: &mut json::Root) -> Vec<u8> {
+        let mut bin = Vec::with_capacity(self.bin.len());
+        for index in 0..json.buffer_views.len() {
+            #[cfg(feature = "EXT_meshopt_compression")]
+            {
+                if super::meshopt::copy_compressed(&mut json.buffer_views[index], self.bin, &mut bin) {
+                    continue;
+                }
+            }
+            let start = json.buffer_views[index].byte_offset.unwrap_or(0) as usize;
+            let data = match self.get(json, index) {
+                Some(data) => data,
+                None => continue,
+            };
+            // Keep the alignment of accessors relative to the buffer.
+            pad(&mut bin);
+            bin.resize(bin.len() + start % 4, 0);
+            let offset = bin.len() as u32;
+            bin.extend_from_slice(data);
+            json.buffer_views[index].byte_offset = Some(offset);
+        }
+        json.buffers[0].byte_length = bin.len() as u32;
+        bin
+    }
+}
+
+/// Quantizes the vertex attributes of a document whose data has been merged
+/// into its only buffer, returning the new data of the buffer.
+pub(crate) fn quantize(json: &mut json::Root, bin: &[u8], options: &Options) -> Vec<u8> {
+    let usages = usages(json);
+    let mut views = Views::new(json, bin);
+    let mut extension = false;
+
+    if options.positions {
+        let mut transforms = HashMap::new();
+        for mesh in 0..json.meshes.len() {
+            if let Some(transform) = quantize_positions(json, &mut views, &usages, mesh) {
+                transforms.insert(mesh, transform);
+                extension = true;
+            }
+        }
+        compensate(json, &transforms);
+    }
+
+    let mut accessors = usages.iter().collect::<Vec<_>>();
+    accessors.sort_by_key(|&(&accessor, _)| accessor);
+    for (&accessor, &usage) in accessors {
+        let quantized = match usage {
+            Usage::Normals if options.normals => views
+                .read(json, accessor, Type::Vec3)
+                .map(|values| quantize_snorm(&values, 3, json.accessors[accessor].min.is_some())),
+            Usage::Tangents if options.normals => views
+                .read(json, accessor, Type::Vec4)
+                .map(|values| quantize_snorm(&values, 4, json.accessors[accessor].min.is_some())),
+            Usage::TexCoords if options.tex_coords => views
+                .read(json, accessor, Type::Vec2)
+                .filter(|values| values.iter().all(|value| (0.0..=1.0).contains(value)))
+                .map(|values| quantize_unorm(&values, 2, json.accessors[accessor].min.is_some())),
+            _ => None,
+        };
+        if let Some(quantized) = quantized {
+            if views.is_writable(json, accessor, quantized.size) {
+                extension |= usage != Usage::TexCoords;
+                views.write(json, accessor, quantized);
+            }
+        }
+    }
+
+    if extension {
+        for extensions in &mut [&mut json.extensions_used, &mut json.extensions_required] {
+            if !extensions.iter().any(|extension| extension == "KHR_mesh_quantization") {
+                extensions.push("KHR_mesh_quantization".to_owned());
+            }
+        }
+    }
+    views.finish(json)
+}
+
+/// Determines how each accessor is used.
+fn usages(json: &json::Root) -> HashMap<usize, Usage> {
+    let mut usages = HashMap::new();
+    let mut add = |accessor: Index<json::Accessor>, usage: Usage| {
+        let entry = usages.entry(accessor.value()).or_insert(usage);
+        if *entry != usage {
+            *entry = Usage::Other;
+        }
+    };
+    for (index, mesh) in json.meshes.iter().enumerate() {
+        for primitive in &mesh.primitives {
+            for (semantic, &accessor) in &primitive.attributes {
+                let usage = match *semantic {
+                    Valid(Semantic::Positions) => Usage::Positions(index),
+                    Valid(Semantic::Normals) => Usage::Normals,
+                    Valid(Semantic::Tangents) => Usage::Tangents,
+                    Valid(Semantic::TexCoords(_)) => Usage::TexCoords,
+                    _ => Usage::Other,
+                };
+                add(accessor, usage);
+            }
+            for target in primitive.targets.iter().flatten() {
+                if let Some(accessor) = target.positions {
+                    add(accessor, Usage::Displacements(index));
+                }
+                for &accessor in [target.normals, target.tangents].iter().flatten() {
+                    add(accessor, Usage::Other);
+                }
+            }
+            if let Some(accessor) = primitive.indices {
+                add(accessor, Usage::Other);
+            }
+        }
+    }
+    for animation in &json.animations {
+        for sampler in &animation.samplers {
+            add(sampler.input, Usage::Other);
+            add(sampler.output, Usage::Other);
+        }
+    }
+    for skin in &json.skins {
+        if let Some(accessor) = skin.inverse_bind_matrices {
+            add(accessor, Usage::Other);
+        }
+    }
+    usages
+}
+
+/// Quantizes the positions of a mesh, returning the translation and uniform
+/// scale mapping them back.
+fn quantize_positions(
+    json: &mut json::Root,
+    views: &mut Views,
+    usages: &HashMap<usize, Usage>,
+    mesh: usize,
+) -> Option<([f32; 3], f32)> {
+    let mut used = false;
+    for node in &json.nodes {
+        if node.mesh.map(|mesh| mesh.value()) != Some(mesh) {
+            continue;
+        }
+        if node.skin.is_some() {
+            return None;
+        }
+        #[cfg(feature = "EXT_mesh_gpu_instancing")]
+        {
+            if let Some(ref extensions) = node.extensions {
+                if extensions.ext_mesh_gpu_instancing.is_some() {
+                    return None;
+                }
+            }
+        }
+        used = true;
+    }
+    if !used {
+        return None;
+    }
+
+    let mut positions = Vec::new();
+    let mut displacements = Vec::new();
+    for primitive in &json.meshes[mesh].primitives {
+        let accessor = primitive.attributes.get(&Valid(Semantic::Positions))?.value();
+        if usages.get(&accessor) != Some(&Usage::Positions(mesh)) {
+            return None;
+        }
+        let values = views.read(json, accessor, Type::Vec3)?;
+        if !positions.iter().any(|&(index, _)| index == accessor) {
+            if !views.is_writable(json, accessor, 6) {
+                return None;
+            }
+            positions.push((accessor, values));
+        }
+        for target in primitive.targets.iter().flatten() {
+            if let Some(accessor) = target.positions.map(|accessor| accessor.value()) {
+                if usages.get(&accessor) != Some(&Usage::Displacements(mesh)) {
+                    return None;
+                }
+                let values = views.read(json, accessor, Type::Vec3)?;
+                if !displacements.iter().any(|&(index, _)| index == accessor) {
+                    if !views.is_writable(json, accessor, 12) {
+                        return None;
+                    }
+                    displacements.push((accessor, values));
+                }
+            }
+        }
+    }
+
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for (_, values) in &positions {
+        for position in values.chunks_exact(3) {
+            for i in 0..3 {
+                min[i] = min[i].min(position[i]);
+                max[i] = max[i].max(position[i]);
+            }
+        }
+    }
+    if !min.iter().chain(&max).all(|value| value.is_finite()) {
+        return None;
+    }
+    let extent = (0..3).map(|i| max[i] - min[i]).fold(0.0, f32::max);
+    let scale = if extent > 0.0 { extent } else { 1.0 };
+
+    for (accessor, values) in positions {
+        let values = values
+            .chunks_exact(3)
+            .flat_map(|position| (0..3).map(move |i| (position[i] - min[i]) / scale))
+            .collect::<Vec<_>>();
+        views.write(json, accessor, quantize_unorm(&values, 3, true));
+    }
+    for (accessor, values) in displacements {
+        let values = values.iter().map(|value| value / scale).collect::<Vec<_>>();
+        let mut min = [f32::INFINITY; 3];
+        let mut max = [f32::NEG_INFINITY; 3];
+        let mut data = Vec::with_capacity(values.len() * 4);
+        for displacement in values.chunks_exact(3) {
+            for i in 0..3 {
+                min[i] = min[i].min(displacement[i]);
+                max[i] = max[i].max(displacement[i]);
+                displacement[i].write(&mut data);
+            }
+        }
+        let has_bounds = json.accessors[accessor].min.is_some();
+        views.write(json, accessor, Quantized {
+            data,
+            size: 12,
+            component_type: ComponentType::F32,
+            normalized: false,
+            min: if has_bounds { Some(to_value(&min)) } else { None },
+            max: if has_bounds { Some(to_value(&max)) } else { None },
+        });
+    }
+    Some((min, scale))
+}
+
+/// Inserts nodes applying the given translation and uniform scale of each
+/// quantized mesh between the nodes and their meshes.
+fn compensate(json: &mut json::Root, transforms: &HashMap<usize, ([f32; 3], f32)>) {
+    for index in 0..json.nodes.len() {
+        let mesh = match json.nodes[index].mesh {
+            Some(mesh) => mesh,
+            None => continue,
+        };
+        let &(translation, scale) = match transforms.get(&mesh.value()) {
+            Some(transform) => transform,
+            None => continue,
+        };
+        let node = Index::new(json.nodes.len() as u32);
+        let parent = &mut json.nodes[index];
+        let weights = parent.weights.take();
+        parent.mesh = None;
+        parent.children.get_or_insert_with(Vec::new).push(node);
+        json.nodes.push(json::Node {
+            mesh: Some(mesh),
+            translation: Some(translation),
+            scale: Some([scale; 3]),
+            weights,
+            ..Default::default()
+        });
+        for animation in &mut json.animations {
+            for channel in &mut animation.channels {
+                let target = &mut channel.target;
+                let is_weights = target.path == Valid(Property::MorphTargetWeights);
+                #[cfg(feature = "KHR_animation_pointer")]
+                let target_node = target.node.as_mut();
+                #[cfg(not(feature = "KHR_animation_pointer"))]
+                let target_node = Some(&mut target.node);
+                match target_node {
+                    Some(target_node) if is_weights && target_node.value() == index => *target_node = node,
+                    _ => {}
+                }
+            }
+        }
+    }
+}
+
+/// Quantizes values in the range [-1, 1] to normalized bytes.
+fn quantize_snorm(values: &[f32], components: usize, bounds: bool) -> Quantized {
+    let data = values
+        .iter()
+        .map(|value| (value.clamp(-1.0, 1.0) * 127.0).round() as i8)
+        .collect::<Vec<_>>();
+    let (min, max) = if bounds { integer_bounds(&data, components) } else { (None, None) };
+    Quantized {
+        data: data.iter().map(|&value| value as u8).collect(),
+        size: components,
+        component_type: ComponentType::I8,
+        normalized: true,
+        min,
+        max,
+    }
+}
+
+/// Quantizes values in the range [0, 1] to normalized unsigned shorts.
+fn quantize_unorm(values: &[f32], components: usize, bounds: bool) -> Quantized {
+    let data = values
+        .iter()
+        .map(|value| (value.clamp(0.0, 1.0) * 65535.0).round() as u16)
+        .collect::<Vec<_>>();
+    let (min, max) = if bounds { integer_bounds(&data, components) } else { (None, None) };
+    let mut bytes = Vec::with_capacity(data.len() * 2);
+    for value in data {
+        value.write(&mut bytes);
+    }
+    Quantized {
+        data: bytes,
+        size: 2 * components,
+        component_type: ComponentType::U16,
+        normalized: true,
+        min,
+        max,
+    }
+}
+
+/// Returns the minimum and maximum of each component of integer values.
+fn integer_bounds<T: Component + Ord>(
+    values: &[T],
+    components: usize,
+) -> (Option<json::Value>, Option<json::Value>) {
+    let mut min = values[..components].to_vec();
+    let mut max = min.clone();
+    for element in values.chunks_exact(components) {
+        for i in 0..components {
+            min[i] = min[i].min(element[i]);
+            max[i] = max[i].max(element[i]);
+        }
+    }
+    (Some(to_value(&min)), Some(to_value(&max)))
+}
+
+/// Converts components to a JSON array.
+fn to_value<T: Component>(values: &[T]) -> json::Value {
+    json::Value::from(values.iter().map(|&value| value.to_value()).collect::<Vec<_>>())
+}
@@ -0,0 +1,296 @@
+use std::{fmt, io, mem};
+
+use byteorder::{LittleEndian, WriteBytesExt};
+use json::accessor::GenericComponentType;
+use json::buffer::Target;
+use json::validation::Checked::Valid;
+use json::Index;
+
+use crate::binary;
+use crate::build::{Component, Element};
+use crate::{DocumentMut, Error, Result};
+
+/// The size of a chunk header of binary glTF in bytes.
+const CHUNK_HEADER_SIZE: usize = 8;
+
+/// Writes binary glTF, reading the data of the `BIN` chunk from readers and
+/// iterators only as it is written.
+///
+/// Each reader or iterator pushed becomes a buffer view of the buffer stored
+/// in the `BIN` chunk, which is laid out like the buffers of a
+/// [`BufferBuilder`]. Since the JSON precedes the `BIN` chunk, the data is not
+/// inspected before it is written, so the lengths of readers must be known in
+/// advance and the bounds of accessors are not computed.
+///
+/// [`BufferBuilder`]: ../build/struct.BufferBuilder.html
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::export::GlbWriter;
+/// use gltf::json::buffer::Target;
+///
+/// let mut document = gltf::DocumentMut::new();
+/// let mut writer = GlbWriter::new(&mut document);
+/// let points = (0..1000).map(|i| [i as f32, 0.0, 0.0]);
+/// let positions = writer.push_iter(&mut document, points, Some(Target::ArrayBuffer));
+/// let accessor = document.get_mut(positions).unwrap();
+/// accessor.min = Some(vec![0.0, 0.0, 0.0].into());
+/// accessor.max = Some(vec![999.0, 0.0, 0.0].into());
+///
+/// let mut glb = Vec::new();
+/// writer.write(&document, &mut glb)?;
+/// let gltf = gltf::Gltf::from_slice(&glb)?;
+/// assert_eq!(gltf.blob.unwrap().len(), 12000);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("runtime error");
+/// # }
+/// ```
+pub struct GlbWriter<'a> {
+    /// The index of the buffer stored in the `BIN` chunk.
+    buffer: Index<json::Buffer>,
+
+    /// The sources of the buffer views, paired with their offsets and
+    /// lengths in bytes.
+    sources: Vec<(usize, usize, Box<dyn io::Read + 'a>)>,
+
+    /// The length of the buffer in bytes.
+    length: usize,
+}
+
+/// Reads the little-endian representation of items from an iterator.
+struct Items<I> {
+    items: I,
+
+    /// The size of each item, including padding, in bytes.
+    stride: usize,
+
+    /// The representation of the current item.
+    item: Vec<u8>,
+
+    /// The number of bytes of the current item already read.
+    position: usize,
+}
+
+impl<'a> GlbWriter<'a> {
+    /// Adds an empty buffer to the document, to be stored in the `BIN` chunk,
+    /// and returns a writer for its data.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the document already has buffers, since the `BIN` chunk must
+    /// store the first buffer.
+    pub fn new(document: &mut DocumentMut) -> Self {
+        assert!(document.as_json().buffers.is_empty(), "document already has buffers");
+        let buffer = document.add(json::Buffer {
+            byte_length: 0,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+        Self {
+            buffer,
+            sources: Vec::new(),
+            length: 0,
+        }
+    }
+
+    /// Returns the index of the buffer stored in the `BIN` chunk.
+    pub fn index(&self) -> Index<json::Buffer> {
+        self.buffer
+    }
+
+    /// Adds a buffer view whose data is read from a reader when written.
+    ///
+    /// Exactly `length` bytes are read. Writing fails if the reader ends
+    /// early.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer would exceed 4 GiB.
+    pub fn push_reader<R>(
+        &mut self,
+        document: &mut DocumentMut,
+        length: usize,
+        reader: R,
+        target: Option<Target>,
+    ) -> Index<json::buffer::View>
+    where
+        R: io::Read + 'a,
+    {
+        self.push_view(document, length, None, Box::new(reader), target)
+    }
+
+    /// Adds a buffer view whose data is taken from an iterator when written,
+    /// and returns the accessor of the data.
+    ///
+    /// Vertex attributes whose items are not a multiple of four bytes in size
+    /// are padded, as with [`BufferBuilder`]. The accessor has no bounds,
+    /// which must be set for vertex positions.
+    ///
+    /// [`BufferBuilder`]: ../build/struct.BufferBuilder.html
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer would exceed 4 GiB.
+    pub fn push_iter<I>(
+        &mut self,
+        document: &mut DocumentMut,
+        items: I,
+        target: Option<Target>,
+    ) -> Index<json::Accessor>
+    where
+        I: IntoIterator,
+        I::IntoIter: ExactSizeIterator + 'a,
+        I::Item: Element,
+    {
+        let items = items.into_iter();
+        let count = items.len();
+        let size = <I::Item as Element>::Component::COMPONENT_TYPE.size() * I::Item::TYPE.multiplicity();
+        let mut stride = size;
+        if let Some(Target::ArrayBuffer) = target {
+            binary::align_to_multiple_of_four(&mut stride);
+        }
+        let reader = Items {
+            items,
+            stride,
+            item: Vec::with_capacity(stride),
+            position: 0,
+        };
+        let byte_stride = if stride != size { Some(stride) } else { None };
+        let view = self.push_view(document, count * stride, byte_stride, Box::new(reader), target);
+        document.add(json::Accessor {
+            buffer_view: Some(view),
+            byte_offset: 0,
+            count: count as u32,
+            component_type: Valid(GenericComponentType(<I::Item as Element>::Component::COMPONENT_TYPE)),
+            extensions: None,
+            extras: Default::default(),
+            type_: Valid(I::Item::TYPE),
+            min: None,
+            max: None,
+            #[cfg(feature = "names")]
+            name: None,
+            normalized: false,
+            sparse: None,
+        })
+    }
+
+    /// Writes binary glTF of the document to a writer, reading the data of
+    /// the buffer views as it goes.
+    pub fn write<W>(self, document: &DocumentMut, mut writer: W) -> Result<()>
+    where
+        W: io::Write,
+    {
+        let json = document.as_json().to_vec().map_err(Error::Serialize)?;
+        let mut json_length = json.len();
+        binary::align_to_multiple_of_four(&mut json_length);
+        let mut bin_length = self.length;
+        binary::align_to_multiple_of_four(&mut bin_length);
+        let mut length = mem::size_of::<binary::Header>() + CHUNK_HEADER_SIZE + json_length;
+        if bin_length > 0 {
+            length += CHUNK_HEADER_SIZE + bin_length;
+        }
+        if length > u32::MAX as usize {
+            let error = io::Error::new(io::ErrorKind::InvalidInput, "binary glTF exceeds 4 GiB");
+            return Err(Error::Io(error));
+        }
+
+        writer.write_all(b"glTF")?;
+        writer.write_u32::<LittleEndian>(2)?;
+        writer.write_u32::<LittleEndian>(length as u32)?;
+
+        writer.write_u32::<LittleEndian>(json_length as u32)?;
+        writer.write_all(b"JSON")?;
+        writer.write_all(&json)?;
+        writer.write_all(&vec![0x20; json_length - json.len()])?;
+
+        if bin_length == 0 {
+            return Ok(());
+        }
+        writer.write_u32::<LittleEndian>(bin_length as u32)?;
+        writer.write_all(b"BIN\0")?;
+        let mut written = 0;
+        for (offset, length, reader) in self.sources {
+            writer.write_all(&vec![0; offset - written])?;
+            let copied = io::copy(&mut io::Read::take(reader, length as u64), &mut writer)? as usize;
+            if copied < length {
+                let error = io::Error::new(io::ErrorKind::UnexpectedEof, "buffer view data ended early");
+                return Err(Error::Io(error));
+            }
+            written = offset + length;
+        }
+        writer.write_all(&vec![0; bin_length - written])?;
+        Ok(())
+    }
+
+    /// Adds a buffer view at the next multiple of four bytes.
+    fn push_view(
+        &mut self,
+        document: &mut DocumentMut,
+        length: usize,
+        stride: Option<usize>,
+        reader: Box<dyn io::Read + 'a>,
+        target: Option<Target>,
+    ) -> Index<json::buffer::View> {
+        let mut offset = self.length;
+        binary::align_to_multiple_of_four(&mut offset);
+        self.length = offset + length;
+        assert!(self.length <= u32::MAX as usize, "buffer exceeds 4 GiB");
+        self.sources.push((offset, length, reader));
+        if let Some(buffer) = document.get_mut(self.buffer) {
+            buffer.byte_length = self.length as u32;
+        }
+        document.add(json::buffer::View {
+            buffer: self.buffer,
+            byte_length: length as u32,
+            byte_offset: Some(offset as u32),
+            byte_stride: stride.map(|stride| stride as u32),
+            #[cfg(feature = "names")]
+            name: None,
+            target: target.map(Valid),
+            extensions: None,
+            extras: Default::default(),
+        })
+    }
+}
+
+impl<'a> fmt::Debug for GlbWriter<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("GlbWriter")
+            .field("buffer", &self.buffer)
+            .field("views", &self.sources.len())
+            .field("length", &self.length)
+            .finish()
+    }
+}
+
+impl<I> io::Read for Items<I>
+where
+    I: Iterator,
+    I::Item: Element,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.position == self.item.len() {
+            let item = match self.items.next() {
+                Some(item) => item,
+                None => return Ok(0),
+            };
+            self.item.clear();
+            for index in 0..I::Item::TYPE.multiplicity() {
+                item.component(index).write(&mut self.item);
+            }
+            self.item.resize(self.stride, 0);
+            self.position = 0;
+        }
+        let length = buf.len().min(self.item.len() - self.position);
+        buf[..length].copy_from_slice(&self.item[self.position..self.position + length]);
+        self.position += length;
+        Ok(length)
+    }
+}
@@ -0,0 +1,67 @@
+use json::Value;
+
+use crate::{Error, Result};
+
+/// Removes the given extensions from all objects of a document, along with
+/// their entries in `extensionsUsed` and `extensionsRequired`.
+pub(crate) fn strip(json: &mut json::Root, names: &[String]) -> Result<()> {
+    json.extensions_used.retain(|name| !names.contains(name));
+    json.extensions_required.retain(|name| !names.contains(name));
+
+    // The extensions are removed from the generic JSON representation, so
+    // that all objects are covered regardless of the extensions supported.
+    let mut value = json.to_value().map_err(Error::Serialize)?;
+    remove(&mut value, names);
+    let data = json::serialize::to_vec(&value).map_err(Error::Serialize)?;
+    *json = json::Root::from_slice(&data).map_err(Error::Deserialize)?;
+    Ok(())
+}
+
+/// Removes the given extensions from a JSON value and all values nested in
+/// it, except for application specific data.
+fn remove(value: &mut Value, names: &[String]) {
+    match value {
+        Value::Object(object) => {
+            if let Some(Value::Object(extensions)) = object.get_mut("extensions") {
+                extensions.retain(|name, _| !names.contains(name));
+                if extensions.is_empty() {
+                    object.remove("extensions");
+                }
+            }
+            for (key, value) in object.iter_mut() {
+                if key != "extras" {
+                    remove(value, names);
+                }
+            }
+        }
+        Value::Array(array) => {
+            for value in array {
+                remove(value, names);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Assigns the materials of the given `KHR_materials_variants` variant to
+/// all primitives with a mapping for it.
+#[cfg(feature = "KHR_materials_variants")]
+pub(crate) fn bake_material_variant(json: &mut json::Root, variant: u32) {
+    for mesh in &mut json.meshes {
+        for primitive in &mut mesh.primitives {
+            let variants = primitive
+                .extensions
+                .as_ref()
+                .and_then(|extensions| extensions.khr_materials_variants.as_ref());
+            let mapping = variants.and_then(|variants| {
+                variants
+                    .mappings
+                    .iter()
+                    .find(|mapping| mapping.variants.contains(&variant))
+            });
+            if let Some(mapping) = mapping {
+                primitive.material = Some(mapping.material);
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{buffer, Document};
+
+/// Describes a light profile data source.
+#[derive(Clone, Debug)]
+pub enum Source<'a> {
+    /// Light profile data is contained in a buffer view.
+    View {
+        /// The buffer view containing the light profile data.
+        view: buffer::View<'a>,
+
+        /// The light profile data MIME type.
+        mime_type: &'a str,
+    },
+
+    /// Light profile data is contained in an external data source.
+    Uri {
+        /// The URI of the external data source.
+        uri: &'a str,
+
+        /// The light profile data MIME type, if provided.
+        mime_type: Option<&'a str>,
+    },
+}
+
+/// An IES light profile describing the distribution of light emitted by a
+/// light source.
+#[derive(Clone, Debug)]
+pub struct LightProfile<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_lights_ies::LightProfile,
+}
+
+/// An IES light profile instantiated by a node.
+#[derive(Clone, Debug)]
+pub struct Light<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_lights_ies::ExtLightsIes,
+}
+
+impl<'a> LightProfile<'a> {
+    /// Constructs a `LightProfile`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::ext_lights_ies::LightProfile,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the light profile data source.
+    pub fn source(&self) -> Source<'a> {
+        if let Some(index) = self.json.buffer_view.as_ref() {
+            let view = self.document.views().nth(index.value()).unwrap();
+            let mime_type = self.json.mime_type.as_deref().unwrap();
+            Source::View { view, mime_type }
+        } else {
+            let uri = self.json.uri.as_deref().unwrap();
+            let mime_type = self.json.mime_type.as_deref();
+            Source::Uri { uri, mime_type }
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Light<'a> {
+    /// Constructs a `Light`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::ext_lights_ies::ExtLightsIes,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the light profile.
+    pub fn profile(&self) -> LightProfile<'a> {
+        self.document
+            .light_profiles()
+            .unwrap()
+            .nth(self.json.light.value())
+            .unwrap()
+    }
+
+    /// Non-negative factor to scale the light intensity.
+    pub fn multiplier(&self) -> f32 {
+        self.json.multiplier.0
+    }
+
+    /// RGB value for the light's color in linear space.
+    pub fn color(&self) -> [f32; 3] {
+        self.json.color
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
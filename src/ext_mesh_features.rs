@@ -0,0 +1,117 @@
+use std::slice;
+
+use crate::{Document, Texture};
+
+/// Describes where the feature IDs of a set are stored.
+#[derive(Clone, Debug)]
+pub enum Source<'a> {
+    /// Feature IDs are stored in the `_FEATURE_ID_n` vertex attribute with the
+    /// given set index.
+    Attribute(u32),
+
+    /// Feature IDs are stored in channels of a texture.
+    Texture {
+        /// The texture storing the feature IDs.
+        texture: Texture<'a>,
+
+        /// The set index of the texture's `TEXCOORD` attribute.
+        tex_coord: u32,
+
+        /// The texture channels storing the feature ID, in little-endian
+        /// order.
+        channels: &'a [u32],
+    },
+
+    /// Feature IDs are implicitly given by the vertex index.
+    Implicit,
+}
+
+/// A set of feature IDs of a primitive.
+#[derive(Clone, Debug)]
+pub struct FeatureId<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::ext_mesh_features::FeatureId,
+}
+
+/// An `Iterator` that visits the sets of feature IDs of a primitive.
+#[derive(Clone, Debug)]
+pub struct FeatureIds<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal feature ID iterator.
+    pub(crate) iter: slice::Iter<'a, json::extensions::mesh::ext_mesh_features::FeatureId>,
+}
+
+impl<'a> FeatureId<'a> {
+    /// Constructs a `FeatureId`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::mesh::ext_mesh_features::FeatureId,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The number of distinct features.
+    pub fn feature_count(&self) -> u32 {
+        self.json.feature_count
+    }
+
+    /// A value denoting that no feature is associated with the vertex or texel.
+    pub fn null_feature_id(&self) -> Option<u32> {
+        self.json.null_feature_id
+    }
+
+    /// A label identifying the set of feature IDs.
+    pub fn label(&self) -> Option<&'a str> {
+        self.json.label.as_deref()
+    }
+
+    /// Returns where the feature IDs are stored.
+    pub fn source(&self) -> Source<'a> {
+        if let Some(set) = self.json.attribute {
+            Source::Attribute(set)
+        } else if let Some(texture) = self.json.texture.as_ref() {
+            Source::Texture {
+                texture: self.document.textures().nth(texture.index.value()).unwrap(),
+                tex_coord: texture.tex_coord,
+                channels: &texture.channels,
+            }
+        } else {
+            Source::Implicit
+        }
+    }
+
+    /// Returns the property table storing the metadata of the features as
+    /// defined by the `EXT_structural_metadata` extension.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn property_table(&self) -> Option<crate::ext_structural_metadata::PropertyTable<'a>> {
+        let index = self.json.property_table?;
+        self.document
+            .structural_metadata()?
+            .property_tables()
+            .nth(index as usize)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for FeatureIds<'a> {}
+impl<'a> Iterator for FeatureIds<'a> {
+    type Item = FeatureId<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|json| FeatureId::new(self.document, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,301 @@
+use std::collections::hash_map;
+
+use crate::{Accessor, Document};
+
+#[cfg(feature = "utils")]
+use crate::{accessor, animation::util::rotations, animation::util::Rotations, Buffer};
+
+#[cfg(feature = "utils")]
+use crate::scene::Transform;
+
+#[cfg(all(feature = "utils", feature = "KHR_mesh_quantization"))]
+use crate::mesh::util::ReadQuantized;
+
+/// Per-instance translations or scales, dequantized if necessary.
+#[cfg(all(feature = "utils", feature = "KHR_mesh_quantization"))]
+type Vectors<'s> = crate::mesh::util::quantized::Dequantize<'s, 3>;
+
+/// Per-instance translations or scales.
+#[cfg(all(feature = "utils", not(feature = "KHR_mesh_quantization")))]
+type Vectors<'s> = accessor::Iter<'s, [f32; 3]>;
+
+/// Per-instance attributes used to draw the mesh of a node many times.
+#[derive(Clone, Debug)]
+pub struct Instancing<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+}
+
+/// An `Iterator` that visits the instance attributes of a node.
+#[derive(Clone, Debug)]
+pub struct Attributes<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal attribute iterator.
+    iter: hash_map::Iter<'a, String, json::Index<json::Accessor>>,
+}
+
+impl<'a> Instancing<'a> {
+    /// Constructs an `Instancing`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::ext_mesh_gpu_instancing::ExtMeshGpuInstancing,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the accessor of the instance attribute with the given name.
+    pub fn get(&self, name: &str) -> Option<Accessor<'a>> {
+        self.json
+            .attributes
+            .get(name)
+            .map(|index| self.document.accessors().nth(index.value()).unwrap())
+    }
+
+    /// Returns an `Iterator` that visits all the instance attributes,
+    /// including application specific ones.
+    pub fn attributes(&self) -> Attributes<'a> {
+        Attributes {
+            document: self.document,
+            iter: self.json.attributes.iter(),
+        }
+    }
+
+    /// Returns the accessor containing the per-instance translations.
+    pub fn translation(&self) -> Option<Accessor<'a>> {
+        self.get("TRANSLATION")
+    }
+
+    /// Returns the accessor containing the per-instance rotations.
+    pub fn rotation(&self) -> Option<Accessor<'a>> {
+        self.get("ROTATION")
+    }
+
+    /// Returns the accessor containing the per-instance scales.
+    pub fn scale(&self) -> Option<Accessor<'a>> {
+        self.get("SCALE")
+    }
+
+    /// Returns the number of instances.
+    pub fn count(&self) -> usize {
+        self.attributes().map(|(_, accessor)| accessor.count()).max().unwrap_or(0)
+    }
+
+    /// Constructs an instance attribute reader.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Reader<'a, 's, F>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        Reader {
+            instancing: self.clone(),
+            get_buffer_data,
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for Attributes<'a> {}
+impl<'a> Iterator for Attributes<'a> {
+    type Item = (&'a str, Accessor<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(name, index)| {
+            let accessor = self.document.accessors().nth(index.value()).unwrap();
+            (name.as_str(), accessor)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Instance attribute reader.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    pub(crate) instancing: Instancing<'a>,
+    pub(crate) get_buffer_data: F,
+}
+
+/// Per-instance transforms as 4x4 matrices in column-major order.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct ReadMatrices<'s> {
+    translations: Option<Vectors<'s>>,
+    rotations: Option<rotations::CastingIter<'s, rotations::F32>>,
+    scales: Option<Vectors<'s>>,
+    remaining: usize,
+}
+
+/// Per-instance world transforms as 4x4 matrices in column-major order.
+#[cfg(feature = "utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+#[derive(Clone, Debug)]
+pub struct ReadWorldMatrices<'s> {
+    matrices: ReadMatrices<'s>,
+    world_transform: [[f32; 4]; 4],
+}
+
+#[cfg(feature = "utils")]
+impl<'a, 's, F> Reader<'a, 's, F>
+where
+    F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+{
+    /// Visits the per-instance translations.
+    pub fn read_translations(&self) -> Option<accessor::Iter<'s, [f32; 3]>> {
+        self.instancing
+            .translation()
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
+    }
+
+    /// Visits the per-instance rotations.
+    pub fn read_rotations(&self) -> Option<Rotations<'s>> {
+        use accessor::{DataType, Iter};
+        let accessor = self.instancing.rotation()?;
+        let get_buffer_data = self.get_buffer_data.clone();
+        match accessor.data_type() {
+            DataType::I8 => Iter::new(accessor, get_buffer_data).ok().map(Rotations::I8),
+            DataType::I16 => Iter::new(accessor, get_buffer_data).ok().map(Rotations::I16),
+            DataType::F32 => Iter::new(accessor, get_buffer_data).ok().map(Rotations::F32),
+            _ => None,
+        }
+    }
+
+    /// Visits the per-instance scales.
+    pub fn read_scales(&self) -> Option<accessor::Iter<'s, [f32; 3]>> {
+        self.instancing
+            .scale()
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
+    }
+
+    /// Visits the per-instance translations, which may be quantized as
+    /// allowed by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_translations(&self) -> Option<ReadQuantized<'s, 3>> {
+        self.instancing
+            .translation()
+            .and_then(|accessor| ReadQuantized::new(accessor, self.get_buffer_data.clone()))
+    }
+
+    /// Visits the per-instance scales, which may be quantized as allowed by
+    /// `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_scales(&self) -> Option<ReadQuantized<'s, 3>> {
+        self.instancing
+            .scale()
+            .and_then(|accessor| ReadQuantized::new(accessor, self.get_buffer_data.clone()))
+    }
+
+    /// Visits the per-instance transforms as 4x4 matrices.
+    ///
+    /// Missing attributes default to the identity transform. The matrices
+    /// do not include the transform of the node itself.
+    ///
+    /// Quantized translations and scales are dequantized with the
+    /// `KHR_mesh_quantization` feature. Returns `None` if an attribute is
+    /// present but cannot be read, such as a quantized attribute without the
+    /// feature, rather than visiting wrong transforms.
+    pub fn read_matrices(&self) -> Option<ReadMatrices<'s>> {
+        #[cfg(feature = "KHR_mesh_quantization")]
+        let (translations, scales) = (
+            self.read_quantized_translations().map(ReadQuantized::into_f32),
+            self.read_quantized_scales().map(ReadQuantized::into_f32),
+        );
+        #[cfg(not(feature = "KHR_mesh_quantization"))]
+        let (translations, scales) = (self.read_translations(), self.read_scales());
+        let rotations = self.read_rotations().map(Rotations::into_f32);
+
+        let instancing = &self.instancing;
+        if translations.is_none() && instancing.translation().is_some()
+            || rotations.is_none() && instancing.rotation().is_some()
+            || scales.is_none() && instancing.scale().is_some()
+        {
+            return None;
+        }
+        Some(ReadMatrices {
+            translations,
+            rotations,
+            scales,
+            remaining: instancing.count(),
+        })
+    }
+
+    /// Visits the per-instance world transforms as 4x4 matrices, given the
+    /// world transform of the node.
+    ///
+    /// Each matrix is the world transform of the node multiplied by the
+    /// transform of the instance, so that the mesh of an instanced node may
+    /// be drawn once per matrix without support for instancing. Returns
+    /// `None` under the same conditions as `read_matrices`.
+    pub fn read_world_matrices(&self, world_transform: [[f32; 4]; 4]) -> Option<ReadWorldMatrices<'s>> {
+        Some(ReadWorldMatrices {
+            matrices: self.read_matrices()?,
+            world_transform,
+        })
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'s> ExactSizeIterator for ReadMatrices<'s> {}
+#[cfg(feature = "utils")]
+impl<'s> Iterator for ReadMatrices<'s> {
+    type Item = [[f32; 4]; 4];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        let translation = self.translations.as_mut().and_then(Iterator::next);
+        let rotation = self.rotations.as_mut().and_then(Iterator::next);
+        let scale = self.scales.as_mut().and_then(Iterator::next);
+        let transform = Transform::Decomposed {
+            translation: translation.unwrap_or([0.0, 0.0, 0.0]),
+            rotation: rotation.unwrap_or([0.0, 0.0, 0.0, 1.0]),
+            scale: scale.unwrap_or([1.0, 1.0, 1.0]),
+        };
+        Some(transform.matrix())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+#[cfg(feature = "utils")]
+impl<'s> ExactSizeIterator for ReadWorldMatrices<'s> {}
+#[cfg(feature = "utils")]
+impl<'s> Iterator for ReadWorldMatrices<'s> {
+    type Item = [[f32; 4]; 4];
+    fn next(&mut self) -> Option<Self::Item> {
+        let instance = self.matrices.next()?;
+        let world = &self.world_transform;
+        let mut m = [[0.0; 4]; 4];
+        for (column, instance) in m.iter_mut().zip(&instance) {
+            for (row, value) in column.iter_mut().enumerate() {
+                *value = (0..4).map(|k| world[k][row] * instance[k]).sum();
+            }
+        }
+        Some(m)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.matrices.size_hint()
+    }
+}
@@ -0,0 +1,1175 @@
+use std::{collections::hash_map, convert::TryInto, iter, slice};
+
+use crate::{Document, Texture};
+
+pub use json::extensions::root::ext_structural_metadata::{ComponentType, Type};
+
+/// The structured metadata of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct Metadata<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::ExtStructuralMetadata,
+}
+
+/// Describes the classes and enums of the metadata.
+#[derive(Clone, Debug)]
+pub struct Schema<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::Schema,
+}
+
+/// A class describing the properties of entities.
+#[derive(Clone, Debug)]
+pub struct Class<'a> {
+    /// The parent `Schema` struct.
+    schema: Schema<'a>,
+
+    /// The class identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::Class,
+}
+
+/// A property of a class.
+#[derive(Clone, Debug)]
+pub struct ClassProperty<'a> {
+    /// The parent `Schema` struct.
+    schema: Schema<'a>,
+
+    /// The property identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::ClassProperty,
+}
+
+/// A set of named integer values.
+#[derive(Clone, Debug)]
+pub struct Enum<'a> {
+    /// The enum identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::Enum,
+}
+
+/// Stores the property values of a class for a number of features in buffer
+/// views.
+#[derive(Clone, Debug)]
+pub struct PropertyTable<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyTable,
+}
+
+/// A column of a property table.
+#[derive(Clone, Debug)]
+pub struct PropertyTableProperty<'a> {
+    /// The parent `PropertyTable` struct.
+    table: PropertyTable<'a>,
+
+    /// The class property identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyTableProperty,
+}
+
+/// Stores the property values of a class in the channels of textures.
+#[derive(Clone, Debug)]
+pub struct PropertyTexture<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyTexture,
+}
+
+/// The texture channels storing a property of a property texture.
+#[derive(Clone, Debug)]
+pub struct PropertyTextureProperty<'a> {
+    /// The parent `PropertyTexture` struct.
+    texture: PropertyTexture<'a>,
+
+    /// The class property identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyTextureProperty,
+}
+
+/// Stores the property values of a class in vertex attributes.
+#[derive(Clone, Debug)]
+pub struct PropertyAttribute<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyAttribute,
+}
+
+/// The vertex attribute storing a property of a property attribute.
+#[derive(Clone, Debug)]
+pub struct PropertyAttributeProperty<'a> {
+    /// The parent `PropertyAttribute` struct.
+    attribute: PropertyAttribute<'a>,
+
+    /// The class property identifier.
+    id: &'a str,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::ext_structural_metadata::PropertyAttributeProperty,
+}
+
+/// A property value read from a property table.
+///
+/// Values are raw, i.e. `normalized`, `offset` and `scale` have not been
+/// applied, and enum values are returned as integers.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'s> {
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A signed integer component.
+    Int(i64),
+
+    /// An unsigned integer component.
+    UInt(u64),
+
+    /// A floating point component.
+    Float(f64),
+
+    /// A string value.
+    String(&'s str),
+
+    /// The components of a vector or matrix, or the elements of an array.
+    Array(Vec<Value<'s>>),
+}
+
+/// Reads the values of a property table column.
+#[derive(Clone, Debug)]
+pub struct ReadValues<'s> {
+    /// The element type.
+    type_: Type,
+
+    /// The component type of numeric and enum elements.
+    component_type: Option<ComponentType>,
+
+    /// Whether each value is an array.
+    array: bool,
+
+    /// The number of elements of fixed-length arrays.
+    count: Option<usize>,
+
+    /// The value data.
+    values: &'s [u8],
+
+    /// The array offset data and type.
+    array_offsets: Option<(&'s [u8], ComponentType)>,
+
+    /// The string offset data and type.
+    string_offsets: Option<(&'s [u8], ComponentType)>,
+
+    /// The index of the next row to visit.
+    row: usize,
+
+    /// The number of rows.
+    rows: usize,
+}
+
+/// An `Iterator` that visits the classes of a schema.
+#[derive(Clone, Debug)]
+pub struct Classes<'a> {
+    /// The parent `Schema` struct.
+    schema: Schema<'a>,
+
+    /// The internal class iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::Class>,
+}
+
+/// An `Iterator` that visits the properties of a class.
+#[derive(Clone, Debug)]
+pub struct ClassProperties<'a> {
+    /// The parent `Schema` struct.
+    schema: Schema<'a>,
+
+    /// The internal property iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::ClassProperty>,
+}
+
+/// An `Iterator` that visits the enums of a schema.
+#[derive(Clone, Debug)]
+pub struct Enums<'a> {
+    /// The internal enum iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::Enum>,
+}
+
+/// An `Iterator` that visits the property tables of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct PropertyTables<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal property table iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::ext_structural_metadata::PropertyTable>>,
+}
+
+/// An `Iterator` that visits the columns of a property table.
+#[derive(Clone, Debug)]
+pub struct PropertyTableProperties<'a> {
+    /// The parent `PropertyTable` struct.
+    table: PropertyTable<'a>,
+
+    /// The internal property iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::PropertyTableProperty>,
+}
+
+/// An `Iterator` that visits the property textures of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct PropertyTextures<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal property texture iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::ext_structural_metadata::PropertyTexture>>,
+}
+
+/// An `Iterator` that visits the properties of a property texture.
+#[derive(Clone, Debug)]
+pub struct PropertyTextureProperties<'a> {
+    /// The parent `PropertyTexture` struct.
+    texture: PropertyTexture<'a>,
+
+    /// The internal property iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::PropertyTextureProperty>,
+}
+
+/// An `Iterator` that visits the property attributes of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct PropertyAttributes<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal property attribute iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::ext_structural_metadata::PropertyAttribute>>,
+}
+
+/// An `Iterator` that visits the properties of a property attribute.
+#[derive(Clone, Debug)]
+pub struct PropertyAttributeProperties<'a> {
+    /// The parent `PropertyAttribute` struct.
+    attribute: PropertyAttribute<'a>,
+
+    /// The internal property iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::ext_structural_metadata::PropertyAttributeProperty>,
+}
+
+/// An `Iterator` that visits the property textures of a primitive.
+#[derive(Clone, Debug)]
+pub struct PrimitivePropertyTextures<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal property texture index iterator.
+    pub(crate) iter: slice::Iter<'a, json::Index<json::extensions::root::ext_structural_metadata::PropertyTexture>>,
+}
+
+/// An `Iterator` that visits the property attributes of a primitive.
+#[derive(Clone, Debug)]
+pub struct PrimitivePropertyAttributes<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal property attribute index iterator.
+    pub(crate) iter: slice::Iter<'a, json::Index<json::extensions::root::ext_structural_metadata::PropertyAttribute>>,
+}
+
+impl<'a> Metadata<'a> {
+    /// Constructs a `Metadata`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::root::ext_structural_metadata::ExtStructuralMetadata,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the schema embedded in the asset, or `None` if the schema is
+    /// stored at `schema_uri`.
+    pub fn schema(&self) -> Option<Schema<'a>> {
+        self.json.schema.as_ref().map(|json| Schema { json })
+    }
+
+    /// Returns the URI of an external schema.
+    pub fn schema_uri(&self) -> Option<&'a str> {
+        self.json.schema_uri.as_deref()
+    }
+
+    /// Returns an `Iterator` that visits the property tables.
+    pub fn property_tables(&self) -> PropertyTables<'a> {
+        PropertyTables {
+            document: self.document,
+            iter: self.json.property_tables.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the property textures.
+    pub fn property_textures(&self) -> PropertyTextures<'a> {
+        PropertyTextures {
+            document: self.document,
+            iter: self.json.property_textures.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the property attributes.
+    pub fn property_attributes(&self) -> PropertyAttributes<'a> {
+        PropertyAttributes {
+            document: self.document,
+            iter: self.json.property_attributes.iter().enumerate(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Schema<'a> {
+    /// The unique identifier of the schema.
+    pub fn id(&self) -> &'a str {
+        &self.json.id
+    }
+
+    /// The name of the schema.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// The description of the schema.
+    pub fn description(&self) -> Option<&'a str> {
+        self.json.description.as_deref()
+    }
+
+    /// The application specific version of the schema.
+    pub fn version(&self) -> Option<&'a str> {
+        self.json.version.as_deref()
+    }
+
+    /// Returns an `Iterator` that visits the classes of the schema.
+    pub fn classes(&self) -> Classes<'a> {
+        Classes {
+            schema: self.clone(),
+            iter: self.json.classes.iter(),
+        }
+    }
+
+    /// Returns the class with the given identifier.
+    pub fn class(&self, id: &str) -> Option<Class<'a>> {
+        let (id, json) = self.json.classes.get_key_value(id)?;
+        Some(Class {
+            schema: self.clone(),
+            id,
+            json,
+        })
+    }
+
+    /// Returns an `Iterator` that visits the enums of the schema.
+    pub fn enums(&self) -> Enums<'a> {
+        Enums {
+            iter: self.json.enums.iter(),
+        }
+    }
+
+    /// Returns the enum with the given identifier.
+    pub fn enumeration(&self, id: &str) -> Option<Enum<'a>> {
+        let (id, json) = self.json.enums.get_key_value(id)?;
+        Some(Enum { id, json })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Class<'a> {
+    /// The class identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// The name of the class.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// The description of the class.
+    pub fn description(&self) -> Option<&'a str> {
+        self.json.description.as_deref()
+    }
+
+    /// Returns an `Iterator` that visits the properties of the class.
+    pub fn properties(&self) -> ClassProperties<'a> {
+        ClassProperties {
+            schema: self.schema.clone(),
+            iter: self.json.properties.iter(),
+        }
+    }
+
+    /// Returns the property with the given identifier.
+    pub fn property(&self, id: &str) -> Option<ClassProperty<'a>> {
+        let (id, json) = self.json.properties.get_key_value(id)?;
+        Some(ClassProperty {
+            schema: self.schema.clone(),
+            id,
+            json,
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ClassProperty<'a> {
+    /// The property identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// The name of the property.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// The description of the property.
+    pub fn description(&self) -> Option<&'a str> {
+        self.json.description.as_deref()
+    }
+
+    /// The element type.
+    pub fn type_(&self) -> Type {
+        self.json.type_.unwrap()
+    }
+
+    /// The component type of numeric elements, or the value type of enum
+    /// elements.
+    pub fn component_type(&self) -> Option<ComponentType> {
+        match self.type_() {
+            Type::Enum => self.enumeration().map(|enumeration| enumeration.value_type()),
+            _ => self.json.component_type.map(|component_type| component_type.unwrap()),
+        }
+    }
+
+    /// The enum of enum elements.
+    pub fn enumeration(&self) -> Option<Enum<'a>> {
+        self.schema.enumeration(self.json.enum_type.as_deref()?)
+    }
+
+    /// Whether the property is an array of elements.
+    pub fn is_array(&self) -> bool {
+        self.json.array
+    }
+
+    /// The number of elements of fixed-length arrays, or `None` for
+    /// variable-length arrays.
+    pub fn count(&self) -> Option<u32> {
+        self.json.count
+    }
+
+    /// Whether integer values are normalized to `[0, 1]` or `[-1, 1]`.
+    pub fn normalized(&self) -> bool {
+        self.json.normalized
+    }
+
+    /// An offset added to the values after normalization.
+    pub fn offset(&self) -> Option<&'a json::Value> {
+        self.json.offset.as_ref()
+    }
+
+    /// A factor applied to the values after normalization.
+    pub fn scale(&self) -> Option<&'a json::Value> {
+        self.json.scale.as_ref()
+    }
+
+    /// The maximum value after transformation.
+    pub fn max(&self) -> Option<&'a json::Value> {
+        self.json.max.as_ref()
+    }
+
+    /// The minimum value after transformation.
+    pub fn min(&self) -> Option<&'a json::Value> {
+        self.json.min.as_ref()
+    }
+
+    /// Whether every entity must provide a value for the property.
+    pub fn required(&self) -> bool {
+        self.json.required
+    }
+
+    /// The raw value denoting that no value is available.
+    pub fn no_data(&self) -> Option<&'a json::Value> {
+        self.json.no_data.as_ref()
+    }
+
+    /// The value to use in place of missing values.
+    pub fn default(&self) -> Option<&'a json::Value> {
+        self.json.default.as_ref()
+    }
+
+    /// An identifier describing how the property should be interpreted.
+    pub fn semantic(&self) -> Option<&'a str> {
+        self.json.semantic.as_deref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Enum<'a> {
+    /// The enum identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// The name of the enum.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// The description of the enum.
+    pub fn description(&self) -> Option<&'a str> {
+        self.json.description.as_deref()
+    }
+
+    /// The integer type used to store the values.
+    pub fn value_type(&self) -> ComponentType {
+        self.json.value_type.unwrap()
+    }
+
+    /// Returns an `Iterator` that visits the names and values of the enum.
+    pub fn values(&self) -> impl Iterator<Item = (&'a str, i64)> + 'a {
+        self.json
+            .values
+            .iter()
+            .map(|value| (value.name.as_str(), value.value))
+    }
+
+    /// Returns the name of the given value.
+    pub fn name_of(&self, value: i64) -> Option<&'a str> {
+        self.values().find(|(_, v)| *v == value).map(|(name, _)| name)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyTable<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the property table.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the class of the property values, or `None` if the schema is
+    /// external.
+    pub fn class(&self) -> Option<Class<'a>> {
+        self.document
+            .structural_metadata()?
+            .schema()?
+            .class(&self.json.class)
+    }
+
+    /// The number of features, i.e. rows of the table.
+    pub fn count(&self) -> u32 {
+        self.json.count
+    }
+
+    /// Returns an `Iterator` that visits the columns of the table.
+    pub fn properties(&self) -> PropertyTableProperties<'a> {
+        PropertyTableProperties {
+            table: self.clone(),
+            iter: self.json.properties.iter(),
+        }
+    }
+
+    /// Returns the column of the class property with the given identifier.
+    pub fn property(&self, id: &str) -> Option<PropertyTableProperty<'a>> {
+        let (id, json) = self.json.properties.get_key_value(id)?;
+        Some(PropertyTableProperty {
+            table: self.clone(),
+            id,
+            json,
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyTableProperty<'a> {
+    /// The class property identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// Returns the class property, or `None` if the schema is external.
+    pub fn class_property(&self) -> Option<ClassProperty<'a>> {
+        self.table.class()?.property(self.id)
+    }
+
+    /// The offset added to the values, overriding the class property.
+    pub fn offset(&self) -> Option<&'a json::Value> {
+        self.json.offset.as_ref()
+    }
+
+    /// The factor applied to the values, overriding the class property.
+    pub fn scale(&self) -> Option<&'a json::Value> {
+        self.json.scale.as_ref()
+    }
+
+    /// The maximum value, overriding the class property.
+    pub fn max(&self) -> Option<&'a json::Value> {
+        self.json.max.as_ref()
+    }
+
+    /// The minimum value, overriding the class property.
+    pub fn min(&self) -> Option<&'a json::Value> {
+        self.json.min.as_ref()
+    }
+
+    /// Returns a reader for the values of the column.
+    ///
+    /// Returns `None` if the schema is external or if the buffer data is
+    /// unavailable.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn reader<'s, F>(&self, get_buffer_data: F) -> Option<ReadValues<'s>>
+    where
+        F: Fn(crate::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let class_property = self.class_property()?;
+        let document = self.table.document;
+        let view = |index: json::Index<json::buffer::View>| {
+            let view = document.views().nth(index.value()).unwrap();
+            let data = get_buffer_data(view.buffer())?;
+            data.get(view.offset()..view.offset() + view.length())
+        };
+        let array_offsets = match self.json.array_offsets {
+            Some(index) => Some((view(index)?, self.json.array_offset_type.unwrap())),
+            None => None,
+        };
+        let string_offsets = match self.json.string_offsets {
+            Some(index) => Some((view(index)?, self.json.string_offset_type.unwrap())),
+            None => None,
+        };
+        Some(ReadValues {
+            type_: class_property.type_(),
+            component_type: class_property.component_type(),
+            array: class_property.is_array(),
+            count: class_property.count().map(|count| count as usize),
+            values: view(self.json.values)?,
+            array_offsets,
+            string_offsets,
+            row: 0,
+            rows: self.table.count() as usize,
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'s> ReadValues<'s> {
+    /// Returns the value of the given row.
+    ///
+    /// Returns `None` if the row is out of range or if the buffer data is
+    /// malformed.
+    pub fn get(&self, row: usize) -> Option<Value<'s>> {
+        if row >= self.rows {
+            return None;
+        }
+        if !self.array {
+            return self.element(row);
+        }
+        let (start, end) = match self.count {
+            Some(count) => (row * count, (row + 1) * count),
+            None => {
+                let (data, offset_type) = self.array_offsets?;
+                (offset(data, offset_type, row)?, offset(data, offset_type, row + 1)?)
+            }
+        };
+        (start..end)
+            .map(|index| self.element(index))
+            .collect::<Option<_>>()
+            .map(Value::Array)
+    }
+
+    /// Reads the element with the given index.
+    fn element(&self, index: usize) -> Option<Value<'s>> {
+        match self.type_ {
+            Type::Boolean => {
+                let byte = self.values.get(index / 8)?;
+                Some(Value::Boolean(byte & (1 << (index % 8)) != 0))
+            }
+            Type::String => {
+                let (data, offset_type) = self.string_offsets?;
+                let start = offset(data, offset_type, index)?;
+                let end = offset(data, offset_type, index + 1)?;
+                let bytes = self.values.get(start..end)?;
+                std::str::from_utf8(bytes).ok().map(Value::String)
+            }
+            Type::Scalar | Type::Enum => component(self.values, self.component_type?, index),
+            type_ => {
+                let n = type_.multiplicity();
+                (index * n..(index + 1) * n)
+                    .map(|index| component(self.values, self.component_type.unwrap(), index))
+                    .collect::<Option<_>>()
+                    .map(Value::Array)
+            }
+        }
+    }
+}
+
+impl<'s> ExactSizeIterator for ReadValues<'s> {}
+impl<'s> Iterator for ReadValues<'s> {
+    type Item = Value<'s>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.get(self.row)?;
+        self.row += 1;
+        Some(value)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let hint = self.rows - self.row;
+        (hint, Some(hint))
+    }
+}
+
+/// Reads the component with the given index.
+fn component<'s>(data: &[u8], component_type: ComponentType, index: usize) -> Option<Value<'s>> {
+    use self::ComponentType::*;
+    let size = component_type.size();
+    let bytes = data.get(index * size..(index + 1) * size)?;
+    Some(match component_type {
+        I8 => Value::Int(bytes[0] as i8 as i64),
+        U8 => Value::UInt(bytes[0] as u64),
+        I16 => Value::Int(i16::from_le_bytes(bytes.try_into().unwrap()) as i64),
+        U16 => Value::UInt(u16::from_le_bytes(bytes.try_into().unwrap()) as u64),
+        I32 => Value::Int(i32::from_le_bytes(bytes.try_into().unwrap()) as i64),
+        U32 => Value::UInt(u32::from_le_bytes(bytes.try_into().unwrap()) as u64),
+        I64 => Value::Int(i64::from_le_bytes(bytes.try_into().unwrap())),
+        U64 => Value::UInt(u64::from_le_bytes(bytes.try_into().unwrap())),
+        F32 => Value::Float(f32::from_le_bytes(bytes.try_into().unwrap()) as f64),
+        F64 => Value::Float(f64::from_le_bytes(bytes.try_into().unwrap())),
+    })
+}
+
+/// Reads the array or string offset with the given index.
+fn offset(data: &[u8], offset_type: ComponentType, index: usize) -> Option<usize> {
+    match component(data, offset_type, index)? {
+        Value::UInt(offset) => Some(offset as usize),
+        _ => None,
+    }
+}
+
+impl<'a> PropertyTexture<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the property texture.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the class of the property values, or `None` if the schema is
+    /// external.
+    pub fn class(&self) -> Option<Class<'a>> {
+        self.document
+            .structural_metadata()?
+            .schema()?
+            .class(&self.json.class)
+    }
+
+    /// Returns an `Iterator` that visits the properties of the property
+    /// texture.
+    pub fn properties(&self) -> PropertyTextureProperties<'a> {
+        PropertyTextureProperties {
+            texture: self.clone(),
+            iter: self.json.properties.iter(),
+        }
+    }
+
+    /// Returns the property of the class property with the given identifier.
+    pub fn property(&self, id: &str) -> Option<PropertyTextureProperty<'a>> {
+        let (id, json) = self.json.properties.get_key_value(id)?;
+        Some(PropertyTextureProperty {
+            texture: self.clone(),
+            id,
+            json,
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyTextureProperty<'a> {
+    /// The class property identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// Returns the class property, or `None` if the schema is external.
+    pub fn class_property(&self) -> Option<ClassProperty<'a>> {
+        self.texture.class()?.property(self.id)
+    }
+
+    /// Returns the texture storing the property values.
+    pub fn texture(&self) -> Texture<'a> {
+        self.texture
+            .document
+            .textures()
+            .nth(self.json.index.value())
+            .unwrap()
+    }
+
+    /// The set index of the texture's `TEXCOORD` attribute.
+    pub fn tex_coord(&self) -> u32 {
+        self.json.tex_coord
+    }
+
+    /// The texture channels storing the value, in little-endian order.
+    pub fn channels(&self) -> &'a [u32] {
+        &self.json.channels
+    }
+
+    /// The offset added to the values, overriding the class property.
+    pub fn offset(&self) -> Option<&'a json::Value> {
+        self.json.offset.as_ref()
+    }
+
+    /// The factor applied to the values, overriding the class property.
+    pub fn scale(&self) -> Option<&'a json::Value> {
+        self.json.scale.as_ref()
+    }
+
+    /// The maximum value, overriding the class property.
+    pub fn max(&self) -> Option<&'a json::Value> {
+        self.json.max.as_ref()
+    }
+
+    /// The minimum value, overriding the class property.
+    pub fn min(&self) -> Option<&'a json::Value> {
+        self.json.min.as_ref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyAttribute<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the property attribute.
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the class of the property values, or `None` if the schema is
+    /// external.
+    pub fn class(&self) -> Option<Class<'a>> {
+        self.document
+            .structural_metadata()?
+            .schema()?
+            .class(&self.json.class)
+    }
+
+    /// Returns an `Iterator` that visits the properties of the property
+    /// attribute.
+    pub fn properties(&self) -> PropertyAttributeProperties<'a> {
+        PropertyAttributeProperties {
+            attribute: self.clone(),
+            iter: self.json.properties.iter(),
+        }
+    }
+
+    /// Returns the property of the class property with the given identifier.
+    pub fn property(&self, id: &str) -> Option<PropertyAttributeProperty<'a>> {
+        let (id, json) = self.json.properties.get_key_value(id)?;
+        Some(PropertyAttributeProperty {
+            attribute: self.clone(),
+            id,
+            json,
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> PropertyAttributeProperty<'a> {
+    /// The class property identifier.
+    pub fn id(&self) -> &'a str {
+        self.id
+    }
+
+    /// Returns the class property, or `None` if the schema is external.
+    pub fn class_property(&self) -> Option<ClassProperty<'a>> {
+        self.attribute.class()?.property(self.id)
+    }
+
+    /// The name of the vertex attribute storing the values, for example
+    /// `_TEMPERATURE`.
+    ///
+    /// With the `extras` feature, the attribute can be retrieved with
+    /// `Primitive::get` using `Semantic::Extras`, without the leading
+    /// underscore.
+    pub fn attribute(&self) -> &'a str {
+        &self.json.attribute
+    }
+
+    /// The offset added to the values, overriding the class property.
+    pub fn offset(&self) -> Option<&'a json::Value> {
+        self.json.offset.as_ref()
+    }
+
+    /// The factor applied to the values, overriding the class property.
+    pub fn scale(&self) -> Option<&'a json::Value> {
+        self.json.scale.as_ref()
+    }
+
+    /// The maximum value, overriding the class property.
+    pub fn max(&self) -> Option<&'a json::Value> {
+        self.json.max.as_ref()
+    }
+
+    /// The minimum value, overriding the class property.
+    pub fn min(&self) -> Option<&'a json::Value> {
+        self.json.min.as_ref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for Classes<'a> {}
+impl<'a> Iterator for Classes<'a> {
+    type Item = Class<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let schema = &self.schema;
+        self.iter.next().map(|(id, json)| Class {
+            schema: schema.clone(),
+            id,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for ClassProperties<'a> {}
+impl<'a> Iterator for ClassProperties<'a> {
+    type Item = ClassProperty<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let schema = &self.schema;
+        self.iter.next().map(|(id, json)| ClassProperty {
+            schema: schema.clone(),
+            id,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Enums<'a> {}
+impl<'a> Iterator for Enums<'a> {
+    type Item = Enum<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(id, json)| Enum { id, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyTables<'a> {}
+impl<'a> Iterator for PropertyTables<'a> {
+    type Item = PropertyTable<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|(index, json)| PropertyTable { document, index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .nth(n)
+            .map(|(index, json)| PropertyTable { document, index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyTableProperties<'a> {}
+impl<'a> Iterator for PropertyTableProperties<'a> {
+    type Item = PropertyTableProperty<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let table = &self.table;
+        self.iter.next().map(|(id, json)| PropertyTableProperty {
+            table: table.clone(),
+            id,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyTextures<'a> {}
+impl<'a> Iterator for PropertyTextures<'a> {
+    type Item = PropertyTexture<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|(index, json)| PropertyTexture { document, index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .nth(n)
+            .map(|(index, json)| PropertyTexture { document, index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyTextureProperties<'a> {}
+impl<'a> Iterator for PropertyTextureProperties<'a> {
+    type Item = PropertyTextureProperty<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let texture = &self.texture;
+        self.iter.next().map(|(id, json)| PropertyTextureProperty {
+            texture: texture.clone(),
+            id,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyAttributes<'a> {}
+impl<'a> Iterator for PropertyAttributes<'a> {
+    type Item = PropertyAttribute<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|(index, json)| PropertyAttribute { document, index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .nth(n)
+            .map(|(index, json)| PropertyAttribute { document, index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for PropertyAttributeProperties<'a> {}
+impl<'a> Iterator for PropertyAttributeProperties<'a> {
+    type Item = PropertyAttributeProperty<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let attribute = &self.attribute;
+        self.iter.next().map(|(id, json)| PropertyAttributeProperty {
+            attribute: attribute.clone(),
+            id,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for PrimitivePropertyTextures<'a> {}
+impl<'a> Iterator for PrimitivePropertyTextures<'a> {
+    type Item = PropertyTexture<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.iter.next()?;
+        self.document
+            .structural_metadata()?
+            .property_textures()
+            .nth(index.value())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for PrimitivePropertyAttributes<'a> {}
+impl<'a> Iterator for PrimitivePropertyAttributes<'a> {
+    type Item = PropertyAttribute<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.iter.next()?;
+        self.document
+            .structural_metadata()?
+            .property_attributes()
+            .nth(index.value())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
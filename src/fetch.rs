@@ -0,0 +1,66 @@
+use js_sys::{Uint8Array, global};
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Response, Window, WorkerGlobalScope};
+
+use crate::import::{AsyncResolver, ResolveFuture};
+use crate::{Error, Result};
+
+/// Fetches URIs with the `fetch` API of the browser on asynchronous import.
+///
+/// Relative URIs are resolved against the URI of the imported file, which
+/// is in turn resolved against the URL of the page or worker by the browser.
+/// Resolvers may be used both in windows and in workers.
+///
+/// # Examples
+///
+/// ```ignore
+/// let resolver = gltf::FetchResolver::new();
+/// let (document, buffers, images) = gltf::import_async("models/Box.gltf", &resolver).await?;
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FetchResolver;
+
+impl FetchResolver {
+    /// Creates a resolver.
+    pub fn new() -> Self {
+        FetchResolver
+    }
+}
+
+impl AsyncResolver for FetchResolver {
+    fn resolve<'a>(&'a self, uri: &'a str) -> ResolveFuture<'a> {
+        Box::pin(fetch(uri))
+    }
+}
+
+/// Fetches the data referenced by a URI.
+async fn fetch(uri: &str) -> Result<Vec<u8>> {
+    let global = global();
+    let promise = if let Some(window) = global.dyn_ref::<Window>() {
+        window.fetch_with_str(uri)
+    } else if let Some(worker) = global.dyn_ref::<WorkerGlobalScope>() {
+        worker.fetch_with_str(uri)
+    } else {
+        return Err(Error::Fetch("fetch is not available".to_owned()));
+    };
+    let response = JsFuture::from(promise).await.map_err(fetch_error)?;
+    let response = response.dyn_into::<Response>().map_err(fetch_error)?;
+    if !response.ok() {
+        let message = format!("{}: {} {}", uri, response.status(), response.status_text());
+        return Err(Error::Fetch(message));
+    }
+    let data = JsFuture::from(response.array_buffer().map_err(fetch_error)?)
+        .await
+        .map_err(fetch_error)?;
+    Ok(Uint8Array::new(&data).to_vec())
+}
+
+/// Converts an error thrown by the browser.
+fn fetch_error(error: JsValue) -> Error {
+    let message = match error.dyn_ref::<js_sys::Error>() {
+        Some(error) => String::from(error.message()),
+        None => format!("{:?}", error),
+    };
+    Error::Fetch(message)
+}
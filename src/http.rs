@@ -0,0 +1,139 @@
+use std::io::{self, Read};
+use std::time::Duration;
+
+use crate::import::Resolver;
+use crate::{Error, Result};
+
+/// Fetches `http` and `https` URIs on import.
+///
+/// Files imported with [`import`] fetch absolute `http` and `https` URIs
+/// with the default settings. Remote files may be imported with other
+/// settings by passing a resolver to [`import_with_resolver`], which also
+/// resolves relative URIs against the URI of the imported file.
+///
+/// [`import`]: fn.import.html
+/// [`import_with_resolver`]: fn.import_with_resolver.html
+///
+/// # Examples
+///
+/// ```no_run
+/// # fn run() -> Result<(), gltf::Error> {
+/// use std::time::Duration;
+///
+/// let mut resolver = gltf::HttpResolver::new();
+/// resolver.timeout(Duration::from_secs(10)).max_size(64 << 20);
+/// let (document, buffers, images) = gltf::import_with_resolver("https://example.com/Box.gltf", &resolver)?;
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("runtime error");
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct HttpResolver {
+    /// The client making the requests.
+    agent: ureq::Agent,
+
+    /// The timeout of each request, if any.
+    timeout: Option<Duration>,
+
+    /// The maximum size of each resource in bytes, if any.
+    max_size: Option<u64>,
+}
+
+impl HttpResolver {
+    /// Creates a resolver with a default client, a timeout of 30 seconds,
+    /// and no size limit.
+    pub fn new() -> Self {
+        Self::with_agent(ureq::Agent::new())
+    }
+
+    /// Creates a resolver making requests with the given client.
+    pub fn with_agent(agent: ureq::Agent) -> Self {
+        Self {
+            agent,
+            timeout: Some(Duration::from_secs(30)),
+            max_size: None,
+        }
+    }
+
+    /// Sets the timeout of each request, or `None` to wait indefinitely.
+    pub fn timeout<T>(&mut self, timeout: T) -> &mut Self
+    where
+        T: Into<Option<Duration>>,
+    {
+        self.timeout = timeout.into();
+        self
+    }
+
+    /// Sets the maximum size of each fetched resource in bytes.
+    ///
+    /// Fetching larger resources fails with an I/O error.
+    pub fn max_size(&mut self, max_size: u64) -> &mut Self {
+        self.max_size = Some(max_size);
+        self
+    }
+}
+
+impl Default for HttpResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpResolver {
+    /// Fetches a resource, reading no more than `limit` bytes of it if
+    /// given.
+    fn fetch(&self, uri: &str, limit: Option<u64>) -> Result<Vec<u8>> {
+        if !is_http(uri) {
+            return Err(Error::UnsupportedScheme);
+        }
+        let mut request = self.agent.get(uri);
+        if let Some(timeout) = self.timeout {
+            request = request.timeout(timeout);
+        }
+        let response = request.call().map_err(|error| Error::Http(Box::new(error)))?;
+
+        let length = response.header("Content-Length").and_then(|length| length.parse::<u64>().ok());
+        if let Some(max_size) = self.max_size {
+            if length.unwrap_or(0) > max_size {
+                return Err(too_large(uri));
+            }
+        }
+        let limit = match (self.max_size.map(|max_size| max_size.saturating_add(1)), limit) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        let mut data = Vec::new();
+        match limit {
+            Some(limit) => response.into_reader().take(limit).read_to_end(&mut data)?,
+            None => response.into_reader().read_to_end(&mut data)?,
+        };
+        match self.max_size {
+            Some(max_size) if data.len() as u64 > max_size => Err(too_large(uri)),
+            _ => Ok(data),
+        }
+    }
+}
+
+impl Resolver for HttpResolver {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        self.fetch(uri, None)
+    }
+
+    fn resolve_bounded(&self, uri: &str, max_size: usize) -> Result<Vec<u8>> {
+        self.fetch(uri, Some((max_size as u64).saturating_add(1)))
+    }
+}
+
+/// Returns whether a URI has the `http` or `https` scheme.
+pub(crate) fn is_http(uri: &str) -> bool {
+    let scheme = uri.split(':').next().unwrap_or_default();
+    uri.contains(':') && (scheme.eq_ignore_ascii_case("http") || scheme.eq_ignore_ascii_case("https"))
+}
+
+/// Returns the error of a resource exceeding the size limit.
+fn too_large(uri: &str) -> Error {
+    let message = format!("{} exceeds the maximum size", uri);
+    Error::Io(io::Error::new(io::ErrorKind::InvalidData, message))
+}
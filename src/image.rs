@@ -76,9 +76,13 @@ pub struct Image<'a> {
 }
 
 /// Image data belonging to an imported glTF asset.
+///
+/// The struct is non-exhaustive, so that enabling features which add fields
+/// cannot break code using it.
 #[cfg(feature = "import")]
 #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct Data {
     /// The image pixel data (8 bits per channel).
     pub pixels: Vec<u8>,
@@ -91,6 +95,127 @@ pub struct Data {
 
     /// The image width in pixels.
     pub height: u32,
+
+    /// The header of the encoded image, if it was a DDS image.
+    #[cfg(feature = "MSFT_texture_dds")]
+    pub(crate) dds: Option<DdsHeader>,
+}
+
+/// Image data imported with [`import_with_options`].
+///
+/// [`import_with_options`]: ../fn.import_with_options.html
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Debug)]
+pub enum Imported {
+    /// An image decoded on import.
+    Decoded(Data),
+
+    /// An image kept in its encoded form on import.
+    Encoded(Encoded),
+
+    /// An image which was not imported.
+    Skipped,
+}
+
+/// An encoded image belonging to an imported glTF asset, which is decoded on
+/// demand.
+///
+/// Images of formats the importer cannot decode, such as the KTX2 images of
+/// `KHR_texture_basisu`, are kept encoded as well, e.g. to be uploaded to the
+/// GPU as they are.
+#[cfg(feature = "import")]
+#[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+#[derive(Clone, Debug)]
+pub struct Encoded {
+    /// The encoded image data.
+    data: Vec<u8>,
+
+    /// The MIME type of the encoded image.
+    mime_type: String,
+
+    /// The format of the encoded image, if it can be decoded.
+    format: Option<image_crate::ImageFormat>,
+
+    /// The decoded image, once decoded.
+    decoded: std::sync::OnceLock<Data>,
+}
+
+/// Pixel format of a DDS image.
+#[cfg(feature = "MSFT_texture_dds")]
+#[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DdsFormat {
+    /// Compressed format identified by a four-character code, e.g. `DXT1`.
+    FourCC([u8; 4]),
+
+    /// Format identified by a `DXGI_FORMAT` value of the DX10 header extension.
+    Dxgi(u32),
+
+    /// Uncompressed format with the given number of bits per pixel.
+    Uncompressed {
+        /// The number of bits per pixel.
+        bit_count: u32,
+    },
+}
+
+/// Header information of a DDS image.
+#[cfg(feature = "MSFT_texture_dds")]
+#[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct DdsHeader {
+    /// The width of the largest mipmap level in pixels.
+    pub width: u32,
+
+    /// The height of the largest mipmap level in pixels.
+    pub height: u32,
+
+    /// The number of mipmap levels stored in the image.
+    pub mip_count: u32,
+
+    /// The pixel format.
+    pub format: DdsFormat,
+}
+
+#[cfg(feature = "MSFT_texture_dds")]
+impl DdsHeader {
+    /// Parses the header of an encoded DDS image.
+    ///
+    /// Returns `None` if the data does not start with a valid DDS header.
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        const MIPMAP_COUNT: u32 = 0x20000;
+        const FOUR_CC: u32 = 0x4;
+
+        let read = |offset: usize| -> Option<u32> {
+            let bytes = data.get(offset..offset + 4)?;
+            Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+        };
+        if data.get(0..4)? != b"DDS " || read(4)? != 124 {
+            return None;
+        }
+
+        let flags = read(8)?;
+        let height = read(12)?;
+        let width = read(16)?;
+        let mip_count = if flags & MIPMAP_COUNT != 0 {
+            read(28)?.max(1)
+        } else {
+            1
+        };
+
+        let format = if read(80)? & FOUR_CC != 0 {
+            let four_cc = read(84)?.to_le_bytes();
+            if &four_cc == b"DX10" {
+                DdsFormat::Dxgi(read(128)?)
+            } else {
+                DdsFormat::FourCC(four_cc)
+            }
+        } else {
+            DdsFormat::Uncompressed { bit_count: read(88)? }
+        };
+
+        Some(DdsHeader { width, height, mip_count, format })
+    }
 }
 
 impl<'a> Image<'a> {
@@ -144,6 +269,76 @@ impl<'a> Image<'a> {
     }
 }
 
+#[cfg(feature = "import")]
+impl Encoded {
+    /// Constructs an `Encoded` image, detecting its MIME type from its format
+    /// or its signature if the document does not specify it.
+    pub(crate) fn new(
+        data: Vec<u8>,
+        mime_type: Option<&str>,
+        uri: Option<&str>,
+        format: Option<image_crate::ImageFormat>,
+    ) -> Self {
+        /// The signature of KTX2 images.
+        const KTX2_SIGNATURE: [u8; 12] = [0xAB, b'K', b'T', b'X', b' ', b'2', b'0', 0xBB, b'\r', b'\n', 0x1A, b'\n'];
+
+        let detected = match format {
+            Some(image_crate::ImageFormat::Png) => "image/png",
+            Some(image_crate::ImageFormat::Jpeg) => "image/jpeg",
+            Some(image_crate::ImageFormat::WebP) => "image/webp",
+            Some(image_crate::ImageFormat::Dds) => "image/vnd-ms.dds",
+            _ if data.starts_with(&KTX2_SIGNATURE) => "image/ktx2",
+            _ => match uri.and_then(|uri| uri.rsplit('.').next()) {
+                Some("ktx2") => "image/ktx2",
+                _ => "application/octet-stream",
+            },
+        };
+        Self {
+            data,
+            mime_type: mime_type.unwrap_or(detected).to_owned(),
+            format,
+            decoded: std::sync::OnceLock::new(),
+        }
+    }
+
+    /// Returns the encoded image data.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Returns the encoded image data, discarding the decoded image.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Returns the MIME type of the encoded image, which is detected if the
+    /// document does not specify it.
+    ///
+    /// PNG, JPEG, WebP, DDS, and KTX2 images are detected, others are
+    /// `application/octet-stream`.
+    pub fn mime_type(&self) -> &str {
+        &self.mime_type
+    }
+
+    /// Returns whether the importer can decode the image.
+    pub fn is_decodable(&self) -> bool {
+        self.format.is_some()
+    }
+
+    /// Decodes the image, or returns the image decoded by an earlier call.
+    ///
+    /// Fails with `Error::UnsupportedImageEncoding` if the importer cannot
+    /// decode the image.
+    pub fn decode(&self) -> crate::Result<&Data> {
+        if let Some(data) = self.decoded.get() {
+            return Ok(data);
+        }
+        let format = self.format.ok_or(crate::Error::UnsupportedImageEncoding)?;
+        let data = crate::import::decode_image(&self.data, format)?;
+        Ok(self.decoded.get_or_init(|| data))
+    }
+}
+
 #[cfg(feature = "import")]
 impl Data {
     /// Note: We don't implement `From<DynamicImage>` since we don't want
@@ -164,6 +359,20 @@ impl Data {
         };
         let (width, height) = image.dimensions();
         let pixels = image.to_bytes();
-        Data { format, width, height, pixels }
+        Data {
+            format,
+            width,
+            height,
+            pixels,
+            #[cfg(feature = "MSFT_texture_dds")]
+            dds: None,
+        }
+    }
+
+    /// Returns the header of the encoded image, if it was a DDS image.
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+    pub fn dds(&self) -> Option<DdsHeader> {
+        self.dds
     }
 }
@@ -1,15 +1,384 @@
 use base64;
 use crate::buffer;
 use crate::image;
-use std::{fs, io};
+use std::future::Future;
+use std::pin::Pin;
+use std::borrow::Cow;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::collections::HashMap;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::{fmt, fs, io, ops};
 
 use image_crate::ImageFormat::{Jpeg, Png};
+#[cfg(feature = "EXT_texture_webp")]
+use image_crate::ImageFormat::WebP;
+#[cfg(feature = "MSFT_texture_dds")]
+use image_crate::ImageFormat::Dds;
 use std::path::Path;
 use crate::{Document, Error, Gltf, Result};
 
 /// Return type of `import`.
 type Import = (Document, Vec<buffer::Data>, Vec<image::Data>);
 
+/// Return type of `import_cached`.
+type ImportCached = (Document, Vec<Arc<buffer::Data>>, Vec<Arc<image::Data>>);
+
+/// Return type of `import_with_options`.
+type ImportWithOptions = (Document, Vec<buffer::Data>, Vec<image::Imported>);
+
+/// Options for importing glTF with [`import_with_options`] or
+/// [`import_async_with_options`].
+///
+/// [`import_with_options`]: fn.import_with_options.html
+/// [`import_async_with_options`]: fn.import_async_with_options.html
+#[derive(Clone, Default)]
+pub struct ImportOptions {
+    /// How images are imported.
+    images: ImageMode,
+
+    /// The callback reporting progress, if any.
+    progress: Option<Arc<dyn Fn(Progress) + Send + Sync>>,
+
+    /// The token cancelling the import, if any.
+    cancellation: Option<CancellationToken>,
+
+    /// The limits on the resources of the imported glTF.
+    limits: Limits,
+}
+
+/// Limits on the resources of glTF imported with [`import_with_options`],
+/// for importing untrusted glTF without unbounded allocation.
+///
+/// Imports exceeding a limit fail with [`Error::LimitExceeded`]. Limits
+/// which are `None` are not enforced.
+///
+/// [`import_with_options`]: fn.import_with_options.html
+/// [`Error::LimitExceeded`]: enum.Error.html#variant.LimitExceeded
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Limits {
+    /// The maximum size of the JSON in bytes.
+    pub max_json_size: Option<usize>,
+
+    /// The maximum total size of the buffers in bytes.
+    ///
+    /// Buffers are read no further than the size left, so that oversized
+    /// files are rejected without being read whole.
+    pub max_buffer_size: Option<usize>,
+
+    /// The maximum size of each encoded image in bytes, such as the size of
+    /// an image file.
+    pub max_image_size: Option<usize>,
+
+    /// The maximum width and height of the images decoded on import, in
+    /// pixels.
+    pub max_image_dimension: Option<u32>,
+
+    /// The maximum number of nodes.
+    pub max_nodes: Option<usize>,
+}
+
+/// Identifies a limit of [`Limits`] which was exceeded.
+///
+/// [`Limits`]: struct.Limits.html
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Limit {
+    /// The size of the JSON.
+    JsonSize,
+
+    /// The total size of the buffers.
+    BufferSize,
+
+    /// The size of an encoded image.
+    ImageSize,
+
+    /// The width or height of an image.
+    ImageDimension,
+
+    /// The number of nodes.
+    Nodes,
+}
+
+impl Limits {
+    /// Fails if a value exceeds a limit.
+    fn check<T: PartialOrd>(max: Option<T>, value: T, limit: Limit) -> Result<()> {
+        match max {
+            Some(max) if value > max => Err(Error::LimitExceeded(limit)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails if the declared lengths of the buffers of a document add up to
+    /// more than the buffer size limit.
+    fn check_buffers(&self, document: &Document) -> Result<()> {
+        if let Some(max) = self.max_buffer_size {
+            let declared = document
+                .buffers()
+                .try_fold(0usize, |total, buffer| total.checked_add(buffer.length()));
+            match declared {
+                Some(declared) if declared <= max => {},
+                _ => return Err(Error::LimitExceeded(Limit::BufferSize)),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match *self {
+            Limit::JsonSize => "JSON size",
+            Limit::BufferSize => "buffer size",
+            Limit::ImageSize => "image size",
+            Limit::ImageDimension => "image dimension",
+            Limit::Nodes => "node count",
+        })
+    }
+}
+
+/// Reports the progress of an import to the callback set with
+/// [`ImportOptions::progress`].
+///
+/// [`ImportOptions::progress`]: struct.ImportOptions.html#method.progress
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Progress {
+    /// The JSON of the document was parsed.
+    Json,
+
+    /// A buffer was loaded.
+    Buffer {
+        /// The index of the buffer.
+        index: usize,
+
+        /// The number of buffers.
+        count: usize,
+    },
+
+    /// An image was imported, or skipped.
+    Image {
+        /// The index of the image.
+        index: usize,
+
+        /// The number of images.
+        count: usize,
+    },
+}
+
+/// Cancels an import from another thread.
+///
+/// Clones of a token share its state. Imports check whether they are
+/// cancelled before loading each resource, and fail with
+/// [`Error::Cancelled`] if so.
+///
+/// [`Error::Cancelled`]: enum.Error.html#variant.Cancelled
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Creates a token which is not cancelled.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Cancels the imports using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the token has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Specifies how images are imported.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ImageMode {
+    /// Images are decoded on import, as with [`import`].
+    ///
+    /// [`import`]: fn.import.html
+    #[default]
+    Decode,
+
+    /// Images are read but kept encoded along with their MIME type, to be
+    /// decoded on demand if at all.
+    ///
+    /// Images of formats the importer cannot decode, e.g. KTX2, are imported
+    /// as well.
+    Encoded,
+
+    /// Images are neither read nor decoded.
+    Skip,
+}
+
+impl ImportOptions {
+    /// Creates options that import glTF as [`import`] does.
+    ///
+    /// [`import`]: fn.import.html
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets how images are imported.
+    pub fn images(&mut self, images: ImageMode) -> &mut Self {
+        self.images = images;
+        self
+    }
+
+    /// Sets a callback reporting the progress of imports, once the JSON is
+    /// parsed and once each resource is loaded.
+    pub fn progress<F>(&mut self, progress: F) -> &mut Self
+    where
+        F: Fn(Progress) + Send + Sync + 'static,
+    {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    /// Sets a token to cancel imports with.
+    pub fn cancellation(&mut self, token: CancellationToken) -> &mut Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Sets the limits on the resources of imported glTF.
+    pub fn limits(&mut self, limits: Limits) -> &mut Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Reports progress to the callback, if any.
+    fn report(&self, progress: Progress) {
+        if let Some(callback) = self.progress.as_ref() {
+            callback(progress);
+        }
+    }
+
+    /// Fails if the import has been cancelled.
+    fn check_cancelled(&self) -> Result<()> {
+        match self.cancellation {
+            Some(ref token) if token.is_cancelled() => Err(Error::Cancelled),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for ImportOptions {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ImportOptions")
+            .field("images", &self.images)
+            .field("progress", &self.progress.is_some())
+            .field("cancellation", &self.cancellation)
+            .field("limits", &self.limits)
+            .finish()
+    }
+}
+
+/// Fetches the data of the buffers and images referenced by URIs on import,
+/// e.g. from archives, databases, or virtual file systems.
+///
+/// Data URIs are decoded by the importer and never passed to the resolver.
+/// Closures taking a URI and returning its data implement this trait.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use std::collections::HashMap;
+///
+/// let mut files = HashMap::new();
+/// files.insert("Box.gltf", std::fs::read("examples/Box.gltf")?);
+/// files.insert("Box0.bin", std::fs::read("examples/Box0.bin")?);
+/// let resolver = |uri: &str| files.get(uri).cloned().ok_or(gltf::Error::UnsupportedScheme);
+/// let (document, buffers, _) = gltf::import_with_resolver("Box.gltf", &resolver)?;
+/// assert_eq!(buffers[0].len(), document.buffers().next().unwrap().length());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub trait Resolver {
+    /// Fetches the data referenced by a URI.
+    ///
+    /// Relative URIs have already been resolved against the URI of the
+    /// imported file, if any.
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>>;
+
+    /// Fetches the data referenced by a URI, reading no more than
+    /// `max_size + 1` bytes, so that data exceeding a limit of [`Limits`] is
+    /// rejected without being fetched whole.
+    ///
+    /// The default implementation fetches all of the data with `resolve`,
+    /// and the importer checks its size afterwards. Resolvers of untrusted
+    /// data should stop reading at the limit instead.
+    ///
+    /// [`Limits`]: struct.Limits.html
+    fn resolve_bounded(&self, uri: &str, max_size: usize) -> Result<Vec<u8>> {
+        let _ = max_size;
+        self.resolve(uri)
+    }
+}
+
+/// Fetches the data of the buffers and images referenced by URIs for
+/// asynchronous import, e.g. over the network.
+///
+/// Data URIs are decoded by the importer and never passed to the resolver.
+///
+/// # Examples
+///
+/// A resolver that reads files with `tokio`:
+///
+/// ```ignore
+/// struct Files;
+///
+/// impl gltf::AsyncResolver for Files {
+///     fn resolve<'a>(&'a self, uri: &'a str) -> gltf::ResolveFuture<'a> {
+///         Box::pin(async move { Ok(tokio::fs::read(uri).await?) })
+///     }
+/// }
+/// ```
+pub trait AsyncResolver {
+    /// Fetches the data referenced by a URI.
+    ///
+    /// Relative URIs have already been resolved against the URI of the
+    /// imported file, if any.
+    fn resolve<'a>(&'a self, uri: &'a str) -> ResolveFuture<'a>;
+
+    /// Fetches the data referenced by a URI, reading no more than
+    /// `max_size + 1` bytes, so that data exceeding a limit of [`Limits`] is
+    /// rejected without being fetched whole.
+    ///
+    /// The default implementation fetches all of the data with `resolve`,
+    /// and the importer checks its size afterwards.
+    ///
+    /// [`Limits`]: struct.Limits.html
+    fn resolve_bounded<'a>(&'a self, uri: &'a str, max_size: usize) -> ResolveFuture<'a> {
+        let _ = max_size;
+        self.resolve(uri)
+    }
+}
+
+/// The future of the data fetched by an [`AsyncResolver`].
+///
+/// The future must be `Send`, except on WebAssembly, where the futures of the
+/// browser are not.
+///
+/// [`AsyncResolver`]: trait.AsyncResolver.html
+#[cfg(not(target_arch = "wasm32"))]
+pub type ResolveFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>>> + Send + 'a>>;
+
+/// The future of the data fetched by an [`AsyncResolver`].
+///
+/// The future must be `Send`, except on WebAssembly, where the futures of the
+/// browser are not.
+///
+/// [`AsyncResolver`]: trait.AsyncResolver.html
+#[cfg(target_arch = "wasm32")]
+pub type ResolveFuture<'a> = Pin<Box<dyn Future<Output = Result<Vec<u8>>> + 'a>>;
+
 /// Represents the set of URI schemes the importer supports.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 enum Scheme<'a> {
@@ -53,20 +422,91 @@ impl<'a> Scheme<'a> {
         }
     }
 
-    fn read(base: Option<&Path>, uri: &str) -> Result<Vec<u8>> {
-        match Scheme::parse(uri) {
+    fn read(resolver: Option<&dyn Resolver>, base: &str, uri: &str) -> Result<Vec<u8>> {
+        match (Scheme::parse(uri), resolver) {
             // The path may be unused in the Scheme::Data case
             // Example: "uri" : "data:application/octet-stream;base64,wsVHPgA...."
-            Scheme::Data(_, base64) => base64::decode(&base64).map_err(Error::Base64),
-            Scheme::File(path) if base.is_some() => read_to_end(path),
-            Scheme::Relative if base.is_some() => read_to_end(base.unwrap().join(uri)),
-            Scheme::Unsupported => Err(Error::UnsupportedScheme),
+            (Scheme::Data(_, base64), _) => decode_base64(base64),
+            (_, Some(resolver)) => resolver.resolve(&join_uri(base, uri)),
+            (Scheme::Unsupported, None) => Err(Error::UnsupportedScheme),
             _ => Err(Error::ExternalReferenceInSliceImport)
         }
     }
+
+    /// Reads the data of a URI, failing if it is longer than `max_size`
+    /// bytes.
+    fn read_bounded(
+        resolver: Option<&dyn Resolver>,
+        base: &str,
+        uri: &str,
+        max_size: Option<usize>,
+        limit: Limit,
+    ) -> Result<Vec<u8>> {
+        let max = match max_size {
+            Some(max) => max,
+            None => return Scheme::read(resolver, base, uri),
+        };
+        let data = match (Scheme::parse(uri), resolver) {
+            (Scheme::Data(..), _) | (_, None) => Scheme::read(resolver, base, uri)?,
+            (_, Some(resolver)) => resolver.resolve_bounded(&join_uri(base, uri), max)?,
+        };
+        Limits::check(Some(max), data.len(), limit)?;
+        Ok(data)
+    }
+}
+
+/// Resolves URIs on the file system, relative to a directory.
+struct FileSystem<'a>(&'a Path);
+
+impl<'a> Resolver for FileSystem<'a> {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        match Scheme::parse(uri) {
+            Scheme::File(path) => read_to_end(path, None),
+            Scheme::Relative => read_to_end(self.0.join(uri), None),
+            #[cfg(feature = "http")]
+            Scheme::Unsupported if crate::http::is_http(uri) => crate::HttpResolver::new().resolve(uri),
+            _ => Err(Error::UnsupportedScheme),
+        }
+    }
+
+    fn resolve_bounded(&self, uri: &str, max_size: usize) -> Result<Vec<u8>> {
+        match Scheme::parse(uri) {
+            Scheme::File(path) => read_to_end(path, Some(max_size)),
+            Scheme::Relative => read_to_end(self.0.join(uri), Some(max_size)),
+            #[cfg(feature = "http")]
+            Scheme::Unsupported if crate::http::is_http(uri) => {
+                crate::HttpResolver::new().resolve_bounded(uri, max_size)
+            },
+            _ => Err(Error::UnsupportedScheme),
+        }
+    }
 }
 
-fn read_to_end<P>(path: P) -> Result<Vec<u8>>
+impl<F> Resolver for F
+    where F: Fn(&str) -> Result<Vec<u8>>
+{
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        self(uri)
+    }
+}
+
+/// Decodes the base64 data of a data URI.
+///
+/// The data is decoded straight into a vector with room for padding the
+/// data to a multiple of four bytes, so that buffers need not be copied to
+/// be padded.
+fn decode_base64(data: &str) -> Result<Vec<u8>> {
+    // The decoder requires room for a whole number of three byte groups.
+    let mut capacity = (data.len() + 3) / 4 * 3;
+    crate::binary::align_to_multiple_of_four(&mut capacity);
+    let mut decoded = vec![0; capacity];
+    let length = base64::decode_config_slice(data, base64::STANDARD, &mut decoded).map_err(Error::Base64)?;
+    decoded.truncate(length);
+    Ok(decoded)
+}
+
+/// Reads a file, or no more than `max_size + 1` bytes of it if given.
+fn read_to_end<P>(path: P, max_size: Option<usize>) -> Result<Vec<u8>>
 where P: AsRef<Path>
 {
     use io::Read;
@@ -74,8 +514,13 @@ where P: AsRef<Path>
     // Allocate one extra byte so the buffer doesn't need to grow before the
     // final `read` call at the end of the file.  Don't worry about `usize`
     // overflow because reading will fail regardless in that case.
-    let length = file.metadata().map(|x| x.len() + 1).unwrap_or(0);
-    let mut reader = io::BufReader::new(file);
+    let mut length = file.metadata().map(|x| x.len() + 1).unwrap_or(0);
+    let max = max_size.map_or(u64::MAX, |max| (max as u64).saturating_add(1));
+    if length > max {
+        // The caller rejects the data anyway, so only read what shows it.
+        length = max;
+    }
+    let mut reader = io::BufReader::new(file).take(max);
     let mut data = Vec::with_capacity(length as usize);
     reader.read_to_end(&mut data).map_err(Error::Io)?;
     Ok(data)
@@ -84,127 +529,337 @@ where P: AsRef<Path>
 /// Import the buffer data referenced by a glTF document.
 pub fn import_buffer_data(
     document: &Document,
-    base: Option<&Path>,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
     mut blob: Option<Vec<u8>>,
 ) -> Result<Vec<buffer::Data>> {
     let mut buffers = Vec::new();
     for buffer in document.buffers() {
-        let mut data = match buffer.source() {
-            buffer::Source::Uri(uri) => Scheme::read(base, uri),
-            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
-        }?;
-        if data.len() < buffer.length() {
-            return Err(
-                Error::BufferLength {
-                    buffer: buffer.index(),
-                    expected: buffer.length(),
-                    actual: data.len(),
-                }
-            );
-        }
-        while data.len() % 4 != 0 {
-            data.push(0);
-        }
-        buffers.push(buffer::Data(data));
+        buffers.push(load_buffer(&buffer, base, resolver, &mut blob, None)?);
     }
     Ok(buffers)
 }
 
+/// Loads the data of a buffer, taking the blob if it is stored in it.
+///
+/// If a budget is given, the buffer is read no further than the budget and
+/// its size is taken from it.
+fn load_buffer(
+    buffer: &buffer::Buffer,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
+    blob: &mut Option<Vec<u8>>,
+    budget: Option<&AtomicUsize>,
+) -> Result<buffer::Data> {
+    let max_size = budget.map(|budget| budget.load(Ordering::Relaxed));
+    let data = match buffer.source() {
+        buffer::Source::Uri(uri) => Scheme::read_bounded(resolver, base, uri, max_size, Limit::BufferSize),
+        buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob),
+    }?;
+    if let Some(budget) = budget {
+        budget
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |left| left.checked_sub(data.len()))
+            .map_err(|_| Error::LimitExceeded(Limit::BufferSize))?;
+    }
+    buffer_data_impl(buffer, data)
+}
+
+/// Checks the length of the data of a buffer and pads it to a multiple of
+/// four bytes.
+fn buffer_data_impl(buffer: &buffer::Buffer, mut data: Vec<u8>) -> Result<buffer::Data> {
+    if data.len() < buffer.length() {
+        return Err(
+            Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            }
+        );
+    }
+    let mut length = data.len();
+    crate::binary::align_to_multiple_of_four(&mut length);
+    data.resize(length, 0);
+    Ok(buffer::Data(data))
+}
+
+/// Decodes an encoded image of the given format.
+pub(crate) fn decode_image(encoded_image: &[u8], encoded_format: image_crate::ImageFormat) -> Result<image::Data> {
+    let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
+    #[allow(unused_mut)]
+    let mut data = image::Data::new(decoded_image);
+    #[cfg(feature = "MSFT_texture_dds")]
+    {
+        if encoded_format == Dds {
+            data.dds = image::DdsHeader::parse(encoded_image);
+        }
+    }
+    Ok(data)
+}
+
+/// Guesses the format of an encoded image from its signature, if enabled.
+#[cfg(feature = "guess_mime_type")]
+fn guess_format(encoded_image: &[u8]) -> Option<image_crate::ImageFormat> {
+    match image_crate::guess_format(encoded_image) {
+        Ok(image_crate::ImageFormat::Png) => Some(Png),
+        Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
+        #[cfg(feature = "EXT_texture_webp")]
+        Ok(image_crate::ImageFormat::WebP) => Some(WebP),
+        #[cfg(feature = "MSFT_texture_dds")]
+        Ok(image_crate::ImageFormat::Dds) => Some(Dds),
+        _ => None,
+    }
+}
+
+/// Guesses the format of an encoded image from its signature, if enabled.
+#[cfg(not(feature = "guess_mime_type"))]
+fn guess_format(_encoded_image: &[u8]) -> Option<image_crate::ImageFormat> {
+    None
+}
+
+/// Determines the format of an encoded image from its MIME type, or from the
+/// extension of its URI if it has no MIME type.
+fn image_format(
+    mime_type: Option<&str>,
+    uri: Option<&str>,
+    encoded_image: &[u8],
+) -> Result<image_crate::ImageFormat> {
+    let format = match mime_type {
+        Some("image/png") => Some(Png),
+        Some("image/jpeg") => Some(Jpeg),
+        #[cfg(feature = "EXT_texture_webp")]
+        Some("image/webp") => Some(WebP),
+        #[cfg(feature = "MSFT_texture_dds")]
+        Some("image/vnd-ms.dds") => Some(Dds),
+        Some(_) => None,
+        None => match uri.and_then(|uri| uri.rsplit('.').next()) {
+            Some("png") => Some(Png),
+            Some("jpg") | Some("jpeg") => Some(Jpeg),
+            #[cfg(feature = "EXT_texture_webp")]
+            Some("webp") => Some(WebP),
+            #[cfg(feature = "MSFT_texture_dds")]
+            Some("dds") => Some(Dds),
+            _ => None,
+        },
+    };
+    format
+        .or_else(|| guess_format(encoded_image))
+        .ok_or(Error::UnsupportedImageEncoding)
+}
+
 /// Import the image data referenced by a glTF document.
 pub fn import_image_data(
     document: &Document,
-    base: Option<&Path>,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
     buffer_data: &[buffer::Data],
 ) -> Result<Vec<image::Data>> {
+    image_data_impl(document, base, resolver, buffer_data)
+}
+
+/// Imports the image data referenced by a glTF document, given the data of
+/// its buffers.
+fn image_data_impl<D>(
+    document: &Document,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
+    buffer_data: &[D],
+) -> Result<Vec<image::Data>>
+    where D: ops::Deref<Target = [u8]>
+{
     let mut images = Vec::new();
-    #[cfg(feature = "guess_mime_type")]
-    let guess_format = |encoded_image: &[u8]| {
-        match image_crate::guess_format(encoded_image) {
-            Ok(image_crate::ImageFormat::Png) => Some(Png),
-            Ok(image_crate::ImageFormat::Jpeg) => Some(Jpeg),
-            _ => None,
-        }
-    };
-    #[cfg(not(feature = "guess_mime_type"))]
-    let guess_format = |_encoded_image: &[u8]| {
-        None
-    };
     for image in document.images() {
-        match image.source() {
-            image::Source::Uri { uri, mime_type } if base.is_some() => {
-                match Scheme::parse(uri) {
-                    Scheme::Data(Some(annoying_case), base64) => {
-                        let encoded_image = base64::decode(&base64).map_err(Error::Base64)?;
-                        let encoded_format = match annoying_case.as_ref() {
-                            "image/png" => Png,
-                            "image/jpeg" => Jpeg,
-                            _ => match guess_format(&encoded_image) {
-                                Some(format) => format,
-                                None => return Err(Error::UnsupportedImageEncoding),
-                            },
-                        };
-                        let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?;
-                        images.push(image::Data::new(decoded_image));
-                        continue;
-                    },
-                    Scheme::Unsupported => return Err(Error::UnsupportedScheme),
-                    _ => {},
-                }
-                let encoded_image = Scheme::read(base, uri)?;
-                let encoded_format =  match mime_type {
-                    Some("image/png") => Png,
-                    Some("image/jpeg") => Jpeg,
-                    Some(_) => match guess_format(&encoded_image) {
-                        Some(format) => format,
-                        None => return Err(Error::UnsupportedImageEncoding),
-                    },
-                    None => match uri.rsplit(".").next() {
-                        Some("png") => Png,
-                        Some("jpg") | Some("jpeg") => Jpeg,
-                        _ => match guess_format(&encoded_image) {
-                            Some(format) => format,
-                            None => return Err(Error::UnsupportedImageEncoding),
-                        },
-                    },
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(&encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-            image::Source::View { view, mime_type } => {
-                let parent_buffer_data = &buffer_data[view.buffer().index()].0;
-                let begin = view.offset();
-                let end = begin + view.length();
-                let encoded_image = &parent_buffer_data[begin..end];
-                let encoded_format = match mime_type {
-                    "image/png" => Png,
-                    "image/jpeg" => Jpeg,
-                    _ => match guess_format(encoded_image) {
-                        Some(format) => format,
-                        None => return Err(Error::UnsupportedImageEncoding),
-                    },
-                };
-                let decoded_image = image_crate::load_from_memory_with_format(encoded_image, encoded_format)?;
-                images.push(image::Data::new(decoded_image));
-            },
-            _ => return Err(Error::ExternalReferenceInSliceImport)
-        }
+        let (encoded_image, encoded_format) = encoded_image_data(&image, base, resolver, buffer_data, None)?;
+        images.push(decode_image(&encoded_image, encoded_format)?);
     }
-
     Ok(images)
 }
 
-fn import_impl(Gltf { document, blob }: Gltf, base: Option<&Path>) -> Result<Import> {
-    let buffer_data = import_buffer_data(&document, base, blob)?;
-    let image_data = import_image_data(&document, base, &buffer_data)?;
+/// Reads the encoded data of an image and determines its format, given the
+/// data of the buffers.
+fn encoded_image_data<'b, D>(
+    image: &image::Image,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
+    buffer_data: &'b [D],
+    max_size: Option<usize>,
+) -> Result<(Cow<'b, [u8]>, image_crate::ImageFormat)>
+    where D: ops::Deref<Target = [u8]>
+{
+    let raw = raw_image_data(image, base, resolver, buffer_data, max_size)?;
+    let encoded_format = raw.format()?;
+    Ok((raw.data, encoded_format))
+}
+
+/// The encoded data of an image, along with the MIME type and the URI it was
+/// read with, if any.
+struct RawImage<'a, 'b> {
+    /// The encoded image data.
+    data: Cow<'b, [u8]>,
+
+    /// The MIME type of the image, if known.
+    mime_type: Option<&'a str>,
+
+    /// The URI of the image, if it is not a data URI.
+    uri: Option<&'a str>,
+}
+
+impl<'a, 'b> RawImage<'a, 'b> {
+    /// Determines the format of the image.
+    fn format(&self) -> Result<image_crate::ImageFormat> {
+        image_format(self.mime_type, self.uri, &self.data)
+    }
+}
+
+/// Reads the encoded data of an image, given the data of the buffers,
+/// failing if it is longer than `max_size` bytes.
+fn raw_image_data<'a, 'b, D>(
+    image: &image::Image<'a>,
+    base: &str,
+    resolver: Option<&dyn Resolver>,
+    buffer_data: &'b [D],
+    max_size: Option<usize>,
+) -> Result<RawImage<'a, 'b>>
+    where D: ops::Deref<Target = [u8]>
+{
+    match image.source() {
+        image::Source::Uri { uri, mime_type } if resolver.is_some() => {
+            if let Scheme::Data(Some(annoying_case), base64) = Scheme::parse(uri) {
+                let data = decode_base64(base64)?;
+                Limits::check(max_size, data.len(), Limit::ImageSize)?;
+                let data = Cow::Owned(data);
+                let mime_type = Some(annoying_case).filter(|mime_type| !mime_type.is_empty());
+                return Ok(RawImage { data, mime_type, uri: None });
+            }
+            let data = Cow::Owned(Scheme::read_bounded(resolver, base, uri, max_size, Limit::ImageSize)?);
+            Ok(RawImage { data, mime_type, uri: Some(uri) })
+        },
+        image::Source::View { view, mime_type } => {
+            Limits::check(max_size, view.length(), Limit::ImageSize)?;
+            let parent_buffer_data = &buffer_data[view.buffer().index()];
+            let begin = view.offset();
+            let end = begin + view.length();
+            let data = Cow::Borrowed(&parent_buffer_data[begin..end]);
+            Ok(RawImage { data, mime_type: Some(mime_type), uri: None })
+        },
+        _ => Err(Error::ExternalReferenceInSliceImport)
+    }
+}
+
+/// Returns the encoded data and the format of an image stored in a buffer
+/// view, given the data of its buffer.
+fn view_encoded_image<'b>(
+    view: &buffer::View,
+    mime_type: &str,
+    parent_buffer_data: &'b [u8],
+) -> Result<(&'b [u8], image_crate::ImageFormat)> {
+    let begin = view.offset();
+    let end = begin + view.length();
+    let encoded_image = &parent_buffer_data[begin..end];
+    let encoded_format = image_format(Some(mime_type), None, encoded_image)?;
+    Ok((encoded_image, encoded_format))
+}
+
+/// Decodes an image stored in a buffer view, given the data of its buffer.
+fn view_image_data(
+    view: &buffer::View,
+    mime_type: &str,
+    parent_buffer_data: &[u8],
+) -> Result<image::Data> {
+    let (encoded_image, encoded_format) = view_encoded_image(view, mime_type, parent_buffer_data)?;
+    decode_image(encoded_image, encoded_format)
+}
+
+fn import_impl(Gltf { document, blob }: Gltf, base: &str, resolver: Option<&dyn Resolver>) -> Result<Import> {
+    let buffer_data = import_buffer_data(&document, base, resolver, blob)?;
+    let image_data = import_image_data(&document, base, resolver, &buffer_data)?;
     let import = (document, buffer_data, image_data);
     Ok(import)
 }
 
+/// Imports the buffers and images of a document, reporting progress and
+/// checking for cancellation as set in the options.
+///
+/// Resources are loaded in parallel if the `rayon` feature is enabled.
+fn import_resources<I, F>(
+    Gltf { document, mut blob }: Gltf,
+    resolver: Option<&(dyn Resolver + Sync)>,
+    options: &ImportOptions,
+    import_image: F,
+) -> Result<(Document, Vec<buffer::Data>, Vec<I>)>
+where
+    I: Send,
+    F: Fn(&image::Image, &[buffer::Data]) -> Result<I> + Send + Sync,
+{
+    options.report(Progress::Json);
+    let limits = &options.limits;
+    Limits::check(limits.max_nodes, document.nodes().len(), Limit::Nodes)?;
+    limits.check_buffers(&document)?;
+
+    let budget = limits.max_buffer_size.map(AtomicUsize::new);
+    let count = document.buffers().len();
+    let buffers = document
+        .buffers()
+        .map(|buffer| {
+            let blob = match buffer.source() {
+                buffer::Source::Bin => blob.take(),
+                buffer::Source::Uri(_) => None,
+            };
+            (buffer, blob)
+        })
+        .collect();
+    let buffer_data = map_resources(buffers, |(buffer, mut blob)| {
+        options.check_cancelled()?;
+        let resolver = resolver.map(|resolver| resolver as _);
+        let data = load_buffer(&buffer, "", resolver, &mut blob, budget.as_ref())?;
+        options.report(Progress::Buffer { index: buffer.index(), count });
+        Ok(data)
+    })?;
+
+    let count = document.images().len();
+    let image_data = map_resources(document.images().collect(), |image| {
+        options.check_cancelled()?;
+        let data = import_image(&image, &buffer_data)?;
+        options.report(Progress::Image { index: image.index(), count });
+        Ok(data)
+    })?;
+    Ok((document, buffer_data, image_data))
+}
+
+/// Applies a fallible function to resources in parallel.
+#[cfg(feature = "rayon")]
+fn map_resources<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>>
+where
+    T: Send,
+    U: Send,
+    F: Fn(T) -> Result<U> + Send + Sync,
+{
+    use rayon::iter::{IntoParallelIterator, ParallelIterator};
+    items.into_par_iter().map(f).collect()
+}
+
+/// Applies a fallible function to resources one after another.
+#[cfg(not(feature = "rayon"))]
+fn map_resources<T, U, F>(items: Vec<T>, f: F) -> Result<Vec<U>>
+where
+    F: Fn(T) -> Result<U>,
+{
+    items.into_iter().map(f).collect()
+}
+
+/// Imports the buffers and decodes the images of a document.
+fn import_decoded(gltf: Gltf, resolver: Option<&(dyn Resolver + Sync)>) -> Result<Import> {
+    import_resources(gltf, resolver, &ImportOptions::new(), |image, buffer_data| {
+        let resolver = resolver.map(|resolver| resolver as _);
+        let (encoded_image, encoded_format) = encoded_image_data(image, "", resolver, buffer_data, None)?;
+        decode_image(&encoded_image, encoded_format)
+    })
+}
+
 fn import_path(path: &Path) -> Result<Import> {
     let base = path.parent().unwrap_or(Path::new("./"));
     let file = fs::File::open(path).map_err(Error::Io)?;
     let reader = io::BufReader::new(file);
-    import_impl(Gltf::from_reader(reader)?, Some(base))
+    import_decoded(Gltf::from_reader(reader)?, Some(&FileSystem(base)))
 }
 
 /// Import some glTF 2.0 from the file system.
@@ -226,22 +881,815 @@ fn import_path(path: &Path) -> Result<Import> {
 /// This function is provided as a convenience for loading glTF and associated
 /// resources from the file system. It is suitable for real world use but may
 /// not be suitable for all real world use cases. More complex import scenarios
-/// such downloading from web URLs are not handled by this function. These
-/// scenarios are delegated to the user.
+/// such downloading from web URLs are not handled by this function, unless
+/// the `http` feature is enabled. These scenarios are delegated to the user,
+/// see [`import_with_resolver`].
 ///
 /// You can read glTF without loading resources by constructing the [`Gltf`]
 /// (standard glTF) or [`Glb`] (binary glTF) data structures explicitly.
 ///
 /// [`Gltf`]: struct.Gltf.html
 /// [`Glb`]: struct.Glb.html
+/// [`import_with_resolver`]: fn.import_with_resolver.html
 pub fn import<P>(path: P) -> Result<Import>
     where P: AsRef<Path>
 {
     import_path(path.as_ref())
 }
 
+/// Import some glTF 2.0 from the file system, memory-mapping the files of its
+/// buffers instead of reading them into memory.
+///
+/// The `BIN` chunk of binary glTF is mapped along with the rest of the file.
+/// Buffers stored in data URIs or fetched remotely are loaded into memory.
+/// Images are decoded as with [`import`].
+///
+/// The files must not be modified while their data is mapped, i.e. until all
+/// of the returned buffer data is dropped. Doing so is undefined behavior.
+///
+/// [`import`]: fn.import.html
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let (document, buffers, _) = gltf::import_mapped("examples/Box.glb")?;
+/// assert!(buffers[0].is_mapped());
+/// assert!(buffers[0].len() >= document.buffers().next().unwrap().length());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+#[cfg(feature = "mmap")]
+pub fn import_mapped<P>(path: P) -> Result<(Document, Vec<buffer::MappedData>, Vec<image::Data>)>
+    where P: AsRef<Path>
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or(Path::new("./"));
+    let file = buffer::MappedData::map(path)?;
+    let (json, mut blob) = if file.starts_with(b"glTF") {
+        let glb = crate::binary::Glb::from_slice(&file)?;
+        // The chunks borrow from the mapped file, so their offsets in it are
+        // known.
+        let bin = glb.bin.as_ref().map(|bin| {
+            let start = bin.as_ptr() as usize - file.as_ptr() as usize;
+            file.slice(start..start + bin.len())
+        });
+        (json::deserialize::from_slice(&glb.json)?, bin)
+    } else {
+        (json::deserialize::from_slice(&file)?, None)
+    };
+    let document = Document::from_json(json)?;
+
+    let file_system = FileSystem(base);
+    let mut buffers = Vec::new();
+    for buffer in document.buffers() {
+        let data = match buffer.source() {
+            buffer::Source::Uri(uri) => match Scheme::parse(uri) {
+                Scheme::File(path) => buffer::MappedData::map(path)?,
+                Scheme::Relative => buffer::MappedData::map(base.join(uri))?,
+                _ => buffer::MappedData::owned(Scheme::read(Some(&file_system), "", uri)?),
+            },
+            buffer::Source::Bin => blob.take().ok_or(Error::MissingBlob)?,
+        };
+        if data.len() < buffer.length() {
+            return Err(Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            });
+        }
+        buffers.push(data);
+    }
+    let images = image_data_impl(&document, "", Some(&file_system), &buffers)?;
+    Ok((document, buffers, images))
+}
+
+/// Buffer data of an imported glTF asset, loaded on demand.
+///
+/// Each buffer is loaded the first time it is requested, and kept until the
+/// handle is dropped. The handle may be shared between threads.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let (document, buffers) = gltf::import_lazy("examples/Box.glb")?;
+/// for node in document.nodes() {
+///     println!("{:?}", node.name());
+/// }
+/// assert!(!buffers.is_loaded(0));
+///
+/// let mesh = document.meshes().next().unwrap();
+/// for primitive in mesh.primitives() {
+///     let reader = primitive.reader(|buffer| buffers.get(buffer));
+///     let positions = reader.read_positions().unwrap();
+///     assert_eq!(positions.len(), 24);
+/// }
+/// assert!(buffers.is_loaded(0));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct LazyBuffers {
+    /// The path of the imported file.
+    path: PathBuf,
+
+    /// The sources of the buffers, paired with their lengths in bytes.
+    sources: Vec<(LazySource, usize)>,
+
+    /// The buffers loaded so far.
+    data: Vec<OnceLock<buffer::Data>>,
+}
+
+/// Source of a buffer loaded on demand.
+#[derive(Debug)]
+enum LazySource {
+    /// The `BIN` chunk of binary glTF, at a range in bytes of the imported
+    /// file, if any.
+    Bin(Option<ops::Range<u64>>),
+
+    /// A URI.
+    Uri(String),
+}
+
+impl LazyBuffers {
+    /// Returns the number of buffers.
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Returns whether there are no buffers.
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    /// Returns whether the data of a buffer has been loaded.
+    pub fn is_loaded(&self, index: usize) -> bool {
+        self.data[index].get().is_some()
+    }
+
+    /// Returns the data of a buffer, loading it if it has not been loaded
+    /// yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of range.
+    pub fn load(&self, index: usize) -> Result<&buffer::Data> {
+        if let Some(data) = self.data[index].get() {
+            return Ok(data);
+        }
+        let (ref source, length) = self.sources[index];
+        let mut data = match *source {
+            LazySource::Bin(Some(ref range)) => {
+                use io::{Read, Seek};
+                let mut file = fs::File::open(&self.path)?;
+                file.seek(io::SeekFrom::Start(range.start))?;
+                let mut data = vec![0; (range.end - range.start) as usize];
+                file.read_exact(&mut data)?;
+                data
+            },
+            LazySource::Bin(None) => return Err(Error::MissingBlob),
+            LazySource::Uri(ref uri) => {
+                let base = self.path.parent().unwrap_or(Path::new("./"));
+                Scheme::read(Some(&FileSystem(base)), "", uri)?
+            },
+        };
+        if data.len() < length {
+            return Err(Error::BufferLength { buffer: index, expected: length, actual: data.len() });
+        }
+        let mut padded = data.len();
+        crate::binary::align_to_multiple_of_four(&mut padded);
+        data.resize(padded, 0);
+        // Another thread may have loaded the buffer in the meantime, in which
+        // case its data is kept.
+        Ok(self.data[index].get_or_init(|| buffer::Data(data)))
+    }
+
+    /// Returns the data of a buffer, loading it if it has not been loaded
+    /// yet, for use with the readers of accessors.
+    ///
+    /// Returns `None` if loading fails. Use [`load`](#method.load) to handle
+    /// the error instead.
+    pub fn get(&self, buffer: buffer::Buffer) -> Option<&[u8]> {
+        self.load(buffer.index()).ok().map(|data| &data[..])
+    }
+
+    /// Imports the data of an image, loading the buffer it is stored in if
+    /// necessary.
+    pub fn image(&self, image: &image::Image) -> Result<image::Data> {
+        match image.source() {
+            image::Source::Uri { uri, mime_type } => {
+                let base = self.path.parent().unwrap_or(Path::new("./"));
+                let encoded_image = Scheme::read(Some(&FileSystem(base)), "", uri)?;
+                let mime_type = match Scheme::parse(uri) {
+                    Scheme::Data(Some(mime_type), _) => Some(mime_type),
+                    _ => mime_type,
+                };
+                let encoded_format = image_format(mime_type, Some(uri), &encoded_image)?;
+                decode_image(&encoded_image, encoded_format)
+            },
+            image::Source::View { view, mime_type } => {
+                let parent_buffer_data = self.load(view.buffer().index())?;
+                view_image_data(&view, mime_type, parent_buffer_data)
+            },
+        }
+    }
+}
+
+fn import_with_options_impl(
+    gltf: Gltf,
+    resolver: Option<&(dyn Resolver + Sync)>,
+    options: &ImportOptions,
+) -> Result<ImportWithOptions> {
+    import_resources(gltf, resolver, options, |image, buffer_data| {
+        let resolver = resolver.map(|resolver| resolver as _);
+        Ok(match options.images {
+            ImageMode::Decode => {
+                let max_size = options.limits.max_image_size;
+                let (encoded_image, encoded_format) = encoded_image_data(image, "", resolver, buffer_data, max_size)?;
+                if let Some(max) = options.limits.max_image_dimension {
+                    let reader = image_crate::io::Reader::with_format(io::Cursor::new(&encoded_image), encoded_format);
+                    let (width, height) = reader.into_dimensions()?;
+                    Limits::check(Some(max), width.max(height), Limit::ImageDimension)?;
+                }
+                image::Imported::Decoded(decode_image(&encoded_image, encoded_format)?)
+            },
+            ImageMode::Encoded => {
+                let raw = raw_image_data(image, "", resolver, buffer_data, options.limits.max_image_size)?;
+                let encoded_format = raw.format().ok();
+                let encoded = image::Encoded::new(raw.data.into_owned(), raw.mime_type, raw.uri, encoded_format);
+                image::Imported::Encoded(encoded)
+            },
+            ImageMode::Skip => image::Imported::Skipped,
+        })
+    })
+}
+
+/// Import some glTF 2.0 from the file system with the given options.
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// use gltf::image::Imported;
+///
+/// let mut options = gltf::ImportOptions::new();
+/// options.images(gltf::ImageMode::Encoded);
+/// let (document, buffers, images) = gltf::import_with_options("examples/Box.gltf", &options)?;
+/// for image in &images {
+///     if let Imported::Encoded(image) = image {
+///         println!("{} bytes of {}", image.data().len(), image.mime_type());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_with_options<P>(path: P, options: &ImportOptions) -> Result<ImportWithOptions>
+    where P: AsRef<Path>
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or(Path::new("./"));
+    options.check_cancelled()?;
+    let file = fs::File::open(path).map_err(Error::Io)?;
+    let reader = io::BufReader::new(file);
+    import_with_options_impl(read_limited(reader, &options.limits)?, Some(&FileSystem(base)), options)
+}
+
+/// Reads glTF from a stream, checking the sizes of the JSON and of the `BIN`
+/// chunk against the limits before reading them.
+fn read_limited<R: io::BufRead>(mut reader: R, limits: &Limits) -> Result<Gltf> {
+    use io::Read;
+    let (json, blob) = if reader.fill_buf()?.starts_with(b"glTF") {
+        let mut glb = crate::binary::GlbReader::new(reader)?;
+        let json = match glb.next_chunk()? {
+            Some(chunk) => {
+                Limits::check(limits.max_json_size, chunk.len(), Limit::JsonSize)?;
+                chunk.to_vec()?
+            },
+            None => Vec::new(),
+        };
+        let blob = match glb.next_chunk()? {
+            Some(chunk) => {
+                Limits::check(limits.max_buffer_size, chunk.len(), Limit::BufferSize)?;
+                Some(chunk.to_vec()?)
+            },
+            None => None,
+        };
+        (json, blob)
+    } else {
+        let mut json = Vec::new();
+        match limits.max_json_size {
+            Some(max) => {
+                reader.take((max as u64).saturating_add(1)).read_to_end(&mut json)?;
+                Limits::check(Some(max), json.len(), Limit::JsonSize)?;
+            },
+            None => {
+                reader.read_to_end(&mut json)?;
+            },
+        }
+        (json, None)
+    };
+    let document = Document::from_json(json::deserialize::from_slice(&json)?)?;
+    Ok(Gltf { document, blob })
+}
+
+/// Import some self-contained glTF 2.0 from a slice with the given options.
+pub fn import_slice_with_options<S>(slice: S, options: &ImportOptions) -> Result<ImportWithOptions>
+    where S: AsRef<[u8]>
+{
+    options.check_cancelled()?;
+    let slice = slice.as_ref();
+    let limits = &options.limits;
+    if slice.starts_with(b"glTF") {
+        let glb = crate::binary::Glb::from_slice(slice)?;
+        Limits::check(limits.max_json_size, glb.json.len(), Limit::JsonSize)?;
+        let bin = glb.bin.as_ref().map_or(0, |bin| bin.len());
+        Limits::check(limits.max_buffer_size, bin, Limit::BufferSize)?;
+    } else {
+        Limits::check(limits.max_json_size, slice.len(), Limit::JsonSize)?;
+    }
+    import_with_options_impl(Gltf::from_slice(slice)?, None, options)
+}
+
+/// Import the document of some glTF 2.0 from the file system, deferring the
+/// loading of its buffers until they are used.
+///
+/// Only the JSON of binary glTF is read up front; its `BIN` chunk is read
+/// from the file when first requested. Images are not imported, but may be
+/// with [`LazyBuffers::image`].
+///
+/// [`LazyBuffers::image`]: struct.LazyBuffers.html#method.image
+pub fn import_lazy<P>(path: P) -> Result<(Document, LazyBuffers)>
+    where P: AsRef<Path>
+{
+    use io::{BufRead, Read};
+    let path = path.as_ref();
+    let mut reader = io::BufReader::new(fs::File::open(path)?);
+    let (json, bin) = if reader.fill_buf()?.starts_with(b"glTF") {
+        crate::binary::Glb::locate_bin(reader)?
+    } else {
+        let mut json = Vec::new();
+        reader.read_to_end(&mut json)?;
+        (json, None)
+    };
+    let document = Document::from_json(json::deserialize::from_slice(&json)?)?;
+    let sources = document
+        .buffers()
+        .map(|buffer| {
+            let source = match buffer.source() {
+                buffer::Source::Bin => LazySource::Bin(bin.clone()),
+                buffer::Source::Uri(uri) => LazySource::Uri(uri.to_owned()),
+            };
+            (source, buffer.length())
+        })
+        .collect::<Vec<_>>();
+    let data = sources.iter().map(|_| OnceLock::new()).collect();
+    let buffers = LazyBuffers {
+        path: path.to_owned(),
+        sources,
+        data,
+    };
+    Ok((document, buffers))
+}
+
+/// A cache of buffers and decoded images shared between imports with
+/// [`import_cached`].
+///
+/// Resources referenced by several documents, e.g. the textures shared by the
+/// tiles of a world, are loaded once and then shared by all of the documents
+/// importing them with the same cache. External resources are identified by
+/// their canonical path or URI, and data URIs and images stored in buffer
+/// views by a hash of their contents. The `BIN` chunk of binary glTF is not
+/// cached.
+///
+/// Entries are kept until they are evicted. Clones of a cache share its
+/// entries, and may be used from other threads.
+///
+/// [`import_cached`]: fn.import_cached.html
+#[derive(Clone, Debug, Default)]
+pub struct Cache(Arc<CacheEntries>);
+
+/// The entries of a `Cache`.
+#[derive(Debug, Default)]
+struct CacheEntries {
+    /// Hashes the contents of embedded resources.
+    hasher: RandomState,
+
+    /// Cached buffer data.
+    buffers: Mutex<HashMap<CacheKey, Arc<buffer::Data>>>,
+
+    /// Cached decoded images.
+    images: Mutex<HashMap<CacheKey, Arc<image::Data>>>,
+}
+
+/// Identifies a resource in a `Cache`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum CacheKey {
+    /// The canonical path of a file.
+    Path(PathBuf),
+
+    /// A URI that is not a path.
+    Uri(String),
+
+    /// The hash of an embedded resource.
+    Hash(u64),
+}
+
+impl Cache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Returns the number of cached buffers.
+    pub fn buffer_count(&self) -> usize {
+        self.0.buffers.lock().unwrap().len()
+    }
+
+    /// Returns the number of cached images.
+    pub fn image_count(&self) -> usize {
+        self.0.images.lock().unwrap().len()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.0.buffers.lock().unwrap().clear();
+        self.0.images.lock().unwrap().clear();
+    }
+
+    /// Removes the entries that are not used by any imported document.
+    pub fn evict_unused(&self) {
+        self.0.buffers.lock().unwrap().retain(|_, data| Arc::strong_count(data) > 1);
+        self.0.images.lock().unwrap().retain(|_, data| Arc::strong_count(data) > 1);
+    }
+
+    /// Returns the key of a resource referenced by a URI, relative to the
+    /// given directory.
+    fn key(&self, base: &Path, uri: &str) -> CacheKey {
+        let canonical = |path: PathBuf| CacheKey::Path(fs::canonicalize(&path).unwrap_or(path));
+        match Scheme::parse(uri) {
+            Scheme::Data(..) => self.hash(uri.as_bytes()),
+            Scheme::File(path) => canonical(PathBuf::from(path)),
+            Scheme::Relative => canonical(base.join(uri)),
+            Scheme::Unsupported => CacheKey::Uri(uri.to_owned()),
+        }
+    }
+
+    /// Returns the key of an embedded resource.
+    fn hash(&self, data: &[u8]) -> CacheKey {
+        let mut hasher = self.0.hasher.build_hasher();
+        data.hash(&mut hasher);
+        CacheKey::Hash(hasher.finish())
+    }
+
+    /// Returns a cached entry, loading it on a cache miss.
+    fn get_or_load<T, F>(entries: &Mutex<HashMap<CacheKey, Arc<T>>>, key: CacheKey, load: F) -> Result<Arc<T>>
+    where
+        F: FnOnce() -> Result<T>,
+    {
+        if let Some(data) = entries.lock().unwrap().get(&key) {
+            return Ok(data.clone());
+        }
+        // The lock is not held while loading, so that other resources may be
+        // loaded meanwhile. Concurrent imports loading the same resource
+        // share the first entry inserted.
+        let data = Arc::new(load()?);
+        Ok(entries.lock().unwrap().entry(key).or_insert(data).clone())
+    }
+}
+
+/// Import some glTF 2.0 from the file system, sharing its buffers and images
+/// with other documents imported with the same cache.
+///
+/// Resources are loaded as with [`import`], in parallel if the `rayon`
+/// feature is enabled.
+///
+/// [`import`]: fn.import.html
+///
+/// # Examples
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let cache = gltf::Cache::new();
+/// let (_, first, _) = gltf::import_cached("examples/Box.gltf", &cache)?;
+/// let (_, second, _) = gltf::import_cached("examples/Box.gltf", &cache)?;
+/// assert!(std::sync::Arc::ptr_eq(&first[0], &second[0]));
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_cached<P>(path: P, cache: &Cache) -> Result<ImportCached>
+    where P: AsRef<Path>
+{
+    let path = path.as_ref();
+    let base = path.parent().unwrap_or(Path::new("./"));
+    let file = fs::File::open(path).map_err(Error::Io)?;
+    let Gltf { document, mut blob } = Gltf::from_reader(io::BufReader::new(file))?;
+    let file_system = FileSystem(base);
+
+    let buffers = document
+        .buffers()
+        .map(|buffer| {
+            let blob = match buffer.source() {
+                buffer::Source::Bin => blob.take(),
+                buffer::Source::Uri(_) => None,
+            };
+            (buffer, blob)
+        })
+        .collect();
+    let buffer_data = map_resources(buffers, |(buffer, mut blob)| {
+        let uri = match buffer.source() {
+            buffer::Source::Uri(uri) => uri,
+            buffer::Source::Bin => return Ok(Arc::new(load_buffer(&buffer, "", None, &mut blob, None)?)),
+        };
+        let data = Cache::get_or_load(&cache.0.buffers, cache.key(base, uri), || {
+            load_buffer(&buffer, "", Some(&file_system), &mut None, None)
+        })?;
+        if data.len() < buffer.length() {
+            return Err(Error::BufferLength {
+                buffer: buffer.index(),
+                expected: buffer.length(),
+                actual: data.len(),
+            });
+        }
+        Ok(data)
+    })?;
+
+    let buffer_slices = buffer_data.iter().map(|data| &data[..]).collect::<Vec<_>>();
+    let image_data = map_resources(document.images().collect(), |image| {
+        let key = match image.source() {
+            image::Source::Uri { uri, .. } => cache.key(base, uri),
+            image::Source::View { view, .. } => {
+                let begin = view.offset();
+                let end = begin + view.length();
+                cache.hash(&buffer_slices[view.buffer().index()][begin..end])
+            }
+        };
+        Cache::get_or_load(&cache.0.images, key, || {
+            let (encoded_image, encoded_format) =
+                encoded_image_data(&image, "", Some(&file_system), &buffer_slices, None)?;
+            decode_image(&encoded_image, encoded_format)
+        })
+    })?;
+    Ok((document, buffer_data, image_data))
+}
+
+/// Resolves a URI relative to the directory of a base URI.
+fn join_uri(base: &str, uri: &str) -> String {
+    if uri.contains(':') || uri.starts_with('/') {
+        uri.to_owned()
+    } else {
+        let directory = base.rfind('/').map_or("", |end| &base[..=end]);
+        format!("{}{}", directory, uri)
+    }
+}
+
+/// Serves the data fetched on asynchronous import to the importer.
+///
+/// The data of a URI is handed over without a copy when it is requested for
+/// the last time.
+struct Fetched(Mutex<HashMap<String, (usize, Vec<u8>)>>);
+
+impl Resolver for Fetched {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>> {
+        let mut entries = self.0.lock().unwrap();
+        let (uses, data) = entries.get_mut(uri).ok_or(Error::ExternalReferenceInSliceImport)?;
+        *uses -= 1;
+        if *uses > 0 {
+            Ok(data.clone())
+        } else {
+            Ok(entries.remove(uri).unwrap().1)
+        }
+    }
+}
+
+impl Fetched {
+    /// Fetches the data of a URI through a resolver, unless it is a data URI
+    /// or has been fetched already, failing if it is longer than `max_size`
+    /// bytes.
+    ///
+    /// Returns the number of bytes fetched.
+    async fn fetch<R>(
+        &mut self,
+        resolver: &R,
+        base: &str,
+        uri: &str,
+        max_size: Option<usize>,
+        limit: Limit,
+    ) -> Result<usize>
+        where R: AsyncResolver + ?Sized
+    {
+        if let Scheme::Data(..) = Scheme::parse(uri) {
+            return Ok(0);
+        }
+        let entries = self.0.get_mut().unwrap();
+        if let Some((uses, data)) = entries.get_mut(uri) {
+            *uses += 1;
+            return Ok(data.len());
+        }
+        let uri_in_base = join_uri(base, uri);
+        let data = match max_size {
+            Some(max) => resolver.resolve_bounded(&uri_in_base, max).await?,
+            None => resolver.resolve(&uri_in_base).await?,
+        };
+        Limits::check(max_size, data.len(), limit)?;
+        let length = data.len();
+        entries.insert(uri.to_owned(), (1, data));
+        Ok(length)
+    }
+}
+
+/// Fetches the resources of a document through an asynchronous resolver,
+/// then imports the document from the fetched data with `import`.
+///
+/// Fetching checks the limits and the cancellation token of the options, so
+/// that no more is fetched than the import accepts. The import itself runs
+/// on the task awaiting it once everything is fetched.
+async fn import_async_impl<R, T, F>(
+    gltf: Gltf,
+    base: &str,
+    resolver: &R,
+    options: &ImportOptions,
+    import: F,
+) -> Result<T>
+    where
+        R: AsyncResolver + ?Sized,
+        F: FnOnce(Gltf, &Fetched, &ImportOptions) -> Result<T>,
+{
+    let limits = &options.limits;
+    limits.check_buffers(&gltf.document)?;
+
+    let mut fetched = Fetched(Mutex::new(HashMap::new()));
+    let mut budget = limits.max_buffer_size;
+    for buffer in gltf.document.buffers() {
+        if let buffer::Source::Uri(uri) = buffer.source() {
+            options.check_cancelled()?;
+            let length = fetched.fetch(resolver, base, uri, budget, Limit::BufferSize).await?;
+            if let Some(ref mut budget) = budget {
+                *budget = budget.checked_sub(length).ok_or(Error::LimitExceeded(Limit::BufferSize))?;
+            }
+        }
+    }
+    if options.images != ImageMode::Skip {
+        for image in gltf.document.images() {
+            if let image::Source::Uri { uri, .. } = image.source() {
+                options.check_cancelled()?;
+                fetched.fetch(resolver, base, uri, limits.max_image_size, Limit::ImageSize).await?;
+            }
+        }
+    }
+
+    import(gltf, &fetched, options)
+}
+
+/// Fetches glTF through an asynchronous resolver, checking the sizes of the
+/// JSON and of the `BIN` chunk against the limits.
+async fn fetch_limited<R>(uri: &str, resolver: &R, limits: &Limits) -> Result<Gltf>
+    where R: AsyncResolver + ?Sized
+{
+    // The header of binary glTF and of its two chunks, and chunk padding.
+    const OVERHEAD: usize = 12 + 2 * (8 + 3);
+    let data = match (limits.max_json_size, limits.max_buffer_size) {
+        (Some(json), Some(bin)) => {
+            let max = json.saturating_add(bin).saturating_add(OVERHEAD);
+            resolver.resolve_bounded(uri, max).await?
+        },
+        _ => resolver.resolve(uri).await?,
+    };
+    read_limited(data.as_slice(), limits)
+}
+
+/// Import some glTF 2.0 asynchronously, fetching the file and the resources
+/// it references through a resolver.
+///
+/// Relative URIs in the file are resolved against the given URI before they
+/// are passed to the resolver. Resources are fetched one after another.
+///
+/// Images are decoded on the task awaiting the import, without spawning
+/// threads, so the task blocks while decoding. Executors that must not block
+/// can import from a blocking task instead, e.g. with `spawn_blocking` in
+/// `tokio`.
+pub async fn import_async<R>(uri: &str, resolver: &R) -> Result<Import>
+    where R: AsyncResolver + ?Sized
+{
+    let data = resolver.resolve(uri).await?;
+    let gltf = Gltf::from_slice(&data)?;
+    import_async_impl(gltf, uri, resolver, &ImportOptions::new(), |gltf, fetched, _| {
+        import_decoded(gltf, Some(fetched))
+    })
+    .await
+}
+
+/// Import some glTF 2.0 asynchronously with the given options, fetching the
+/// file and the resources it references through a resolver.
+///
+/// Resources are fetched and imported as with [`import_async`], and the
+/// options apply as with [`import_with_options`].
+///
+/// [`import_async`]: fn.import_async.html
+/// [`import_with_options`]: fn.import_with_options.html
+pub async fn import_async_with_options<R>(
+    uri: &str,
+    resolver: &R,
+    options: &ImportOptions,
+) -> Result<ImportWithOptions>
+    where R: AsyncResolver + ?Sized
+{
+    options.check_cancelled()?;
+    let gltf = fetch_limited(uri, resolver, &options.limits).await?;
+    import_async_impl(gltf, uri, resolver, options, |gltf, fetched, options| {
+        import_with_options_impl(gltf, Some(fetched), options)
+    })
+    .await
+}
+
+/// Import some glTF 2.0 from a slice asynchronously, fetching the resources
+/// it references through a resolver.
+///
+/// URIs in the file are passed to the resolver as they are.
+pub async fn import_slice_async<S, R>(slice: S, resolver: &R) -> Result<Import>
+    where S: AsRef<[u8]>, R: AsyncResolver + ?Sized
+{
+    let gltf = Gltf::from_slice(slice.as_ref())?;
+    import_async_impl(gltf, "", resolver, &ImportOptions::new(), |gltf, fetched, _| {
+        import_decoded(gltf, Some(fetched))
+    })
+    .await
+}
+
+/// Import some glTF 2.0 from a slice asynchronously with the given options,
+/// fetching the resources it references through a resolver.
+///
+/// URIs in the file are passed to the resolver as they are.
+pub async fn import_slice_async_with_options<S, R>(
+    slice: S,
+    resolver: &R,
+    options: &ImportOptions,
+) -> Result<ImportWithOptions>
+    where S: AsRef<[u8]>, R: AsyncResolver + ?Sized
+{
+    options.check_cancelled()?;
+    let gltf = read_limited(slice.as_ref(), &options.limits)?;
+    import_async_impl(gltf, "", resolver, options, |gltf, fetched, options| {
+        import_with_options_impl(gltf, Some(fetched), options)
+    })
+    .await
+}
+
 pub fn import_slice_impl(slice: &[u8]) -> Result<Import> {
-    import_impl(Gltf::from_slice(slice)?, None)
+    import_decoded(Gltf::from_slice(slice)?, None)
+}
+
+/// Import some glTF 2.0, reading the file and the resources it references
+/// through a resolver.
+///
+/// Relative URIs in the file are resolved against the given URI before they
+/// are passed to the resolver. Resources are read one after another, even if
+/// the `rayon` feature is enabled.
+pub fn import_with_resolver<R>(uri: &str, resolver: &R) -> Result<Import>
+    where R: Resolver
+{
+    let data = resolver.resolve(uri)?;
+    import_impl(Gltf::from_slice(&data)?, uri, Some(resolver))
+}
+
+/// Import some glTF 2.0 from a slice, reading the resources it references
+/// through a resolver.
+///
+/// URIs in the file are passed to the resolver as they are. Closures mapping
+/// URIs to data may be used as resolvers, e.g. to serve relative URIs from a
+/// virtual file system.
+///
+/// ```
+/// # fn run() -> Result<(), gltf::Error> {
+/// let gltf = std::fs::read("examples/Box.gltf")?;
+/// let bin = std::fs::read("examples/Box0.bin")?;
+/// let (document, buffers, _) = gltf::import_slice_with_resolver(&gltf, &|uri: &str| match uri {
+///     "Box0.bin" => Ok(bin.clone()),
+///     _ => Err(gltf::Error::ExternalReferenceInSliceImport),
+/// })?;
+/// assert!(buffers[0].len() >= document.buffers().next().unwrap().length());
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #     run().expect("test failure");
+/// # }
+/// ```
+pub fn import_slice_with_resolver<S, R>(slice: S, resolver: &R) -> Result<Import>
+    where S: AsRef<[u8]>, R: Resolver
+{
+    import_impl(Gltf::from_slice(slice.as_ref())?, "", Some(resolver))
 }
 
 /// Import some glTF 2.0 from a slice
@@ -263,6 +1711,14 @@ pub fn import_slice_impl(slice: &[u8]) -> Result<Import> {
 /// #     run().expect("test failure");
 /// # }
 /// ```
+///
+/// ### Note
+///
+/// Resources referenced by URIs other than data URIs cannot be imported from
+/// a slice alone, and fail with `Error::ExternalReferenceInSliceImport`. Use
+/// [`import_slice_with_resolver`] to provide them.
+///
+/// [`import_slice_with_resolver`]: fn.import_slice_with_resolver.html
 pub fn import_slice<S>(slice: S) -> Result<Import>
     where S: AsRef<[u8]>
 {
@@ -92,6 +92,93 @@ pub struct Lights<'a> {
     pub(crate) document: &'a Document,
 }
 
+/// An `Iterator` that visits every audio data in a glTF asset.
+#[cfg(feature = "KHR_audio")]
+#[derive(Clone, Debug)]
+pub struct AudioData<'a> {
+    /// Internal audio data iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_audio::Audio>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits every audio source in a glTF asset.
+#[cfg(feature = "KHR_audio")]
+#[derive(Clone, Debug)]
+pub struct AudioSources<'a> {
+    /// Internal audio source iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_audio::AudioSource>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits every audio emitter in a glTF asset.
+#[cfg(feature = "KHR_audio")]
+#[derive(Clone, Debug)]
+pub struct AudioEmitters<'a> {
+    /// Internal audio emitter iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_audio::Emitter>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits every implicit shape in a glTF asset.
+#[cfg(feature = "KHR_implicit_shapes")]
+#[derive(Clone, Debug)]
+pub struct Shapes<'a> {
+    /// Internal implicit shape iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_implicit_shapes::Shape>>,
+}
+
+/// An `Iterator` that visits every physics material in a glTF asset.
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+#[derive(Clone, Debug)]
+pub struct PhysicsMaterials<'a> {
+    /// Internal physics material iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_physics_rigid_bodies::Material>>,
+}
+
+/// An `Iterator` that visits every collision filter in a glTF asset.
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+#[derive(Clone, Debug)]
+pub struct CollisionFilters<'a> {
+    /// Internal collision filter iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_physics_rigid_bodies::CollisionFilter>>,
+}
+
+/// An `Iterator` that visits every physics joint in a glTF asset.
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+#[derive(Clone, Debug)]
+pub struct PhysicsJoints<'a> {
+    /// Internal physics joint iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::khr_physics_rigid_bodies::Joint>>,
+}
+
+/// An `Iterator` that visits every IES light profile in a glTF asset.
+#[cfg(feature = "EXT_lights_ies")]
+#[derive(Clone, Debug)]
+pub struct LightProfiles<'a> {
+    /// Internal light profile iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::scene::ext_lights_ies::LightProfile>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
+/// An `Iterator` that visits every material variant in a glTF asset.
+#[cfg(feature = "KHR_materials_variants")]
+#[derive(Clone, Debug)]
+pub struct Variants<'a> {
+    /// Internal variant iterator.
+    pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::extensions::mesh::khr_materials_variants::Variant>>,
+
+    /// The internal root glTF object.
+    pub(crate) document: &'a Document,
+}
+
 /// An `Iterator` that visits every material in a glTF asset.
 #[derive(Clone, Debug)]
 pub struct Materials<'a> {
@@ -354,6 +441,136 @@ impl<'a> Iterator for Lights<'a> {
     }
 }
 
+#[cfg(feature = "KHR_audio")]
+impl<'a> ExactSizeIterator for AudioData<'a> {}
+
+#[cfg(feature = "KHR_audio")]
+impl<'a> Iterator for AudioData<'a> {
+    type Item = crate::khr_audio::Audio<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_audio::Audio::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| crate::khr_audio::Audio::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_audio::Audio::new(self.document, index, json))
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+impl<'a> ExactSizeIterator for AudioSources<'a> {}
+
+#[cfg(feature = "KHR_audio")]
+impl<'a> Iterator for AudioSources<'a> {
+    type Item = crate::khr_audio::AudioSource<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_audio::AudioSource::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| crate::khr_audio::AudioSource::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_audio::AudioSource::new(self.document, index, json))
+    }
+}
+
+#[cfg(feature = "KHR_audio")]
+impl<'a> ExactSizeIterator for AudioEmitters<'a> {}
+
+#[cfg(feature = "KHR_audio")]
+impl<'a> Iterator for AudioEmitters<'a> {
+    type Item = crate::khr_audio::Emitter<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_audio::Emitter::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| crate::khr_audio::Emitter::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_audio::Emitter::new(self.document, index, json))
+    }
+}
+
+#[cfg(feature = "EXT_lights_ies")]
+impl<'a> ExactSizeIterator for LightProfiles<'a> {}
+
+#[cfg(feature = "EXT_lights_ies")]
+impl<'a> Iterator for LightProfiles<'a> {
+    type Item = crate::ext_lights_ies::LightProfile<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::ext_lights_ies::LightProfile::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| crate::ext_lights_ies::LightProfile::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::ext_lights_ies::LightProfile::new(self.document, index, json))
+    }
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> ExactSizeIterator for Variants<'a> {}
+
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> Iterator for Variants<'a> {
+    type Item = crate::khr_materials_variants::Variant<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_materials_variants::Variant::new(self.document, index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|(index, json)| crate::khr_materials_variants::Variant::new(document, index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_materials_variants::Variant::new(self.document, index, json))
+    }
+}
+
 impl<'a> ExactSizeIterator for Materials<'a> {}
 impl<'a> Iterator for Materials<'a> {
     type Item = Material<'a>;
@@ -500,3 +717,103 @@ impl<'a> Iterator for Textures<'a> {
         self.iter.nth(n).map(|(index, json)| Texture::new(self.document, index, json))
     }
 }
+
+#[cfg(feature = "KHR_implicit_shapes")]
+impl<'a> ExactSizeIterator for Shapes<'a> {}
+
+#[cfg(feature = "KHR_implicit_shapes")]
+impl<'a> Iterator for Shapes<'a> {
+    type Item = crate::khr_implicit_shapes::Shape<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_implicit_shapes::Shape::new(index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(index, json)| crate::khr_implicit_shapes::Shape::new(index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_implicit_shapes::Shape::new(index, json))
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> ExactSizeIterator for PhysicsMaterials<'a> {}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> Iterator for PhysicsMaterials<'a> {
+    type Item = crate::khr_physics_rigid_bodies::Material<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_physics_rigid_bodies::Material::new(index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(index, json)| crate::khr_physics_rigid_bodies::Material::new(index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_physics_rigid_bodies::Material::new(index, json))
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> ExactSizeIterator for CollisionFilters<'a> {}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> Iterator for CollisionFilters<'a> {
+    type Item = crate::khr_physics_rigid_bodies::CollisionFilter<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_physics_rigid_bodies::CollisionFilter::new(index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(index, json)| crate::khr_physics_rigid_bodies::CollisionFilter::new(index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_physics_rigid_bodies::CollisionFilter::new(index, json))
+    }
+}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> ExactSizeIterator for PhysicsJoints<'a> {}
+
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+impl<'a> Iterator for PhysicsJoints<'a> {
+    type Item = crate::khr_physics_rigid_bodies::Joint<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(index, json)| crate::khr_physics_rigid_bodies::Joint::new(index, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(index, json)| crate::khr_physics_rigid_bodies::Joint::new(index, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| crate::khr_physics_rigid_bodies::Joint::new(index, json))
+    }
+}
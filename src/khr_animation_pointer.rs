@@ -0,0 +1,112 @@
+use crate::{Camera, Document, Material, Mesh, Node};
+
+/// The object containing the property targeted by an animation pointer.
+#[derive(Clone, Debug)]
+pub enum Object<'a> {
+    /// A node, e.g. `/nodes/0/translation`.
+    Node(Node<'a>),
+
+    /// A mesh, e.g. `/meshes/0/weights`.
+    Mesh(Mesh<'a>),
+
+    /// A material, e.g. `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+    Material(Material<'a>),
+
+    /// A camera, e.g. `/cameras/0/perspective/yfov`.
+    Camera(Camera<'a>),
+
+    /// A punctual light, e.g. `/extensions/KHR_lights_punctual/lights/0/color`.
+    #[cfg(feature = "KHR_lights_punctual")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
+    Light(crate::khr_lights_punctual::Light<'a>),
+}
+
+/// A JSON pointer to the property targeted by an animation channel.
+#[derive(Clone, Debug)]
+pub struct Pointer<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::animation::khr_animation_pointer::KhrAnimationPointer,
+}
+
+impl<'a> Pointer<'a> {
+    /// Constructs a `Pointer`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::animation::khr_animation_pointer::KhrAnimationPointer,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the JSON pointer, for example
+    /// `/materials/0/pbrMetallicRoughness/baseColorFactor`.
+    pub fn as_str(&self) -> &'a str {
+        &self.json.pointer
+    }
+
+    /// Splits the pointer into the collection name, the object index and the
+    /// path of the property relative to the object.
+    fn split(&self) -> Option<(&'a str, usize, &'a str)> {
+        let pointer = self.as_str().strip_prefix('/')?;
+        let pointer = pointer.strip_prefix("extensions/KHR_lights_punctual/").unwrap_or(pointer);
+        let mut tokens = pointer.splitn(3, '/');
+        let collection = tokens.next()?;
+        let index = tokens.next()?.parse().ok()?;
+        let property = tokens.next()?;
+        Some((collection, index, property))
+    }
+
+    /// Resolves the object containing the targeted property.
+    ///
+    /// Returns `None` if the pointer is malformed, refers to an object that does
+    /// not exist or to a kind of object that is not supported.
+    pub fn object(&self) -> Option<Object<'a>> {
+        let (collection, index, _) = self.split()?;
+        let document = self.document;
+        match collection {
+            "nodes" if !self.is_light() => document.nodes().nth(index).map(Object::Node),
+            "meshes" if !self.is_light() => document.meshes().nth(index).map(Object::Mesh),
+            "materials" if !self.is_light() => document.materials().nth(index).map(Object::Material),
+            "cameras" if !self.is_light() => document.cameras().nth(index).map(Object::Camera),
+            #[cfg(feature = "KHR_lights_punctual")]
+            "lights" if self.is_light() => document.lights()?.nth(index).map(Object::Light),
+            _ => None,
+        }
+    }
+
+    /// Returns the path of the targeted property relative to its object, for
+    /// example `pbrMetallicRoughness/baseColorFactor`.
+    pub fn property(&self) -> Option<&'a str> {
+        self.split().map(|(_, _, property)| property)
+    }
+
+    /// Returns the un-animated value of the targeted property.
+    ///
+    /// Returns `None` if the property is not present in the JSON, in which case
+    /// its default value applies.
+    pub fn value(&self) -> Option<json::Value> {
+        let (_, _, property) = self.split()?;
+        let root = &self.document.0;
+        let object = match self.object()? {
+            Object::Node(node) => json::serialize::to_value(&root.nodes[node.index()]),
+            Object::Mesh(mesh) => json::serialize::to_value(&root.meshes[mesh.index()]),
+            Object::Material(material) => {
+                json::serialize::to_value(&root.materials[material.index()?])
+            }
+            Object::Camera(camera) => json::serialize::to_value(&root.cameras[camera.index()]),
+            #[cfg(feature = "KHR_lights_punctual")]
+            Object::Light(light) => {
+                let lights = &root.extensions.as_ref()?.khr_lights_punctual.as_ref()?.lights;
+                json::serialize::to_value(&lights[light.index()])
+            }
+        };
+        object.ok()?.pointer(&format!("/{}", property)).cloned()
+    }
+
+    /// Returns `true` if the pointer refers to a `KHR_lights_punctual` light.
+    fn is_light(&self) -> bool {
+        self.as_str().starts_with("/extensions/KHR_lights_punctual/")
+    }
+}
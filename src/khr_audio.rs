@@ -0,0 +1,344 @@
+use std::slice;
+
+use crate::{buffer, Buffer, Document};
+
+pub use json::extensions::scene::khr_audio::{DistanceModel, Type};
+
+lazy_static! {
+    static ref DEFAULT_POSITIONAL: json::extensions::scene::khr_audio::Positional =
+        Default::default();
+}
+
+/// Describes an audio data source.
+#[derive(Clone, Debug)]
+pub enum Source<'a> {
+    /// Audio data is contained in a buffer view.
+    View {
+        /// The buffer view containing the encoded audio data.
+        view: buffer::View<'a>,
+
+        /// The audio data MIME type.
+        mime_type: &'a str,
+    },
+
+    /// Audio data is contained in an external data source.
+    Uri {
+        /// The URI of the external data source.
+        uri: &'a str,
+
+        /// The audio data MIME type, if provided.
+        mime_type: Option<&'a str>,
+    },
+}
+
+/// Encoded audio data played by audio sources.
+#[derive(Clone, Debug)]
+pub struct Audio<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_audio::Audio,
+}
+
+/// A playable audio source referencing audio data.
+#[derive(Clone, Debug)]
+pub struct AudioSource<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_audio::AudioSource,
+}
+
+/// An audio emitter playing one or more audio sources.
+#[derive(Clone, Debug)]
+pub struct Emitter<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_audio::Emitter,
+}
+
+/// Positional emitter parameters.
+#[derive(Clone, Debug)]
+pub struct Positional<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_audio::Positional,
+}
+
+/// An `Iterator` that visits the audio sources played by an emitter.
+#[derive(Clone, Debug)]
+pub struct Sources<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal audio source index iterator.
+    iter: slice::Iter<'a, json::Index<json::extensions::scene::khr_audio::AudioSource>>,
+}
+
+/// An `Iterator` that visits the global audio emitters of a scene.
+#[derive(Clone, Debug)]
+pub struct Emitters<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal emitter index iterator.
+    pub(crate) iter: slice::Iter<'a, json::Index<json::extensions::scene::khr_audio::Emitter>>,
+}
+
+impl<'a> Audio<'a> {
+    /// Constructs an `Audio`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::khr_audio::Audio,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the audio data source.
+    pub fn source(&self) -> Source<'a> {
+        if let Some(index) = self.json.buffer_view.as_ref() {
+            let view = self.document.views().nth(index.value()).unwrap();
+            let mime_type = self.json.mime_type.as_deref().unwrap();
+            Source::View { view, mime_type }
+        } else {
+            let uri = self.json.uri.as_deref().unwrap();
+            let mime_type = self.json.mime_type.as_deref();
+            Source::Uri { uri, mime_type }
+        }
+    }
+
+    /// Returns the encoded audio data if it is contained in a buffer view.
+    ///
+    /// Returns `None` if the audio data is stored at a URI or if the buffer
+    /// data is unavailable.
+    pub fn read<'s, F>(&self, get_buffer_data: F) -> Option<&'s [u8]>
+    where
+        F: Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        match self.source() {
+            Source::View { view, .. } => {
+                let data = get_buffer_data(view.buffer())?;
+                data.get(view.offset()..view.offset() + view.length())
+            }
+            Source::Uri { .. } => None,
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> AudioSource<'a> {
+    /// Constructs an `AudioSource`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::khr_audio::AudioSource,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the audio data played by this source.
+    pub fn audio(&self) -> Option<Audio<'a>> {
+        self.json
+            .audio
+            .map(|index| self.document.audio().unwrap().nth(index.value()).unwrap())
+    }
+
+    /// Whether the source starts playing when the scene is loaded.
+    pub fn auto_play(&self) -> bool {
+        self.json.auto_play
+    }
+
+    /// Unitless multiplier applied to the audio volume.
+    pub fn gain(&self) -> f32 {
+        self.json.gain.0
+    }
+
+    /// Whether the audio restarts when it reaches its end.
+    pub fn looping(&self) -> bool {
+        self.json.loop_
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Emitter<'a> {
+    /// Constructs an `Emitter`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::scene::khr_audio::Emitter,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the emitter type.
+    pub fn kind(&self) -> Type {
+        self.json.type_.unwrap()
+    }
+
+    /// Unitless multiplier applied to the volume of all sources.
+    pub fn gain(&self) -> f32 {
+        self.json.gain.0
+    }
+
+    /// Returns the positional emitter parameters, or `None` for global
+    /// emitters.
+    ///
+    /// Positional emitters without explicit parameters use the default values.
+    pub fn positional(&self) -> Option<Positional<'a>> {
+        match self.kind() {
+            Type::Global => None,
+            Type::Positional => Some(Positional {
+                json: self.json.positional.as_ref().unwrap_or(&DEFAULT_POSITIONAL),
+            }),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the audio sources played by this
+    /// emitter.
+    pub fn sources(&self) -> Sources<'a> {
+        Sources {
+            document: self.document,
+            iter: self.json.sources.iter(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Positional<'a> {
+    /// The angle in radians of the cone inside of which there is no volume
+    /// reduction.
+    pub fn cone_inner_angle(&self) -> f32 {
+        self.json.cone_inner_angle
+    }
+
+    /// The angle in radians of the cone outside of which the volume is reduced
+    /// by `cone_outer_gain`.
+    pub fn cone_outer_angle(&self) -> f32 {
+        self.json.cone_outer_angle
+    }
+
+    /// The gain of the audio emitter outside of the outer cone.
+    pub fn cone_outer_gain(&self) -> f32 {
+        self.json.cone_outer_gain
+    }
+
+    /// Specifies how the volume decreases with distance.
+    pub fn distance_model(&self) -> DistanceModel {
+        self.json.distance_model.unwrap()
+    }
+
+    /// The maximum distance between the emitter and the listener, after which
+    /// the volume is no longer reduced.
+    pub fn max_distance(&self) -> f32 {
+        self.json.max_distance
+    }
+
+    /// The reference distance for reducing the volume.
+    pub fn ref_distance(&self) -> f32 {
+        self.json.ref_distance
+    }
+
+    /// How quickly the volume is reduced as the distance increases.
+    pub fn rolloff_factor(&self) -> f32 {
+        self.json.rolloff_factor
+    }
+}
+
+impl<'a> ExactSizeIterator for Sources<'a> {}
+impl<'a> Iterator for Sources<'a> {
+    type Item = AudioSource<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|index| self.document.audio_sources().unwrap().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Emitters<'a> {}
+impl<'a> Iterator for Emitters<'a> {
+    type Item = Emitter<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|index| self.document.audio_emitters().unwrap().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -0,0 +1,122 @@
+pub use json::extensions::scene::khr_implicit_shapes::Type;
+
+/// The parameters of an implicit shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Kind {
+    /// A sphere centered at the origin.
+    Sphere {
+        /// The radius of the sphere.
+        radius: f32,
+    },
+
+    /// An axis-aligned box centered at the origin.
+    Box {
+        /// The extents of the box along the X, Y and Z axes.
+        size: [f32; 3],
+    },
+
+    /// A capsule centered at the origin and aligned with the Y axis.
+    Capsule {
+        /// The distance between the centers of the two hemispherical caps.
+        height: f32,
+
+        /// The radius of the cap at the positive Y end.
+        radius_top: f32,
+
+        /// The radius of the cap at the negative Y end.
+        radius_bottom: f32,
+    },
+
+    /// A cylinder centered at the origin and aligned with the Y axis.
+    Cylinder {
+        /// The distance between the two flat ends.
+        height: f32,
+
+        /// The radius of the end at the positive Y end.
+        radius_top: f32,
+
+        /// The radius of the end at the negative Y end.
+        radius_bottom: f32,
+    },
+}
+
+/// A parametric shape, typically used as the geometry of a physics collider.
+#[derive(Clone, Debug)]
+pub struct Shape<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_implicit_shapes::Shape,
+}
+
+impl<'a> Shape<'a> {
+    /// Constructs a `Shape`.
+    pub(crate) fn new(
+        index: usize,
+        json: &'a json::extensions::scene::khr_implicit_shapes::Shape,
+    ) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the shape type.
+    pub fn kind(&self) -> Type {
+        self.json.type_.unwrap()
+    }
+
+    /// Returns the shape parameters.
+    ///
+    /// Parameters that are not present in the JSON take their default values.
+    pub fn parameters(&self) -> Kind {
+        use json::extensions::scene::khr_implicit_shapes as json;
+        match self.kind() {
+            Type::Sphere => {
+                let default = json::Sphere::default();
+                let sphere = self.json.sphere.as_ref().unwrap_or(&default);
+                Kind::Sphere {
+                    radius: sphere.radius,
+                }
+            }
+            Type::Box => {
+                let default = json::Box::default();
+                let box_ = self.json.box_.as_ref().unwrap_or(&default);
+                Kind::Box { size: box_.size }
+            }
+            Type::Capsule => {
+                let default = json::Capsule::default();
+                let capsule = self.json.capsule.as_ref().unwrap_or(&default);
+                Kind::Capsule {
+                    height: capsule.height,
+                    radius_top: capsule.radius_top,
+                    radius_bottom: capsule.radius_bottom,
+                }
+            }
+            Type::Cylinder => {
+                let default = json::Cylinder::default();
+                let cylinder = self.json.cylinder.as_ref().unwrap_or(&default);
+                Kind::Cylinder {
+                    height: cylinder.height,
+                    radius_top: cylinder.radius_top,
+                    radius_bottom: cylinder.radius_bottom,
+                }
+            }
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
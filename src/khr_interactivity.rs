@@ -0,0 +1,618 @@
+use std::{collections::hash_map, iter, slice};
+
+use crate::Document;
+
+pub use json::extensions::root::khr_interactivity::Literal;
+
+/// The behavior graphs of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct Interactivity<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::KhrInteractivity,
+}
+
+/// A behavior graph.
+#[derive(Clone, Debug)]
+pub struct Graph<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Graph,
+}
+
+/// A value type of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Type<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Type,
+}
+
+/// A variable of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Variable<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Variable,
+}
+
+/// A custom event of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Event<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Event,
+}
+
+/// An operation that nodes of a behavior graph may perform.
+#[derive(Clone, Debug)]
+pub struct Declaration<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Declaration,
+}
+
+/// A node of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Node<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::khr_interactivity::Node,
+}
+
+/// An input value of a node.
+#[derive(Clone, Debug)]
+pub enum Value<'a> {
+    /// A literal value of the given type.
+    Literal {
+        /// The value type.
+        type_: Type<'a>,
+
+        /// The value components.
+        value: &'a [Literal],
+    },
+
+    /// The output value of another node.
+    Reference {
+        /// The node providing the value.
+        node: Node<'a>,
+
+        /// The output value socket of the node providing the value.
+        socket: &'a str,
+    },
+}
+
+/// An output flow of a node.
+#[derive(Clone, Debug)]
+pub struct Flow<'a> {
+    /// The node to activate.
+    pub node: Node<'a>,
+
+    /// The input flow socket of the node to activate.
+    pub socket: &'a str,
+}
+
+/// An `Iterator` that visits the behavior graphs of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct Graphs<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal graph iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Graph>>,
+}
+
+/// An `Iterator` that visits the value types of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Types<'a> {
+    /// The internal type iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Type>>,
+}
+
+/// An `Iterator` that visits the variables of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Variables<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal variable iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Variable>>,
+}
+
+/// An `Iterator` that visits the custom events of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Events<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal event iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Event>>,
+}
+
+/// An `Iterator` that visits the declarations of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Declarations<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal declaration iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Declaration>>,
+}
+
+/// An `Iterator` that visits the nodes of a behavior graph.
+#[derive(Clone, Debug)]
+pub struct Nodes<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal node iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::khr_interactivity::Node>>,
+}
+
+/// An `Iterator` that visits the input values of a node.
+#[derive(Clone, Debug)]
+pub struct Values<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal value iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::khr_interactivity::ValueInput>,
+}
+
+/// An `Iterator` that visits the output flows of a node.
+#[derive(Clone, Debug)]
+pub struct Flows<'a> {
+    /// The parent `Graph` struct.
+    graph: Graph<'a>,
+
+    /// The internal flow iterator.
+    iter: hash_map::Iter<'a, String, json::extensions::root::khr_interactivity::Flow>,
+}
+
+impl<'a> Interactivity<'a> {
+    /// Constructs an `Interactivity`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::root::khr_interactivity::KhrInteractivity,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns an `Iterator` that visits the behavior graphs.
+    pub fn graphs(&self) -> Graphs<'a> {
+        Graphs {
+            document: self.document,
+            iter: self.json.graphs.iter().enumerate(),
+        }
+    }
+
+    /// Returns the behavior graph to execute when the asset is loaded.
+    pub fn default_graph(&self) -> Option<Graph<'a>> {
+        let index = self.json.graph.unwrap_or(0) as usize;
+        self.graphs().nth(index)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Graph<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the parent `Document` struct.
+    pub fn document(&self) -> &'a Document {
+        self.document
+    }
+
+    /// Returns an `Iterator` that visits the value types of the graph.
+    pub fn types(&self) -> Types<'a> {
+        Types {
+            iter: self.json.types.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the variables of the graph.
+    pub fn variables(&self) -> Variables<'a> {
+        Variables {
+            graph: self.clone(),
+            iter: self.json.variables.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the custom events of the graph.
+    pub fn events(&self) -> Events<'a> {
+        Events {
+            graph: self.clone(),
+            iter: self.json.events.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the declarations of the graph.
+    pub fn declarations(&self) -> Declarations<'a> {
+        Declarations {
+            graph: self.clone(),
+            iter: self.json.declarations.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the nodes of the graph.
+    pub fn nodes(&self) -> Nodes<'a> {
+        Nodes {
+            graph: self.clone(),
+            iter: self.json.nodes.iter().enumerate(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+
+    fn type_(&self, index: u32) -> Type<'a> {
+        self.types().nth(index as usize).unwrap()
+    }
+
+    fn node(&self, index: u32) -> Node<'a> {
+        self.nodes().nth(index as usize).unwrap()
+    }
+}
+
+impl<'a> Type<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the type signature, for example `float3` or `bool`.
+    pub fn signature(&self) -> &'a str {
+        &self.json.signature
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Variable<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns the variable type.
+    pub fn type_(&self) -> Type<'a> {
+        self.graph.type_(self.json.type_)
+    }
+
+    /// Returns the initial value of the variable, if any.
+    pub fn value(&self) -> Option<&'a [Literal]> {
+        self.json.value.as_deref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Event<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the external identifier of the event, if any.
+    pub fn id(&self) -> Option<&'a str> {
+        self.json.id.as_deref()
+    }
+
+    /// Returns the type and initial value of the event value with the given
+    /// name.
+    pub fn value(&self, name: &str) -> Option<(Type<'a>, Option<&'a [Literal]>)> {
+        self.json
+            .values
+            .get(name)
+            .map(|value| (self.graph.type_(value.type_), value.value.as_deref()))
+    }
+
+    /// Returns the names of the values carried by the event.
+    pub fn value_names(&self) -> impl Iterator<Item = &'a str> {
+        self.json.values.keys().map(String::as_str)
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Declaration<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the operation identifier, for example `math/add`.
+    pub fn op(&self) -> &'a str {
+        &self.json.op
+    }
+
+    /// Returns the extension defining the operation, if any.
+    pub fn extension(&self) -> Option<&'a str> {
+        self.json.extension.as_deref()
+    }
+
+    /// Returns the type of the input value socket with the given name, for
+    /// operations defined by extensions.
+    pub fn input_value_socket(&self, name: &str) -> Option<Type<'a>> {
+        self.json
+            .input_value_sockets
+            .get(name)
+            .map(|socket| self.graph.type_(socket.type_))
+    }
+
+    /// Returns the type of the output value socket with the given name, for
+    /// operations defined by extensions.
+    pub fn output_value_socket(&self, name: &str) -> Option<Type<'a>> {
+        self.json
+            .output_value_sockets
+            .get(name)
+            .map(|socket| self.graph.type_(socket.type_))
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Node<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the declaration of the operation performed by the node.
+    pub fn declaration(&self) -> Declaration<'a> {
+        self.graph
+            .declarations()
+            .nth(self.json.declaration as usize)
+            .unwrap()
+    }
+
+    /// Returns the input value with the given name.
+    pub fn value(&self, name: &str) -> Option<Value<'a>> {
+        self.json
+            .values
+            .get(name)
+            .map(|value| Value::new(&self.graph, value))
+    }
+
+    /// Returns an `Iterator` that visits the input values of the node.
+    pub fn values(&self) -> Values<'a> {
+        Values {
+            graph: self.graph.clone(),
+            iter: self.json.values.iter(),
+        }
+    }
+
+    /// Returns the output flow with the given name.
+    pub fn flow(&self, name: &str) -> Option<Flow<'a>> {
+        self.json
+            .flows
+            .get(name)
+            .map(|flow| Flow::new(&self.graph, flow))
+    }
+
+    /// Returns an `Iterator` that visits the output flows of the node.
+    pub fn flows(&self) -> Flows<'a> {
+        Flows {
+            graph: self.graph.clone(),
+            iter: self.json.flows.iter(),
+        }
+    }
+
+    /// Returns the configuration value with the given name.
+    pub fn configuration(&self, name: &str) -> Option<&'a [Literal]> {
+        self.json
+            .configuration
+            .get(name)
+            .map(|configuration| configuration.value.as_slice())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Value<'a> {
+    /// Constructs a `Value`.
+    fn new(graph: &Graph<'a>, json: &'a json::extensions::root::khr_interactivity::ValueInput) -> Self {
+        // NOTE: cannot panic if validated
+        match json.node {
+            Some(node) => Value::Reference {
+                node: graph.node(node),
+                socket: json.socket.as_deref().unwrap(),
+            },
+            None => Value::Literal {
+                type_: graph.type_(json.type_.unwrap()),
+                value: json.value.as_deref().unwrap(),
+            },
+        }
+    }
+}
+
+impl<'a> Flow<'a> {
+    /// Constructs a `Flow`.
+    fn new(graph: &Graph<'a>, json: &'a json::extensions::root::khr_interactivity::Flow) -> Self {
+        Flow {
+            node: graph.node(json.node),
+            socket: &json.socket,
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Graphs<'a> {}
+impl<'a> Iterator for Graphs<'a> {
+    type Item = Graph<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|(index, json)| Graph { document, index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.nth(n).map(|(index, json)| Graph { document, index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Types<'a> {}
+impl<'a> Iterator for Types<'a> {
+    type Item = Type<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(index, json)| Type { index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|(index, json)| Type { index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Variables<'a> {}
+impl<'a> Iterator for Variables<'a> {
+    type Item = Variable<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.next().map(|(index, json)| Variable { graph: graph.clone(), index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.nth(n).map(|(index, json)| Variable { graph: graph.clone(), index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Events<'a> {}
+impl<'a> Iterator for Events<'a> {
+    type Item = Event<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.next().map(|(index, json)| Event { graph: graph.clone(), index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.nth(n).map(|(index, json)| Event { graph: graph.clone(), index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Declarations<'a> {}
+impl<'a> Iterator for Declarations<'a> {
+    type Item = Declaration<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.next().map(|(index, json)| Declaration { graph: graph.clone(), index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.nth(n).map(|(index, json)| Declaration { graph: graph.clone(), index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Nodes<'a> {}
+impl<'a> Iterator for Nodes<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.next().map(|(index, json)| Node { graph: graph.clone(), index, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter.nth(n).map(|(index, json)| Node { graph: graph.clone(), index, json })
+    }
+}
+
+impl<'a> ExactSizeIterator for Values<'a> {}
+impl<'a> Iterator for Values<'a> {
+    type Item = (&'a str, Value<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter
+            .next()
+            .map(|(name, json)| (name.as_str(), Value::new(graph, json)))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Flows<'a> {}
+impl<'a> Iterator for Flows<'a> {
+    type Item = (&'a str, Flow<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let graph = &self.graph;
+        self.iter
+            .next()
+            .map(|(name, json)| (name.as_str(), Flow::new(graph, json)))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
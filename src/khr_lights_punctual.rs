@@ -2,6 +2,7 @@ use gltf_json::Extras;
 use crate::Document;
 
 /// A light in the scene.
+#[derive(Clone, Debug)]
 pub struct Light<'a> {
     /// The parent `Document` struct.
     #[allow(dead_code)]
@@ -26,7 +27,7 @@ impl<'a> Light<'a> {
 
     /// Color of the light source.
     pub fn color(&self) -> [f32; 3] {
-        self.json.color.clone()
+        self.json.color
     }
 
     /// Returns the internal JSON index.
@@ -36,6 +37,7 @@ impl<'a> Light<'a> {
 
     /// Optional user-defined name for this object.
     #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
     pub fn name(&self) -> Option<&'a str> {
         self.json.name.as_ref().map(String::as_str)
     }
@@ -75,6 +77,7 @@ impl<'a> Light<'a> {
 }
 
 /// Light subcategory.
+#[derive(Clone, Debug)]
 pub enum Kind {
     /// Directional lights are light sources that act as though they are infinitely far away
     /// and emit light in the direction of the local -z axis. This light type inherits the
@@ -0,0 +1,92 @@
+use crate::{Document, Material};
+
+/// A material variant that may be selected by the application.
+#[derive(Clone, Debug)]
+pub struct Variant<'a> {
+    /// The parent `Document` struct.
+    #[allow(dead_code)]
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::khr_materials_variants::Variant,
+}
+
+impl<'a> Variant<'a> {
+    /// Constructs a `Variant`.
+    pub(crate) fn new(
+        document: &'a Document,
+        index: usize,
+        json: &'a json::extensions::mesh::khr_materials_variants::Variant,
+    ) -> Self {
+        Self {
+            document,
+            index,
+            json,
+        }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The name of the material variant.
+    pub fn name(&self) -> &'a str {
+        &self.json.name
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Selects the material of a primitive when any of a set of variants is active.
+#[derive(Clone, Debug)]
+pub struct Mapping<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::mesh::khr_materials_variants::Mapping,
+}
+
+impl<'a> Mapping<'a> {
+    /// Constructs a `Mapping`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::mesh::khr_materials_variants::Mapping,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// Returns the indices of the variants that select this mapping's material.
+    ///
+    /// Each index refers to a variant returned by `Document::variants`.
+    pub fn variants(&self) -> &'a [u32] {
+        &self.json.variants
+    }
+
+    /// Returns the material to apply when any of the variants is active.
+    pub fn material(&self) -> Material<'a> {
+        self.document.materials().nth(self.json.material.value()).unwrap()
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
@@ -0,0 +1,459 @@
+use std::slice;
+
+use crate::khr_implicit_shapes::Shape;
+use crate::{Document, Node};
+
+pub use json::extensions::scene::khr_physics_rigid_bodies::{
+    CombineMode, DriveMode, DriveType, JointDrive, JointLimit,
+};
+
+/// The physics properties of a node.
+#[derive(Clone, Debug)]
+pub struct Physics<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::KhrPhysicsRigidBodies,
+}
+
+/// The motion properties of a rigid body.
+#[derive(Clone, Debug)]
+pub struct Motion<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::Motion,
+}
+
+/// The geometry of a collider or trigger.
+#[derive(Clone, Debug)]
+pub enum Geometry<'a> {
+    /// An implicit shape.
+    Shape(Shape<'a>),
+
+    /// The mesh of a node.
+    Mesh {
+        /// The node whose mesh is used as the geometry.
+        node: Node<'a>,
+
+        /// Whether the convex hull of the mesh is used instead of the mesh
+        /// itself.
+        convex_hull: bool,
+    },
+}
+
+/// A solid collider.
+#[derive(Clone, Debug)]
+pub struct Collider<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::Collider,
+}
+
+/// A trigger volume that reports overlaps without a collision response.
+#[derive(Clone, Debug)]
+pub struct Trigger<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::Trigger,
+}
+
+/// Constrains a node relative to another node.
+#[derive(Clone, Debug)]
+pub struct NodeJoint<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::NodeJoint,
+}
+
+/// Surface properties of a collider.
+#[derive(Clone, Debug)]
+pub struct Material<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::Material,
+}
+
+/// Restricts which colliders may collide with each other.
+#[derive(Clone, Debug)]
+pub struct CollisionFilter<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::CollisionFilter,
+}
+
+/// The degrees of freedom of a joint.
+#[derive(Clone, Debug)]
+pub struct Joint<'a> {
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::scene::khr_physics_rigid_bodies::Joint,
+}
+
+/// An `Iterator` that visits the nodes forming a compound trigger.
+#[derive(Clone, Debug)]
+pub struct Nodes<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal node index iterator.
+    iter: slice::Iter<'a, json::Index<json::Node>>,
+}
+
+impl<'a> Physics<'a> {
+    /// Constructs a `Physics`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::khr_physics_rigid_bodies::KhrPhysicsRigidBodies,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// Returns the motion properties if the node is a rigid body.
+    ///
+    /// Colliders of descendant nodes without motion properties of their own
+    /// belong to the closest ancestor rigid body. Colliders without a rigid body
+    /// ancestor are static.
+    pub fn motion(&self) -> Option<Motion<'a>> {
+        self.json.motion.as_ref().map(|json| Motion { json })
+    }
+
+    /// Returns the collider attached to the node.
+    pub fn collider(&self) -> Option<Collider<'a>> {
+        let document = self.document;
+        self.json
+            .collider
+            .as_ref()
+            .map(|json| Collider { document, json })
+    }
+
+    /// Returns the trigger volume attached to the node.
+    pub fn trigger(&self) -> Option<Trigger<'a>> {
+        let document = self.document;
+        self.json
+            .trigger
+            .as_ref()
+            .map(|json| Trigger { document, json })
+    }
+
+    /// Returns the joint constraining the node.
+    pub fn joint(&self) -> Option<NodeJoint<'a>> {
+        let document = self.document;
+        self.json
+            .joint
+            .as_ref()
+            .map(|json| NodeJoint { document, json })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Motion<'a> {
+    /// Whether the body is moved by animation rather than by the simulation.
+    pub fn is_kinematic(&self) -> bool {
+        self.json.is_kinematic
+    }
+
+    /// The mass of the body, or `None` if it should be computed from the
+    /// colliders.
+    pub fn mass(&self) -> Option<f32> {
+        self.json.mass
+    }
+
+    /// The center of mass relative to the node origin.
+    pub fn center_of_mass(&self) -> [f32; 3] {
+        self.json.center_of_mass
+    }
+
+    /// The principal moments of inertia, or `None` if they should be computed
+    /// from the colliders.
+    pub fn inertia_diagonal(&self) -> Option<[f32; 3]> {
+        self.json.inertia_diagonal
+    }
+
+    /// The orientation of the inertia tensor as a unit quaternion `[x, y, z, w]`.
+    pub fn inertia_orientation(&self) -> Option<[f32; 4]> {
+        self.json.inertia_orientation
+    }
+
+    /// The initial linear velocity in meters per second.
+    pub fn linear_velocity(&self) -> [f32; 3] {
+        self.json.linear_velocity
+    }
+
+    /// The initial angular velocity in radians per second.
+    pub fn angular_velocity(&self) -> [f32; 3] {
+        self.json.angular_velocity
+    }
+
+    /// Multiplier applied to the acceleration due to gravity.
+    pub fn gravity_factor(&self) -> f32 {
+        self.json.gravity_factor
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Geometry<'a> {
+    /// Constructs a `Geometry`.
+    fn new(
+        document: &'a Document,
+        json: &'a json::extensions::scene::khr_physics_rigid_bodies::Geometry,
+    ) -> Self {
+        // NOTE: cannot panic if validated
+        match json.shape {
+            Some(index) => {
+                Geometry::Shape(document.implicit_shapes().unwrap().nth(index.value()).unwrap())
+            }
+            None => Geometry::Mesh {
+                node: document.nodes().nth(json.node.unwrap().value()).unwrap(),
+                convex_hull: json.convex_hull,
+            },
+        }
+    }
+}
+
+impl<'a> Collider<'a> {
+    /// Returns the geometry of the collider.
+    pub fn geometry(&self) -> Geometry<'a> {
+        Geometry::new(self.document, &self.json.geometry)
+    }
+
+    /// Returns the physics material of the collider, or `None` if the default
+    /// material should be used.
+    pub fn material(&self) -> Option<Material<'a>> {
+        self.json
+            .physics_material
+            .map(|index| self.document.physics_materials().unwrap().nth(index.value()).unwrap())
+    }
+
+    /// Returns the collision filter of the collider.
+    pub fn collision_filter(&self) -> Option<CollisionFilter<'a>> {
+        self.json
+            .collision_filter
+            .map(|index| self.document.collision_filters().unwrap().nth(index.value()).unwrap())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Trigger<'a> {
+    /// Returns the geometry of a simple trigger, or `None` for a compound
+    /// trigger.
+    pub fn geometry(&self) -> Option<Geometry<'a>> {
+        self.json
+            .geometry
+            .as_ref()
+            .map(|json| Geometry::new(self.document, json))
+    }
+
+    /// Returns an `Iterator` that visits the nodes forming a compound trigger.
+    pub fn nodes(&self) -> Nodes<'a> {
+        Nodes {
+            document: self.document,
+            iter: self.json.nodes.iter(),
+        }
+    }
+
+    /// Returns the collision filter of the trigger.
+    pub fn collision_filter(&self) -> Option<CollisionFilter<'a>> {
+        self.json
+            .collision_filter
+            .map(|index| self.document.collision_filters().unwrap().nth(index.value()).unwrap())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> NodeJoint<'a> {
+    /// Returns the node the joint connects to.
+    pub fn connected_node(&self) -> Node<'a> {
+        self.document
+            .nodes()
+            .nth(self.json.connected_node.value())
+            .unwrap()
+    }
+
+    /// Returns the joint description.
+    pub fn joint(&self) -> Joint<'a> {
+        self.document
+            .physics_joints()
+            .unwrap()
+            .nth(self.json.joint.value())
+            .unwrap()
+    }
+
+    /// Whether the connected bodies collide with each other.
+    pub fn enable_collision(&self) -> bool {
+        self.json.enable_collision
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Material<'a> {
+    /// Constructs a `Material`.
+    pub(crate) fn new(
+        index: usize,
+        json: &'a json::extensions::scene::khr_physics_rigid_bodies::Material,
+    ) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The friction coefficient when the surfaces are at rest.
+    pub fn static_friction(&self) -> f32 {
+        self.json.static_friction
+    }
+
+    /// The friction coefficient when the surfaces are sliding.
+    pub fn dynamic_friction(&self) -> f32 {
+        self.json.dynamic_friction
+    }
+
+    /// The coefficient of restitution.
+    pub fn restitution(&self) -> f32 {
+        self.json.restitution
+    }
+
+    /// How the friction coefficients of two colliders are combined.
+    pub fn friction_combine(&self) -> Option<CombineMode> {
+        self.json.friction_combine.map(|mode| mode.unwrap())
+    }
+
+    /// How the restitution coefficients of two colliders are combined.
+    pub fn restitution_combine(&self) -> Option<CombineMode> {
+        self.json.restitution_combine.map(|mode| mode.unwrap())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> CollisionFilter<'a> {
+    /// Constructs a `CollisionFilter`.
+    pub(crate) fn new(
+        index: usize,
+        json: &'a json::extensions::scene::khr_physics_rigid_bodies::CollisionFilter,
+    ) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The collision systems the collider belongs to.
+    pub fn collision_systems(&self) -> &'a [String] {
+        &self.json.collision_systems
+    }
+
+    /// The only collision systems the collider collides with, if not empty.
+    pub fn collide_with_systems(&self) -> &'a [String] {
+        &self.json.collide_with_systems
+    }
+
+    /// The collision systems the collider does not collide with.
+    pub fn not_collide_with_systems(&self) -> &'a [String] {
+        &self.json.not_collide_with_systems
+    }
+
+    /// Returns `true` if colliders using the two filters may collide.
+    ///
+    /// Both filters must allow the collision.
+    pub fn collides_with(&self, other: &CollisionFilter) -> bool {
+        self.allows(other) && other.allows(self)
+    }
+
+    /// Returns `true` if this filter allows collisions with the systems of
+    /// `other`.
+    fn allows(&self, other: &CollisionFilter) -> bool {
+        let mut systems = other.collision_systems().iter();
+        if self.collide_with_systems().is_empty() {
+            !systems.any(|system| self.not_collide_with_systems().contains(system))
+        } else {
+            systems.any(|system| self.collide_with_systems().contains(system))
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Joint<'a> {
+    /// Constructs a `Joint`.
+    pub(crate) fn new(
+        index: usize,
+        json: &'a json::extensions::scene::khr_physics_rigid_bodies::Joint,
+    ) -> Self {
+        Self { index, json }
+    }
+
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The limits restricting the motion of the connected bodies.
+    pub fn limits(&self) -> &'a [JointLimit] {
+        &self.json.limits
+    }
+
+    /// The drives applying forces to the connected bodies.
+    pub fn drives(&self) -> &'a [JointDrive] {
+        &self.json.drives
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ExactSizeIterator for Nodes<'a> {}
+impl<'a> Iterator for Nodes<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|index| self.document.nodes().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -100,9 +100,48 @@ pub mod binary;
 /// Buffers and buffer views.
 pub mod buffer;
 
+/// Builders for authoring glTF assets.
+pub mod build;
+
 /// Cameras and their projections.
 pub mod camera;
 
+/// The mutable document wrapper.
+mod document_mut;
+
+/// Exporting glTF assets.
+pub mod export;
+
+/// Support for the `EXT_lights_ies` extension.
+#[cfg(feature = "EXT_lights_ies")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_ies")))]
+pub mod ext_lights_ies;
+
+/// Support for the `EXT_mesh_features` extension.
+#[cfg(feature = "EXT_mesh_features")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+pub mod ext_mesh_features;
+
+/// Support for the `EXT_mesh_gpu_instancing` extension.
+#[cfg(feature = "EXT_mesh_gpu_instancing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+pub mod ext_mesh_gpu_instancing;
+
+/// Support for the `EXT_structural_metadata` extension.
+#[cfg(feature = "EXT_structural_metadata")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+pub mod ext_structural_metadata;
+
+/// Fetching of resources in the browser on import.
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "wasm", target_arch = "wasm32"))))]
+mod fetch;
+
+/// Fetching of remote resources on import.
+#[cfg(feature = "http")]
+#[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+mod http;
+
 /// Images that may be used by textures.
 pub mod image;
 
@@ -114,11 +153,41 @@ mod import;
 /// Iterators for walking the glTF node hierarchy.
 pub mod iter;
 
+/// Support for the `KHR_animation_pointer` extension.
+#[cfg(feature = "KHR_animation_pointer")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_animation_pointer")))]
+pub mod khr_animation_pointer;
+
+/// Support for the `KHR_audio` extension.
+#[cfg(feature = "KHR_audio")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+pub mod khr_audio;
+
+/// Support for the `KHR_implicit_shapes` extension.
+#[cfg(feature = "KHR_implicit_shapes")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_implicit_shapes")))]
+pub mod khr_implicit_shapes;
+
+/// Support for the `KHR_interactivity` extension.
+#[cfg(feature = "KHR_interactivity")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_interactivity")))]
+pub mod khr_interactivity;
+
 /// Support for the `KHR_lights_punctual` extension.
 #[cfg(feature = "KHR_lights_punctual")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
 pub mod khr_lights_punctual;
 
+/// Support for the `KHR_physics_rigid_bodies` extension.
+#[cfg(feature = "KHR_physics_rigid_bodies")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_physics_rigid_bodies")))]
+pub mod khr_physics_rigid_bodies;
+
+/// Support for the `KHR_materials_variants` extension.
+#[cfg(feature = "KHR_materials_variants")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+pub mod khr_materials_variants;
+
 /// Material properties of primitives.
 pub mod material;
 
@@ -134,9 +203,21 @@ pub mod scene;
 /// Mesh skinning primitives.
 pub mod skin;
 
+/// Summary statistics of a document.
+pub mod statistics;
+
 /// Textures and their samplers.
 pub mod texture;
 
+/// Reverse reference queries between the objects of a document.
+pub mod users;
+
+/// Support for the `VRMC_vrm`, `VRMC_springBone`, and `VRMC_materials_mtoon`
+/// extensions of VRM 1.0 humanoid avatars.
+#[cfg(feature = "VRM")]
+#[cfg_attr(docsrs, doc(cfg(feature = "VRM")))]
+pub mod vrm;
+
 #[doc(inline)]
 pub use self::animation::Animation;
 #[doc(inline)]
@@ -148,6 +229,14 @@ pub use self::buffer::Buffer;
 #[doc(inline)]
 pub use self::camera::Camera;
 #[doc(inline)]
+pub use self::document_mut::DocumentMut;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+#[doc(inline)]
+pub use self::fetch::FetchResolver;
+#[cfg(feature = "http")]
+#[doc(inline)]
+pub use self::http::HttpResolver;
+#[doc(inline)]
 pub use self::image::Image;
 #[cfg(feature = "import")]
 #[doc(inline)]
@@ -155,6 +244,30 @@ pub use self::import::import;
 #[cfg(feature = "import")]
 #[doc(inline)]
 pub use self::import::import_slice;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_lazy, LazyBuffers};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_cached, Cache};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{
+    import_slice_with_options, import_with_options, CancellationToken, ImageMode, ImportOptions, Limit, Limits,
+    Progress,
+};
+#[cfg(feature = "mmap")]
+#[doc(inline)]
+pub use self::import::import_mapped;
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{
+    import_async, import_async_with_options, import_slice_async, import_slice_async_with_options, AsyncResolver,
+    ResolveFuture,
+};
+#[cfg(feature = "import")]
+#[doc(inline)]
+pub use self::import::{import_slice_with_resolver, import_with_resolver, Resolver};
 #[doc(inline)]
 pub use self::material::Material;
 #[doc(inline)]
@@ -187,9 +300,10 @@ pub enum Error {
     /// GLB parsing error.
     Binary(binary::Error),
 
+    /// Error building glTF objects.
+    Build(build::Error),
+
     /// Buffer length does not match expected length.
-    #[cfg(feature = "import")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     BufferLength {
         /// The index of the offending buffer.
         buffer: usize,
@@ -204,6 +318,16 @@ pub enum Error {
     /// JSON deserialization error.
     Deserialize(json::Error),
 
+    /// Error fetching a resource in the browser.
+    #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "wasm", target_arch = "wasm32"))))]
+    Fetch(String),
+
+    /// HTTP request error.
+    #[cfg(feature = "http")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http")))]
+    Http(Box<ureq::Error>),
+
     /// Standard I/O error.
     Io(std::io::Error),
 
@@ -212,6 +336,16 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     Image(image_crate::ImageError),
     
+    /// The import was cancelled with a `CancellationToken`.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    Cancelled,
+
+    /// A limit on the resources of imported glTF was exceeded.
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    LimitExceeded(import::Limit),
+
     /// The `BIN` chunk of binary glTF is referenced but does not exist.
     #[cfg(feature = "import")]
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
@@ -222,9 +356,13 @@ pub enum Error {
     #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     ExternalReferenceInSliceImport,
 
+    /// The data of a buffer was not provided for export.
+    MissingBufferData(usize),
+
+    /// JSON serialization error.
+    Serialize(json::Error),
+
     /// Unsupported image encoding.
-    #[cfg(feature = "import")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
     UnsupportedImageEncoding,
 
     /// Unsupported URI scheme.
@@ -294,6 +432,32 @@ impl Gltf {
         Ok(gltf)
     }
 
+    /// Loads glTF from an asynchronous reader.
+    ///
+    /// The whole stream is read before it is parsed. Readers of `tokio` may
+    /// be adapted with `tokio_util::compat`.
+    #[cfg(feature = "futures-io")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+    pub async fn from_reader_async<R>(mut reader: R) -> Result<Self>
+    where
+        R: futures_io::AsyncRead + Unpin,
+    {
+        use std::pin::Pin;
+
+        let mut data = Vec::new();
+        let mut chunk = [0u8; 8192];
+        loop {
+            let read = std::future::poll_fn(|context| Pin::new(&mut reader).poll_read(context, &mut chunk));
+            match read.await {
+                Ok(0) => break,
+                Ok(n) => data.extend_from_slice(&chunk[..n]),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => return Err(Error::Io(error)),
+            }
+        }
+        Self::from_slice(&data)
+    }
+
     /// Loads glTF from a slice of bytes without performing validation
     /// checks.
     pub fn from_slice_without_validation(slice: &[u8]) -> Result<Self> {
@@ -443,6 +607,159 @@ impl Document {
         }
     }
 
+    /// Returns an `Iterator` that visits the audio data of the glTF asset as defined
+    /// by the `KHR_audio` extension.
+    #[cfg(feature = "KHR_audio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+    pub fn audio(&self) -> Option<iter::AudioData<'_>> {
+        let khr_audio = self.0.extensions.as_ref()?.khr_audio.as_ref()?;
+        Some(iter::AudioData {
+            iter: khr_audio.audio.iter().enumerate(),
+            document: self,
+        })
+    }
+
+    /// Returns an `Iterator` that visits the audio sources of the glTF asset as
+    /// defined by the `KHR_audio` extension.
+    #[cfg(feature = "KHR_audio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+    pub fn audio_sources(&self) -> Option<iter::AudioSources<'_>> {
+        let khr_audio = self.0.extensions.as_ref()?.khr_audio.as_ref()?;
+        Some(iter::AudioSources {
+            iter: khr_audio.sources.iter().enumerate(),
+            document: self,
+        })
+    }
+
+    /// Returns an `Iterator` that visits the audio emitters of the glTF asset as
+    /// defined by the `KHR_audio` extension.
+    #[cfg(feature = "KHR_audio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+    pub fn audio_emitters(&self) -> Option<iter::AudioEmitters<'_>> {
+        let khr_audio = self.0.extensions.as_ref()?.khr_audio.as_ref()?;
+        Some(iter::AudioEmitters {
+            iter: khr_audio.emitters.iter().enumerate(),
+            document: self,
+        })
+    }
+
+    /// Returns an `Iterator` that visits the collision filters of the glTF asset
+    /// as defined by the `KHR_physics_rigid_bodies` extension.
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_physics_rigid_bodies")))]
+    pub fn collision_filters(&self) -> Option<iter::CollisionFilters<'_>> {
+        let khr_physics_rigid_bodies = self.0.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?;
+        Some(iter::CollisionFilters {
+            iter: khr_physics_rigid_bodies.collision_filters.iter().enumerate(),
+        })
+    }
+
+    /// Returns an `Iterator` that visits the implicit shapes of the glTF asset
+    /// as defined by the `KHR_implicit_shapes` extension.
+    #[cfg(feature = "KHR_implicit_shapes")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_implicit_shapes")))]
+    pub fn implicit_shapes(&self) -> Option<iter::Shapes<'_>> {
+        let khr_implicit_shapes = self.0.extensions.as_ref()?.khr_implicit_shapes.as_ref()?;
+        Some(iter::Shapes {
+            iter: khr_implicit_shapes.shapes.iter().enumerate(),
+        })
+    }
+
+    /// Returns the behavior graphs of the glTF asset as defined by the
+    /// `KHR_interactivity` extension.
+    #[cfg(feature = "KHR_interactivity")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_interactivity")))]
+    pub fn interactivity(&self) -> Option<khr_interactivity::Interactivity<'_>> {
+        let khr_interactivity = self.0.extensions.as_ref()?.khr_interactivity.as_ref()?;
+        Some(khr_interactivity::Interactivity::new(self, khr_interactivity))
+    }
+
+    /// Returns an `Iterator` that visits the IES light profiles of the glTF asset as
+    /// defined by the `EXT_lights_ies` extension.
+    #[cfg(feature = "EXT_lights_ies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_ies")))]
+    pub fn light_profiles(&self) -> Option<iter::LightProfiles<'_>> {
+        let extensions = self.0.extensions.as_ref()?;
+        let ext_lights_ies = extensions.ext_lights_ies.as_ref()?;
+        Some(iter::LightProfiles {
+            iter: ext_lights_ies.lights.iter().enumerate(),
+            document: self,
+        })
+    }
+
+    /// Returns an `Iterator` that visits the physics joints of the glTF asset as
+    /// defined by the `KHR_physics_rigid_bodies` extension.
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_physics_rigid_bodies")))]
+    pub fn physics_joints(&self) -> Option<iter::PhysicsJoints<'_>> {
+        let khr_physics_rigid_bodies = self.0.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?;
+        Some(iter::PhysicsJoints {
+            iter: khr_physics_rigid_bodies.physics_joints.iter().enumerate(),
+        })
+    }
+
+    /// Returns an `Iterator` that visits the physics materials of the glTF asset
+    /// as defined by the `KHR_physics_rigid_bodies` extension.
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_physics_rigid_bodies")))]
+    pub fn physics_materials(&self) -> Option<iter::PhysicsMaterials<'_>> {
+        let khr_physics_rigid_bodies = self.0.extensions.as_ref()?.khr_physics_rigid_bodies.as_ref()?;
+        Some(iter::PhysicsMaterials {
+            iter: khr_physics_rigid_bodies.physics_materials.iter().enumerate(),
+        })
+    }
+
+    /// Returns the relative-to-center origin of the glTF asset as defined by the
+    /// `CESIUM_RTC` extension.
+    ///
+    /// All node positions are relative to this center, which is typically given
+    /// in Earth-centered, Earth-fixed coordinates.
+    #[cfg(feature = "CESIUM_RTC")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "CESIUM_RTC")))]
+    pub fn rtc_center(&self) -> Option<[f64; 3]> {
+        Some(self.0.extensions.as_ref()?.cesium_rtc.as_ref()?.center)
+    }
+
+    /// Returns the spring bone simulation of the glTF asset as defined by the
+    /// `VRMC_springBone` extension.
+    #[cfg(feature = "VRM")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "VRM")))]
+    pub fn spring_bone(&self) -> Option<vrm::SpringBone<'_>> {
+        let vrmc_spring_bone = self.0.extensions.as_ref()?.vrmc_spring_bone.as_ref()?;
+        Some(vrm::SpringBone::new(self, vrmc_spring_bone))
+    }
+
+    /// Returns the structured metadata of the glTF asset as defined by the
+    /// `EXT_structural_metadata` extension.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn structural_metadata(&self) -> Option<ext_structural_metadata::Metadata<'_>> {
+        let ext_structural_metadata = self.0.extensions.as_ref()?.ext_structural_metadata.as_ref()?;
+        Some(ext_structural_metadata::Metadata::new(self, ext_structural_metadata))
+    }
+
+    /// Returns an `Iterator` that visits the material variants of the glTF asset as
+    /// defined by the `KHR_materials_variants` extension.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn variants(&self) -> Option<iter::Variants<'_>> {
+        let extensions = self.0.extensions.as_ref()?;
+        let khr_materials_variants = extensions.khr_materials_variants.as_ref()?;
+        Some(iter::Variants {
+            iter: khr_materials_variants.variants.iter().enumerate(),
+            document: self,
+        })
+    }
+
+    /// Returns the humanoid avatar description of the glTF asset as defined by
+    /// the `VRMC_vrm` extension.
+    #[cfg(feature = "VRM")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "VRM")))]
+    pub fn vrm(&self) -> Option<vrm::Vrm<'_>> {
+        let vrmc_vrm = self.0.extensions.as_ref()?.vrmc_vrm.as_ref()?;
+        Some(vrm::Vrm::new(self, vrmc_vrm))
+    }
+
     /// Returns an `Iterator` that visits the materials of the glTF asset.
     pub fn materials(&self) -> iter::Materials {
         iter::Materials {
@@ -467,6 +784,125 @@ impl Document {
         }
     }
 
+    /// Builds an index of the parent of every node, since nodes only
+    /// reference their children.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// let parents = document.build_parent_index();
+    /// for node in document.nodes() {
+    ///     let depth = parents.ancestors(&node).count();
+    ///     println!("node {} at depth {}", node.index(), depth);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn build_parent_index(&self) -> scene::ParentIndex<'_> {
+        scene::ParentIndex::new(self)
+    }
+
+    /// Builds an index of the nodes by name, for looking up many nodes by
+    /// name.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn build_name_index(&self) -> scene::NameIndex<'_> {
+        scene::NameIndex::new(self)
+    }
+
+    /// Returns the first node with the given name.
+    ///
+    /// This visits the nodes of the document. Use [`build_name_index`] to
+    /// look up many nodes.
+    ///
+    /// [`build_name_index`]: #method.build_name_index
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn node_by_name(&self, name: &str) -> Option<Node<'_>> {
+        self.nodes().find(|node| node.name() == Some(name))
+    }
+
+    /// Returns the node hierarchy as a directed graph with edges from parents
+    /// to their children.
+    ///
+    /// Every node of the document is in the graph, including nodes outside
+    /// of any scene, and the index of each graph node is that of the glTF
+    /// node, which is also its weight.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// let graph = document.node_graph();
+    /// for index in petgraph::algo::toposort(&graph, None).unwrap() {
+    ///     println!("node {}", graph[index]);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    pub fn node_graph(&self) -> petgraph::graph::DiGraph<usize, ()> {
+        use petgraph::graph::{DiGraph, NodeIndex};
+        let count = self.0.nodes.len();
+        let mut graph = DiGraph::with_capacity(count, 0);
+        for index in 0..count {
+            graph.add_node(index);
+        }
+        for (index, node) in self.0.nodes.iter().enumerate() {
+            for child in node.children.iter().flatten().filter(|child| child.value() < count) {
+                graph.add_edge(NodeIndex::new(index), NodeIndex::new(child.value()), ());
+            }
+        }
+        graph
+    }
+
+    /// Returns the objects referencing a resource, such as the primitives
+    /// drawn with a material or the materials using a texture.
+    ///
+    /// The resource may be given as a reference to an [`Accessor`],
+    /// [`Material`], [`Mesh`], or [`Texture`]. Only references made by the
+    /// core glTF specification and the supported material and instancing
+    /// extensions are considered.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// use gltf::users::User;
+    ///
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// for accessor in document.accessors() {
+    ///     for user in document.users_of(&accessor) {
+    ///         if let User::Primitive(primitive) = user {
+    ///             println!("accessor {} is read by primitive {}", accessor.index(), primitive.index());
+    ///         }
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn users_of<R: Into<users::Resource>>(&self, resource: R) -> Vec<users::User<'_>> {
+        users::users_of(self, resource.into())
+    }
+
+    /// Returns a summary of the document, such as the number of nodes and
+    /// triangles, the sizes of its buffers, and the extensions it uses.
+    ///
+    /// The resolutions of images are only known once they are imported, see
+    /// [`Statistics::with_image_data`].
+    ///
+    /// [`Statistics::with_image_data`]: statistics/struct.Statistics.html#method.with_image_data
+    pub fn statistics(&self) -> statistics::Statistics {
+        statistics::Statistics::new(self)
+    }
+
     /// Returns an `Iterator` that visits the samplers of the glTF asset.
     pub fn samplers(&self) -> iter::Samplers {
         iter::Samplers {
@@ -515,7 +951,7 @@ impl std::fmt::Display for Error {
             #[cfg(feature = "import")]
             Error::Base64(ref e) => e.fmt(f),
             Error::Binary(ref e) => e.fmt(f),
-            #[cfg(feature = "import")]
+            Error::Build(ref e) => e.fmt(f),
             Error::BufferLength { buffer, expected, actual } => {
                 write!(
                     f,
@@ -526,14 +962,23 @@ impl std::fmt::Display for Error {
                 )
             },
             Error::Deserialize(ref e) => e.fmt(f),
+            #[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+            Error::Fetch(ref message) => write!(f, "fetch failed: {}", message),
+            #[cfg(feature = "http")]
+            Error::Http(ref e) => e.fmt(f),
             Error::Io(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
             Error::Image(ref e) => e.fmt(f),
             #[cfg(feature = "import")]
+            Error::Cancelled => write!(f, "import cancelled"),
+            #[cfg(feature = "import")]
+            Error::LimitExceeded(limit) => write!(f, "import limit exceeded: {}", limit),
+            #[cfg(feature = "import")]
             Error::MissingBlob => write!(f, "missing binary portion of binary glTF"),
             #[cfg(feature = "import")]
             Error::ExternalReferenceInSliceImport => write!(f, "external reference in slice only import"),
-            #[cfg(feature = "import")]
+            Error::MissingBufferData(buffer) => write!(f, "missing data of buffer {}", buffer),
+            Error::Serialize(ref e) => e.fmt(f),
             Error::UnsupportedImageEncoding => write!(f, "unsupported image encoding"),
             #[cfg(feature = "import")]
             Error::UnsupportedScheme => write!(f, "unsupported URI scheme"),
@@ -556,6 +1001,12 @@ impl From<binary::Error> for Error {
     }
 }
 
+impl From<build::Error> for Error {
+    fn from(err: build::Error) -> Self {
+        Error::Build(err)
+    }
+}
+
 impl From<std::io::Error> for Error {
     fn from(err: std::io::Error) -> Self {
         Error::Io(err)
@@ -598,7 +1049,7 @@ impl Normalize<u16> for i8 {
 }
 
 impl Normalize<f32> for i8 {
-    fn normalize(self) -> f32 { (self as f32 * 127.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { (self as f32 / 127.0).max(-1.0) }
 }
 
 impl Normalize<i8> for u8 {
@@ -618,7 +1069,7 @@ impl Normalize<u16> for u8 {
 }
 
 impl Normalize<f32> for u8 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { self as f32 / 255.0 }
 }
 
 impl Normalize<i8> for i16 {
@@ -638,7 +1089,7 @@ impl Normalize<u16> for i16 {
 }
 
 impl Normalize<f32> for i16 {
-    fn normalize(self) -> f32 { (self as f32 * 32767.0_f32.recip()).max(-1.0) }
+    fn normalize(self) -> f32 { (self as f32 / 32767.0).max(-1.0) }
 }
 
 impl Normalize<i8> for u16 {
@@ -658,7 +1109,7 @@ impl Normalize<u16> for u16 {
 }
 
 impl Normalize<f32> for u16 {
-    fn normalize(self) -> f32 { self as f32 * 65535.0_f32.recip() }
+    fn normalize(self) -> f32 { self as f32 / 65535.0 }
 }
 
 impl Normalize<i8> for f32 {
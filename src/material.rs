@@ -145,6 +145,67 @@ impl<'a> Material<'a> {
             .map(|x| Specular::new(self.document, x))
     }
 
+    /// Parameter values that define a sheen layer for cloth-like materials.
+    #[cfg(feature = "KHR_materials_sheen")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+    pub fn sheen(&self) -> Option<Sheen<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .sheen.as_ref()
+            .map(|x| Sheen::new(self.document, x))
+    }
+
+    /// Parameter values that define a clear coating layer on top of the material.
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+    pub fn clearcoat(&self) -> Option<Clearcoat<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .clearcoat.as_ref()
+            .map(|x| Clearcoat::new(self.document, x))
+    }
+
+    /// Parameter values that define a thin-film iridescence layer on top of the material.
+    #[cfg(feature = "KHR_materials_iridescence")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+    pub fn iridescence(&self) -> Option<Iridescence<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .iridescence.as_ref()
+            .map(|x| Iridescence::new(self.document, x))
+    }
+
+    /// Parameter values that define the anisotropic specular reflection of the material.
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+    pub fn anisotropy(&self) -> Option<Anisotropy<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .anisotropy.as_ref()
+            .map(|x| Anisotropy::new(self.document, x))
+    }
+
+    /// Parameter values that define the diffuse transmission of light through thin surfaces.
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+    pub fn diffuse_transmission(&self) -> Option<DiffuseTransmission<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .diffuse_transmission.as_ref()
+            .map(|x| DiffuseTransmission::new(self.document, x))
+    }
+
+    /// Parameter values that define the MToon toon shading model of VRM
+    /// avatars.
+    #[cfg(feature = "VRM")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "VRM")))]
+    pub fn mtoon(&self) -> Option<crate::vrm::MToon<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .mtoon.as_ref()
+            .map(|x| crate::vrm::MToon::new(self.document, x))
+    }
+
     /// A tangent space normal map.
     ///
     /// The texture contains RGB components in linear space. Each texel represents
@@ -199,6 +260,19 @@ impl<'a> Material<'a> {
         self.json.emissive_factor.0
     }
 
+    /// The strength multiplier applied to the emissive color of the material.
+    ///
+    /// Returns `None` when the extension is absent, in which case the emissive
+    /// color is used as is. The default value is `1.0`.
+    #[cfg(feature = "KHR_materials_emissive_strength")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_emissive_strength")))]
+    pub fn emissive_strength(&self) -> Option<f32> {
+        self.json.extensions
+            .as_ref()?
+            .emissive_strength.as_ref()
+            .map(|x| x.emissive_strength.0)
+    }
+
     /// Specifies whether the material is unlit.
     /// 
     /// Returns `true` if the [`KHR_materials_unlit`] property was specified, in which 
@@ -308,7 +382,7 @@ pub struct Transmission<'a> {
 #[cfg(feature = "KHR_materials_transmission")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_transmission")))]
 impl<'a> Transmission<'a> {
-    /// Constructs `Ior`.
+    /// Constructs `Transmission`.
     pub(crate) fn new(
         document: &'a Document,
         json: &'a json::extensions::material::Transmission,
@@ -418,7 +492,7 @@ pub struct Specular<'a> {
 #[cfg(feature = "KHR_materials_specular")]
 #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_specular")))]
 impl<'a> Specular<'a> {
-    /// Constructs `Volume`.
+    /// Constructs `Specular`.
     pub(crate) fn new(
         document: &'a Document,
         json: &'a json::extensions::material::Specular,
@@ -459,6 +533,343 @@ impl<'a> Specular<'a> {
         })
     }
 
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define a sheen layer for cloth-like materials.
+#[cfg(feature = "KHR_materials_sheen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+pub struct Sheen<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Sheen,
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_sheen")))]
+impl<'a> Sheen<'a> {
+    /// Constructs `Sheen`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Sheen,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// The sheen color in linear space.
+    ///
+    /// The default value is `[0.0, 0.0, 0.0]`, which disables the sheen layer.
+    pub fn sheen_color_factor(&self) -> [f32; 3] {
+        self.json.sheen_color_factor.0
+    }
+
+    /// The sheen color (RGB) texture, stored in the `RGB` channels and
+    /// encoded in sRGB. This will be multiplied by `sheen_color_factor`.
+    pub fn sheen_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The sheen roughness.
+    ///
+    /// The default value is `0.0`.
+    pub fn sheen_roughness_factor(&self) -> f32 {
+        self.json.sheen_roughness_factor.0
+    }
+
+    /// The sheen roughness texture, stored in the alpha (`A`) channel.
+    /// This will be multiplied by `sheen_roughness_factor`.
+    pub fn sheen_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.sheen_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define a clear coating layer on top of the material.
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+pub struct Clearcoat<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Clearcoat,
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_clearcoat")))]
+impl<'a> Clearcoat<'a> {
+    /// Constructs `Clearcoat`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Clearcoat,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// The clearcoat layer intensity.
+    ///
+    /// The default value is `0.0`, which disables the clearcoat layer.
+    pub fn clearcoat_factor(&self) -> f32 {
+        self.json.clearcoat_factor.0
+    }
+
+    /// The clearcoat layer intensity texture, stored in the `R` channel.
+    /// This will be multiplied by `clearcoat_factor`.
+    pub fn clearcoat_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The clearcoat layer roughness.
+    ///
+    /// The default value is `0.0`.
+    pub fn clearcoat_roughness_factor(&self) -> f32 {
+        self.json.clearcoat_roughness_factor.0
+    }
+
+    /// The clearcoat layer roughness texture, stored in the `G` channel.
+    /// This will be multiplied by `clearcoat_roughness_factor`.
+    pub fn clearcoat_roughness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.clearcoat_roughness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The clearcoat normal map texture.
+    pub fn clearcoat_normal_texture(&self) -> Option<NormalTexture<'a>> {
+        self.json.clearcoat_normal_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            NormalTexture::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define a thin-film iridescence layer on top of the material.
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+pub struct Iridescence<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Iridescence,
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_iridescence")))]
+impl<'a> Iridescence<'a> {
+    /// Constructs `Iridescence`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Iridescence,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// The iridescence intensity factor.
+    ///
+    /// The default value is `0.0`, which disables the iridescence layer.
+    pub fn iridescence_factor(&self) -> f32 {
+        self.json.iridescence_factor.0
+    }
+
+    /// The iridescence intensity texture, stored in the `R` channel.
+    /// This will be multiplied by `iridescence_factor`.
+    pub fn iridescence_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.iridescence_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The index of refraction of the dielectric thin-film layer.
+    ///
+    /// The default value is `1.3`.
+    pub fn iridescence_ior(&self) -> f32 {
+        self.json.iridescence_ior.0
+    }
+
+    /// The minimum thickness of the thin-film layer given in nanometers.
+    ///
+    /// The default value is `100.0`.
+    pub fn iridescence_thickness_minimum(&self) -> f32 {
+        self.json.iridescence_thickness_minimum.0
+    }
+
+    /// The maximum thickness of the thin-film layer given in nanometers.
+    ///
+    /// The default value is `400.0`.
+    pub fn iridescence_thickness_maximum(&self) -> f32 {
+        self.json.iridescence_thickness_maximum.0
+    }
+
+    /// The thickness texture of the thin-film layer, stored in the `G` channel.
+    /// This is used to interpolate between `iridescence_thickness_minimum`
+    /// and `iridescence_thickness_maximum`.
+    pub fn iridescence_thickness_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.iridescence_thickness_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define the anisotropic specular reflection of the material.
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+pub struct Anisotropy<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::Anisotropy,
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_anisotropy")))]
+impl<'a> Anisotropy<'a> {
+    /// Constructs `Anisotropy`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::Anisotropy,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// The anisotropy strength. When the anisotropy texture is present, this
+    /// value is multiplied by the texture's blue channel.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_strength(&self) -> f32 {
+        self.json.anisotropy_strength.0
+    }
+
+    /// The rotation of the anisotropy in tangent, bitangent space, measured in
+    /// radians counter-clockwise from the tangent. When the anisotropy texture
+    /// is present, this value provides additional rotation to the vectors in
+    /// the texture.
+    ///
+    /// The default value is `0.0`.
+    pub fn anisotropy_rotation(&self) -> f32 {
+        self.json.anisotropy_rotation.0
+    }
+
+    /// The anisotropy texture. The red and green channels contain the direction
+    /// in `[-1, 1]` tangent, bitangent space, to be rotated by
+    /// `anisotropy_rotation`. The blue channel contains strength as `[0, 1]`
+    /// to be multiplied by `anisotropy_strength`.
+    pub fn anisotropy_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.anisotropy_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+/// Parameter values that define the diffuse transmission of light through thin surfaces.
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+pub struct DiffuseTransmission<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::DiffuseTransmission,
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_diffuse_transmission")))]
+impl<'a> DiffuseTransmission<'a> {
+    /// Constructs `DiffuseTransmission`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::DiffuseTransmission,
+    ) -> Self {
+        Self {
+            document,
+            json,
+        }
+    }
+
+    /// The percentage of non-specularly reflected light that is transmitted
+    /// through the surface via the Lambertian diffuse transmission.
+    ///
+    /// The default value is `0.0`.
+    pub fn diffuse_transmission_factor(&self) -> f32 {
+        self.json.diffuse_transmission_factor.0
+    }
+
+    /// A texture that defines the percentage of light transmitted through the
+    /// surface, stored in the alpha (`A`) channel. This will be multiplied by
+    /// `diffuse_transmission_factor`.
+    pub fn diffuse_transmission_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.diffuse_transmission_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The color that modulates the transmitted light (linear RGB).
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn diffuse_transmission_color_factor(&self) -> [f32; 3] {
+        self.json.diffuse_transmission_color_factor.0
+    }
+
+    /// A texture that defines the color of the transmitted light, stored in
+    /// the `RGB` channels and encoded in sRGB. This will be multiplied by
+    /// `diffuse_transmission_color_factor`.
+    pub fn diffuse_transmission_color_texture(&self) -> Option<texture::Info<'a>> {
+        self.json.diffuse_transmission_color_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
 
     /// Optional application specific data.
     pub fn extras(&self) -> &'a json::Extras {
@@ -492,14 +903,21 @@ impl<'a> PbrSpecularGlossiness<'a> {
         }
     }
 
-    /// Returns the material's base color factor.
+    /// Returns the material's diffuse factor.
+    ///
+    /// The RGBA components of the reflected diffuse color of the material.
+    /// Metals have a diffuse value of `[0.0, 0.0, 0.0]`. The fourth component
+    /// (A) is the alpha coverage of the material. The values are linear.
     ///
     /// The default value is `[1.0, 1.0, 1.0, 1.0]`.
     pub fn diffuse_factor(&self) -> [f32; 4] {
         self.json.diffuse_factor.0
     }
 
-    /// Returns the base color texture.
+    /// Returns the diffuse texture.
+    ///
+    /// This texture contains RGB(A) components of the reflected diffuse color
+    /// of the material in sRGB color space.
     pub fn diffuse_texture(&self) -> Option<texture::Info<'a>> {
         self.json.diffuse_texture.as_ref().map(|json| {
             let texture = self.document.textures().nth(json.index.value()).unwrap();
@@ -509,6 +927,8 @@ impl<'a> PbrSpecularGlossiness<'a> {
 
     /// Returns the specular factor of the material.
     ///
+    /// The specular RGB color of the material. This value is linear.
+    ///
     /// The default value is `[1.0, 1.0, 1.0]`.
     pub fn specular_factor(&self) -> [f32; 3] {
         self.json.specular_factor.0
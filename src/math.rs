@@ -260,7 +260,6 @@ impl Matrix4 {
         }
     }
 
-    #[cfg(test)]
     pub fn from_array(m: [[f32; 4]; 4]) -> Matrix4 {
         Matrix4::new(
             m[0][0], m[0][1], m[0][2], m[0][3],
@@ -324,6 +323,55 @@ impl Matrix4 {
             self.w.as_array(),
         ]
     }
+
+    /// Returns the inverse of the matrix, or `None` if it is not invertible.
+    pub fn inverse(&self) -> Option<Matrix4> {
+        let [
+            [a00, a01, a02, a03],
+            [a10, a11, a12, a13],
+            [a20, a21, a22, a23],
+            [a30, a31, a32, a33],
+        ] = self.as_array();
+
+        let s0 = a00 * a11 - a10 * a01;
+        let s1 = a00 * a12 - a10 * a02;
+        let s2 = a00 * a13 - a10 * a03;
+        let s3 = a01 * a12 - a11 * a02;
+        let s4 = a01 * a13 - a11 * a03;
+        let s5 = a02 * a13 - a12 * a03;
+
+        let c0 = a20 * a31 - a30 * a21;
+        let c1 = a20 * a32 - a30 * a22;
+        let c2 = a20 * a33 - a30 * a23;
+        let c3 = a21 * a32 - a31 * a22;
+        let c4 = a21 * a33 - a31 * a23;
+        let c5 = a22 * a33 - a32 * a23;
+
+        let determinant = s0 * c5 - s1 * c4 + s2 * c3 + s3 * c2 - s4 * c1 + s5 * c0;
+        if determinant == 0.0 || !determinant.is_finite() {
+            return None;
+        }
+        let d = 1.0 / determinant;
+
+        Some(Matrix4::new(
+            (a11 * c5 - a12 * c4 + a13 * c3) * d,
+            (-a01 * c5 + a02 * c4 - a03 * c3) * d,
+            (a31 * s5 - a32 * s4 + a33 * s3) * d,
+            (-a21 * s5 + a22 * s4 - a23 * s3) * d,
+            (-a10 * c5 + a12 * c2 - a13 * c1) * d,
+            (a00 * c5 - a02 * c2 + a03 * c1) * d,
+            (-a30 * s5 + a32 * s2 - a33 * s1) * d,
+            (a20 * s5 - a22 * s2 + a23 * s1) * d,
+            (a10 * c4 - a11 * c2 + a13 * c0) * d,
+            (-a00 * c4 + a01 * c2 - a03 * c0) * d,
+            (a30 * s4 - a31 * s2 + a33 * s0) * d,
+            (-a20 * s4 + a21 * s2 - a23 * s0) * d,
+            (-a10 * c3 + a11 * c1 - a12 * c0) * d,
+            (a00 * c3 - a01 * c1 + a02 * c0) * d,
+            (-a30 * s3 + a31 * s1 - a32 * s0) * d,
+            (a20 * s3 - a21 * s1 + a22 * s0) * d,
+        ))
+    }
 }
 
 impl ops::Mul<Matrix4> for Matrix4 {
@@ -0,0 +1,407 @@
+//! The functions of this module work on plain vertex data, so that they may be
+//! used both on data read from a primitive and on data about to be exported
+//! with [`PrimitiveBuilder`]. Triangles are given as triangle list indices, as
+//! visited by `Reader::read_triangles`.
+//!
+//! [`PrimitiveBuilder`]: ../../build/struct.PrimitiveBuilder.html
+
+use std::collections::HashMap;
+
+/// Computes per-vertex tangents by averaging the tangent frames of the
+/// triangles sharing each vertex.
+///
+/// Each triangle contributes its tangent and bitangent, derived from the
+/// texture co-ordinates and projected onto the plane of the vertex normal,
+/// weighted by its angle at each of its vertices. The handedness in `w`
+/// follows the glTF convention where the bitangent is
+/// `cross(normal, tangent.xyz) * w`.
+///
+/// This approximates the MikkTSpace algorithm the glTF specification asks
+/// for, but never splits vertices: vertices shared by triangles of opposite
+/// texture orientation get a single tangent, so normal maps may show seams
+/// where textures are mirrored. See [`mikktspace_tangents`] for the exact
+/// algorithm. Vertices without a valid contribution, e.g. those of triangles
+/// with degenerate texture co-ordinates, get an arbitrary tangent
+/// perpendicular to their normal.
+///
+/// [`mikktspace_tangents`]: fn.mikktspace_tangents.html
+///
+/// # Panics
+///
+/// Panics if `normals` or `tex_coords` have fewer elements than `positions`,
+/// or if an index is out of bounds.
+pub fn tangents(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tex_coords: &[[f32; 2]],
+    indices: &[u32],
+) -> Vec<[f32; 4]> {
+    let count = positions.len();
+    assert!(normals.len() >= count && tex_coords.len() >= count);
+    let mut tangents = vec![[0.0; 3]; count];
+    let mut bitangents = vec![[0.0; 3]; count];
+    for triangle in indices.chunks_exact(3) {
+        let [a, b, c] = [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize];
+        let (e1, e2) = (sub(positions[b], positions[a]), sub(positions[c], positions[a]));
+        let [s1, t1] = [tex_coords[b][0] - tex_coords[a][0], tex_coords[b][1] - tex_coords[a][1]];
+        let [s2, t2] = [tex_coords[c][0] - tex_coords[a][0], tex_coords[c][1] - tex_coords[a][1]];
+        let area = s1 * t2 - s2 * t1;
+        if area == 0.0 {
+            continue;
+        }
+        let sign = area.signum();
+        let tangent = scale(sub(scale(e1, t2), scale(e2, t1)), sign);
+        let bitangent = scale(sub(scale(e2, s1), scale(e1, s2)), sign);
+        for [vertex, previous, next] in [[a, c, b], [b, a, c], [c, b, a]] {
+            let normal = normals[vertex];
+            let angle = angle(
+                project(sub(positions[next], positions[vertex]), normal),
+                project(sub(positions[previous], positions[vertex]), normal),
+            );
+            let tangent = normalize(project(tangent, normal));
+            let bitangent = normalize(project(bitangent, normal));
+            tangents[vertex] = add(tangents[vertex], scale(tangent, angle));
+            bitangents[vertex] = add(bitangents[vertex], scale(bitangent, angle));
+        }
+    }
+    tangents
+        .into_iter()
+        .zip(bitangents)
+        .zip(normals)
+        .map(|((tangent, bitangent), &normal)| {
+            let mut tangent = normalize(project(tangent, normal));
+            if tangent == [0.0; 3] {
+                tangent = perpendicular(normal);
+            }
+            // MikkTSpace bitangents point towards increasing V, which is
+            // down in glTF images, whereas glTF normal textures point up.
+            let w = if dot(cross(normal, tangent), bitangent) > 0.0 { -1.0 } else { 1.0 };
+            [tangent[0], tangent[1], tangent[2], w]
+        })
+        .collect()
+}
+
+/// Computes tangents with the MikkTSpace algorithm, one per corner of each
+/// triangle.
+///
+/// The tangents match those baked into normal maps by most tools. Corners of
+/// a vertex may get different tangents, e.g. where a texture is mirrored, so
+/// the tangents are in the order of `indices`, as with [`flat_normals`], and
+/// vertices are split by welding them along with their tangents. The
+/// handedness in `w` follows the glTF convention where the bitangent is
+/// `cross(normal, tangent.xyz) * w`.
+///
+/// Returns `None` if the algorithm fails, e.g. if there are no triangles.
+///
+/// [`flat_normals`]: fn.flat_normals.html
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+#[cfg(feature = "mikktspace")]
+#[cfg_attr(docsrs, doc(cfg(feature = "mikktspace")))]
+pub fn mikktspace_tangents(
+    positions: &[[f32; 3]],
+    normals: &[[f32; 3]],
+    tex_coords: &[[f32; 2]],
+    indices: &[u32],
+) -> Option<Vec<[f32; 4]>> {
+    struct Geometry<'a> {
+        positions: &'a [[f32; 3]],
+        normals: &'a [[f32; 3]],
+        tex_coords: &'a [[f32; 2]],
+        indices: &'a [u32],
+        tangents: Vec<[f32; 4]>,
+    }
+
+    impl<'a> Geometry<'a> {
+        fn vertex(&self, face: usize, vert: usize) -> usize {
+            self.indices[face * 3 + vert] as usize
+        }
+    }
+
+    impl<'a> mikktspace::Geometry for Geometry<'a> {
+        fn num_faces(&self) -> usize {
+            self.indices.len() / 3
+        }
+
+        fn num_vertices_of_face(&self, _face: usize) -> usize {
+            3
+        }
+
+        fn position(&self, face: usize, vert: usize) -> [f32; 3] {
+            self.positions[self.vertex(face, vert)]
+        }
+
+        fn normal(&self, face: usize, vert: usize) -> [f32; 3] {
+            self.normals[self.vertex(face, vert)]
+        }
+
+        fn tex_coord(&self, face: usize, vert: usize) -> [f32; 2] {
+            // MikkTSpace expects the texture origin at the bottom-left
+            // corner, whereas glTF puts it at the top-left corner.
+            let [u, v] = self.tex_coords[self.vertex(face, vert)];
+            [u, 1.0 - v]
+        }
+
+        fn set_tangent_encoded(&mut self, tangent: [f32; 4], face: usize, vert: usize) {
+            self.tangents[face * 3 + vert] = tangent;
+        }
+    }
+
+    let indices = &indices[..indices.len() - indices.len() % 3];
+    let mut geometry = Geometry {
+        positions,
+        normals,
+        tex_coords,
+        indices,
+        tangents: vec![[0.0; 4]; indices.len()],
+    };
+    if mikktspace::generate_tangents(&mut geometry) {
+        Some(geometry.tangents)
+    } else {
+        None
+    }
+}
+
+/// Computes flat normals, one per corner of each triangle.
+///
+/// Every corner of a triangle gets the normal of its plane, following the
+/// counter-clockwise winding of glTF. The normals are in the order of
+/// `indices`, so that other attributes have to be expanded with the indices to
+/// match them, and may be indexed again by welding. Degenerate triangles get
+/// zero normals.
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+pub fn flat_normals(positions: &[[f32; 3]], indices: &[u32]) -> Vec<[f32; 3]> {
+    indices
+        .chunks_exact(3)
+        .flat_map(|triangle| {
+            let normal = normalize(face_normal(positions, triangle));
+            [normal; 3]
+        })
+        .collect()
+}
+
+/// Computes smooth normals with creases, one per corner of each triangle.
+///
+/// The normal of a corner averages the normals of the triangles sharing its
+/// vertex position, weighted by their angle at that vertex. Triangles whose
+/// normals differ from the one of the corner by more than `crease_angle`
+/// radians are left out, so that sharp edges stay sharp. Vertices are matched
+/// by position rather than by index, so that triangles are smoothed across
+/// texture seams.
+///
+/// A crease angle of `std::f32::consts::PI` smooths all triangles and zero
+/// yields flat normals for all but coplanar triangles. The normals are in the
+/// order of `indices`, as with [`flat_normals`]. Corners of degenerate
+/// triangles may get zero normals.
+///
+/// [`flat_normals`]: fn.flat_normals.html
+///
+/// # Panics
+///
+/// Panics if an index is out of bounds.
+pub fn smooth_normals(positions: &[[f32; 3]], indices: &[u32], crease_angle: f32) -> Vec<[f32; 3]> {
+    let indices = &indices[..indices.len() - indices.len() % 3];
+    let faces = indices
+        .chunks_exact(3)
+        .map(|triangle| normalize(face_normal(positions, triangle)))
+        .collect::<Vec<_>>();
+    let weights = indices
+        .chunks_exact(3)
+        .flat_map(|triangle| {
+            let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+            [angle(sub(b, a), sub(c, a)), angle(sub(c, b), sub(a, b)), angle(sub(a, c), sub(b, c))]
+        })
+        .collect::<Vec<_>>();
+
+    // Corners sharing a vertex position, with `-0.0` matching `0.0`.
+    let mut shared = HashMap::<[u32; 3], Vec<usize>>::new();
+    for (corner, &index) in indices.iter().enumerate() {
+        let key = positions[index as usize].map(|x| (x + 0.0).to_bits());
+        shared.entry(key).or_default().push(corner);
+    }
+
+    let threshold = crease_angle.cos();
+    indices
+        .iter()
+        .enumerate()
+        .map(|(corner, &index)| {
+            let face = faces[corner / 3];
+            let key = positions[index as usize].map(|x| (x + 0.0).to_bits());
+            let normal = shared[&key]
+                .iter()
+                .filter(|&&other| other / 3 == corner / 3 || dot(face, faces[other / 3]) >= threshold)
+                .fold([0.0; 3], |sum, &other| add(sum, scale(faces[other / 3], weights[other])));
+            normalize(normal)
+        })
+        .collect()
+}
+
+/// The result of welding vertices.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Weld {
+    /// The index of the welded vertex of every original vertex, which is the
+    /// index buffer of non-indexed primitives.
+    pub indices: Vec<u32>,
+
+    /// The index of the original vertex kept for every welded vertex.
+    pub vertices: Vec<u32>,
+}
+
+impl Weld {
+    /// Returns the values of a vertex attribute for the welded vertices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the attribute has fewer elements than the original vertices.
+    pub fn remap<T: Clone>(&self, attribute: &[T]) -> Vec<T> {
+        self.vertices.iter().map(|&vertex| attribute[vertex as usize].clone()).collect()
+    }
+
+    /// Returns an index buffer of the original vertices as indices of the
+    /// welded vertices, for welding indexed primitives.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an index is out of bounds.
+    pub fn reindex(&self, indices: &[u32]) -> Vec<u32> {
+        indices.iter().map(|&index| self.indices[index as usize]).collect()
+    }
+}
+
+/// Welds duplicate vertices.
+///
+/// Each vertex is given as all of its components, e.g. a position followed by
+/// a normal and texture co-ordinates. Vertices are duplicates if all of their
+/// components are equal, or differ by at most `epsilon` if it is positive.
+/// The first vertex of each set of duplicates is kept.
+///
+/// Duplicates within `epsilon` of each other are found with a grid over the
+/// first three components, which should thus be the position.
+///
+/// # Examples
+///
+/// Indexing triangles with flat normals.
+///
+/// ```
+/// use gltf::mesh::generate;
+///
+/// let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+/// let indices = [0, 1, 2, 0, 2, 3];
+/// let normals = generate::flat_normals(&positions, &indices);
+/// let vertices = indices
+///     .iter()
+///     .zip(&normals)
+///     .map(|(&index, normal)| {
+///         let [x, y, z] = positions[index as usize];
+///         [x, y, z, normal[0], normal[1], normal[2]]
+///     })
+///     .collect::<Vec<_>>();
+/// let weld = generate::weld(&vertices, 0.0);
+/// assert_eq!(weld.indices, [0, 1, 2, 0, 2, 3]);
+/// assert_eq!(weld.remap(&normals), [[0.0, 0.0, 1.0]; 4]);
+/// ```
+pub fn weld<V: AsRef<[f32]>>(vertices: &[V], epsilon: f32) -> Weld {
+    let mut weld = Weld::default();
+    if epsilon > 0.0 {
+        let cell = |vertex: &[f32]| {
+            let mut cell = [0i64; 3];
+            for (cell, x) in cell.iter_mut().zip(vertex) {
+                *cell = (x / epsilon).floor() as i64;
+            }
+            cell
+        };
+        let mut grid = HashMap::<[i64; 3], Vec<u32>>::new();
+        for vertex in vertices {
+            let vertex = vertex.as_ref();
+            let [x, y, z] = cell(vertex);
+            let duplicate = (0..27)
+                .flat_map(|i| grid.get(&[x + i % 3 - 1, y + i / 3 % 3 - 1, z + i / 9 - 1]))
+                .flatten()
+                .copied()
+                .find(|&welded| {
+                    let other = vertices[weld.vertices[welded as usize] as usize].as_ref();
+                    other.len() == vertex.len() && other.iter().zip(vertex).all(|(a, b)| (a - b).abs() <= epsilon)
+                });
+            let welded = duplicate.unwrap_or_else(|| {
+                let welded = weld.vertices.len() as u32;
+                weld.vertices.push(weld.indices.len() as u32);
+                grid.entry([x, y, z]).or_default().push(welded);
+                welded
+            });
+            weld.indices.push(welded);
+        }
+    } else {
+        let mut unique = HashMap::<Vec<u32>, u32>::new();
+        for vertex in vertices {
+            let key = vertex.as_ref().iter().map(|x| (x + 0.0).to_bits()).collect();
+            let next = weld.vertices.len() as u32;
+            let welded = *unique.entry(key).or_insert(next);
+            if welded == next {
+                weld.vertices.push(weld.indices.len() as u32);
+            }
+            weld.indices.push(welded);
+        }
+    }
+    weld
+}
+
+/// Returns the unnormalized normal of a triangle.
+fn face_normal(positions: &[[f32; 3]], triangle: &[u32]) -> [f32; 3] {
+    let [a, b, c] = [0, 1, 2].map(|i| positions[triangle[i] as usize]);
+    cross(sub(b, a), sub(c, a))
+}
+
+/// Returns a unit vector perpendicular to `v`, or `[1.0, 0.0, 0.0]` if `v`
+/// is zero.
+fn perpendicular(v: [f32; 3]) -> [f32; 3] {
+    let axis = if v[0].abs() < 0.9 { [1.0, 0.0, 0.0] } else { [0.0, 1.0, 0.0] };
+    let perpendicular = normalize(project(axis, normalize(v)));
+    if perpendicular == [0.0; 3] { [1.0, 0.0, 0.0] } else { perpendicular }
+}
+
+/// Removes the component of `v` along the unit vector `n`.
+fn project(v: [f32; 3], n: [f32; 3]) -> [f32; 3] {
+    sub(v, scale(n, dot(v, n)))
+}
+
+/// Returns the angle between two vectors in radians, or zero if either is
+/// zero.
+fn angle(a: [f32; 3], b: [f32; 3]) -> f32 {
+    let length = (dot(a, a) * dot(b, b)).sqrt();
+    if length > 0.0 {
+        (dot(a, b) / length).clamp(-1.0, 1.0).acos()
+    } else {
+        0.0
+    }
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale(a: [f32; 3], s: f32) -> [f32; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: [f32; 3], b: [f32; 3]) -> f32 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Returns `v` scaled to unit length, or zero if it is zero.
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let length = dot(v, v).sqrt();
+    if length > 0.0 { scale(v, 1.0 / length) } else { [0.0; 3] }
+}
@@ -40,6 +40,18 @@ pub struct Primitives<'a>  {
     pub(crate) iter: iter::Enumerate<slice::Iter<'a, json::mesh::Primitive>>,
 }
 
+/// An `Iterator` that visits the material variant mappings of a `Primitive`.
+#[cfg(feature = "KHR_materials_variants")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+#[derive(Clone, Debug)]
+pub struct Mappings<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal JSON iterator.
+    pub(crate) iter: slice::Iter<'a, json::extensions::mesh::khr_materials_variants::Mapping>,
+}
+
 impl<'a> ExactSizeIterator for Attributes<'a> {}
 impl<'a> Iterator for Attributes<'a> {
     type Item = Attribute<'a>;
@@ -118,3 +130,31 @@ impl<'a> Iterator for MorphTargets<'a> {
         self.iter.nth(n).map(|json| map_morph_target(self.document, json))
     }
 }
+
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> ExactSizeIterator for Mappings<'a> {}
+
+#[cfg(feature = "KHR_materials_variants")]
+impl<'a> Iterator for Mappings<'a> {
+    type Item = crate::khr_materials_variants::Mapping<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter
+            .next()
+            .map(|json| crate::khr_materials_variants::Mapping::new(document, json))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|json| crate::khr_materials_variants::Mapping::new(document, json))
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.nth(n).map(|json| crate::khr_materials_variants::Mapping::new(document, json))
+    }
+}
@@ -48,6 +48,9 @@
 //! # }
 //! ```
 
+/// Generation of derived vertex data.
+pub mod generate;
+
 /// Iterators.
 pub mod iter;
 
@@ -70,6 +73,84 @@ pub type Attribute<'a> = (Semantic, Accessor<'a>);
 /// Vertex position bounding box.
 pub type BoundingBox = Bounds<[f32; 3]>;
 
+/// A vertex attribute stored in a field of a [`Vertex`] struct.
+///
+/// [`Vertex`]: trait.Vertex.html
+#[cfg(all(feature = "utils", feature = "bytemuck"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "utils", feature = "bytemuck"))))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct VertexAttribute {
+    /// The semantic of the attribute.
+    pub semantic: Semantic,
+
+    /// The offset of the field in bytes.
+    pub offset: usize,
+
+    /// The component type of the field.
+    pub data_type: accessor::DataType,
+
+    /// The dimensions of the field.
+    pub dimensions: accessor::Dimensions,
+}
+
+/// A `#[repr(C)]` struct with the layout of interleaved vertex attributes.
+///
+/// Interleaved vertices may be borrowed from buffer data as a slice of such
+/// structs with [`Reader::read_vertices`].
+///
+/// [`Reader::read_vertices`]: struct.Reader.html#method.read_vertices
+///
+/// # Examples
+///
+/// ```
+/// use gltf::accessor::{DataType, Dimensions};
+/// use gltf::mesh::{Vertex, VertexAttribute};
+/// use gltf::Semantic;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct PositionNormal {
+///     position: [f32; 3],
+///     normal: [f32; 3],
+/// }
+///
+/// unsafe impl bytemuck::Zeroable for PositionNormal {}
+/// unsafe impl bytemuck::Pod for PositionNormal {}
+///
+/// impl Vertex for PositionNormal {
+///     const ATTRIBUTES: &'static [VertexAttribute] = &[
+///         VertexAttribute {
+///             semantic: Semantic::Positions,
+///             offset: 0,
+///             data_type: DataType::F32,
+///             dimensions: Dimensions::Vec3,
+///         },
+///         VertexAttribute {
+///             semantic: Semantic::Normals,
+///             offset: 12,
+///             data_type: DataType::F32,
+///             dimensions: Dimensions::Vec3,
+///         },
+///     ];
+/// }
+/// ```
+#[cfg(all(feature = "utils", feature = "bytemuck"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "utils", feature = "bytemuck"))))]
+pub trait Vertex: bytemuck::Pod {
+    /// The attributes stored in the fields of the struct.
+    const ATTRIBUTES: &'static [VertexAttribute];
+}
+
+/// A sphere bounding vertex positions.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BoundingSphere {
+    /// The center of the sphere.
+    pub center: [f32; 3],
+
+    /// The radius of the sphere.
+    pub radius: f32,
+}
+
 /// The minimum and maximum values for a generic accessor.
 #[derive(Clone, Debug, PartialEq)]
 pub struct Bounds<T> {
@@ -120,6 +201,9 @@ pub struct Primitive<'a>  {
 }
 
 /// Mesh primitive reader.
+///
+/// Values substituted by sparse storage are applied over the base values of
+/// the accessors read, which are zero for accessors without a buffer view.
 #[derive(Clone, Debug)]
 pub struct Reader<'a, 's, F>
 where
@@ -239,6 +323,70 @@ impl<'a> Primitive<'a> {
             .unwrap_or_else(|| Material::default(self.mesh.document))
     }
 
+    /// Returns an `Iterator` that visits the material variant mappings of the
+    /// primitive as defined by the `KHR_materials_variants` extension.
+    #[cfg(feature = "KHR_materials_variants")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_materials_variants")))]
+    pub fn mappings(&self) -> iter::Mappings<'a> {
+        let mappings = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_materials_variants.as_ref())
+            .map(|variants| variants.mappings.as_slice())
+            .unwrap_or(&[]);
+        iter::Mappings {
+            document: self.mesh.document,
+            iter: mappings.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the sets of feature IDs of the
+    /// primitive as defined by the `EXT_mesh_features` extension.
+    #[cfg(feature = "EXT_mesh_features")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+    pub fn feature_ids(&self) -> crate::ext_mesh_features::FeatureIds<'a> {
+        let feature_ids = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_mesh_features.as_ref())
+            .map(|features| features.feature_ids.as_slice())
+            .unwrap_or(&[]);
+        crate::ext_mesh_features::FeatureIds {
+            document: self.mesh.document,
+            iter: feature_ids.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the property textures of the primitive
+    /// as defined by the `EXT_structural_metadata` extension.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn property_textures(&self) -> crate::ext_structural_metadata::PrimitivePropertyTextures<'a> {
+        let property_textures = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_structural_metadata.as_ref())
+            .map(|metadata| metadata.property_textures.as_slice())
+            .unwrap_or(&[]);
+        crate::ext_structural_metadata::PrimitivePropertyTextures {
+            document: self.mesh.document,
+            iter: property_textures.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the property attributes of the
+    /// primitive as defined by the `EXT_structural_metadata` extension.
+    #[cfg(feature = "EXT_structural_metadata")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_structural_metadata")))]
+    pub fn property_attributes(&self) -> crate::ext_structural_metadata::PrimitivePropertyAttributes<'a> {
+        let property_attributes = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.ext_structural_metadata.as_ref())
+            .map(|metadata| metadata.property_attributes.as_slice())
+            .unwrap_or(&[]);
+        crate::ext_structural_metadata::PrimitivePropertyAttributes {
+            document: self.mesh.document,
+            iter: property_attributes.iter(),
+        }
+    }
+
     /// The type of primitives to render.
     pub fn mode(&self) -> Mode {
         self.json.mode.unwrap()
@@ -271,6 +419,73 @@ impl<'a> Primitive<'a> {
     {
         Reader { primitive: self, get_buffer_data }
     }
+
+    /// Computes the bounding box and a bounding sphere of the vertex positions
+    /// from their data.
+    ///
+    /// Unlike [`bounding_box`], this does not trust the `min` and `max`
+    /// properties of the `POSITION` accessor, which some exporters get wrong.
+    /// The positions are displaced by the morph targets with the given
+    /// weights, if any; pass an empty slice to ignore morph targets. The
+    /// sphere is not minimal but close to it.
+    ///
+    /// Returns `None` if the positions cannot be read or there are none.
+    ///
+    /// [`bounding_box`]: #method.bounding_box
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn compute_bounds<'s, F>(
+        &'a self,
+        get_buffer_data: F,
+        weights: &[f32],
+    ) -> Option<(BoundingBox, BoundingSphere)>
+    where
+        F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let positions = self.reader(get_buffer_data).read_blended_positions(weights)?.collect::<Vec<_>>();
+        let first = *positions.first()?;
+        let mut bounds = Bounds { min: first, max: first };
+        for position in &positions {
+            for ((min, max), &x) in bounds.min.iter_mut().zip(&mut bounds.max).zip(position) {
+                *min = min.min(x);
+                *max = max.max(x);
+            }
+        }
+        Some((bounds, bounding_sphere(&positions, first)))
+    }
+}
+
+/// Computes a bounding sphere of non-empty positions with Ritter's
+/// algorithm.
+#[cfg(feature = "utils")]
+fn bounding_sphere(positions: &[[f32; 3]], first: [f32; 3]) -> BoundingSphere {
+    let distance = |a: [f32; 3], b: [f32; 3]| {
+        ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt()
+    };
+    let farthest = |from: [f32; 3]| {
+        positions
+            .iter()
+            .copied()
+            .fold(from, |far, p| if distance(from, p) > distance(from, far) { p } else { far })
+    };
+    let a = farthest(first);
+    let b = farthest(a);
+    let mut center = [(a[0] + b[0]) / 2.0, (a[1] + b[1]) / 2.0, (a[2] + b[2]) / 2.0];
+    let mut radius = distance(a, b) / 2.0;
+    for &position in positions {
+        let d = distance(center, position);
+        if d > radius {
+            // Grow the sphere just enough to enclose the position.
+            let grown = (radius + d) / 2.0;
+            for i in 0..3 {
+                center[i] += (position[i] - center[i]) * (grown - radius) / d;
+            }
+            radius = grown;
+        }
+    }
+    // Make up for rounding errors in the center.
+    let radius = positions.iter().map(|&p| distance(center, p)).fold(radius, f32::max);
+    BoundingSphere { center, radius }
 }
 
 #[cfg(feature = "utils")]
@@ -278,24 +493,74 @@ impl<'a, 's, F> Reader<'a, 's, F>
     where F: Clone + Fn(Buffer<'a>) -> Option<&'s [u8]>,
 {
     /// Visits the vertex positions of a primitive.
+    ///
+    /// The positions must be stored as `f32`. Use `read_quantized_positions` to
+    /// read positions compressed with `KHR_mesh_quantization`.
     pub fn read_positions(&self) -> Option<util::ReadPositions<'s>> {
         self.primitive
             .get(&Semantic::Positions)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
     }
 
     /// Visits the vertex normals of a primitive.
+    ///
+    /// The normals must be stored as `f32`. Use `read_quantized_normals` to
+    /// read normals compressed with `KHR_mesh_quantization`.
     pub fn read_normals(&self) -> Option<util::ReadNormals<'s>> {
         self.primitive
             .get(&Semantic::Normals)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
+    }
+
+    /// Visits the vertex positions of a primitive, transformed by a 4x4 matrix
+    /// in column-major order.
+    ///
+    /// The matrix is typically the world transform of a node instantiating
+    /// the mesh, for baking geometry into world space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// for node in document.nodes() {
+    ///     let transform = node.transform().matrix();
+    ///     for primitive in node.mesh().iter().flat_map(|mesh| mesh.primitives()) {
+    ///         let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    ///         let positions = reader.read_world_positions(transform).unwrap();
+    ///         assert_eq!(positions.len(), 24);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn read_world_positions(&self, transform: [[f32; 4]; 4]) -> Option<util::world::WorldPositions<'s>> {
+        self.read_positions()
+            .map(|positions| util::world::WorldPositions::new(positions, transform))
+    }
+
+    /// Visits the vertex normals of a primitive, transformed by a 4x4 matrix
+    /// in column-major order.
+    ///
+    /// The normals are transformed by the inverse transpose of the matrix and
+    /// normalized, so that they stay perpendicular to the transformed surface.
+    /// Translation is ignored.
+    pub fn read_world_normals(&self, transform: [[f32; 4]; 4]) -> Option<util::world::WorldNormals<'s>> {
+        self.read_normals()
+            .map(|normals| util::world::WorldNormals::new(normals, transform))
     }
 
     /// Visits the vertex tangents of a primitive.
+    ///
+    /// The tangents must be stored as `f32`. Use `read_quantized_tangents` to
+    /// read tangents compressed with `KHR_mesh_quantization`.
     pub fn read_tangents(&self) -> Option<util::ReadTangents<'s>> {
         self.primitive
             .get(&Semantic::Tangents)
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
     }
 
     /// Visits the vertex colors of a primitive.
@@ -307,12 +572,12 @@ impl<'a, 's, F> Reader<'a, 's, F>
             .get(&Semantic::Colors(set))
             .and_then(|accessor| {
                 match (accessor.data_type(), accessor.dimensions()) {
-                    (U8, Vec3)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbU8),
-                    (U16, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbU16),
-                    (F32, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbF32),
-                    (U8, Vec4)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaU8),
-                    (U16, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaU16),
-                    (F32, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadColors::RgbaF32),
+                    (U8, Vec3)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbU8),
+                    (U16, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbU16),
+                    (F32, Vec3) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbF32),
+                    (U8, Vec4)  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbaU8),
+                    (U16, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbaU16),
+                    (F32, Vec4) => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadColors::RgbaF32),
                     _ => unreachable!(),
                 }
             })
@@ -326,14 +591,230 @@ impl<'a, 's, F> Reader<'a, 's, F>
             .indices()
             .and_then(|accessor| {
                 match accessor.data_type() {
-                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U8),
-                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U16),
-                    DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadIndices::U32),
+                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadIndices::U8),
+                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadIndices::U16),
+                    DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadIndices::U32),
                     _ => unreachable!(),
                 }
             })
     }
 
+    /// Visits the vertex draw sequence of a primitive as `u32`.
+    ///
+    /// Indices are synthesized as `0..count` for non-indexed primitives, where
+    /// `count` is the number of vertices, so that every primitive may be drawn
+    /// with a single index format. Returns `None` if the indices cannot be
+    /// read, or if the primitive has neither indices nor attributes.
+    pub fn read_indices_u32(&self) -> Option<util::ReadIndicesU32<'s>> {
+        use self::util::ReadIndicesU32;
+        if self.primitive.indices().is_some() {
+            return self.read_indices().map(|indices| ReadIndicesU32::Indexed(indices.into_u32()));
+        }
+        let (_, accessor) = self.primitive.attributes().next()?;
+        Some(ReadIndicesU32::Sequential(0..accessor.count() as u32))
+    }
+
+    /// Visits the vertex indices of the triangles of a primitive.
+    ///
+    /// Triangle strips and fans are unrolled into separate triangles, and
+    /// non-indexed primitives are indexed sequentially. Returns `None` if the
+    /// primitive is not made of triangles or its indices cannot be read.
+    pub fn read_triangles(&self) -> Option<util::triangles::ReadTriangles<'s>> {
+        match self.primitive.mode() {
+            Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan => {
+                let indices = self.read_indices_u32()?;
+                Some(util::triangles::ReadTriangles::new(indices, self.primitive.mode()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Visits the vertex positions of the triangles of a primitive.
+    ///
+    /// See [`read_triangles`](#method.read_triangles) for how triangles are
+    /// resolved.
+    pub fn read_triangle_positions(&self) -> Option<util::triangles::ReadTrianglePositions<'s>> {
+        let triangles = self.read_triangles()?;
+        let positions = self.primitive.get(&Semantic::Positions)?;
+        let positions = accessor::Get::new(positions, self.get_buffer_data.clone()).ok()?;
+        Some(util::triangles::ReadTrianglePositions::new(triangles, positions))
+    }
+
+    /// Visits the vertex indices of the line segments of a primitive.
+    ///
+    /// Line strips and loops are unrolled into separate segments, and
+    /// non-indexed primitives are indexed sequentially. Returns `None` if the
+    /// primitive is not made of lines or its indices cannot be read.
+    pub fn read_lines(&self) -> Option<util::lines::ReadLines<'s>> {
+        match self.primitive.mode() {
+            Mode::Lines | Mode::LineStrip | Mode::LineLoop => {
+                let indices = self.read_indices_u32()?;
+                Some(util::lines::ReadLines::new(indices, self.primitive.mode()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Visits the vertex positions of the line segments of a primitive.
+    ///
+    /// See [`read_lines`](#method.read_lines) for how segments are resolved.
+    pub fn read_line_positions(&self) -> Option<util::lines::ReadLinePositions<'s>> {
+        let lines = self.read_lines()?;
+        let positions = self.primitive.get(&Semantic::Positions)?;
+        let positions = accessor::Get::new(positions, self.get_buffer_data.clone()).ok()?;
+        Some(util::lines::ReadLinePositions::new(lines, positions))
+    }
+
+    /// Visits the vertex indices of the points of a primitive.
+    ///
+    /// Non-indexed primitives are indexed sequentially. Returns `None` if the
+    /// primitive is not made of points or its indices cannot be read.
+    pub fn read_points(&self) -> Option<util::ReadIndicesU32<'s>> {
+        match self.primitive.mode() {
+            Mode::Points => self.read_indices_u32(),
+            _ => None,
+        }
+    }
+
+    /// Visits the vertex positions of the points of a primitive in draw
+    /// order.
+    pub fn read_point_positions(&self) -> Option<util::lines::ReadPointPositions<'s>> {
+        let points = self.read_points()?;
+        let positions = self.primitive.get(&Semantic::Positions)?;
+        let positions = accessor::Get::new(positions, self.get_buffer_data.clone()).ok()?;
+        Some(util::lines::ReadPointPositions::new(points, positions))
+    }
+
+    /// Computes the vertex tangents of a primitive by averaging the tangent
+    /// frames of its triangles, for primitives lacking the `TANGENT`
+    /// attribute.
+    ///
+    /// The tangents are computed from the texture co-ordinates of the given
+    /// set, which should be the one of the normal texture. Returns `None` if
+    /// the primitive is not made of triangles, or if its positions, normals,
+    /// or texture co-ordinates cannot be read. See [`generate::tangents`] for
+    /// details, and [`generate_mikktspace_tangents`] for the MikkTSpace
+    /// algorithm.
+    ///
+    /// [`generate::tangents`]: generate/fn.tangents.html
+    /// [`generate_mikktspace_tangents`]: #method.generate_mikktspace_tangents
+    pub fn generate_tangents(&self, set: u32) -> Option<Vec<[f32; 4]>> {
+        self.generate_from_tex_coords(set, generate::tangents)
+    }
+
+    /// Computes tangents for a primitive with the MikkTSpace algorithm, one
+    /// per corner of each of the triangles visited by
+    /// [`read_triangles`](#method.read_triangles).
+    ///
+    /// The tangents are computed from the texture co-ordinates of the given
+    /// set, which should be the one of the normal texture. Returns `None` if
+    /// the primitive is not made of triangles, if its positions, normals, or
+    /// texture co-ordinates cannot be read, or if the algorithm fails. See
+    /// [`generate::mikktspace_tangents`] for details.
+    ///
+    /// [`generate::mikktspace_tangents`]: generate/fn.mikktspace_tangents.html
+    #[cfg(feature = "mikktspace")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "mikktspace")))]
+    pub fn generate_mikktspace_tangents(&self, set: u32) -> Option<Vec<[f32; 4]>> {
+        self.generate_from_tex_coords(set, generate::mikktspace_tangents)?
+    }
+
+    /// Reads the vertex positions, normals, and texture co-ordinates of the
+    /// given set of a primitive along with the indices of its triangles, and
+    /// generates vertex data from them.
+    fn generate_from_tex_coords<T, G>(&self, set: u32, generate: G) -> Option<T>
+    where
+        G: FnOnce(&[[f32; 3]], &[[f32; 3]], &[[f32; 2]], &[u32]) -> T,
+    {
+        let (positions, indices) = self.read_triangle_list()?;
+        let normals = self.read_normals()?.collect::<Vec<_>>();
+        let tex_coords = self.read_tex_coords(set)?.into_f32().collect::<Vec<_>>();
+        if normals.len() != positions.len() || tex_coords.len() != positions.len() {
+            return None;
+        }
+        Some(generate(&positions, &normals, &tex_coords, &indices))
+    }
+
+    /// Computes flat normals for a primitive, one per corner of each of the
+    /// triangles visited by [`read_triangles`](#method.read_triangles).
+    ///
+    /// Returns `None` if the primitive is not made of triangles or its
+    /// positions cannot be read. See [`generate::flat_normals`] for details.
+    ///
+    /// [`generate::flat_normals`]: generate/fn.flat_normals.html
+    pub fn generate_flat_normals(&self) -> Option<Vec<[f32; 3]>> {
+        let (positions, indices) = self.read_triangle_list()?;
+        Some(generate::flat_normals(&positions, &indices))
+    }
+
+    /// Computes smooth normals for a primitive, one per corner of each of the
+    /// triangles visited by [`read_triangles`](#method.read_triangles).
+    ///
+    /// Triangles meeting at an angle greater than `crease_angle` radians are
+    /// not smoothed together. Returns `None` if the primitive is not made of
+    /// triangles or its positions cannot be read. See
+    /// [`generate::smooth_normals`] for details.
+    ///
+    /// [`generate::smooth_normals`]: generate/fn.smooth_normals.html
+    pub fn generate_smooth_normals(&self, crease_angle: f32) -> Option<Vec<[f32; 3]>> {
+        let (positions, indices) = self.read_triangle_list()?;
+        Some(generate::smooth_normals(&positions, &indices, crease_angle))
+    }
+
+    /// Reads the vertex positions of a primitive along with the indices of its
+    /// triangles, leaving out triangles with indices out of bounds.
+    fn read_triangle_list(&self) -> Option<(Vec<[f32; 3]>, Vec<u32>)> {
+        let positions = self.read_positions()?.collect::<Vec<_>>();
+        let indices = self.read_triangles()?
+            .filter(|triangle| triangle.iter().all(|&index| (index as usize) < positions.len()))
+            .flatten()
+            .collect::<Vec<_>>();
+        Some((positions, indices))
+    }
+
+    /// Returns the interleaved vertices of a primitive as a slice of the
+    /// buffer data, without copying them.
+    ///
+    /// The attributes of [`V::ATTRIBUTES`] must be stored in the same buffer
+    /// view, with a byte stride of the size of `V`, at the offsets of their
+    /// fields relative to each other, and with the declared component types
+    /// and dimensions. Returns `None` if this is not the case, if an attribute
+    /// is missing or sparse, if the data is not aligned for `V`, or if the
+    /// target is big endian. The attributes may be read one by one instead.
+    ///
+    /// [`V::ATTRIBUTES`]: trait.Vertex.html#associatedconstant.ATTRIBUTES
+    #[cfg(feature = "bytemuck")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+    pub fn read_vertices<V: Vertex>(&self) -> Option<&'s [V]> {
+        let size = std::mem::size_of::<V>();
+        if cfg!(target_endian = "big") || V::ATTRIBUTES.is_empty() {
+            return None;
+        }
+        let mut layout = None;
+        for attribute in V::ATTRIBUTES {
+            let accessor = self.primitive.get(&attribute.semantic)?;
+            let end = attribute.offset.checked_add(accessor.size())?;
+            if accessor.data_type() != attribute.data_type
+                || accessor.dimensions() != attribute.dimensions
+                || accessor.sparse().is_some()
+                || end > size
+            {
+                return None;
+            }
+            let view = accessor.view()?;
+            let start = accessor.offset().checked_sub(attribute.offset)?;
+            let current = (view.index(), start, accessor.count());
+            if *layout.get_or_insert(current) != current || view.stride().unwrap_or(accessor.size()) != size {
+                return None;
+            }
+        }
+        let (_, start, count) = layout?;
+        let view = self.primitive.get(&V::ATTRIBUTES[0].semantic)?.view()?;
+        let get_buffer_data = self.get_buffer_data.clone();
+        let (data, _) = accessor::util::element_slice(view, start, count, size, &get_buffer_data).ok()?;
+        bytemuck::try_cast_slice(data).ok()
+    }
+
     /// Visits the joint indices of the primitive.
     pub fn read_joints(&self, set: u32) -> Option<util::ReadJoints<'s>> {
         use accessor::DataType;
@@ -342,13 +823,47 @@ impl<'a, 's, F> Reader<'a, 's, F>
             .get(&Semantic::Joints(set))
             .and_then(|accessor| {
                 match accessor.data_type() {
-                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U8),
-                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadJoints::U16),
+                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadJoints::U8),
+                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadJoints::U16),
                     _ => unreachable!(),
                 }
             })
     }
 
+    /// Visits the `N` most influential joints of every vertex along with
+    /// their weights, gathered from all sets of joints and weights.
+    ///
+    /// The weights are renormalized to sum to one after dropping the least
+    /// influential joints, as expected by GPU skinning with a fixed number of
+    /// influences per vertex. Returns `None` if the primitive has no joints or
+    /// weights.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let (document, buffers, _) = gltf::import("examples/Box.gltf")?;
+    /// # let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    /// let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    /// if let Some(influences) = reader.read_influences::<4>() {
+    ///     for (joints, weights) in influences {
+    ///         println!("{:?} {:?}", joints, weights);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #     run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn read_influences<const N: usize>(&self) -> Option<util::influences::Influences<'s, N>> {
+        let sets = (0..)
+            .map_while(|set| Some((self.read_joints(set)?.into_u16(), self.read_weights(set)?.into_f32())))
+            .collect::<Vec<_>>();
+        if sets.is_empty() {
+            return None;
+        }
+        Some(util::influences::Influences::new(sets))
+    }
+
     /// Visits the vertex texture co-ordinates of a primitive.
     pub fn read_tex_coords(&self, set: u32) -> Option<util::ReadTexCoords<'s>> {
         use accessor::DataType;
@@ -357,9 +872,9 @@ impl<'a, 's, F> Reader<'a, 's, F>
             .get(&Semantic::TexCoords(set))
             .and_then(|accessor| {
                 match accessor.data_type() {
-                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U8),
-                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::U16),
-                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadTexCoords::F32),
+                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadTexCoords::U8),
+                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadTexCoords::U16),
+                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadTexCoords::F32),
                     _ => unreachable!(),
                 }
             })
@@ -373,14 +888,85 @@ impl<'a, 's, F> Reader<'a, 's, F>
             .get(&Semantic::Weights(set))
             .and_then(|accessor| {
                 match accessor.data_type() {
-                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::U8),
-                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::U16),
-                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).map(ReadWeights::F32),
+                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadWeights::U8),
+                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadWeights::U16),
+                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadWeights::F32),
                     _ => unreachable!(),
                 }
             })
     }
 
+    /// Visits the feature IDs stored in the `_FEATURE_ID_n` attribute with the
+    /// given set index, as defined by the `EXT_mesh_features` extension.
+    #[cfg(all(feature = "EXT_mesh_features", feature = "extras"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "EXT_mesh_features", feature = "extras"))))]
+    pub fn read_feature_ids(&self, set: u32) -> Option<util::ReadFeatureIds<'s>> {
+        use accessor::DataType;
+        use self::util::ReadFeatureIds;
+        self.primitive
+            .get(&Semantic::Extras(format!("FEATURE_ID_{}", set)))
+            .and_then(|accessor| {
+                match accessor.data_type() {
+                    DataType::U8  => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadFeatureIds::U8),
+                    DataType::U16 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadFeatureIds::U16),
+                    DataType::U32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadFeatureIds::U32),
+                    DataType::F32 => accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok().map(ReadFeatureIds::F32),
+                    _ => None,
+                }
+            })
+    }
+
+    /// Visits the vertex positions of a primitive, which may be quantized as
+    /// allowed by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_positions(&self) -> Option<util::ReadQuantizedPositions<'s>> {
+        self.primitive
+            .get(&Semantic::Positions)
+            .and_then(|accessor| self.read_quantized(accessor))
+    }
+
+    /// Visits the vertex normals of a primitive, which may be quantized as
+    /// allowed by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_normals(&self) -> Option<util::ReadQuantizedNormals<'s>> {
+        self.primitive
+            .get(&Semantic::Normals)
+            .and_then(|accessor| self.read_quantized(accessor))
+    }
+
+    /// Visits the vertex tangents of a primitive, which may be quantized as
+    /// allowed by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_tangents(&self) -> Option<util::ReadQuantizedTangents<'s>> {
+        self.primitive
+            .get(&Semantic::Tangents)
+            .and_then(|accessor| self.read_quantized(accessor))
+    }
+
+    /// Visits the vertex texture co-ordinates of a primitive, which may be
+    /// quantized as allowed by `KHR_mesh_quantization`.
+    #[cfg(feature = "KHR_mesh_quantization")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+    pub fn read_quantized_tex_coords(
+        &self,
+        set: u32,
+    ) -> Option<util::ReadQuantizedTexCoords<'s>> {
+        self.primitive
+            .get(&Semantic::TexCoords(set))
+            .and_then(|accessor| self.read_quantized(accessor))
+    }
+
+    #[cfg(feature = "KHR_mesh_quantization")]
+    fn read_quantized<const N: usize>(
+        &self,
+        accessor: Accessor<'a>,
+    ) -> Option<util::ReadQuantized<'s, N>> {
+        util::ReadQuantized::new(accessor, self.get_buffer_data.clone())
+    }
+
     /// Visits the morph targets of the primitive.
     pub fn read_morph_targets(&self) -> util::ReadMorphTargets<'a, 's, F> {
         util::ReadMorphTargets {
@@ -388,6 +974,57 @@ impl<'a, 's, F> Reader<'a, 's, F>
             reader: self.clone(),
         }
     }
+
+    /// Visits the vertex positions of the primitive, displaced by its morph
+    /// targets with the given weights.
+    ///
+    /// The weights are usually those of the node instantiating the mesh, or
+    /// of the mesh itself. Missing weights are taken to be zero.
+    ///
+    /// The positions must be stored as `f32`.
+    pub fn read_blended_positions(&self, weights: &[f32]) -> Option<util::morph::Blended<'s, 3>> {
+        let base = self.read_positions()?;
+        Some(util::morph::Blended::new(base, self.read_displacements(weights, MorphTarget::positions)))
+    }
+
+    /// Visits the vertex normals of the primitive, displaced by its morph
+    /// targets with the given weights.
+    ///
+    /// The blended normals are not normalized. Missing weights are taken to
+    /// be zero.
+    pub fn read_blended_normals(&self, weights: &[f32]) -> Option<util::morph::Blended<'s, 3>> {
+        let base = self.read_normals()?;
+        Some(util::morph::Blended::new(base, self.read_displacements(weights, MorphTarget::normals)))
+    }
+
+    /// Visits the vertex tangents of the primitive, displaced by its morph
+    /// targets with the given weights.
+    ///
+    /// Only the XYZ components are displaced; the handedness is kept. The
+    /// blended tangents are not normalized. Missing weights are taken to be
+    /// zero.
+    pub fn read_blended_tangents(&self, weights: &[f32]) -> Option<util::morph::Blended<'s, 4>> {
+        let base = self.read_tangents()?;
+        Some(util::morph::Blended::new(base, self.read_displacements(weights, MorphTarget::tangents)))
+    }
+
+    /// Reads the displacements of an attribute by the morph targets with
+    /// non-zero weights.
+    fn read_displacements(
+        &self,
+        weights: &[f32],
+        attribute: fn(&MorphTarget<'a>) -> Option<Accessor<'a>>,
+    ) -> Vec<(f32, accessor::Iter<'s, [f32; 3]>)> {
+        self.primitive
+            .morph_targets()
+            .zip(weights)
+            .filter(|&(_, &weight)| weight != 0.0)
+            .filter_map(|(target, &weight)| {
+                let displacements = accessor::Iter::new(attribute(&target)?, self.get_buffer_data.clone()).ok()?;
+                Some((weight, displacements))
+            })
+            .collect()
+    }
 }
 
 impl<'a> MorphTarget<'a> {
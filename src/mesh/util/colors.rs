@@ -32,6 +32,54 @@ pub struct RgbaU16;
 #[derive(Clone, Debug)]
 pub struct RgbaF32;
 
+/// Type which describes how to cast any color into sRGB encoded RGB u8.
+#[derive(Clone, Debug)]
+pub struct SrgbU8;
+
+/// Type which describes how to cast any color into sRGB encoded RGB f32.
+#[derive(Clone, Debug)]
+pub struct SrgbF32;
+
+/// Type which describes how to cast any color into sRGB encoded RGBA u8,
+/// with linear alpha.
+#[derive(Clone, Debug)]
+pub struct SrgbaU8;
+
+/// Type which describes how to cast any color into sRGB encoded RGBA f32,
+/// with linear alpha.
+#[derive(Clone, Debug)]
+pub struct SrgbaF32;
+
+/// Encodes a linear color channel with the sRGB transfer function.
+///
+/// Values are clamped to `[0.0, 1.0]`.
+pub fn linear_to_srgb(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    if x <= 0.003_130_8 {
+        x * 12.92
+    } else {
+        1.055 * x.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Decodes an sRGB encoded color channel to linear.
+///
+/// Values are clamped to `[0.0, 1.0]`. Vertex colors are linear in glTF, so
+/// this is only needed for colors known to be authored in sRGB.
+pub fn srgb_to_linear(x: f32) -> f32 {
+    let x = x.clamp(0.0, 1.0);
+    if x <= 0.040_45 {
+        x / 12.92
+    } else {
+        ((x + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Quantizes a color channel in `[0.0, 1.0]` to u8, rounding to nearest.
+fn quantize(x: f32) -> u8 {
+    (x * 255.0).round() as u8
+}
+
 trait ColorChannel {
     fn max_color() -> Self;
 }
@@ -321,3 +369,121 @@ impl Cast for RgbaF32 {
         x.normalize().into_rgba()
     }
 }
+
+impl Cast for SrgbF32 {
+    type Output = [f32; 3];
+
+    fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
+        RgbF32::cast_rgb_u8(x).map(linear_to_srgb)
+    }
+
+    fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
+        RgbF32::cast_rgb_u16(x).map(linear_to_srgb)
+    }
+
+    fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
+        RgbF32::cast_rgb_f32(x).map(linear_to_srgb)
+    }
+
+    fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
+        RgbF32::cast_rgba_u8(x).map(linear_to_srgb)
+    }
+
+    fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
+        RgbF32::cast_rgba_u16(x).map(linear_to_srgb)
+    }
+
+    fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
+        RgbF32::cast_rgba_f32(x).map(linear_to_srgb)
+    }
+}
+
+impl Cast for SrgbaF32 {
+    type Output = [f32; 4];
+
+    fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u8(x).into_rgba()
+    }
+
+    fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u16(x).into_rgba()
+    }
+
+    fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_f32(x).into_rgba()
+    }
+
+    fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
+        let [r, g, b] = SrgbF32::cast_rgba_u8(x);
+        [r, g, b, x[3].normalize()]
+    }
+
+    fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
+        let [r, g, b] = SrgbF32::cast_rgba_u16(x);
+        [r, g, b, x[3].normalize()]
+    }
+
+    fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
+        let [r, g, b] = SrgbF32::cast_rgba_f32(x);
+        [r, g, b, x[3]]
+    }
+}
+
+impl Cast for SrgbU8 {
+    type Output = [u8; 3];
+
+    fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u8(x).map(quantize)
+    }
+
+    fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u16(x).map(quantize)
+    }
+
+    fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_f32(x).map(quantize)
+    }
+
+    fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
+        SrgbF32::cast_rgba_u8(x).map(quantize)
+    }
+
+    fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
+        SrgbF32::cast_rgba_u16(x).map(quantize)
+    }
+
+    fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
+        SrgbF32::cast_rgba_f32(x).map(quantize)
+    }
+}
+
+impl Cast for SrgbaU8 {
+    type Output = [u8; 4];
+
+    fn cast_rgb_u8(x: [u8; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u8(x).map(quantize).into_rgba()
+    }
+
+    fn cast_rgb_u16(x: [u16; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_u16(x).map(quantize).into_rgba()
+    }
+
+    fn cast_rgb_f32(x: [f32; 3]) -> Self::Output {
+        SrgbF32::cast_rgb_f32(x).map(quantize).into_rgba()
+    }
+
+    fn cast_rgba_u8(x: [u8; 4]) -> Self::Output {
+        let [r, g, b] = SrgbU8::cast_rgba_u8(x);
+        [r, g, b, x[3]]
+    }
+
+    fn cast_rgba_u16(x: [u16; 4]) -> Self::Output {
+        let [r, g, b] = SrgbU8::cast_rgba_u16(x);
+        [r, g, b, x[3].normalize()]
+    }
+
+    fn cast_rgba_f32(x: [f32; 4]) -> Self::Output {
+        let [r, g, b] = SrgbU8::cast_rgba_f32(x);
+        [r, g, b, x[3].normalize()]
+    }
+}
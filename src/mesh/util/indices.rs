@@ -1,6 +1,6 @@
 use std::marker::PhantomData;
 
-use super::ReadIndices;
+use super::{ReadIndices, ReadIndicesU32};
 
 /// Casting iterator for `Indices`.
 #[derive(Clone, Debug)]
@@ -87,3 +87,43 @@ impl Cast for U32 {
     fn cast_u16(x: u16) -> Self::Output { x as Self::Output }
     fn cast_u32(x: u32) -> Self::Output { x }
 }
+
+impl<'a> ExactSizeIterator for ReadIndicesU32<'a> {}
+impl<'a> Iterator for ReadIndicesU32<'a> {
+    type Item = u32;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            ReadIndicesU32::Indexed(ref mut i) => i.next(),
+            ReadIndicesU32::Sequential(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, x: usize) -> Option<Self::Item> {
+        match self {
+            ReadIndicesU32::Indexed(ref mut i) => i.nth(x),
+            ReadIndicesU32::Sequential(ref mut i) => i.nth(x),
+        }
+    }
+
+    fn last(self) -> Option<Self::Item> {
+        match self {
+            ReadIndicesU32::Indexed(i) => i.last(),
+            ReadIndicesU32::Sequential(i) => i.last(),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            ReadIndicesU32::Indexed(ref i) => i.size_hint(),
+            ReadIndicesU32::Sequential(ref i) => i.size_hint(),
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use super::{joints, weights};
+
+/// The joints and weights of one set of `JOINTS_n` and `WEIGHTS_n`.
+type Set<'a> = (joints::CastingIter<'a, joints::U16>, weights::CastingIter<'a, weights::F32>);
+
+/// Capping iterator for the joint influences of vertices.
+///
+/// Visits the `N` most influential joints of every vertex along with their
+/// weights, gathered from all sets of joints and weights. The weights are
+/// renormalized to sum to one, and vertices with fewer than `N` influences
+/// are padded with joint zero and weight zero. Vertices without any influence
+/// get zero weights only.
+#[derive(Clone, Debug)]
+pub struct Influences<'a, const N: usize> {
+    /// The sets of joints and weights.
+    sets: Vec<Set<'a>>,
+}
+
+impl<'a, const N: usize> Influences<'a, N> {
+    pub(crate) fn new(sets: Vec<Set<'a>>) -> Self {
+        Influences { sets }
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for Influences<'a, N> {}
+impl<'a, const N: usize> Iterator for Influences<'a, N> {
+    type Item = ([u16; N], [f32; N]);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut influences = Vec::with_capacity(4 * self.sets.len());
+        for (joints, weights) in &mut self.sets {
+            let (joints, weights) = (joints.next()?, weights.next()?);
+            influences.extend(joints.iter().copied().zip(weights).filter(|&(_, weight)| weight > 0.0));
+        }
+        // The sort is stable, so the first of equal influences are kept.
+        influences.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        influences.truncate(N);
+
+        let sum = influences.iter().map(|&(_, weight)| weight).sum::<f32>();
+        let (mut joints, mut weights) = ([0; N], [0.0; N]);
+        for (i, (joint, weight)) in influences.into_iter().enumerate() {
+            joints[i] = joint;
+            weights[i] = weight / sum;
+        }
+        Some((joints, weights))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sets
+            .iter()
+            .map(|(joints, weights)| joints.len().min(weights.len()))
+            .min()
+            .map_or((0, Some(0)), |len| (len, Some(len)))
+    }
+}
@@ -0,0 +1,156 @@
+use crate::accessor::Get;
+use crate::mesh::Mode;
+
+use super::ReadIndicesU32;
+
+/// Resolving iterator for the vertex indices of line segments.
+///
+/// Line lists are visited as they are. Line strips and loops are unrolled
+/// into segments sharing their end points, and loops are closed by a last
+/// segment from the last vertex back to the first one.
+///
+/// A trailing index that does not form a complete segment is ignored.
+#[derive(Clone, Debug)]
+pub struct ReadLines<'a> {
+    /// The vertex indices of the primitive.
+    indices: ReadIndicesU32<'a>,
+
+    /// The topology of the primitive.
+    mode: Mode,
+
+    /// The first and the last vertex indices of a strip or loop visited so
+    /// far.
+    ends: Option<[u32; 2]>,
+
+    /// Whether the closing segment of a loop is still to be visited.
+    open: bool,
+}
+
+impl<'a> ReadLines<'a> {
+    /// Constructor.
+    ///
+    /// The mode must be one of `Lines`, `LineStrip`, or `LineLoop`.
+    pub(crate) fn new(indices: ReadIndicesU32<'a>, mode: Mode) -> Self {
+        debug_assert!(matches!(mode, Mode::Lines | Mode::LineStrip | Mode::LineLoop));
+        ReadLines {
+            indices,
+            mode,
+            ends: None,
+            open: false,
+        }
+    }
+}
+
+impl<'a> Iterator for ReadLines<'a> {
+    type Item = [u32; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mode == Mode::Lines {
+            return Some([self.indices.next()?, self.indices.next()?]);
+        }
+        let [first, last] = match self.ends {
+            Some(ends) => ends,
+            None => {
+                let first = self.indices.next()?;
+                [first, first]
+            }
+        };
+        match self.indices.next() {
+            Some(next) => {
+                self.ends = Some([first, next]);
+                self.open = self.mode == Mode::LineLoop;
+                Some([last, next])
+            }
+            None if self.open => {
+                self.open = false;
+                Some([last, first])
+            }
+            None => None,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.indices.len();
+        let segments = match self.mode {
+            Mode::Lines => remaining / 2,
+            _ if self.ends.is_some() => remaining + self.open as usize,
+            Mode::LineLoop if remaining > 1 => remaining,
+            _ => remaining.saturating_sub(1),
+        };
+        (segments, Some(segments))
+    }
+}
+
+impl<'a> ExactSizeIterator for ReadLines<'a> {}
+
+/// Resolving iterator for the vertex positions of line segments.
+///
+/// Visits the positions of the segments visited by [`ReadLines`]. Iteration
+/// stops at the first vertex index out of bounds of the positions.
+///
+/// [`ReadLines`]: struct.ReadLines.html
+#[derive(Clone, Debug)]
+pub struct ReadLinePositions<'a> {
+    /// The vertex indices of the segments.
+    lines: ReadLines<'a>,
+
+    /// The vertex positions of the primitive.
+    positions: Get<'a, [f32; 3]>,
+}
+
+impl<'a> ReadLinePositions<'a> {
+    pub(crate) fn new(lines: ReadLines<'a>, positions: Get<'a, [f32; 3]>) -> Self {
+        ReadLinePositions { lines, positions }
+    }
+}
+
+impl<'a> Iterator for ReadLinePositions<'a> {
+    type Item = [[f32; 3]; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let [a, b] = self.lines.next()?;
+        Some([self.positions.get(a as usize)?, self.positions.get(b as usize)?])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.lines.size_hint().1)
+    }
+}
+
+/// Resolving iterator for the vertex positions of points.
+///
+/// Visits the position of every point in draw order. Iteration stops at the
+/// first vertex index out of bounds of the positions.
+#[derive(Clone, Debug)]
+pub struct ReadPointPositions<'a> {
+    /// The vertex indices of the points.
+    points: ReadIndicesU32<'a>,
+
+    /// The vertex positions of the primitive.
+    positions: Get<'a, [f32; 3]>,
+}
+
+impl<'a> ReadPointPositions<'a> {
+    pub(crate) fn new(points: ReadIndicesU32<'a>, positions: Get<'a, [f32; 3]>) -> Self {
+        ReadPointPositions { points, positions }
+    }
+}
+
+impl<'a> Iterator for ReadPointPositions<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.points.next()?;
+        self.positions.get(index as usize)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.points.size_hint().1)
+    }
+}
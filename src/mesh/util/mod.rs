@@ -1,18 +1,43 @@
 /// Casting iterator adapters for colors.
 pub mod colors;
 
+/// Casting iterator adapters for feature IDs.
+#[cfg(feature = "EXT_mesh_features")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+pub mod feature_ids;
+
 /// Casting iterator adapters for vertex indices.
 pub mod indices;
 
+/// Capping iterator adapters for joint influences.
+pub mod influences;
+
 /// Casting iterator adapters for joint indices.
 pub mod joints;
 
+/// Resolving iterator adapters for line segments and points.
+pub mod lines;
+
+/// Blending iterator adapters for morph targets.
+pub mod morph;
+
+/// Resolving iterator adapters for triangles.
+pub mod triangles;
+
 /// Casting iterator adapters for texture co-ordinates.
 pub mod tex_coords;
 
 /// Casting iterator adapters for node weights.
 pub mod weights;
 
+/// Transforming iterator adapters for world-space geometry.
+pub mod world;
+
+/// Dequantizing iterator adapters for quantized vertex attributes.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub mod quantized;
+
 use crate::mesh;
 
 use crate::accessor::Iter;
@@ -54,6 +79,21 @@ pub enum ReadColors<'a> {
     RgbaF32(Iter<'a, [f32; 4]>),
 }
 
+/// Feature IDs stored in a vertex attribute.
+#[cfg(feature = "EXT_mesh_features")]
+#[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_features")))]
+#[derive(Clone, Debug)]
+pub enum ReadFeatureIds<'a> {
+    /// Feature IDs of type `u8`.
+    U8(Iter<'a, u8>),
+    /// Feature IDs of type `u16`.
+    U16(Iter<'a, u16>),
+    /// Feature IDs of type `u32`.
+    U32(Iter<'a, u32>),
+    /// Feature IDs of type `f32`, holding integer values.
+    F32(Iter<'a, f32>),
+}
+
 /// Index data.
 #[derive(Clone, Debug)]
 pub enum ReadIndices<'a> {
@@ -65,6 +105,15 @@ pub enum ReadIndices<'a> {
     U32(Iter<'a, u32>),
 }
 
+/// Index data as `u32`, synthesized for non-indexed primitives.
+#[derive(Clone, Debug)]
+pub enum ReadIndicesU32<'a> {
+    /// Index data of an indexed primitive.
+    Indexed(self::indices::CastingIter<'a, self::indices::U32>),
+    /// Sequential indices of a non-indexed primitive.
+    Sequential(std::ops::Range<u32>),
+}
+
 /// Vertex joints.
 #[derive(Clone, Debug)]
 pub enum ReadJoints<'a> {
@@ -100,6 +149,51 @@ pub enum ReadWeights<'a> {
     F32(Iter<'a, [f32; 4]>),
 }
 
+/// Vertex attribute components of any type allowed by `KHR_mesh_quantization`.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+#[derive(Clone, Debug)]
+pub enum ReadQuantized<'a, const N: usize> {
+    /// Components of type `i8`.
+    I8(Iter<'a, [i8; N]>),
+    /// Normalized components of type `i8`.
+    I8Normalized(Iter<'a, [i8; N]>),
+    /// Components of type `u8`.
+    U8(Iter<'a, [u8; N]>),
+    /// Normalized components of type `u8`.
+    U8Normalized(Iter<'a, [u8; N]>),
+    /// Components of type `i16`.
+    I16(Iter<'a, [i16; N]>),
+    /// Normalized components of type `i16`.
+    I16Normalized(Iter<'a, [i16; N]>),
+    /// Components of type `u16`.
+    U16(Iter<'a, [u16; N]>),
+    /// Normalized components of type `u16`.
+    U16Normalized(Iter<'a, [u16; N]>),
+    /// Components of type `f32`.
+    F32(Iter<'a, [f32; N]>),
+}
+
+/// XYZ vertex positions of any type allowed by `KHR_mesh_quantization`.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub type ReadQuantizedPositions<'a> = ReadQuantized<'a, 3>;
+
+/// XYZ vertex normals of any type allowed by `KHR_mesh_quantization`.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub type ReadQuantizedNormals<'a> = ReadQuantized<'a, 3>;
+
+/// XYZW vertex tangents of any type allowed by `KHR_mesh_quantization`.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub type ReadQuantizedTangents<'a> = ReadQuantized<'a, 4>;
+
+/// UV texture co-ordinates of any type allowed by `KHR_mesh_quantization`.
+#[cfg(feature = "KHR_mesh_quantization")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_mesh_quantization")))]
+pub type ReadQuantizedTexCoords<'a> = ReadQuantized<'a, 2>;
+
 /// Morph targets.
 #[derive(Clone, Debug)]
 pub struct ReadMorphTargets<'a, 's, F>
@@ -132,13 +226,13 @@ where
             .map(|morph_target| {
                 let positions = morph_target
                     .positions()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
+                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()).ok());
                 let normals = morph_target
                     .normals()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
+                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()).ok());
                 let tangents = morph_target
                     .tangents()
-                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()));
+                    .and_then(|accessor| Iter::new(accessor, self.reader.get_buffer_data.clone()).ok());
                 (positions, normals, tangents)
             })
     }
@@ -184,6 +278,84 @@ impl<'a> ReadColors<'a> {
     pub fn into_rgba_f32(self) -> self::colors::CastingIter<'a, self::colors::RgbaF32> {
         self::colors::CastingIter::new(self)
     }
+
+    /// Reinterpret colors as RGB u8 encoded with the sRGB transfer function,
+    /// discarding alpha, if present.  Lossy in all cases.
+    pub fn into_srgb_u8(self) -> self::colors::CastingIter<'a, self::colors::SrgbU8> {
+        self::colors::CastingIter::new(self)
+    }
+
+    /// Reinterpret colors as RGB f32 encoded with the sRGB transfer function,
+    /// discarding alpha, if present.
+    pub fn into_srgb_f32(self) -> self::colors::CastingIter<'a, self::colors::SrgbF32> {
+        self::colors::CastingIter::new(self)
+    }
+
+    /// Reinterpret colors as RGBA u8 encoded with the sRGB transfer function,
+    /// with linear alpha and default alpha 255.  Lossy in all cases.
+    pub fn into_srgba_u8(self) -> self::colors::CastingIter<'a, self::colors::SrgbaU8> {
+        self::colors::CastingIter::new(self)
+    }
+
+    /// Reinterpret colors as RGBA f32 encoded with the sRGB transfer function,
+    /// with linear alpha and default alpha 1.0.
+    pub fn into_srgba_f32(self) -> self::colors::CastingIter<'a, self::colors::SrgbaF32> {
+        self::colors::CastingIter::new(self)
+    }
+}
+
+#[cfg(feature = "KHR_mesh_quantization")]
+impl<'a, const N: usize> ReadQuantized<'a, N> {
+    /// Reads an accessor of any type allowed by `KHR_mesh_quantization`.
+    pub(crate) fn new<'b, F>(accessor: crate::Accessor<'b>, get_buffer_data: F) -> Option<Self>
+    where
+        F: Clone + Fn(Buffer<'b>) -> Option<&'a [u8]>,
+    {
+        use crate::accessor::DataType;
+        match (accessor.data_type(), accessor.normalized()) {
+            (DataType::I8, false) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::I8)
+            }
+            (DataType::I8, true) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::I8Normalized)
+            }
+            (DataType::U8, false) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::U8)
+            }
+            (DataType::U8, true) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::U8Normalized)
+            }
+            (DataType::I16, false) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::I16)
+            }
+            (DataType::I16, true) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::I16Normalized)
+            }
+            (DataType::U16, false) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::U16)
+            }
+            (DataType::U16, true) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::U16Normalized)
+            }
+            (DataType::F32, _) => {
+                Iter::new(accessor, get_buffer_data).ok().map(ReadQuantized::F32)
+            }
+            _ => None,
+        }
+    }
+
+    /// Dequantize the components to f32, taking normalization into account.
+    pub fn into_f32(self) -> self::quantized::Dequantize<'a, N> {
+        self::quantized::Dequantize::new(self)
+    }
+}
+
+#[cfg(feature = "EXT_mesh_features")]
+impl<'a> ReadFeatureIds<'a> {
+    /// Reinterpret feature IDs as u32, which can fit any possible feature ID.
+    pub fn into_u32(self) -> self::feature_ids::CastingIter<'a, self::feature_ids::U32> {
+        self::feature_ids::CastingIter::new(self)
+    }
 }
 
 impl<'a> ReadIndices<'a> {
@@ -218,6 +390,16 @@ impl<'a> ReadTexCoords<'a> {
     pub fn into_f32(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::F32> {
         self::tex_coords::CastingIter::new(self)
     }
+
+    /// Reinterpret texture coordinates as f32 with the V coordinate flipped,
+    /// for engines placing the texture origin at the bottom-left corner.
+    /// Lossy if the underlying iterator yields u16.
+    ///
+    /// Texture transforms must be adjusted accordingly, see
+    /// `TextureTransform::matrix_flipped_v`.
+    pub fn into_f32_flipped_v(self) -> self::tex_coords::CastingIter<'a, self::tex_coords::FlippedF32> {
+        self::tex_coords::CastingIter::new(self)
+    }
 }
 
 impl<'a> ReadWeights<'a> {
@@ -0,0 +1,60 @@
+use crate::accessor::Iter;
+
+/// Blending iterator for vertex attributes displaced by morph targets.
+///
+/// Visits `base + Σ weight · displacement` for every vertex, where the
+/// displacements are added to the XYZ components of the base values. Other
+/// components, such as the handedness of tangents, are left as they are.
+#[derive(Clone, Debug)]
+pub struct Blended<'a, const N: usize> {
+    /// The base values of the attribute.
+    base: Iter<'a, [f32; N]>,
+
+    /// The weight and displacements of each morph target displacing the
+    /// attribute with a non-zero weight.
+    targets: Vec<(f32, Iter<'a, [f32; 3]>)>,
+}
+
+impl<'a, const N: usize> Blended<'a, N> {
+    pub(crate) fn new(base: Iter<'a, [f32; N]>, targets: Vec<(f32, Iter<'a, [f32; 3]>)>) -> Self {
+        Blended { base, targets }
+    }
+
+    /// Adds the weighted displacements of the next vertex to a base value.
+    fn blend(&mut self, mut value: [f32; N], next: impl Fn(&mut Iter<'a, [f32; 3]>) -> Option<[f32; 3]>) -> [f32; N] {
+        for (weight, displacements) in &mut self.targets {
+            if let Some(displacement) = next(displacements) {
+                for (component, delta) in value.iter_mut().zip(displacement) {
+                    *component += *weight * delta;
+                }
+            }
+        }
+        value
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for Blended<'a, N> {}
+impl<'a, const N: usize> Iterator for Blended<'a, N> {
+    type Item = [f32; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.base.next()?;
+        Some(self.blend(value, Iterator::next))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let value = self.base.nth(n)?;
+        Some(self.blend(value, |displacements| displacements.nth(n)))
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.base.size_hint()
+    }
+}
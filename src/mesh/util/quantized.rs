@@ -0,0 +1,90 @@
+use super::ReadQuantized;
+
+/// Dequantizing iterator for `ReadQuantized`.
+///
+/// Normalized integer components are mapped to `[0.0, 1.0]` (unsigned) or
+/// `[-1.0, 1.0]` (signed) as defined by the glTF specification, while other
+/// integer components are converted to `f32` as is.
+#[derive(Clone, Debug)]
+pub struct Dequantize<'a, const N: usize>(ReadQuantized<'a, N>);
+
+impl<'a, const N: usize> Dequantize<'a, N> {
+    pub(crate) fn new(iter: ReadQuantized<'a, N>) -> Self {
+        Dequantize(iter)
+    }
+
+    /// Unwrap underlying `ReadQuantized` object.
+    pub fn unwrap(self) -> ReadQuantized<'a, N> {
+        self.0
+    }
+}
+
+fn map<T: Copy, const N: usize>(x: [T; N], f: impl Fn(T) -> f32) -> [f32; N] {
+    std::array::from_fn(|i| f(x[i]))
+}
+
+fn i8_to_f32(x: i8) -> f32 {
+    (x as f32 / 127.0).max(-1.0)
+}
+
+fn u8_to_f32(x: u8) -> f32 {
+    x as f32 / 255.0
+}
+
+fn i16_to_f32(x: i16) -> f32 {
+    (x as f32 / 32767.0).max(-1.0)
+}
+
+fn u16_to_f32(x: u16) -> f32 {
+    x as f32 / 65535.0
+}
+
+impl<'a, const N: usize> ExactSizeIterator for Dequantize<'a, N> {}
+impl<'a, const N: usize> Iterator for Dequantize<'a, N> {
+    type Item = [f32; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.0 {
+            ReadQuantized::I8(ref mut i) => i.next().map(|x| map(x, f32::from)),
+            ReadQuantized::I8Normalized(ref mut i) => i.next().map(|x| map(x, i8_to_f32)),
+            ReadQuantized::U8(ref mut i) => i.next().map(|x| map(x, f32::from)),
+            ReadQuantized::U8Normalized(ref mut i) => i.next().map(|x| map(x, u8_to_f32)),
+            ReadQuantized::I16(ref mut i) => i.next().map(|x| map(x, f32::from)),
+            ReadQuantized::I16Normalized(ref mut i) => i.next().map(|x| map(x, i16_to_f32)),
+            ReadQuantized::U16(ref mut i) => i.next().map(|x| map(x, f32::from)),
+            ReadQuantized::U16Normalized(ref mut i) => i.next().map(|x| map(x, u16_to_f32)),
+            ReadQuantized::F32(ref mut i) => i.next(),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        match self.0 {
+            ReadQuantized::I8(ref mut i) => i.nth(n).map(|x| map(x, f32::from)),
+            ReadQuantized::I8Normalized(ref mut i) => i.nth(n).map(|x| map(x, i8_to_f32)),
+            ReadQuantized::U8(ref mut i) => i.nth(n).map(|x| map(x, f32::from)),
+            ReadQuantized::U8Normalized(ref mut i) => i.nth(n).map(|x| map(x, u8_to_f32)),
+            ReadQuantized::I16(ref mut i) => i.nth(n).map(|x| map(x, f32::from)),
+            ReadQuantized::I16Normalized(ref mut i) => i.nth(n).map(|x| map(x, i16_to_f32)),
+            ReadQuantized::U16(ref mut i) => i.nth(n).map(|x| map(x, f32::from)),
+            ReadQuantized::U16Normalized(ref mut i) => i.nth(n).map(|x| map(x, u16_to_f32)),
+            ReadQuantized::F32(ref mut i) => i.nth(n),
+        }
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.0 {
+            ReadQuantized::I8(ref i) | ReadQuantized::I8Normalized(ref i) => i.size_hint(),
+            ReadQuantized::U8(ref i) | ReadQuantized::U8Normalized(ref i) => i.size_hint(),
+            ReadQuantized::I16(ref i) | ReadQuantized::I16Normalized(ref i) => i.size_hint(),
+            ReadQuantized::U16(ref i) | ReadQuantized::U16Normalized(ref i) => i.size_hint(),
+            ReadQuantized::F32(ref i) => i.size_hint(),
+        }
+    }
+}
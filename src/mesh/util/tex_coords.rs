@@ -20,6 +20,11 @@ pub struct U16;
 #[derive(Clone, Debug)]
 pub struct F32;
 
+/// Type which describes how to cast any texture coordinate into pair of f32
+/// with the V co-ordinate flipped, as `[u, 1.0 - v]`.
+#[derive(Clone, Debug)]
+pub struct FlippedF32;
+
 /// Trait for types which describe casting behaviour.
 pub trait Cast {
     /// Output type.
@@ -137,3 +142,24 @@ impl Cast for F32 {
         x.normalize()
     }
 }
+
+impl Cast for FlippedF32 {
+    type Output = [f32; 2];
+
+    fn cast_u8(x: [u8; 2]) -> Self::Output {
+        flip_v(F32::cast_u8(x))
+    }
+
+    fn cast_u16(x: [u16; 2]) -> Self::Output {
+        flip_v(F32::cast_u16(x))
+    }
+
+    fn cast_f32(x: [f32; 2]) -> Self::Output {
+        flip_v(F32::cast_f32(x))
+    }
+}
+
+/// Flips the V co-ordinate of a texture co-ordinate.
+fn flip_v([u, v]: [f32; 2]) -> [f32; 2] {
+    [u, 1.0 - v]
+}
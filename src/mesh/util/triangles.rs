@@ -0,0 +1,135 @@
+use crate::accessor::Get;
+use crate::mesh::Mode;
+
+use super::ReadIndicesU32;
+
+/// Resolving iterator for the vertex indices of triangles.
+///
+/// Triangle lists are visited as they are. Triangle strips and fans are
+/// unrolled following the glTF specification, which alternates the winding
+/// of strips so that every triangle keeps the orientation of the first one.
+/// Degenerate triangles, which repeat a vertex index, are skipped in strips
+/// and fans since they are commonly used to join several strips into one.
+///
+/// Trailing indices that do not form a complete triangle are ignored.
+#[derive(Clone, Debug)]
+pub struct ReadTriangles<'a> {
+    /// The vertex indices of the primitive.
+    indices: ReadIndicesU32<'a>,
+
+    /// The topology of the primitive.
+    mode: Mode,
+
+    /// The last two vertex indices of a strip, or the first and the last
+    /// vertex indices of a fan.
+    previous: Option<[u32; 2]>,
+
+    /// The number of triangles unrolled from a strip so far, including
+    /// degenerate ones.
+    unrolled: usize,
+}
+
+impl<'a> ReadTriangles<'a> {
+    /// Constructor.
+    ///
+    /// The mode must be one of `Triangles`, `TriangleStrip`, or `TriangleFan`.
+    pub(crate) fn new(indices: ReadIndicesU32<'a>, mode: Mode) -> Self {
+        debug_assert!(matches!(mode, Mode::Triangles | Mode::TriangleStrip | Mode::TriangleFan));
+        ReadTriangles {
+            indices,
+            mode,
+            previous: None,
+            unrolled: 0,
+        }
+    }
+
+    /// Returns the first two vertex indices of a strip or fan.
+    fn previous(&mut self) -> Option<[u32; 2]> {
+        if self.previous.is_none() {
+            let first = self.indices.next()?;
+            let second = self.indices.next()?;
+            self.previous = Some([first, second]);
+        }
+        self.previous
+    }
+}
+
+impl<'a> Iterator for ReadTriangles<'a> {
+    type Item = [u32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.mode == Mode::Triangles {
+            return Some([self.indices.next()?, self.indices.next()?, self.indices.next()?]);
+        }
+        loop {
+            let [a, b] = self.previous()?;
+            let c = self.indices.next()?;
+            let triangle = match self.mode {
+                Mode::TriangleStrip => {
+                    self.previous = Some([b, c]);
+                    self.unrolled += 1;
+                    if self.unrolled % 2 == 1 { [a, b, c] } else { [a, c, b] }
+                }
+                _ => {
+                    self.previous = Some([a, c]);
+                    [b, c, a]
+                }
+            };
+            let [a, b, c] = triangle;
+            if a != b && b != c && c != a {
+                return Some(triangle);
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.indices.len();
+        match self.mode {
+            Mode::Triangles => (remaining / 3, Some(remaining / 3)),
+            _ if self.previous.is_some() => (0, Some(remaining)),
+            _ => (0, Some(remaining.saturating_sub(2))),
+        }
+    }
+}
+
+/// Resolving iterator for the vertex positions of triangles.
+///
+/// Visits the positions of the triangles visited by [`ReadTriangles`].
+/// Iteration stops at the first vertex index out of bounds of the positions.
+///
+/// [`ReadTriangles`]: struct.ReadTriangles.html
+#[derive(Clone, Debug)]
+pub struct ReadTrianglePositions<'a> {
+    /// The vertex indices of the triangles.
+    triangles: ReadTriangles<'a>,
+
+    /// The vertex positions of the primitive.
+    positions: Get<'a, [f32; 3]>,
+}
+
+impl<'a> ReadTrianglePositions<'a> {
+    pub(crate) fn new(triangles: ReadTriangles<'a>, positions: Get<'a, [f32; 3]>) -> Self {
+        ReadTrianglePositions { triangles, positions }
+    }
+}
+
+impl<'a> Iterator for ReadTrianglePositions<'a> {
+    type Item = [[f32; 3]; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let [a, b, c] = self.triangles.next()?;
+        Some([
+            self.positions.get(a as usize)?,
+            self.positions.get(b as usize)?,
+            self.positions.get(c as usize)?,
+        ])
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.triangles.size_hint().1)
+    }
+}
@@ -0,0 +1,129 @@
+use super::{ReadNormals, ReadPositions};
+
+/// Transforming iterator for vertex positions.
+///
+/// Visits every position multiplied by a 4x4 affine transform, such as the
+/// local or world transform of the node instantiating the mesh.
+#[derive(Clone, Debug)]
+pub struct WorldPositions<'a> {
+    /// The positions in object space.
+    positions: ReadPositions<'a>,
+
+    /// The transform in column-major order.
+    transform: [[f32; 4]; 4],
+}
+
+impl<'a> WorldPositions<'a> {
+    pub(crate) fn new(positions: ReadPositions<'a>, transform: [[f32; 4]; 4]) -> Self {
+        WorldPositions { positions, transform }
+    }
+
+    /// Transforms a position in object space.
+    fn transform(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let m = &self.transform;
+        let mut p = [0.0; 3];
+        for (row, value) in p.iter_mut().enumerate() {
+            *value = m[0][row] * x + m[1][row] * y + m[2][row] * z + m[3][row];
+        }
+        p
+    }
+}
+
+impl<'a> ExactSizeIterator for WorldPositions<'a> {}
+impl<'a> Iterator for WorldPositions<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions.next().map(|position| self.transform(position))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.positions.nth(n).map(|position| self.transform(position))
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+/// Transforming iterator for vertex normals.
+///
+/// Visits every normal multiplied by the inverse transpose of the upper 3x3
+/// part of a transform, so that normals stay perpendicular to surfaces under
+/// non-uniform scaling, and normalized again afterwards.
+#[derive(Clone, Debug)]
+pub struct WorldNormals<'a> {
+    /// The normals in object space.
+    normals: ReadNormals<'a>,
+
+    /// The normal matrix in column-major order, scaled by the absolute
+    /// value of the determinant of the transform.
+    matrix: [[f32; 3]; 3],
+}
+
+impl<'a> WorldNormals<'a> {
+    pub(crate) fn new(normals: ReadNormals<'a>, transform: [[f32; 4]; 4]) -> Self {
+        let cross = |a: [f32; 4], b: [f32; 4]| {
+            [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+        };
+        let [c0, c1, c2, _] = transform;
+        // The columns of the cofactor matrix are the determinant times those
+        // of the inverse transpose, which is well-defined even for singular
+        // transforms.
+        let mut matrix = [cross(c1, c2), cross(c2, c0), cross(c0, c1)];
+        let determinant = (0..3).map(|i| c0[i] * matrix[0][i]).sum::<f32>();
+        if determinant < 0.0 {
+            for value in matrix.iter_mut().flatten() {
+                *value = -*value;
+            }
+        }
+        WorldNormals { normals, matrix }
+    }
+
+    /// Transforms a normal in object space.
+    fn transform(&self, [x, y, z]: [f32; 3]) -> [f32; 3] {
+        let m = &self.matrix;
+        let mut n = [0.0; 3];
+        for (row, value) in n.iter_mut().enumerate() {
+            *value = m[0][row] * x + m[1][row] * y + m[2][row] * z;
+        }
+        let length = n.iter().map(|value| value * value).sum::<f32>().sqrt();
+        if length > 0.0 {
+            for value in &mut n {
+                *value /= length;
+            }
+        }
+        n
+    }
+}
+
+impl<'a> ExactSizeIterator for WorldNormals<'a> {}
+impl<'a> Iterator for WorldNormals<'a> {
+    type Item = [f32; 3];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.normals.next().map(|normal| self.transform(normal))
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.normals.nth(n).map(|normal| self.transform(normal))
+    }
+
+    fn count(self) -> usize {
+        self.size_hint().0
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.normals.size_hint()
+    }
+}
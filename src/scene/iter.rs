@@ -1,6 +1,7 @@
 use std::slice;
 
-use crate::{Document, Node};
+use crate::scene::ParentIndex;
+use crate::{Camera, Document, Node};
 
 /// An `Iterator` that visits the nodes in a scene.
 #[derive(Clone, Debug)]
@@ -36,6 +37,166 @@ impl<'a> Iterator for Nodes<'a> {
     }
 }
 
+/// An `Iterator` that visits the ancestors of a node.
+#[derive(Clone, Debug)]
+pub struct Ancestors<'a, 'b> {
+    /// The parent index of the document.
+    pub(crate) index: &'b ParentIndex<'a>,
+
+    /// The index of the last node visited.
+    pub(crate) node: usize,
+
+    /// The maximum number of ancestors remaining to be visited.
+    pub(crate) remaining: usize,
+}
+
+/// An `Iterator` that visits the lower levels of detail of a node.
+#[cfg(feature = "MSFT_lod")]
+#[cfg_attr(docsrs, doc(cfg(feature = "MSFT_lod")))]
+#[derive(Clone, Debug)]
+pub struct LodLevels<'a> {
+    /// The parent `Document` struct.
+    pub(crate) document: &'a Document,
+
+    /// The internal node index iterator.
+    pub(crate) iter: slice::Iter<'a, json::Index<json::scene::Node>>,
+}
+
+/// An `Iterator` that visits the visible nodes of a scene hierarchy.
+#[cfg(feature = "KHR_node_visibility")]
+#[cfg_attr(docsrs, doc(cfg(feature = "KHR_node_visibility")))]
+#[derive(Clone, Debug)]
+pub struct VisibleNodes<'a> {
+    /// The nodes remaining to be visited, in reverse order.
+    pub(crate) stack: Vec<Node<'a>>,
+
+    /// Whether each node of the document has been visited, so that cycles do
+    /// not make the traversal endless.
+    pub(crate) visited: Vec<bool>,
+}
+
+/// The 4x4 identity matrix.
+pub(crate) const IDENTITY: [[f64; 4]; 4] = [
+    [1.0, 0.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0, 0.0],
+    [0.0, 0.0, 1.0, 0.0],
+    [0.0, 0.0, 0.0, 1.0],
+];
+
+/// An `Iterator` that visits the nodes of a scene hierarchy depth-first,
+/// together with their depth and world transforms.
+///
+/// Root nodes have a depth of zero. The world transforms are 4x4 matrices in
+/// column-major order, accumulated in double precision.
+#[derive(Clone, Debug)]
+pub struct DepthFirst<'a> {
+    /// The nodes remaining to be visited with their depth and the world
+    /// transforms of their parents, in reverse order.
+    stack: Vec<(Node<'a>, usize, [[f64; 4]; 4])>,
+
+    /// The number of children of the last node visited that are on top of
+    /// the stack.
+    children: usize,
+
+    /// Whether each node of the document has been visited, so that cycles,
+    /// which are not valid glTF, do not make the traversal endless.
+    visited: Vec<bool>,
+}
+
+impl<'a> DepthFirst<'a> {
+    /// Constructs a `DepthFirst` visiting the given root nodes, placed relative
+    /// to `origin`.
+    pub(crate) fn new(roots: Nodes<'a>, origin: [[f64; 4]; 4]) -> Self {
+        let visited = vec![false; roots.document.nodes().len()];
+        let mut stack = roots.map(|node| (node, 0, origin)).collect::<Vec<_>>();
+        stack.reverse();
+        Self { stack, children: 0, visited }
+    }
+
+    /// Skips the descendants of the last node visited.
+    pub fn skip_children(&mut self) {
+        let len = self.stack.len() - self.children;
+        self.stack.truncate(len);
+        self.children = 0;
+    }
+
+    /// Visits the next node with its depth and its world transform in double
+    /// precision.
+    ///
+    /// Nodes that were already visited are skipped.
+    pub(crate) fn visit(&mut self) -> Option<(Node<'a>, usize, [[f64; 4]; 4])> {
+        let (node, depth, parent) = loop {
+            let (node, depth, parent) = self.stack.pop()?;
+            if !std::mem::replace(&mut self.visited[node.index()], true) {
+                break (node, depth, parent);
+            }
+        };
+        let world = multiply(&parent, &to_f64(&node.transform().matrix()));
+        let start = self.stack.len();
+        self.stack.extend(node.children().map(|child| (child, depth + 1, world)));
+        self.stack[start..].reverse();
+        self.children = self.stack.len() - start;
+        Some((node, depth, world))
+    }
+}
+
+/// An `Iterator` that visits the cameras of a scene hierarchy together with
+/// the nodes referencing them and their world transforms.
+#[derive(Clone, Debug)]
+pub struct Cameras<'a> {
+    /// The traversal of the scene hierarchy.
+    pub(crate) depth_first: DepthFirst<'a>,
+}
+
+/// An `Iterator` that visits the nodes of a scene hierarchy together with their
+/// world transforms.
+#[derive(Clone, Debug)]
+pub struct WorldTransforms<'a> {
+    /// The traversal of the scene hierarchy.
+    depth_first: DepthFirst<'a>,
+}
+
+impl<'a> WorldTransforms<'a> {
+    /// Constructs a `WorldTransforms` visiting the given root nodes, placed
+    /// relative to `origin`.
+    pub(crate) fn new(roots: Nodes<'a>, origin: [[f64; 4]; 4]) -> Self {
+        Self { depth_first: DepthFirst::new(roots, origin) }
+    }
+}
+
+/// Multiplies two column-major 4x4 matrices.
+fn multiply(a: &[[f64; 4]; 4], b: &[[f64; 4]; 4]) -> [[f64; 4]; 4] {
+    let mut m = [[0.0; 4]; 4];
+    for (column, b) in m.iter_mut().zip(b) {
+        for (row, value) in column.iter_mut().enumerate() {
+            *value = (0..4).map(|k| a[k][row] * b[k]).sum();
+        }
+    }
+    m
+}
+
+/// Converts a single precision matrix to double precision.
+fn to_f64(matrix: &[[f32; 4]; 4]) -> [[f64; 4]; 4] {
+    let mut m = [[0.0; 4]; 4];
+    for (column, matrix) in m.iter_mut().zip(matrix) {
+        for (value, matrix) in column.iter_mut().zip(matrix) {
+            *value = f64::from(*matrix);
+        }
+    }
+    m
+}
+
+/// Converts a double precision matrix to single precision.
+pub(crate) fn to_f32(matrix: &[[f64; 4]; 4]) -> [[f32; 4]; 4] {
+    let mut m = [[0.0; 4]; 4];
+    for (column, matrix) in m.iter_mut().zip(matrix) {
+        for (value, matrix) in column.iter_mut().zip(matrix) {
+            *value = *matrix as f32;
+        }
+    }
+    m
+}
+
 impl<'a> ExactSizeIterator for Children<'a> {}
 impl<'a> Iterator for Children<'a> {
     type Item = Node<'a>;
@@ -58,3 +219,77 @@ impl<'a> Iterator for Children<'a> {
         self.iter.nth(n).map(|index| self.document.nodes().nth(index.value()).unwrap())
     }
 }
+
+impl<'a, 'b> Iterator for Ancestors<'a, 'b> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.remaining = self.remaining.checked_sub(1)?;
+        let parent = self.index.parent_of(self.node)?;
+        self.node = parent.index();
+        Some(parent)
+    }
+}
+
+#[cfg(feature = "MSFT_lod")]
+impl<'a> ExactSizeIterator for LodLevels<'a> {}
+
+#[cfg(feature = "MSFT_lod")]
+impl<'a> Iterator for LodLevels<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|index| self.document.nodes().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+    fn count(self) -> usize {
+        self.iter.count()
+    }
+    fn last(mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next_back().map(|index| document.nodes().nth(index.value()).unwrap())
+    }
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.iter.nth(n).map(|index| self.document.nodes().nth(index.value()).unwrap())
+    }
+}
+
+#[cfg(feature = "KHR_node_visibility")]
+impl<'a> Iterator for VisibleNodes<'a> {
+    type Item = Node<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            if node.visible() && !std::mem::replace(&mut self.visited[node.index()], true) {
+                let start = self.stack.len();
+                self.stack.extend(node.children());
+                self.stack[start..].reverse();
+                return Some(node);
+            }
+        }
+        None
+    }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (Node<'a>, usize, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.visit().map(|(node, depth, world)| (node, depth, to_f32(&world)))
+    }
+}
+
+impl<'a> Iterator for Cameras<'a> {
+    type Item = (Camera<'a>, Node<'a>, [[f32; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.depth_first
+            .find_map(|(node, _, transform)| node.camera().map(|camera| (camera, node, transform)))
+    }
+}
+
+impl<'a> Iterator for WorldTransforms<'a> {
+    type Item = (Node<'a>, [[f64; 4]; 4]);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.depth_first.visit().map(|(node, _, world)| (node, world))
+    }
+}
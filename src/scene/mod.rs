@@ -1,5 +1,9 @@
 use crate::math::*;
-use crate::{Camera, Document, Mesh, Skin};
+use crate::mesh::{BoundingBox, Bounds};
+use crate::{Camera, Document, Material, Mesh, Primitive, Skin};
+
+#[cfg(feature = "names")]
+use std::collections::HashMap;
 
 /// Iterators.
 pub mod iter;
@@ -90,11 +94,80 @@ pub struct Node<'a> {
     json: &'a json::scene::Node,
 }
 
+/// An entry of the draw list of a scene, with everything needed to draw a
+/// primitive once.
+///
+/// Returned by [`Scene::draw_list`].
+///
+/// [`Scene::draw_list`]: struct.Scene.html#method.draw_list
+#[derive(Clone, Debug)]
+pub struct Draw<'a> {
+    /// The node instantiating the mesh.
+    pub node: Node<'a>,
+
+    /// The primitive to draw.
+    pub primitive: Primitive<'a>,
+
+    /// The material of the primitive.
+    pub material: Material<'a>,
+
+    /// The world transform of the primitive as a 4x4 matrix in column-major
+    /// order, including the transform of the instance, if any.
+    pub transform: [[f32; 4]; 4],
+
+    /// The skin of the node, if any.
+    pub skin: Option<Skin<'a>>,
+
+    /// The index of the instance defined by the `EXT_mesh_gpu_instancing`
+    /// extension, if any.
+    pub instance: Option<usize>,
+}
+
+/// The world transforms of the nodes of a scene, indexed by node.
+///
+/// The transforms are computed once by traversing the scene hierarchy, which
+/// makes looking up the transform of any node cheap afterwards.
+#[derive(Clone, Debug)]
+pub struct TransformCache {
+    /// The world transforms in column-major order, or `None` for nodes
+    /// outside of the scene.
+    transforms: Vec<Option<[[f64; 4]; 4]>>,
+}
+
+/// The parent of every node of a document, which the JSON does not store.
+///
+/// Returned by [`Document::build_parent_index`].
+///
+/// [`Document::build_parent_index`]: ../struct.Document.html#method.build_parent_index
+#[derive(Clone, Debug)]
+pub struct ParentIndex<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The index of the parent of every node, if any.
+    parents: Vec<Option<usize>>,
+}
+
+/// The nodes of a document by name.
+///
+/// Returned by [`Document::build_name_index`].
+///
+/// [`Document::build_name_index`]: ../struct.Document.html#method.build_name_index
+#[cfg(feature = "names")]
+#[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+#[derive(Clone, Debug)]
+pub struct NameIndex<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The indices of the nodes with each name, in ascending order.
+    names: HashMap<&'a str, Vec<usize>>,
+}
+
 /// The root nodes of a scene.
 #[derive(Clone, Debug)]
 pub struct Scene<'a> {
     /// The parent `Document` struct.
-    #[allow(dead_code)]
     document: &'a Document,
 
     /// The corresponding JSON index.
@@ -143,6 +216,108 @@ impl<'a> Node<'a> {
         &self.json.extras
     }
 
+    /// Returns the positional audio emitter attached to this node as defined by
+    /// the `KHR_audio` extension.
+    #[cfg(feature = "KHR_audio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+    pub fn audio_emitter(&self) -> Option<crate::khr_audio::Emitter<'a>> {
+        let khr_audio = self.json.extensions.as_ref()?.khr_audio.as_ref()?;
+        self.document.audio_emitters()?.nth(khr_audio.emitter.value())
+    }
+
+    /// Returns whether this node is visible as defined by the
+    /// `KHR_node_visibility` extension.
+    ///
+    /// Nodes are visible by default. A node that is not visible hides all of its
+    /// descendants, regardless of their own visibility; use
+    /// `Scene::visible_nodes` to visit only the nodes that should be rendered.
+    #[cfg(feature = "KHR_node_visibility")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_node_visibility")))]
+    pub fn visible(&self) -> bool {
+        self.json
+            .extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_node_visibility.as_ref())
+            .map_or(true, |visibility| visibility.visible)
+    }
+
+    /// Returns the physics properties of this node as defined by the
+    /// `KHR_physics_rigid_bodies` extension.
+    #[cfg(feature = "KHR_physics_rigid_bodies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_physics_rigid_bodies")))]
+    pub fn physics(&self) -> Option<crate::khr_physics_rigid_bodies::Physics<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .khr_physics_rigid_bodies
+            .as_ref()
+            .map(|json| crate::khr_physics_rigid_bodies::Physics::new(self.document, json))
+    }
+
+    /// Returns the IES light profile instantiated at this node as defined by the
+    /// `EXT_lights_ies` extension.
+    #[cfg(feature = "EXT_lights_ies")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_lights_ies")))]
+    pub fn ies_light(&self) -> Option<crate::ext_lights_ies::Light<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .ext_lights_ies
+            .as_ref()
+            .map(|json| crate::ext_lights_ies::Light::new(self.document, json))
+    }
+
+    /// Returns the per-instance attributes of this node as defined by the
+    /// `EXT_mesh_gpu_instancing` extension.
+    #[cfg(feature = "EXT_mesh_gpu_instancing")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_mesh_gpu_instancing")))]
+    pub fn instancing(&self) -> Option<crate::ext_mesh_gpu_instancing::Instancing<'a>> {
+        self.json
+            .extensions
+            .as_ref()?
+            .ext_mesh_gpu_instancing
+            .as_ref()
+            .map(|json| crate::ext_mesh_gpu_instancing::Instancing::new(self.document, json))
+    }
+
+    /// Returns an `Iterator` that visits the nodes replacing this node at
+    /// successively lower levels of detail as defined by the `MSFT_lod`
+    /// extension.
+    ///
+    /// The iterator is empty if the node has no lower levels of detail.
+    #[cfg(feature = "MSFT_lod")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_lod")))]
+    pub fn lod_levels(&self) -> iter::LodLevels<'a> {
+        let ids = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.msft_lod.as_ref())
+            .map(|lod| lod.ids.as_slice())
+            .unwrap_or(&[]);
+        iter::LodLevels {
+            document: self.document,
+            iter: ids.iter(),
+        }
+    }
+
+    /// Returns the screen coverage thresholds of the levels of detail of this
+    /// node, as stored in the `MSFT_screencoverage` extras property alongside
+    /// the `MSFT_lod` extension.
+    ///
+    /// The first value applies to this node and the following values to the
+    /// nodes returned by `lod_levels`, in order.
+    #[cfg(all(feature = "MSFT_lod", feature = "extras"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "MSFT_lod", feature = "extras"))))]
+    pub fn lod_screen_coverage(&self) -> Option<Vec<f32>> {
+        let extras = self.json.extras.as_ref()?;
+        let extras: json::Value = json::deserialize::from_str(extras.get()).ok()?;
+        extras
+            .get("MSFT_screencoverage")?
+            .as_array()?
+            .iter()
+            .map(|value| value.as_f64().map(|value| value as f32))
+            .collect()
+    }
+
     /// Returns the light at this node as defined by the `KHR_lights_punctual` extension.
     #[cfg(feature = "KHR_lights_punctual")]
     #[cfg_attr(docsrs, doc(cfg(feature = "KHR_lights_punctual")))]
@@ -206,6 +381,146 @@ impl<'a> Node<'a> {
     pub fn weights(&self) -> Option<&'a [f32]> {
         self.json.weights.as_ref().map(Vec::as_slice)
     }
+
+    /// Returns the world transform of this node within `scene` as a 4x4
+    /// matrix in column-major order, or `None` if the node is not part of the
+    /// scene hierarchy.
+    ///
+    /// This traverses the scene. Use a [`TransformCache`] to look up the
+    /// transforms of many nodes.
+    ///
+    /// [`TransformCache`]: struct.TransformCache.html
+    pub fn world_transform(&self, scene: &Scene<'a>) -> Option<[[f32; 4]; 4]> {
+        scene
+            .world_transforms()
+            .find(|(node, _)| node.index() == self.index)
+            .map(|(_, transform)| iter::to_f32(&transform))
+    }
+}
+
+impl<'a> ParentIndex<'a> {
+    /// Builds the index of the parents of the nodes of `document`.
+    pub(crate) fn new(document: &'a Document) -> Self {
+        let mut parents = vec![None; document.nodes().len()];
+        for node in document.nodes() {
+            for child in node.json.children.iter().flatten() {
+                if let Some(parent) = parents.get_mut(child.value()) {
+                    parent.get_or_insert(node.index);
+                }
+            }
+        }
+        Self { document, parents }
+    }
+
+    /// Returns the parent of a node, or `None` for root nodes.
+    pub fn parent(&self, node: &Node) -> Option<Node<'a>> {
+        self.parent_of(node.index())
+    }
+
+    /// Returns the parent of the node at `index`, or `None` for root nodes.
+    pub(crate) fn parent_of(&self, index: usize) -> Option<Node<'a>> {
+        self.parents
+            .get(index)
+            .copied()
+            .flatten()
+            .map(|index| self.document.nodes().nth(index).unwrap())
+    }
+
+    /// Returns an `Iterator` that visits the ancestors of a node, from its
+    /// parent up to the root node.
+    pub fn ancestors<'b>(&'b self, node: &Node) -> iter::Ancestors<'a, 'b> {
+        iter::Ancestors {
+            index: self,
+            node: node.index(),
+            // Stops on cycles, which are not valid glTF.
+            remaining: self.parents.len(),
+        }
+    }
+}
+
+#[cfg(feature = "names")]
+impl<'a> NameIndex<'a> {
+    /// Builds the index of the names of the nodes of `document`.
+    pub(crate) fn new(document: &'a Document) -> Self {
+        let mut names = HashMap::<_, Vec<_>>::new();
+        for node in document.nodes() {
+            if let Some(name) = node.name() {
+                names.entry(name).or_default().push(node.index);
+            }
+        }
+        Self { document, names }
+    }
+
+    /// Returns the first node with the given name.
+    pub fn get(&self, name: &str) -> Option<Node<'a>> {
+        self.get_all(name).into_iter().next()
+    }
+
+    /// Returns all nodes with the given name, in document order.
+    pub fn get_all(&self, name: &str) -> Vec<Node<'a>> {
+        self.names
+            .get(name)
+            .into_iter()
+            .flatten()
+            .map(|&index| self.document.nodes().nth(index).unwrap())
+            .collect()
+    }
+}
+
+/// Appends an entry for every primitive of the mesh of a node.
+fn push_draws<'a>(draws: &mut Vec<Draw<'a>>, node: &Node<'a>, transform: [[f32; 4]; 4], instance: Option<usize>) {
+    for primitive in node.mesh().iter().flat_map(Mesh::primitives) {
+        draws.push(Draw {
+            node: node.clone(),
+            material: primitive.material(),
+            primitive,
+            transform,
+            skin: node.skin(),
+            instance,
+        });
+    }
+}
+
+/// Transforms a position by a 4x4 affine transform in column-major order.
+fn transform_point(m: &[[f32; 4]; 4], [x, y, z]: [f32; 3]) -> [f32; 3] {
+    let mut p = [0.0; 3];
+    for (row, value) in p.iter_mut().enumerate() {
+        *value = m[0][row] * x + m[1][row] * y + m[2][row] * z + m[3][row];
+    }
+    p
+}
+
+/// Grows a bounding box to include a position.
+fn extend(bounds: &mut Option<BoundingBox>, position: [f32; 3]) {
+    let bounds = bounds.get_or_insert(Bounds { min: position, max: position });
+    for ((min, max), x) in bounds.min.iter_mut().zip(&mut bounds.max).zip(position) {
+        *min = min.min(x);
+        *max = max.max(x);
+    }
+}
+
+impl TransformCache {
+    /// Computes the world transforms of the nodes of `scene`.
+    pub fn new(scene: &Scene) -> Self {
+        let mut transforms = vec![None; scene.document.nodes().len()];
+        for (node, transform) in scene.world_transforms() {
+            transforms[node.index()].get_or_insert(transform);
+        }
+        Self { transforms }
+    }
+
+    /// Returns the world transform of a node as a 4x4 matrix in
+    /// column-major order, or `None` if the node is not part of the scene
+    /// hierarchy.
+    pub fn get(&self, node: &Node) -> Option<[[f32; 4]; 4]> {
+        self.get_f64(node).map(|transform| iter::to_f32(&transform))
+    }
+
+    /// Returns the world transform of a node in double precision, or `None`
+    /// if the node is not part of the scene hierarchy.
+    pub fn get_f64(&self, node: &Node) -> Option<[[f64; 4]; 4]> {
+        self.transforms.get(node.index()).copied().flatten()
+    }
 }
 
 impl<'a> Scene<'a> {
@@ -238,6 +553,306 @@ impl<'a> Scene<'a> {
         self.json.name.as_ref().map(String::as_str)
     }
 
+    /// Returns an `Iterator` that visits the global audio emitters of the scene as
+    /// defined by the `KHR_audio` extension.
+    ///
+    /// The iterator is empty if the scene has no audio emitters.
+    #[cfg(feature = "KHR_audio")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_audio")))]
+    pub fn audio_emitters(&self) -> crate::khr_audio::Emitters<'a> {
+        let emitters = self.json.extensions
+            .as_ref()
+            .and_then(|extensions| extensions.khr_audio.as_ref())
+            .map(|khr_audio| khr_audio.emitters.as_slice())
+            .unwrap_or(&[]);
+        crate::khr_audio::Emitters {
+            document: self.document,
+            iter: emitters.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene hierarchy that is
+    /// visible as defined by the `KHR_node_visibility` extension.
+    ///
+    /// The nodes are visited depth-first, each at most once. The descendants
+    /// of a node that is not visible are skipped.
+    #[cfg(feature = "KHR_node_visibility")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_node_visibility")))]
+    pub fn visible_nodes(&self) -> iter::VisibleNodes<'a> {
+        let mut stack = self.nodes().collect::<Vec<_>>();
+        stack.reverse();
+        let visited = vec![false; self.document.nodes().len()];
+        iter::VisibleNodes { stack, visited }
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene hierarchy
+    /// depth-first, together with its depth and its world transform.
+    ///
+    /// Each node is visited at most once, even if the hierarchy contains
+    /// cycles, which are not valid glTF.
+    ///
+    /// The descendants of the last node visited may be skipped with
+    /// [`DepthFirst::skip_children`].
+    ///
+    /// [`DepthFirst::skip_children`]: iter/struct.DepthFirst.html#method.skip_children
+    pub fn depth_first(&self) -> iter::DepthFirst<'a> {
+        iter::DepthFirst::new(self.nodes(), iter::IDENTITY)
+    }
+
+    /// Calls `visitor` for every node of the scene hierarchy depth-first,
+    /// with its depth and its world transform.
+    ///
+    /// The descendants of a node are skipped if `visitor` returns `false`
+    /// for it.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// let scene = document.default_scene().unwrap();
+    /// scene.traverse(|node, depth, transform| {
+    ///     println!("{}{} at {:?}", "  ".repeat(depth), node.index(), transform[3]);
+    ///     true
+    /// });
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn traverse<F>(&self, mut visitor: F)
+    where
+        F: FnMut(&Node<'a>, usize, [[f32; 4]; 4]) -> bool,
+    {
+        let mut nodes = self.depth_first();
+        while let Some((node, depth, transform)) = nodes.next() {
+            if !visitor(&node, depth, transform) {
+                nodes.skip_children();
+            }
+        }
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene hierarchy
+    /// together with its world transform.
+    ///
+    /// The nodes are visited depth-first. The world transforms are 4x4 matrices
+    /// in column-major order, accumulated in double precision.
+    pub fn world_transforms(&self) -> iter::WorldTransforms<'a> {
+        iter::WorldTransforms::new(self.nodes(), iter::IDENTITY)
+    }
+
+    /// Returns an `Iterator` that visits every node of the scene hierarchy
+    /// together with its world transform, offset by the relative-to-center
+    /// origin defined by the `CESIUM_RTC` extension.
+    ///
+    /// The center is applied in double precision, so that large coordinates
+    /// such as Earth-centered, Earth-fixed positions keep their precision. If
+    /// the asset does not define a center, this is the same as
+    /// `world_transforms`.
+    #[cfg(feature = "CESIUM_RTC")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "CESIUM_RTC")))]
+    pub fn rtc_world_transforms(&self) -> iter::WorldTransforms<'a> {
+        let mut origin = iter::IDENTITY;
+        if let Some(center) = self.document.rtc_center() {
+            origin[3] = [center[0], center[1], center[2], 1.0];
+        }
+        iter::WorldTransforms::new(self.nodes(), origin)
+    }
+
+    /// Returns the node at a path of node names separated by `/`, starting
+    /// with the name of a root node of the scene, e.g. `"Root/Arm/Hand"`.
+    ///
+    /// If several nodes match, the first one in depth-first order is
+    /// returned. Nodes with names containing `/` cannot be found this way.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn find(&self, path: &str) -> Option<Node<'a>> {
+        let mut segments = path.split('/');
+        let first = segments.next()?;
+        let mut nodes = self.nodes().filter(|node| node.name() == Some(first)).collect::<Vec<_>>();
+        for segment in segments {
+            nodes = nodes
+                .iter()
+                .flat_map(Node::children)
+                .filter(|node| node.name() == Some(segment))
+                .collect();
+        }
+        nodes.into_iter().next()
+    }
+
+    /// Flattens the scene hierarchy into a list of the primitives to draw,
+    /// with their materials, world transforms, and skins, in depth-first
+    /// order of their nodes.
+    ///
+    /// Instances defined by the `EXT_mesh_gpu_instancing` extension are not
+    /// expanded. See [`instanced_draw_list`] to expand them.
+    ///
+    /// [`instanced_draw_list`]: #method.instanced_draw_list
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// let scene = document.default_scene().unwrap();
+    /// for draw in scene.draw_list() {
+    ///     println!("{:?} at {:?}", draw.material.index(), draw.transform[3]);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn draw_list(&self) -> Vec<Draw<'a>> {
+        let mut draws = Vec::new();
+        for (node, _, transform) in self.depth_first() {
+            push_draws(&mut draws, &node, transform, None);
+        }
+        draws
+    }
+
+    /// Flattens the scene hierarchy into a list of the primitives to draw,
+    /// as with [`draw_list`], with one entry per instance of nodes using the
+    /// `EXT_mesh_gpu_instancing` extension.
+    ///
+    /// The transforms of instances are applied after the world transforms
+    /// of their nodes. Nodes whose instance attributes cannot be read
+    /// contribute no entries.
+    ///
+    /// [`draw_list`]: #method.draw_list
+    #[cfg(all(feature = "EXT_mesh_gpu_instancing", feature = "utils"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "EXT_mesh_gpu_instancing", feature = "utils"))))]
+    pub fn instanced_draw_list<'s, F>(&self, get_buffer_data: F) -> Vec<Draw<'a>>
+    where
+        F: Clone + Fn(crate::Buffer<'a>) -> Option<&'s [u8]>,
+    {
+        let mut draws = Vec::new();
+        let mut nodes = self.depth_first();
+        while let Some((node, _, world)) = nodes.visit() {
+            let instancing = match node.instancing() {
+                Some(instancing) => instancing,
+                None => {
+                    push_draws(&mut draws, &node, iter::to_f32(&world), None);
+                    continue;
+                }
+            };
+            let reader = instancing.reader(get_buffer_data.clone());
+            let matrices = reader.read_world_matrices(iter::to_f32(&world));
+            for (instance, transform) in matrices.into_iter().flatten().enumerate() {
+                push_draws(&mut draws, &node, transform, Some(instance));
+            }
+        }
+        draws
+    }
+
+    /// Returns the bounding box of the primitives of the scene in world
+    /// space, e.g. to frame a camera on the scene.
+    ///
+    /// This trusts the `min` and `max` properties of the `POSITION`
+    /// accessors, whose boxes are transformed to world space, which is fast
+    /// but may be loose. See [`compute_bounds`] for a tight box computed from
+    /// the vertex positions. Returns `None` if the scene has no primitives.
+    ///
+    /// [`compute_bounds`]: #method.compute_bounds
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        let mut bounds = None;
+        for draw in self.draw_list() {
+            let Bounds { min, max } = draw.primitive.bounding_box();
+            for corner in 0..8 {
+                let mut position = min;
+                for (axis, value) in position.iter_mut().enumerate() {
+                    if corner & (1 << axis) != 0 {
+                        *value = max[axis];
+                    }
+                }
+                extend(&mut bounds, transform_point(&draw.transform, position));
+            }
+        }
+        bounds
+    }
+
+    /// Computes the bounding box of the primitives of the scene in world
+    /// space from their vertex positions.
+    ///
+    /// The positions are displaced by the morph targets with the weights of
+    /// their node or mesh. Skinning is not applied. Primitives whose
+    /// positions cannot be read are skipped. Returns `None` if no positions
+    /// are read.
+    #[cfg(feature = "utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "utils")))]
+    pub fn compute_bounds<'s, F>(&self, get_buffer_data: F) -> Option<BoundingBox>
+    where
+        F: Clone + Fn(crate::Buffer<'_>) -> Option<&'s [u8]>,
+    {
+        let mut bounds = None;
+        for draw in self.draw_list() {
+            let weights = draw
+                .node
+                .weights()
+                .or_else(|| draw.node.mesh().and_then(|mesh| mesh.weights()))
+                .unwrap_or(&[]);
+            let reader = draw.primitive.reader(get_buffer_data.clone());
+            for position in reader.read_blended_positions(weights).into_iter().flatten() {
+                extend(&mut bounds, transform_point(&draw.transform, position));
+            }
+        }
+        bounds
+    }
+
+    /// Returns an `Iterator` that visits the cameras of the scene hierarchy
+    /// depth-first, together with the nodes referencing them and their world
+    /// transforms.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, _) = gltf::import("examples/Box.gltf")?;
+    /// let scene = document.default_scene().unwrap();
+    /// for (camera, node, transform) in scene.cameras() {
+    ///     let view = camera.view_matrix(transform);
+    ///     println!("camera {} of node {}: {:?}", camera.index(), node.index(), view);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    pub fn cameras(&self) -> iter::Cameras<'a> {
+        iter::Cameras { depth_first: self.depth_first() }
+    }
+
+    /// Returns the node hierarchy of the scene as a directed graph with edges
+    /// from parents to their children.
+    ///
+    /// Only the nodes of the scene are in the graph, in depth-first order.
+    /// The weight of each graph node is the index of the glTF node.
+    #[cfg(feature = "petgraph")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "petgraph")))]
+    pub fn node_graph(&self) -> petgraph::graph::DiGraph<usize, ()> {
+        use petgraph::graph::{DiGraph, NodeIndex};
+        let mut graph = DiGraph::new();
+        let mut indices = vec![None::<NodeIndex>; self.document.nodes().len()];
+        let mut stack = self.nodes().map(|node| (node, None)).collect::<Vec<_>>();
+        stack.reverse();
+        while let Some((node, parent)) = stack.pop() {
+            let index = match indices.get(node.index()) {
+                Some(Some(index)) => *index,
+                // Nodes are only visited once, even in invalid hierarchies.
+                Some(None) => {
+                    let index = graph.add_node(node.index());
+                    indices[node.index()] = Some(index);
+                    let start = stack.len();
+                    stack.extend(node.children().map(|child| (child, Some(index))));
+                    stack[start..].reverse();
+                    index
+                }
+                None => continue,
+            };
+            if let Some(parent) = parent {
+                graph.add_edge(parent, index, ());
+            }
+        }
+        graph
+    }
+
     /// Returns an `Iterator` that visits each root node of the scene.
     pub fn nodes(&self) -> iter::Nodes<'a> {
         iter::Nodes {
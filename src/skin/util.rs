@@ -24,6 +24,6 @@ where
     pub fn read_inverse_bind_matrices(&self) -> Option<ReadInverseBindMatrices<'s>> {
         self.skin
             .inverse_bind_matrices()
-            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()))
+            .and_then(|accessor| accessor::Iter::new(accessor, self.get_buffer_data.clone()).ok())
     }
 }
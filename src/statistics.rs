@@ -0,0 +1,190 @@
+use crate::mesh::{Mode, Semantic};
+use crate::Document;
+
+/// Summary of the contents of a document, such as for asset QA.
+///
+/// Returned by [`Document::statistics`]. Enabling the `serde` feature makes
+/// the summary serializable, for example to JSON with `gltf::json::serialize`.
+///
+/// [`Document::statistics`]: ../struct.Document.html#method.statistics
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Statistics {
+    /// The number of scenes.
+    pub scenes: usize,
+
+    /// The number of nodes.
+    pub nodes: usize,
+
+    /// The number of meshes.
+    pub meshes: usize,
+
+    /// The number of mesh primitives.
+    pub primitives: usize,
+
+    /// The number of vertices, summed over all primitives.
+    ///
+    /// Vertices shared by several primitives are counted once per primitive.
+    pub vertices: usize,
+
+    /// The number of triangles drawn by triangle list, strip, and fan
+    /// primitives.
+    pub triangles: usize,
+
+    /// The number of line segments drawn by line list, strip, and loop
+    /// primitives.
+    pub lines: usize,
+
+    /// The number of points drawn by point primitives.
+    pub points: usize,
+
+    /// The number of materials.
+    pub materials: usize,
+
+    /// The number of textures.
+    pub textures: usize,
+
+    /// The number of animations.
+    pub animations: usize,
+
+    /// The number of skins.
+    pub skins: usize,
+
+    /// The number of cameras.
+    pub cameras: usize,
+
+    /// The length in bytes of every buffer.
+    pub buffer_byte_lengths: Vec<usize>,
+
+    /// The length in bytes of every buffer view.
+    pub buffer_view_byte_lengths: Vec<usize>,
+
+    /// The length in bytes of the elements of every accessor.
+    pub accessor_byte_lengths: Vec<usize>,
+
+    /// The statistics of every image.
+    pub images: Vec<ImageStatistics>,
+
+    /// The names of the extensions used by the document.
+    pub extensions_used: Vec<String>,
+
+    /// The names of the extensions required to load the document.
+    pub extensions_required: Vec<String>,
+}
+
+/// Summary of an image and of its use by textures.
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ImageStatistics {
+    /// The MIME type of the image, if specified by the document.
+    pub mime_type: Option<String>,
+
+    /// The length in bytes of the encoded image, if stored in a buffer view.
+    pub byte_length: Option<usize>,
+
+    /// The width of the image in pixels, if known.
+    ///
+    /// See [`Statistics::with_image_data`].
+    ///
+    /// [`Statistics::with_image_data`]: struct.Statistics.html#method.with_image_data
+    pub width: Option<u32>,
+
+    /// The height of the image in pixels, if known.
+    pub height: Option<u32>,
+
+    /// The number of textures sampling the image.
+    pub textures: usize,
+}
+
+impl Statistics {
+    /// Gathers the statistics of a document.
+    pub(crate) fn new(document: &Document) -> Self {
+        let mut statistics = Statistics {
+            scenes: document.scenes().len(),
+            nodes: document.nodes().len(),
+            meshes: document.meshes().len(),
+            materials: document.materials().len(),
+            textures: document.textures().len(),
+            animations: document.animations().len(),
+            skins: document.skins().len(),
+            cameras: document.cameras().len(),
+            buffer_byte_lengths: document.buffers().map(|buffer| buffer.length()).collect(),
+            buffer_view_byte_lengths: document.views().map(|view| view.length()).collect(),
+            accessor_byte_lengths: document
+                .accessors()
+                .map(|accessor| accessor.count() * accessor.size())
+                .collect(),
+            extensions_used: document.extensions_used().map(str::to_owned).collect(),
+            extensions_required: document.extensions_required().map(str::to_owned).collect(),
+            ..Default::default()
+        };
+
+        for primitive in document.meshes().flat_map(|mesh| mesh.primitives().collect::<Vec<_>>()) {
+            let vertices = primitive.get(&Semantic::Positions).map_or(0, |accessor| accessor.count());
+            let n = primitive.indices().map_or(vertices, |accessor| accessor.count());
+            statistics.primitives += 1;
+            statistics.vertices += vertices;
+            match primitive.mode() {
+                Mode::Points => statistics.points += n,
+                Mode::Lines => statistics.lines += n / 2,
+                Mode::LineLoop if n > 1 => statistics.lines += n,
+                Mode::LineLoop | Mode::LineStrip => statistics.lines += n.saturating_sub(1),
+                Mode::Triangles => statistics.triangles += n / 3,
+                Mode::TriangleStrip | Mode::TriangleFan => statistics.triangles += n.saturating_sub(2),
+            }
+        }
+
+        statistics.images = document
+            .images()
+            .map(|image| match image.source() {
+                crate::image::Source::View { view, mime_type } => ImageStatistics {
+                    mime_type: Some(mime_type.to_owned()),
+                    byte_length: Some(view.length()),
+                    ..Default::default()
+                },
+                crate::image::Source::Uri { mime_type, .. } => ImageStatistics {
+                    mime_type: mime_type.map(str::to_owned),
+                    ..Default::default()
+                },
+            })
+            .collect();
+        for texture in document.textures() {
+            if let Some(image) = statistics.images.get_mut(texture.source().index()) {
+                image.textures += 1;
+            }
+        }
+
+        statistics
+    }
+
+    /// Fills in the resolutions of the images from the image data of an
+    /// imported document.
+    ///
+    /// ```
+    /// # fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let (document, _, images) = gltf::import("examples/Box.gltf")?;
+    /// let statistics = document.statistics().with_image_data(&images);
+    /// for image in &statistics.images {
+    ///     println!("{:?}x{:?}", image.width, image.height);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// # fn main() {
+    /// #    let _ = run().expect("runtime error");
+    /// # }
+    /// ```
+    #[cfg(feature = "import")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "import")))]
+    pub fn with_image_data(mut self, images: &[crate::image::Data]) -> Self {
+        for (image, data) in self.images.iter_mut().zip(images) {
+            image.width = Some(data.width);
+            image.height = Some(data.height);
+        }
+        self
+    }
+
+    /// Returns the total length in bytes of all buffers.
+    pub fn total_buffer_byte_length(&self) -> usize {
+        self.buffer_byte_lengths.iter().sum()
+    }
+}
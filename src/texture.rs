@@ -139,8 +139,101 @@ impl<'a> Texture<'a> {
     }
 
     /// Returns the image used by this texture.
+    ///
+    /// When `EXT_texture_webp` or `MSFT_texture_dds` is enabled, the WebP or DDS
+    /// image is preferred over the standard source since the importer is able to
+    /// decode it. When the texture has no standard source, the image provided by
+    /// another supported extension is returned instead (e.g. the KTX2 image of
+    /// `KHR_texture_basisu`).
     pub fn source(&self) -> image::Image<'a> {
-        self.document.images().nth(self.json.source.value() as usize).unwrap()
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            if let Some(image) = self.webp_source() {
+                return image;
+            }
+        }
+
+        #[cfg(feature = "MSFT_texture_dds")]
+        {
+            if let Some(image) = self.dds_source() {
+                return image;
+            }
+        }
+
+        #[cfg(not(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds")))]
+        let index = self.json.source;
+        #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+        let index = match self.json.source {
+            Some(index) => index,
+            // NOTE: cannot panic if validated, since textures without a
+            // standard source are only valid when an extension provides one
+            None => self.extension_source().unwrap(),
+        };
+        self.document.images().nth(index.value()).unwrap()
+    }
+
+    /// Returns the image provided by a supported extension, if any.
+    ///
+    /// The extensions are the ones accepted as a source by validation.
+    #[cfg(any(feature = "KHR_texture_basisu", feature = "EXT_texture_webp", feature = "MSFT_texture_dds"))]
+    fn extension_source(&self) -> Option<json::Index<json::image::Image>> {
+        let extensions = self.json.extensions.as_ref()?;
+
+        #[cfg(feature = "KHR_texture_basisu")]
+        {
+            if let Some(basisu) = extensions.texture_basisu.as_ref() {
+                return Some(basisu.source);
+            }
+        }
+
+        #[cfg(feature = "EXT_texture_webp")]
+        {
+            if let Some(webp) = extensions.texture_webp.as_ref() {
+                return Some(webp.source);
+            }
+        }
+
+        #[cfg(feature = "MSFT_texture_dds")]
+        {
+            if let Some(dds) = extensions.texture_dds.as_ref() {
+                return Some(dds.source);
+            }
+        }
+
+        None
+    }
+
+    /// Returns the KTX2 image provided by the `KHR_texture_basisu` extension.
+    #[cfg(feature = "KHR_texture_basisu")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "KHR_texture_basisu")))]
+    pub fn basisu_source(&self) -> Option<image::Image<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .texture_basisu
+            .as_ref()
+            .map(|x| self.document.images().nth(x.source.value()).unwrap())
+    }
+
+    /// Returns the WebP image provided by the `EXT_texture_webp` extension.
+    #[cfg(feature = "EXT_texture_webp")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "EXT_texture_webp")))]
+    pub fn webp_source(&self) -> Option<image::Image<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .texture_webp
+            .as_ref()
+            .map(|x| self.document.images().nth(x.source.value()).unwrap())
+    }
+
+    /// Returns the DDS image provided by the `MSFT_texture_dds` extension.
+    #[cfg(feature = "MSFT_texture_dds")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "MSFT_texture_dds")))]
+    pub fn dds_source(&self) -> Option<image::Image<'a>> {
+        self.json.extensions
+            .as_ref()?
+            .texture_dds
+            .as_ref()
+            .map(|x| self.document.images().nth(x.source.value()).unwrap())
     }
 
     /// Optional application specific data.
@@ -234,6 +327,36 @@ impl<'a> TextureTransform<'a> {
         self.json.tex_coord
     }
 
+    /// Returns the 3x3 matrix in column-major order transforming homogeneous
+    /// UV coordinates, i.e. `translation * rotation * scale`.
+    pub fn matrix(&self) -> [[f32; 3]; 3] {
+        let [x, y] = self.offset();
+        let [sx, sy] = self.scale();
+        let (sin, cos) = self.rotation().sin_cos();
+        [[sx * cos, -sx * sin, 0.0], [sy * sin, sy * cos, 0.0], [x, y, 1.0]]
+    }
+
+    /// Returns the matrix of [`matrix`] adjusted for UV coordinates with the
+    /// V coordinate flipped, as `[u, 1.0 - v]`.
+    ///
+    /// Transforming flipped coordinates with this matrix is the same as
+    /// flipping coordinates transformed with the original matrix.
+    ///
+    /// [`matrix`]: #method.matrix
+    pub fn matrix_flipped_v(&self) -> [[f32; 3]; 3] {
+        let flip = [[1.0, 0.0, 0.0], [0.0, -1.0, 0.0], [0.0, 1.0, 1.0]];
+        let multiply = |a: [[f32; 3]; 3], b: [[f32; 3]; 3]| {
+            let mut m = [[0.0; 3]; 3];
+            for (column, b) in m.iter_mut().zip(&b) {
+                for (row, value) in column.iter_mut().enumerate() {
+                    *value = (0..3).map(|k| a[k][row] * b[k]).sum();
+                }
+            }
+            m
+        };
+        multiply(multiply(flip, self.matrix()), flip)
+    }
+
     /// Optional application specific data.
     pub fn extras(&self) -> &json::Extras {
         &self.json.extras
@@ -0,0 +1,194 @@
+use crate::{Accessor, Animation, Document, Material, Mesh, Node, Primitive, Skin, Texture};
+
+/// A resource that may be referenced by other objects of a document.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Resource {
+    /// The accessor with the given index.
+    Accessor(usize),
+
+    /// The material with the given index, or the default material.
+    Material(Option<usize>),
+
+    /// The mesh with the given index.
+    Mesh(usize),
+
+    /// The texture with the given index.
+    Texture(usize),
+}
+
+/// An object referencing a resource.
+///
+/// Returned by [`Document::users_of`].
+///
+/// [`Document::users_of`]: ../struct.Document.html#method.users_of
+#[derive(Clone, Debug)]
+pub enum User<'a> {
+    /// An animation with a sampler reading the accessor.
+    Animation(Animation<'a>),
+
+    /// A material with a texture slot referencing the texture.
+    Material(Material<'a>),
+
+    /// A node instantiating the mesh, or reading the accessor for the
+    /// instances defined by the `EXT_mesh_gpu_instancing` extension.
+    Node(Node<'a>),
+
+    /// A primitive drawn with the material, including through a
+    /// `KHR_materials_variants` mapping, or reading the accessor for its
+    /// vertex attributes, indices, or morph targets.
+    Primitive(Primitive<'a>),
+
+    /// A skin reading the accessor for its inverse bind matrices.
+    Skin(Skin<'a>),
+}
+
+impl<'a> From<&Accessor<'a>> for Resource {
+    fn from(accessor: &Accessor<'a>) -> Self {
+        Resource::Accessor(accessor.index())
+    }
+}
+
+impl<'a> From<&Material<'a>> for Resource {
+    fn from(material: &Material<'a>) -> Self {
+        Resource::Material(material.index())
+    }
+}
+
+impl<'a> From<&Mesh<'a>> for Resource {
+    fn from(mesh: &Mesh<'a>) -> Self {
+        Resource::Mesh(mesh.index())
+    }
+}
+
+impl<'a> From<&Texture<'a>> for Resource {
+    fn from(texture: &Texture<'a>) -> Self {
+        Resource::Texture(texture.index())
+    }
+}
+
+/// Returns the objects of `document` referencing `resource`.
+pub(crate) fn users_of(document: &Document, resource: Resource) -> Vec<User<'_>> {
+    let mut users = Vec::new();
+    match resource {
+        Resource::Accessor(index) => {
+            let primitives = document.meshes().flat_map(|mesh| mesh.primitives().collect::<Vec<_>>());
+            for primitive in primitives {
+                let mut accessors = primitive.attributes().map(|(_, accessor)| accessor).collect::<Vec<_>>();
+                accessors.extend(primitive.indices());
+                for target in primitive.morph_targets() {
+                    accessors.extend(target.positions());
+                    accessors.extend(target.normals());
+                    accessors.extend(target.tangents());
+                }
+                if accessors.iter().any(|accessor| accessor.index() == index) {
+                    users.push(User::Primitive(primitive));
+                }
+            }
+            for skin in document.skins() {
+                if skin.inverse_bind_matrices().map(|accessor| accessor.index()) == Some(index) {
+                    users.push(User::Skin(skin));
+                }
+            }
+            for animation in document.animations() {
+                let mut samplers = animation.samplers();
+                if samplers.any(|sampler| sampler.input().index() == index || sampler.output().index() == index) {
+                    users.push(User::Animation(animation));
+                }
+            }
+            #[cfg(feature = "EXT_mesh_gpu_instancing")]
+            for node in document.nodes() {
+                let instancing = node.instancing();
+                let mut attributes = instancing.iter().flat_map(|instancing| instancing.attributes());
+                if attributes.any(|(_, accessor)| accessor.index() == index) {
+                    users.push(User::Node(node));
+                }
+            }
+        }
+        Resource::Material(index) => {
+            for mesh in document.meshes() {
+                for primitive in mesh.primitives() {
+                    #[cfg(feature = "KHR_materials_variants")]
+                    let mapped = primitive.mappings().any(|mapping| mapping.material().index() == index);
+                    #[cfg(not(feature = "KHR_materials_variants"))]
+                    let mapped = false;
+                    if primitive.material().index() == index || mapped {
+                        users.push(User::Primitive(primitive));
+                    }
+                }
+            }
+        }
+        Resource::Mesh(index) => {
+            for node in document.nodes() {
+                if node.mesh().map(|mesh| mesh.index()) == Some(index) {
+                    users.push(User::Node(node));
+                }
+            }
+        }
+        Resource::Texture(index) => {
+            for material in document.materials() {
+                if textures(&material).contains(&index) {
+                    users.push(User::Material(material));
+                }
+            }
+        }
+    }
+    users
+}
+
+/// Returns the indices of the textures referenced by the texture slots of a
+/// material, including those of supported material extensions.
+fn textures(material: &Material) -> Vec<usize> {
+    let pbr = material.pbr_metallic_roughness();
+    let mut textures = Vec::new();
+    textures.extend([
+        pbr.base_color_texture().map(|info| info.texture()),
+        pbr.metallic_roughness_texture().map(|info| info.texture()),
+        material.normal_texture().map(|info| info.texture()),
+        material.occlusion_texture().map(|info| info.texture()),
+        material.emissive_texture().map(|info| info.texture()),
+    ]);
+    #[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+    if let Some(extension) = material.pbr_specular_glossiness() {
+        textures.push(extension.diffuse_texture().map(|info| info.texture()));
+        textures.push(extension.specular_glossiness_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_transmission")]
+    if let Some(extension) = material.transmission() {
+        textures.push(extension.transmission_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_volume")]
+    if let Some(extension) = material.volume() {
+        textures.push(extension.thickness_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_specular")]
+    if let Some(extension) = material.specular() {
+        textures.push(extension.specular_texture().map(|info| info.texture()));
+        textures.push(extension.specular_color_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_sheen")]
+    if let Some(extension) = material.sheen() {
+        textures.push(extension.sheen_color_texture().map(|info| info.texture()));
+        textures.push(extension.sheen_roughness_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_clearcoat")]
+    if let Some(extension) = material.clearcoat() {
+        textures.push(extension.clearcoat_texture().map(|info| info.texture()));
+        textures.push(extension.clearcoat_roughness_texture().map(|info| info.texture()));
+        textures.push(extension.clearcoat_normal_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_iridescence")]
+    if let Some(extension) = material.iridescence() {
+        textures.push(extension.iridescence_texture().map(|info| info.texture()));
+        textures.push(extension.iridescence_thickness_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_anisotropy")]
+    if let Some(extension) = material.anisotropy() {
+        textures.push(extension.anisotropy_texture().map(|info| info.texture()));
+    }
+    #[cfg(feature = "KHR_materials_diffuse_transmission")]
+    if let Some(extension) = material.diffuse_transmission() {
+        textures.push(extension.diffuse_transmission_texture().map(|info| info.texture()));
+        textures.push(extension.diffuse_transmission_color_texture().map(|info| info.texture()));
+    }
+    textures.into_iter().flatten().map(|texture| texture.index()).collect()
+}
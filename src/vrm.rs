@@ -0,0 +1,1214 @@
+use std::{collections::hash_map, iter, slice};
+
+use crate::{texture, Document, Image, Material, Node};
+
+pub use json::extensions::material::vrmc_materials_mtoon::OutlineWidthMode;
+pub use json::extensions::root::vrmc_vrm::{
+    AvatarPermission, CommercialUsage, CreditNotation, ExpressionOverride, FirstPersonType,
+    HumanBoneName, LookAtType, MaterialColorType, Modification, RangeMap,
+};
+
+/// The humanoid avatar description of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct Vrm<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_vrm::VrmcVrm,
+}
+
+/// The license and authorship information of an avatar.
+#[derive(Clone, Debug)]
+pub struct Meta<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_vrm::Meta,
+}
+
+/// The mapping of humanoid bones to nodes.
+#[derive(Clone, Debug)]
+pub struct Humanoid<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_vrm::Humanoid,
+}
+
+/// The first-person visibility of the mesh of a node.
+#[derive(Clone, Debug)]
+pub struct MeshAnnotation<'a> {
+    /// The node whose mesh is annotated.
+    pub node: Node<'a>,
+
+    /// In which views the mesh is visible.
+    pub kind: FirstPersonType,
+}
+
+/// How the eyes of an avatar are controlled.
+#[derive(Clone, Debug)]
+pub struct LookAt<'a> {
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_vrm::LookAt,
+}
+
+/// A facial expression, combining morph target weights and material property
+/// changes.
+#[derive(Clone, Debug)]
+pub struct Expression<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The name of the expression.
+    name: &'a str,
+
+    /// Whether the expression is one of the presets.
+    preset: bool,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_vrm::Expression,
+}
+
+/// Sets the weight of a morph target of the mesh of a node.
+#[derive(Clone, Debug)]
+pub struct MorphTargetBind<'a> {
+    /// The node whose mesh is affected.
+    pub node: Node<'a>,
+
+    /// The index of the morph target.
+    pub index: u32,
+
+    /// The weight of the morph target at full expression weight.
+    pub weight: f32,
+}
+
+/// Sets a color property of a material.
+#[derive(Clone, Debug)]
+pub struct MaterialColorBind<'a> {
+    /// The affected material.
+    pub material: Material<'a>,
+
+    /// The color property that is set.
+    pub kind: MaterialColorType,
+
+    /// The color at full expression weight.
+    pub target_value: [f32; 4],
+}
+
+/// Sets the texture transform of a material.
+#[derive(Clone, Debug)]
+pub struct TextureTransformBind<'a> {
+    /// The affected material.
+    pub material: Material<'a>,
+
+    /// The texture coordinate scale at full expression weight.
+    pub scale: [f32; 2],
+
+    /// The texture coordinate offset at full expression weight.
+    pub offset: [f32; 2],
+}
+
+/// The spring bone simulation of a glTF asset.
+#[derive(Clone, Debug)]
+pub struct SpringBone<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_spring_bone::VrmcSpringBone,
+}
+
+/// The shape of a spring bone collider.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ColliderShape {
+    /// A sphere.
+    Sphere {
+        /// The center of the sphere relative to the node.
+        offset: [f32; 3],
+
+        /// The radius of the sphere.
+        radius: f32,
+    },
+
+    /// A capsule.
+    Capsule {
+        /// The center of the first cap relative to the node.
+        offset: [f32; 3],
+
+        /// The radius of the capsule.
+        radius: f32,
+
+        /// The center of the second cap relative to the node.
+        tail: [f32; 3],
+    },
+}
+
+/// A collision shape attached to a node.
+#[derive(Clone, Debug)]
+pub struct Collider<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_spring_bone::Collider,
+}
+
+/// A named set of colliders.
+#[derive(Clone, Debug)]
+pub struct ColliderGroup<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_spring_bone::ColliderGroup,
+}
+
+/// A chain of joints swaying under inertia and gravity.
+#[derive(Clone, Debug)]
+pub struct Spring<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The corresponding JSON index.
+    index: usize,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_spring_bone::Spring,
+}
+
+/// A joint of a spring.
+#[derive(Clone, Debug)]
+pub struct Joint<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::root::vrmc_spring_bone::Joint,
+}
+
+/// The parameters of the MToon toon shading model.
+#[derive(Clone, Debug)]
+pub struct MToon<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::vrmc_materials_mtoon::MToon,
+}
+
+/// A texture shifting the boundary between lit and shaded areas.
+#[derive(Clone, Debug)]
+pub struct ShadingShiftTexture<'a> {
+    /// The parent `Texture` struct.
+    texture: texture::Texture<'a>,
+
+    /// The corresponding JSON struct.
+    json: &'a json::extensions::material::vrmc_materials_mtoon::ShadingShiftTexture,
+}
+
+/// An `Iterator` that visits the mapped bones of a humanoid.
+#[derive(Clone, Debug)]
+pub struct HumanBones<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal bone iterator.
+    iter: hash_map::Iter<
+        'a,
+        json::validation::Checked<HumanBoneName>,
+        json::extensions::root::vrmc_vrm::HumanBone,
+    >,
+}
+
+/// An `Iterator` that visits the first-person mesh annotations of an avatar.
+#[derive(Clone, Debug)]
+pub struct MeshAnnotations<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal mesh annotation iterator.
+    iter: slice::Iter<'a, json::extensions::root::vrmc_vrm::MeshAnnotation>,
+}
+
+/// An `Iterator` that visits the expressions of an avatar.
+#[derive(Clone, Debug)]
+pub struct Expressions<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal preset expression iterator.
+    preset: hash_map::Iter<'a, String, json::extensions::root::vrmc_vrm::Expression>,
+
+    /// The internal custom expression iterator.
+    custom: hash_map::Iter<'a, String, json::extensions::root::vrmc_vrm::Expression>,
+}
+
+/// An `Iterator` that visits the morph target binds of an expression.
+#[derive(Clone, Debug)]
+pub struct MorphTargetBinds<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal bind iterator.
+    iter: slice::Iter<'a, json::extensions::root::vrmc_vrm::MorphTargetBind>,
+}
+
+/// An `Iterator` that visits the material color binds of an expression.
+#[derive(Clone, Debug)]
+pub struct MaterialColorBinds<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal bind iterator.
+    iter: slice::Iter<'a, json::extensions::root::vrmc_vrm::MaterialColorBind>,
+}
+
+/// An `Iterator` that visits the texture transform binds of an expression.
+#[derive(Clone, Debug)]
+pub struct TextureTransformBinds<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal bind iterator.
+    iter: slice::Iter<'a, json::extensions::root::vrmc_vrm::TextureTransformBind>,
+}
+
+/// An `Iterator` that visits spring bone colliders.
+#[derive(Clone, Debug)]
+pub struct Colliders<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal collider iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::vrmc_spring_bone::Collider>>,
+}
+
+/// An `Iterator` that visits the colliders of a collider group.
+#[derive(Clone, Debug)]
+pub struct GroupColliders<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The internal collider index iterator.
+    iter: slice::Iter<'a, json::Index<json::extensions::root::vrmc_spring_bone::Collider>>,
+}
+
+/// An `Iterator` that visits spring bone collider groups.
+#[derive(Clone, Debug)]
+pub struct ColliderGroups<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The internal collider group iterator.
+    iter:
+        iter::Enumerate<slice::Iter<'a, json::extensions::root::vrmc_spring_bone::ColliderGroup>>,
+}
+
+/// An `Iterator` that visits the collider groups of a spring.
+#[derive(Clone, Debug)]
+pub struct SpringColliderGroups<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The internal collider group index iterator.
+    iter: slice::Iter<'a, json::Index<json::extensions::root::vrmc_spring_bone::ColliderGroup>>,
+}
+
+/// An `Iterator` that visits springs.
+#[derive(Clone, Debug)]
+pub struct Springs<'a> {
+    /// The parent `SpringBone` struct.
+    spring_bone: SpringBone<'a>,
+
+    /// The internal spring iterator.
+    iter: iter::Enumerate<slice::Iter<'a, json::extensions::root::vrmc_spring_bone::Spring>>,
+}
+
+/// An `Iterator` that visits the joints of a spring.
+#[derive(Clone, Debug)]
+pub struct Joints<'a> {
+    /// The parent `Document` struct.
+    document: &'a Document,
+
+    /// The internal joint iterator.
+    iter: slice::Iter<'a, json::extensions::root::vrmc_spring_bone::Joint>,
+}
+
+impl<'a> Vrm<'a> {
+    /// Constructs a `Vrm`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::root::vrmc_vrm::VrmcVrm,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The version of the VRM specification the asset conforms to.
+    pub fn spec_version(&self) -> &'a str {
+        &self.json.spec_version
+    }
+
+    /// The license and authorship information of the avatar.
+    pub fn meta(&self) -> Meta<'a> {
+        Meta {
+            document: self.document,
+            json: &self.json.meta,
+        }
+    }
+
+    /// The mapping of humanoid bones to nodes.
+    pub fn humanoid(&self) -> Humanoid<'a> {
+        Humanoid {
+            document: self.document,
+            json: &self.json.humanoid,
+        }
+    }
+
+    /// Returns an `Iterator` that visits the first-person visibility of meshes.
+    pub fn mesh_annotations(&self) -> MeshAnnotations<'a> {
+        let annotations = match self.json.first_person.as_ref() {
+            Some(first_person) => first_person.mesh_annotations.as_slice(),
+            None => &[],
+        };
+        MeshAnnotations {
+            document: self.document,
+            iter: annotations.iter(),
+        }
+    }
+
+    /// How the eyes of the avatar are controlled.
+    pub fn look_at(&self) -> Option<LookAt<'a>> {
+        self.json.look_at.as_ref().map(|json| LookAt { json })
+    }
+
+    /// Returns an `Iterator` that visits the preset and custom expressions of
+    /// the avatar.
+    pub fn expressions(&self) -> Expressions<'a> {
+        lazy_static! {
+            static ref NO_EXPRESSIONS: json::extensions::root::vrmc_vrm::Expressions =
+                Default::default();
+        }
+        let expressions = self.json.expressions.as_ref().unwrap_or(&NO_EXPRESSIONS);
+        Expressions {
+            document: self.document,
+            preset: expressions.preset.iter(),
+            custom: expressions.custom.iter(),
+        }
+    }
+
+    /// Looks up an expression by name, preferring presets over custom
+    /// expressions.
+    pub fn expression(&self, name: &str) -> Option<Expression<'a>> {
+        let expressions = self.json.expressions.as_ref()?;
+        if let Some((name, json)) = expressions.preset.get_key_value(name) {
+            Some(Expression::new(self.document, name, true, json))
+        } else {
+            let (name, json) = expressions.custom.get_key_value(name)?;
+            Some(Expression::new(self.document, name, false, json))
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Meta<'a> {
+    /// The name of the avatar.
+    pub fn name(&self) -> &'a str {
+        &self.json.name
+    }
+
+    /// The version of the avatar.
+    pub fn version(&self) -> Option<&'a str> {
+        self.json.version.as_deref()
+    }
+
+    /// The authors of the avatar.
+    pub fn authors(&self) -> &'a [String] {
+        &self.json.authors
+    }
+
+    /// The copyright information of the avatar.
+    pub fn copyright_information(&self) -> Option<&'a str> {
+        self.json.copyright_information.as_deref()
+    }
+
+    /// Contact information for the authors.
+    pub fn contact_information(&self) -> Option<&'a str> {
+        self.json.contact_information.as_deref()
+    }
+
+    /// References to the works the avatar is based on.
+    pub fn references(&self) -> &'a [String] {
+        &self.json.references
+    }
+
+    /// The licenses of third-party works used by the avatar.
+    pub fn third_party_licenses(&self) -> Option<&'a str> {
+        self.json.third_party_licenses.as_deref()
+    }
+
+    /// The thumbnail image of the avatar.
+    pub fn thumbnail_image(&self) -> Option<Image<'a>> {
+        self.json
+            .thumbnail_image
+            .map(|index| self.document.images().nth(index.value()).unwrap())
+    }
+
+    /// The URL of the license document of the avatar.
+    pub fn license_url(&self) -> &'a str {
+        &self.json.license_url
+    }
+
+    /// Who may perform as the avatar.
+    pub fn avatar_permission(&self) -> AvatarPermission {
+        self.json.avatar_permission.unwrap()
+    }
+
+    /// Whether the avatar may be used in excessively violent content.
+    pub fn allow_excessively_violent_usage(&self) -> bool {
+        self.json.allow_excessively_violent_usage
+    }
+
+    /// Whether the avatar may be used in excessively sexual content.
+    pub fn allow_excessively_sexual_usage(&self) -> bool {
+        self.json.allow_excessively_sexual_usage
+    }
+
+    /// Who may use the avatar commercially.
+    pub fn commercial_usage(&self) -> CommercialUsage {
+        self.json.commercial_usage.unwrap()
+    }
+
+    /// Whether the avatar may be used for political or religious purposes.
+    pub fn allow_political_or_religious_usage(&self) -> bool {
+        self.json.allow_political_or_religious_usage
+    }
+
+    /// Whether the avatar may be used for antisocial or hateful purposes.
+    pub fn allow_antisocial_or_hate_usage(&self) -> bool {
+        self.json.allow_antisocial_or_hate_usage
+    }
+
+    /// Whether the authors must be credited.
+    pub fn credit_notation(&self) -> CreditNotation {
+        self.json.credit_notation.unwrap()
+    }
+
+    /// Whether the avatar may be redistributed.
+    pub fn allow_redistribution(&self) -> bool {
+        self.json.allow_redistribution
+    }
+
+    /// Whether and how the avatar may be modified.
+    pub fn modification(&self) -> Modification {
+        self.json.modification.unwrap()
+    }
+
+    /// The URL of a license document overriding the above terms.
+    pub fn other_license_url(&self) -> Option<&'a str> {
+        self.json.other_license_url.as_deref()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Humanoid<'a> {
+    /// Returns the node mapped to a bone, if any.
+    pub fn bone(&self, bone: HumanBoneName) -> Option<Node<'a>> {
+        let json = self.json.human_bones.get(&json::validation::Checked::Valid(bone))?;
+        self.document.nodes().nth(json.node.value())
+    }
+
+    /// Returns an `Iterator` that visits the mapped bones in no particular
+    /// order.
+    pub fn bones(&self) -> HumanBones<'a> {
+        HumanBones {
+            document: self.document,
+            iter: self.json.human_bones.iter(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> LookAt<'a> {
+    /// The position of the eyes relative to the head bone.
+    pub fn offset_from_head_bone(&self) -> Option<[f32; 3]> {
+        self.json.offset_from_head_bone
+    }
+
+    /// Whether the eyes are moved by bones or by expressions.
+    pub fn kind(&self) -> LookAtType {
+        self.json.type_.unwrap()
+    }
+
+    /// The mapping of inward horizontal gaze angles.
+    pub fn range_map_horizontal_inner(&self) -> Option<RangeMap> {
+        self.json.range_map_horizontal_inner
+    }
+
+    /// The mapping of outward horizontal gaze angles.
+    pub fn range_map_horizontal_outer(&self) -> Option<RangeMap> {
+        self.json.range_map_horizontal_outer
+    }
+
+    /// The mapping of downward gaze angles.
+    pub fn range_map_vertical_down(&self) -> Option<RangeMap> {
+        self.json.range_map_vertical_down
+    }
+
+    /// The mapping of upward gaze angles.
+    pub fn range_map_vertical_up(&self) -> Option<RangeMap> {
+        self.json.range_map_vertical_up
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Expression<'a> {
+    /// Constructs an `Expression`.
+    fn new(
+        document: &'a Document,
+        name: &'a str,
+        preset: bool,
+        json: &'a json::extensions::root::vrmc_vrm::Expression,
+    ) -> Self {
+        Self {
+            document,
+            name,
+            preset,
+            json,
+        }
+    }
+
+    /// The preset or custom name of the expression.
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// Whether the expression is one of the presets, such as `happy` or
+    /// `blink`.
+    pub fn is_preset(&self) -> bool {
+        self.preset
+    }
+
+    /// Returns an `Iterator` that visits the morph target weights applied by
+    /// the expression.
+    pub fn morph_target_binds(&self) -> MorphTargetBinds<'a> {
+        MorphTargetBinds {
+            document: self.document,
+            iter: self.json.morph_target_binds.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the material colors applied by the
+    /// expression.
+    pub fn material_color_binds(&self) -> MaterialColorBinds<'a> {
+        MaterialColorBinds {
+            document: self.document,
+            iter: self.json.material_color_binds.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the texture transforms applied by the
+    /// expression.
+    pub fn texture_transform_binds(&self) -> TextureTransformBinds<'a> {
+        TextureTransformBinds {
+            document: self.document,
+            iter: self.json.texture_transform_binds.iter(),
+        }
+    }
+
+    /// Whether the weight of the expression is rounded to 0 or 1.
+    pub fn is_binary(&self) -> bool {
+        self.json.is_binary
+    }
+
+    /// How the expression affects blink expressions.
+    pub fn override_blink(&self) -> ExpressionOverride {
+        self.json.override_blink.unwrap()
+    }
+
+    /// How the expression affects look-at expressions.
+    pub fn override_look_at(&self) -> ExpressionOverride {
+        self.json.override_look_at.unwrap()
+    }
+
+    /// How the expression affects mouth expressions.
+    pub fn override_mouth(&self) -> ExpressionOverride {
+        self.json.override_mouth.unwrap()
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> SpringBone<'a> {
+    /// Constructs a `SpringBone`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::root::vrmc_spring_bone::VrmcSpringBone,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    /// The version of the specification the asset conforms to.
+    pub fn spec_version(&self) -> &'a str {
+        &self.json.spec_version
+    }
+
+    /// Returns an `Iterator` that visits the colliders.
+    pub fn colliders(&self) -> Colliders<'a> {
+        Colliders {
+            document: self.document,
+            iter: self.json.colliders.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the collider groups.
+    pub fn collider_groups(&self) -> ColliderGroups<'a> {
+        ColliderGroups {
+            spring_bone: self.clone(),
+            iter: self.json.collider_groups.iter().enumerate(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the springs.
+    pub fn springs(&self) -> Springs<'a> {
+        Springs {
+            spring_bone: self.clone(),
+            iter: self.json.springs.iter().enumerate(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Collider<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// The node the shape is attached to.
+    pub fn node(&self) -> Node<'a> {
+        self.document.nodes().nth(self.json.node.value()).unwrap()
+    }
+
+    /// The shape of the collider.
+    pub fn shape(&self) -> ColliderShape {
+        let shape = &self.json.shape;
+        if let Some(capsule) = shape.capsule.as_ref() {
+            ColliderShape::Capsule {
+                offset: capsule.offset,
+                radius: capsule.radius,
+                tail: capsule.tail,
+            }
+        } else {
+            let sphere = shape.sphere.clone().unwrap_or_default();
+            ColliderShape::Sphere {
+                offset: sphere.offset,
+                radius: sphere.radius,
+            }
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ColliderGroup<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns an `Iterator` that visits the colliders of the group.
+    pub fn colliders(&self) -> GroupColliders<'a> {
+        GroupColliders {
+            spring_bone: self.spring_bone.clone(),
+            iter: self.json.colliders.iter(),
+        }
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Spring<'a> {
+    /// Returns the internal JSON index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Optional user-defined name for this object.
+    #[cfg(feature = "names")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "names")))]
+    pub fn name(&self) -> Option<&'a str> {
+        self.json.name.as_deref()
+    }
+
+    /// Returns an `Iterator` that visits the joints of the chain, from root to
+    /// tip.
+    pub fn joints(&self) -> Joints<'a> {
+        Joints {
+            document: self.spring_bone.document,
+            iter: self.json.joints.iter(),
+        }
+    }
+
+    /// Returns an `Iterator` that visits the collider groups the joints
+    /// collide with.
+    pub fn collider_groups(&self) -> SpringColliderGroups<'a> {
+        SpringColliderGroups {
+            spring_bone: self.spring_bone.clone(),
+            iter: self.json.collider_groups.iter(),
+        }
+    }
+
+    /// The node whose space the simulation runs in, or `None` for world space.
+    pub fn center(&self) -> Option<Node<'a>> {
+        self.json
+            .center
+            .map(|index| self.spring_bone.document.nodes().nth(index.value()).unwrap())
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> Joint<'a> {
+    /// The node moved by the joint.
+    pub fn node(&self) -> Node<'a> {
+        self.document.nodes().nth(self.json.node.value()).unwrap()
+    }
+
+    /// The radius of the joint for collision detection.
+    pub fn hit_radius(&self) -> f32 {
+        self.json.hit_radius
+    }
+
+    /// The force returning the joint to its initial pose.
+    pub fn stiffness(&self) -> f32 {
+        self.json.stiffness
+    }
+
+    /// The strength of gravity on the joint.
+    pub fn gravity_power(&self) -> f32 {
+        self.json.gravity_power
+    }
+
+    /// The direction of gravity in world space.
+    pub fn gravity_dir(&self) -> [f32; 3] {
+        self.json.gravity_dir
+    }
+
+    /// The damping of the motion of the joint.
+    pub fn drag_force(&self) -> f32 {
+        self.json.drag_force
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> MToon<'a> {
+    /// Constructs `MToon`.
+    pub(crate) fn new(
+        document: &'a Document,
+        json: &'a json::extensions::material::vrmc_materials_mtoon::MToon,
+    ) -> Self {
+        Self { document, json }
+    }
+
+    fn texture_info(&self, json: &'a Option<json::texture::Info>) -> Option<texture::Info<'a>> {
+        json.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            texture::Info::new(texture, json)
+        })
+    }
+
+    /// The version of the specification the material conforms to.
+    pub fn spec_version(&self) -> &'a str {
+        &self.json.spec_version
+    }
+
+    /// Whether depth is written for transparent materials.
+    pub fn transparent_with_z_write(&self) -> bool {
+        self.json.transparent_with_z_write
+    }
+
+    /// The offset of the render queue within the alpha mode.
+    pub fn render_queue_offset_number(&self) -> i32 {
+        self.json.render_queue_offset_number
+    }
+
+    /// The color of shaded areas in linear space.
+    ///
+    /// The default value is `[1.0, 1.0, 1.0]`.
+    pub fn shade_color_factor(&self) -> [f32; 3] {
+        self.json.shade_color_factor
+    }
+
+    /// A texture multiplied with `shade_color_factor`.
+    pub fn shade_multiply_texture(&self) -> Option<texture::Info<'a>> {
+        self.texture_info(&self.json.shade_multiply_texture)
+    }
+
+    /// Shifts the boundary between lit and shaded areas.
+    pub fn shading_shift_factor(&self) -> f32 {
+        self.json.shading_shift_factor
+    }
+
+    /// A texture added to `shading_shift_factor`, stored in the `R` channel.
+    pub fn shading_shift_texture(&self) -> Option<ShadingShiftTexture<'a>> {
+        self.json.shading_shift_texture.as_ref().map(|json| {
+            let texture = self.document.textures().nth(json.index.value()).unwrap();
+            ShadingShiftTexture { texture, json }
+        })
+    }
+
+    /// The sharpness of the boundary between lit and shaded areas.
+    ///
+    /// The default value is `0.9`.
+    pub fn shading_toony_factor(&self) -> f32 {
+        self.json.shading_toony_factor
+    }
+
+    /// How much global illumination is made uniform.
+    ///
+    /// The default value is `0.9`.
+    pub fn gi_equalization_factor(&self) -> f32 {
+        self.json.gi_equalization_factor
+    }
+
+    /// The color multiplied with the matcap texture.
+    pub fn matcap_factor(&self) -> [f32; 3] {
+        self.json.matcap_factor
+    }
+
+    /// A texture sampled by view-space normals.
+    pub fn matcap_texture(&self) -> Option<texture::Info<'a>> {
+        self.texture_info(&self.json.matcap_texture)
+    }
+
+    /// The color of the parametric rim light.
+    pub fn parametric_rim_color_factor(&self) -> [f32; 3] {
+        self.json.parametric_rim_color_factor
+    }
+
+    /// A texture multiplied with the rim lighting.
+    pub fn rim_multiply_texture(&self) -> Option<texture::Info<'a>> {
+        self.texture_info(&self.json.rim_multiply_texture)
+    }
+
+    /// How much the rim lighting is affected by scene lighting.
+    pub fn rim_lighting_mix_factor(&self) -> f32 {
+        self.json.rim_lighting_mix_factor
+    }
+
+    /// The fresnel exponent of the parametric rim light.
+    ///
+    /// The default value is `5.0`.
+    pub fn parametric_rim_fresnel_power_factor(&self) -> f32 {
+        self.json.parametric_rim_fresnel_power_factor
+    }
+
+    /// The lift of the parametric rim light.
+    pub fn parametric_rim_lift_factor(&self) -> f32 {
+        self.json.parametric_rim_lift_factor
+    }
+
+    /// How the outline width is measured.
+    pub fn outline_width_mode(&self) -> OutlineWidthMode {
+        self.json.outline_width_mode.unwrap()
+    }
+
+    /// The width of the outline.
+    pub fn outline_width_factor(&self) -> f32 {
+        self.json.outline_width_factor
+    }
+
+    /// A texture multiplied with `outline_width_factor`, stored in the `G`
+    /// channel.
+    pub fn outline_width_multiply_texture(&self) -> Option<texture::Info<'a>> {
+        self.texture_info(&self.json.outline_width_multiply_texture)
+    }
+
+    /// The color of the outline in linear space.
+    pub fn outline_color_factor(&self) -> [f32; 3] {
+        self.json.outline_color_factor
+    }
+
+    /// How much the outline color is affected by scene lighting.
+    pub fn outline_lighting_mix_factor(&self) -> f32 {
+        self.json.outline_lighting_mix_factor
+    }
+
+    /// A texture masking the UV animation, stored in the `B` channel.
+    pub fn uv_animation_mask_texture(&self) -> Option<texture::Info<'a>> {
+        self.texture_info(&self.json.uv_animation_mask_texture)
+    }
+
+    /// The horizontal UV scroll speed in UV units per second.
+    pub fn uv_animation_scroll_x_speed_factor(&self) -> f32 {
+        self.json.uv_animation_scroll_x_speed_factor
+    }
+
+    /// The vertical UV scroll speed in UV units per second.
+    pub fn uv_animation_scroll_y_speed_factor(&self) -> f32 {
+        self.json.uv_animation_scroll_y_speed_factor
+    }
+
+    /// The UV rotation speed in radians per second.
+    pub fn uv_animation_rotation_speed_factor(&self) -> f32 {
+        self.json.uv_animation_rotation_speed_factor
+    }
+
+    /// Optional application specific data.
+    pub fn extras(&self) -> &'a json::Extras {
+        &self.json.extras
+    }
+}
+
+impl<'a> ShadingShiftTexture<'a> {
+    /// Returns the referenced `Texture`.
+    pub fn texture(&self) -> texture::Texture<'a> {
+        self.texture.clone()
+    }
+
+    /// The set index of the texture's `TEXCOORD` attribute.
+    pub fn tex_coord(&self) -> u32 {
+        self.json.tex_coord
+    }
+
+    /// The scalar multiplier applied to the texture values.
+    pub fn scale(&self) -> f32 {
+        self.json.scale
+    }
+}
+
+impl<'a> Iterator for HumanBones<'a> {
+    type Item = (HumanBoneName, Node<'a>);
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.by_ref().find_map(|(name, json)| match *name {
+            json::validation::Checked::Valid(name) => {
+                Some((name, document.nodes().nth(json.node.value()).unwrap()))
+            }
+            json::validation::Checked::Invalid => None,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<'a> ExactSizeIterator for MeshAnnotations<'a> {}
+impl<'a> Iterator for MeshAnnotations<'a> {
+    type Item = MeshAnnotation<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|json| MeshAnnotation {
+            node: document.nodes().nth(json.node.value()).unwrap(),
+            kind: json.type_.unwrap(),
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Expressions<'a> {}
+impl<'a> Iterator for Expressions<'a> {
+    type Item = Expression<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((name, json)) = self.preset.next() {
+            Some(Expression::new(self.document, name, true, json))
+        } else {
+            let (name, json) = self.custom.next()?;
+            Some(Expression::new(self.document, name, false, json))
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.preset.len() + self.custom.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for MorphTargetBinds<'a> {}
+impl<'a> Iterator for MorphTargetBinds<'a> {
+    type Item = MorphTargetBind<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|json| MorphTargetBind {
+            node: document.nodes().nth(json.node.value()).unwrap(),
+            index: json.index,
+            weight: json.weight,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for MaterialColorBinds<'a> {}
+impl<'a> Iterator for MaterialColorBinds<'a> {
+    type Item = MaterialColorBind<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|json| MaterialColorBind {
+            material: document.materials().nth(json.material.value()).unwrap(),
+            kind: json.type_.unwrap(),
+            target_value: json.target_value,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for TextureTransformBinds<'a> {}
+impl<'a> Iterator for TextureTransformBinds<'a> {
+    type Item = TextureTransformBind<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|json| TextureTransformBind {
+            material: document.materials().nth(json.material.value()).unwrap(),
+            scale: json.scale,
+            offset: json.offset,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Colliders<'a> {}
+impl<'a> Iterator for Colliders<'a> {
+    type Item = Collider<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|(index, json)| Collider {
+            document,
+            index,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for GroupColliders<'a> {}
+impl<'a> Iterator for GroupColliders<'a> {
+    type Item = Collider<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let spring_bone = &self.spring_bone;
+        self.iter
+            .next()
+            .map(|index| spring_bone.colliders().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for ColliderGroups<'a> {}
+impl<'a> Iterator for ColliderGroups<'a> {
+    type Item = ColliderGroup<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let spring_bone = &self.spring_bone;
+        self.iter.next().map(|(index, json)| ColliderGroup {
+            spring_bone: spring_bone.clone(),
+            index,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for SpringColliderGroups<'a> {}
+impl<'a> Iterator for SpringColliderGroups<'a> {
+    type Item = ColliderGroup<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let spring_bone = &self.spring_bone;
+        self.iter
+            .next()
+            .map(|index| spring_bone.collider_groups().nth(index.value()).unwrap())
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Springs<'a> {}
+impl<'a> Iterator for Springs<'a> {
+    type Item = Spring<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let spring_bone = &self.spring_bone;
+        self.iter.next().map(|(index, json)| Spring {
+            spring_bone: spring_bone.clone(),
+            index,
+            json,
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Joints<'a> {}
+impl<'a> Iterator for Joints<'a> {
+    type Item = Joint<'a>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let document = self.document;
+        self.iter.next().map(|json| Joint { document, json })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
@@ -18,3 +18,4517 @@ fn test_accessor_bounds() {
 }
 
 
+
+#[cfg(feature = "KHR_lights_punctual")]
+#[test]
+fn test_khr_lights_punctual() {
+    use gltf::khr_lights_punctual::Kind;
+
+    let gltf = gltf::Gltf::open("tests/khr_lights_punctual.gltf").unwrap();
+    let lights = gltf.lights().unwrap().collect::<Vec<_>>();
+    assert_eq!(lights.len(), 3);
+
+    assert!(matches!(lights[0].kind(), Kind::Directional));
+    assert_eq!(lights[0].color(), [1.0, 0.9, 0.8]);
+    assert_eq!(lights[0].intensity(), 3.0);
+    assert_eq!(lights[0].range(), None);
+
+    assert!(matches!(lights[1].kind(), Kind::Point));
+    assert_eq!(lights[1].color(), [1.0, 1.0, 1.0]);
+    assert_eq!(lights[1].intensity(), 1.0);
+    assert_eq!(lights[1].range(), Some(10.0));
+
+    match lights[2].kind() {
+        Kind::Spot { inner_cone_angle, outer_cone_angle } => {
+            assert_eq!(inner_cone_angle, 0.25);
+            assert_eq!(outer_cone_angle, std::f32::consts::FRAC_PI_4);
+        }
+        _ => panic!("expected spot light"),
+    }
+
+    let mut nodes = gltf.nodes();
+    assert_eq!(nodes.next().unwrap().light().unwrap().index(), 2);
+    assert!(nodes.next().unwrap().light().is_none());
+}
+
+#[cfg(feature = "KHR_materials_unlit")]
+#[test]
+fn test_khr_materials_unlit() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    let mut materials = gltf.materials();
+    assert!(!materials.next().unwrap().unlit());
+    assert!(materials.next().unwrap().unlit());
+}
+
+#[cfg(feature = "KHR_materials_pbrSpecularGlossiness")]
+#[test]
+fn test_khr_materials_pbr_specular_glossiness() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().pbr_specular_glossiness().is_none());
+
+    let material = gltf.materials().nth(2).unwrap();
+    let pbr = material.pbr_specular_glossiness().unwrap();
+    assert_eq!(pbr.diffuse_factor(), [0.5, 0.5, 0.5, 1.0]);
+    assert_eq!(pbr.specular_factor(), [0.0, 0.0, 0.0]);
+    assert_eq!(pbr.glossiness_factor(), 0.25);
+    assert!(pbr.diffuse_texture().is_none());
+    assert!(pbr.specular_glossiness_texture().is_none());
+}
+
+#[cfg(feature = "KHR_materials_transmission")]
+#[test]
+fn test_khr_materials_transmission() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().transmission().is_none());
+
+    let material = gltf.materials().nth(3).unwrap();
+    let transmission = material.transmission().unwrap();
+    assert_eq!(transmission.transmission_factor(), 0.75);
+    let info = transmission.transmission_texture().unwrap();
+    assert_eq!(info.texture().index(), 0);
+    assert_eq!(info.tex_coord(), 1);
+}
+
+#[cfg(feature = "KHR_materials_volume")]
+#[test]
+fn test_khr_materials_volume() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().volume().is_none());
+
+    let material = gltf.materials().nth(4).unwrap();
+    let volume = material.volume().unwrap();
+    assert_eq!(volume.thickness_factor(), 0.5);
+    assert_eq!(volume.thickness_texture().unwrap().texture().index(), 0);
+    assert_eq!(volume.attenuation_distance(), f32::INFINITY);
+    assert_eq!(volume.attenuation_color(), [0.9, 0.8, 0.7]);
+
+    // The infinite default attenuation distance has no JSON representation.
+    let json = gltf::json::serialize::to_string(&gltf.document.into_json()).unwrap();
+    let root: gltf::json::Root = gltf::json::deserialize::from_str(&json).unwrap();
+    let extensions = root.materials[4].extensions.as_ref().unwrap();
+    let volume = extensions.volume.as_ref().unwrap();
+    assert_eq!(volume.attenuation_distance.0, f32::INFINITY);
+}
+
+#[cfg(feature = "KHR_materials_specular")]
+#[test]
+fn test_khr_materials_specular() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().specular().is_none());
+
+    let material = gltf.materials().nth(5).unwrap();
+    let specular = material.specular().unwrap();
+    assert_eq!(specular.specular_factor(), 0.5);
+    assert!(specular.specular_texture().is_none());
+    assert_eq!(specular.specular_color_factor(), [1.0, 1.0, 1.0]);
+    assert_eq!(specular.specular_color_texture().unwrap().texture().index(), 0);
+}
+
+#[cfg(feature = "KHR_materials_sheen")]
+#[test]
+fn test_khr_materials_sheen() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().sheen().is_none());
+
+    let material = gltf.materials().nth(6).unwrap();
+    let sheen = material.sheen().unwrap();
+    assert_eq!(sheen.sheen_color_factor(), [0.5, 0.25, 0.0]);
+    assert!(sheen.sheen_color_texture().is_none());
+    assert_eq!(sheen.sheen_roughness_factor(), 0.5);
+    assert_eq!(sheen.sheen_roughness_texture().unwrap().texture().index(), 0);
+}
+
+#[cfg(feature = "KHR_materials_clearcoat")]
+#[test]
+fn test_khr_materials_clearcoat() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().clearcoat().is_none());
+
+    let material = gltf.materials().nth(7).unwrap();
+    let clearcoat = material.clearcoat().unwrap();
+    assert_eq!(clearcoat.clearcoat_factor(), 1.0);
+    assert!(clearcoat.clearcoat_texture().is_none());
+    assert_eq!(clearcoat.clearcoat_roughness_factor(), 0.125);
+    assert!(clearcoat.clearcoat_roughness_texture().is_none());
+    let normal = clearcoat.clearcoat_normal_texture().unwrap();
+    assert_eq!(normal.texture().index(), 0);
+    assert_eq!(normal.scale(), 0.5);
+}
+
+#[cfg(feature = "KHR_materials_iridescence")]
+#[test]
+fn test_khr_materials_iridescence() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().iridescence().is_none());
+
+    let material = gltf.materials().nth(8).unwrap();
+    let iridescence = material.iridescence().unwrap();
+    assert_eq!(iridescence.iridescence_factor(), 1.0);
+    assert!(iridescence.iridescence_texture().is_none());
+    assert_eq!(iridescence.iridescence_ior(), 1.3);
+    assert_eq!(iridescence.iridescence_thickness_minimum(), 100.0);
+    assert_eq!(iridescence.iridescence_thickness_maximum(), 800.0);
+    assert_eq!(iridescence.iridescence_thickness_texture().unwrap().texture().index(), 0);
+}
+
+#[cfg(feature = "KHR_materials_anisotropy")]
+#[test]
+fn test_khr_materials_anisotropy() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().anisotropy().is_none());
+
+    let material = gltf.materials().nth(9).unwrap();
+    let anisotropy = material.anisotropy().unwrap();
+    assert_eq!(anisotropy.anisotropy_strength(), 0.5);
+    assert_eq!(anisotropy.anisotropy_rotation(), 1.5);
+    assert_eq!(anisotropy.anisotropy_texture().unwrap().texture().index(), 0);
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[test]
+fn test_khr_materials_emissive_strength() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert_eq!(gltf.materials().next().unwrap().emissive_strength(), None);
+    assert_eq!(gltf.materials().nth(10).unwrap().emissive_strength(), Some(5.0));
+}
+
+#[cfg(feature = "KHR_materials_diffuse_transmission")]
+#[test]
+fn test_khr_materials_diffuse_transmission() {
+    let gltf = gltf::Gltf::open("tests/material_extensions.gltf").unwrap();
+    assert!(gltf.materials().next().unwrap().diffuse_transmission().is_none());
+
+    let material = gltf.materials().nth(11).unwrap();
+    let transmission = material.diffuse_transmission().unwrap();
+    assert_eq!(transmission.diffuse_transmission_factor(), 0.25);
+    assert!(transmission.diffuse_transmission_texture().is_none());
+    assert_eq!(transmission.diffuse_transmission_color_factor(), [0.0, 1.0, 0.0]);
+    let info = transmission.diffuse_transmission_color_texture().unwrap();
+    assert_eq!(info.texture().index(), 0);
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_khr_materials_variants() {
+    let gltf = gltf::Gltf::open("tests/khr_materials_variants.gltf").unwrap();
+    let variants = gltf.variants().unwrap().map(|v| v.name()).collect::<Vec<_>>();
+    assert_eq!(variants, ["Red", "Blue", "Green"]);
+
+    let mesh = gltf.meshes().next().unwrap();
+    let mut primitives = mesh.primitives();
+    let mappings = primitives
+        .next()
+        .unwrap()
+        .mappings()
+        .map(|mapping| (mapping.variants().to_vec(), mapping.material().index()))
+        .collect::<Vec<_>>();
+    assert_eq!(mappings, [(vec![0, 2], Some(0)), (vec![1], Some(1))]);
+    assert_eq!(primitives.next().unwrap().mappings().count(), 0);
+}
+
+#[cfg(feature = "KHR_texture_basisu")]
+#[test]
+fn test_khr_texture_basisu() {
+    let gltf = gltf::Gltf::open("tests/khr_texture_basisu.gltf").unwrap();
+    let textures = gltf.textures().collect::<Vec<_>>();
+
+    assert_eq!(textures[0].source().index(), 0);
+    assert_eq!(textures[0].basisu_source().unwrap().index(), 1);
+
+    assert_eq!(textures[1].source().index(), 1);
+    assert_eq!(textures[1].basisu_source().unwrap().index(), 1);
+
+    assert_eq!(textures[2].source().index(), 0);
+    assert!(textures[2].basisu_source().is_none());
+}
+
+#[test]
+fn test_texture_source_required() {
+    // The source may only be omitted when a supported extension provides the
+    // image, which is checked by validation if any such extension is enabled.
+    let result = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "images": [{"uri": "image.png"}],
+            "textures": [{}]
+        }"#,
+    );
+    assert!(result.is_err());
+}
+
+#[cfg(feature = "EXT_texture_webp")]
+#[test]
+fn test_ext_texture_webp() {
+    let gltf = gltf::Gltf::open("tests/ext_texture_webp.gltf").unwrap();
+    let textures = gltf.textures().collect::<Vec<_>>();
+
+    assert_eq!(textures[0].webp_source().unwrap().index(), 1);
+    assert_eq!(textures[0].source().index(), 1);
+
+    assert!(textures[1].webp_source().is_none());
+    assert_eq!(textures[1].source().index(), 0);
+}
+
+#[cfg(all(feature = "import", feature = "KHR_mesh_quantization"))]
+#[test]
+fn test_khr_mesh_quantization() {
+    let (document, buffers, _) = gltf::import("tests/khr_mesh_quantization.gltf").unwrap();
+    let mesh = document.meshes().next().unwrap();
+    let primitive = mesh.primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+
+    let positions = reader.read_quantized_positions().unwrap();
+    assert!(matches!(positions, gltf::mesh::util::ReadQuantized::I16(_)));
+    let positions = positions.into_f32().collect::<Vec<_>>();
+    assert_eq!(positions, [[0.0, 0.0, 0.0], [100.0, 0.0, 0.0], [0.0, -200.0, 0.0]]);
+
+    let normals = reader.read_quantized_normals().unwrap().into_f32().collect::<Vec<_>>();
+    assert_eq!(normals, [[0.0, 0.0, 1.0], [0.0, 0.0, -1.0], [1.0, 0.0, 0.0]]);
+
+    let tex_coords = reader.read_quantized_tex_coords(0).unwrap().into_f32().collect::<Vec<_>>();
+    assert_eq!(tex_coords, [[0.0, 0.0], [1.0, 0.0], [0.0, 32768.0 / 65535.0]]);
+}
+
+#[cfg(all(feature = "import", feature = "EXT_mesh_gpu_instancing"))]
+#[test]
+fn test_ext_mesh_gpu_instancing() {
+    let (document, buffers, _) = gltf::import("tests/ext_mesh_gpu_instancing.gltf").unwrap();
+    let mut nodes = document.nodes();
+    let instancing = nodes.next().unwrap().instancing().unwrap();
+    assert!(nodes.next().unwrap().instancing().is_none());
+
+    assert_eq!(instancing.count(), 2);
+    assert_eq!(instancing.translation().unwrap().index(), 1);
+    assert_eq!(instancing.rotation().unwrap().index(), 2);
+    assert_eq!(instancing.scale().unwrap().index(), 3);
+    assert_eq!(instancing.attributes().count(), 3);
+
+    let reader = instancing.reader(|buffer| Some(&buffers[buffer.index()]));
+    let matrices = reader.read_matrices().unwrap().collect::<Vec<_>>();
+    assert_eq!(
+        matrices,
+        [
+            [
+                [1.0, 0.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [1.0, 2.0, 3.0, 1.0],
+            ],
+            [
+                [-2.0, 0.0, 0.0, 0.0],
+                [0.0, -2.0, 0.0, 0.0],
+                [0.0, 0.0, 2.0, 0.0],
+                [-4.0, 0.0, 0.0, 1.0],
+            ],
+        ]
+    );
+}
+
+#[cfg(all(feature = "import", feature = "KHR_animation_pointer"))]
+#[test]
+fn test_khr_animation_pointer() {
+    use gltf::animation::util::{PointerValues, ReadOutputs};
+    use gltf::khr_animation_pointer::Object;
+
+    let (document, buffers, _) = gltf::import("tests/khr_animation_pointer.gltf").unwrap();
+    let animation = document.animations().next().unwrap();
+    let mut channels = animation.channels();
+
+    let channel = channels.next().unwrap();
+    let target = channel.target();
+    assert_eq!(target.property(), gltf::animation::Property::Pointer);
+    assert!(target.node().is_none());
+    let pointer = target.pointer().unwrap();
+    assert_eq!(pointer.as_str(), "/materials/0/pbrMetallicRoughness/baseColorFactor");
+    assert!(matches!(pointer.object(), Some(Object::Material(material)) if material.index() == Some(0)));
+    assert_eq!(pointer.property(), Some("pbrMetallicRoughness/baseColorFactor"));
+    assert_eq!(pointer.value(), Some(gltf::json::Value::from(vec![0.5, 0.5, 0.5, 1.0])));
+
+    let reader = channel.reader(|buffer| Some(&buffers[buffer.index()]));
+    match reader.read_outputs().unwrap() {
+        ReadOutputs::Pointer(PointerValues::Vec4(values)) => {
+            assert_eq!(values.collect::<Vec<_>>(), [[1.0, 0.0, 0.0, 1.0], [0.0, 0.0, 1.0, 1.0]]);
+        }
+        _ => panic!("expected vec4 pointer outputs"),
+    }
+
+    let target = channels.next().unwrap().target();
+    assert_eq!(target.node().unwrap().index(), 0);
+    assert!(target.pointer().is_none());
+}
+
+#[cfg(all(feature = "EXT_lights_ies", feature = "names"))]
+#[test]
+fn test_ext_lights_ies() {
+    use gltf::ext_lights_ies::Source;
+
+    let gltf = gltf::Gltf::open("tests/ext_lights_ies.gltf").unwrap();
+    let profiles = gltf.light_profiles().unwrap().collect::<Vec<_>>();
+    assert_eq!(profiles.len(), 2);
+    assert_eq!(profiles[0].name(), Some("Downlight"));
+    assert!(matches!(profiles[0].source(), Source::Uri { uri: "downlight.ies", mime_type: None }));
+    match profiles[1].source() {
+        Source::View { view, mime_type } => {
+            assert_eq!(view.index(), 0);
+            assert_eq!(mime_type, "application/x-ies-lm-63");
+        }
+        _ => panic!("expected buffer view source"),
+    }
+
+    let mut nodes = gltf.nodes();
+    let light = nodes.next().unwrap().ies_light().unwrap();
+    assert_eq!(light.profile().index(), 1);
+    assert_eq!(light.multiplier(), 2.5);
+    assert_eq!(light.color(), [1.0, 0.5, 0.25]);
+
+    let light = nodes.next().unwrap().ies_light().unwrap();
+    assert_eq!(light.profile().index(), 0);
+    assert_eq!(light.multiplier(), 1.0);
+    assert_eq!(light.color(), [1.0, 1.0, 1.0]);
+
+    assert!(nodes.next().unwrap().ies_light().is_none());
+}
+
+#[cfg(all(feature = "MSFT_lod", feature = "names"))]
+#[test]
+fn test_msft_lod() {
+    let gltf = gltf::Gltf::open("tests/msft_lod.gltf").unwrap();
+    let mut nodes = gltf.nodes();
+    let node = nodes.next().unwrap();
+    let levels = node.lod_levels().map(|node| node.name()).collect::<Vec<_>>();
+    assert_eq!(levels, [Some("Medium"), Some("Low")]);
+    assert_eq!(nodes.next().unwrap().lod_levels().count(), 0);
+
+    #[cfg(feature = "extras")]
+    {
+        assert_eq!(node.lod_screen_coverage(), Some(vec![0.5, 0.25, 0.125]));
+        assert_eq!(nodes.next().unwrap().lod_screen_coverage(), None);
+    }
+}
+
+#[cfg(all(feature = "import", feature = "MSFT_texture_dds"))]
+#[test]
+fn test_msft_texture_dds() {
+    use gltf::image::{DdsFormat, DdsHeader};
+
+    let (document, _, images) = gltf::import("tests/msft_texture_dds.gltf").unwrap();
+    let texture = document.textures().next().unwrap();
+    assert_eq!(texture.dds_source().unwrap().index(), 0);
+    assert_eq!(texture.source().index(), 0);
+
+    let image = &images[0];
+    assert_eq!((image.width, image.height), (4, 4));
+    assert_eq!(&image.pixels[..3], &[255, 0, 0]);
+    assert_eq!(
+        image.dds(),
+        Some(DdsHeader {
+            width: 4,
+            height: 4,
+            mip_count: 1,
+            format: DdsFormat::FourCC(*b"DXT1"),
+        })
+    );
+}
+
+#[cfg(all(feature = "import", feature = "KHR_audio", feature = "names"))]
+#[test]
+fn test_khr_audio() {
+    use gltf::khr_audio::{DistanceModel, Source, Type};
+
+    let (document, buffers, _) = gltf::import("tests/khr_audio.gltf").unwrap();
+    let scene = document.scenes().next().unwrap();
+    let global = scene.audio_emitters().collect::<Vec<_>>();
+    assert_eq!(global.len(), 1);
+    assert_eq!(global[0].name(), Some("Background"));
+    assert_eq!(global[0].kind(), Type::Global);
+    assert!(global[0].positional().is_none());
+    let source = global[0].sources().next().unwrap();
+    assert_eq!(source.name(), Some("Ambience"));
+    assert!(source.auto_play());
+    assert!(!source.looping());
+    assert_eq!(source.gain(), 1.0);
+    let audio = source.audio().unwrap();
+    assert!(matches!(audio.source(), Source::Uri { uri: "ambient.mp3", mime_type: None }));
+    assert!(audio.read(|buffer| Some(&buffers[buffer.index()])).is_none());
+
+    let mut nodes = document.nodes();
+    let emitter = nodes.next().unwrap().audio_emitter().unwrap();
+    assert!(nodes.next().unwrap().audio_emitter().is_none());
+    assert_eq!(emitter.name(), Some("Bell"));
+    assert_eq!(emitter.kind(), Type::Positional);
+    assert_eq!(emitter.gain(), 2.0);
+    let positional = emitter.positional().unwrap();
+    assert_eq!(positional.distance_model(), DistanceModel::Linear);
+    assert_eq!(positional.max_distance(), 50.0);
+    assert_eq!(positional.ref_distance(), 1.0);
+    let source = emitter.sources().next().unwrap();
+    assert_eq!(source.gain(), 0.5);
+    assert!(source.looping());
+    let audio = source.audio().unwrap();
+    let data = audio.read(|buffer| Some(&buffers[buffer.index()])).unwrap();
+    assert_eq!(data, b"RIFF\x04\x00\x00\x00WAVE");
+}
+
+#[cfg(all(feature = "KHR_node_visibility", feature = "names"))]
+#[test]
+fn test_khr_node_visibility() {
+    let gltf = gltf::Gltf::open("tests/khr_node_visibility.gltf").unwrap();
+    let visible = gltf.nodes().map(|node| node.visible()).collect::<Vec<_>>();
+    assert_eq!(visible, [true, false, true, true, true]);
+
+    let scene = gltf.scenes().next().unwrap();
+    let names = scene.visible_nodes().map(|node| node.name()).collect::<Vec<_>>();
+    assert_eq!(names, [Some("Root"), Some("Visible"), Some("Other")]);
+}
+
+#[cfg(feature = "KHR_node_visibility")]
+#[test]
+fn test_khr_node_visibility_cycle() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0]}],
+            "nodes": [{"children": [1]}, {"children": [0]}]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let visible = scene.visible_nodes().map(|node| node.index()).collect::<Vec<_>>();
+    assert_eq!(visible, [0, 1]);
+}
+
+#[cfg(all(feature = "KHR_interactivity", feature = "names"))]
+#[test]
+fn test_khr_interactivity() {
+    use gltf::khr_interactivity::{Literal, Value};
+
+    let gltf = gltf::Gltf::open("tests/khr_interactivity.gltf").unwrap();
+    let interactivity = gltf.interactivity().unwrap();
+    assert_eq!(interactivity.graphs().len(), 1);
+    let graph = interactivity.default_graph().unwrap();
+
+    let variable = graph.variables().next().unwrap();
+    assert_eq!(variable.name(), Some("Counter"));
+    assert_eq!(variable.type_().signature(), "float");
+    assert_eq!(variable.value(), Some(&[Literal::Number(0.0)][..]));
+
+    let event = graph.events().next().unwrap();
+    assert_eq!(event.id(), Some("ping"));
+    let (type_, value) = event.value("amount").unwrap();
+    assert_eq!(type_.signature(), "int");
+    assert_eq!(value, Some(&[Literal::Number(1.0)][..]));
+
+    let mut nodes = graph.nodes();
+    let start = nodes.next().unwrap();
+    assert_eq!(start.declaration().op(), "event/onStart");
+    let flow = start.flow("out").unwrap();
+    assert_eq!(flow.node.index(), 2);
+    assert_eq!(flow.socket, "in");
+
+    let add = nodes.next().unwrap();
+    assert_eq!(add.declaration().op(), "math/add");
+    assert_eq!(add.values().len(), 2);
+    match add.value("a").unwrap() {
+        Value::Literal { type_, value } => {
+            assert_eq!(type_.signature(), "float");
+            assert_eq!(value, &[Literal::Number(1.5)]);
+        }
+        Value::Reference { .. } => panic!("expected a literal value"),
+    }
+
+    let set = nodes.next().unwrap();
+    assert_eq!(set.configuration("variable"), Some(&[Literal::Number(0.0)][..]));
+    match set.value("value").unwrap() {
+        Value::Reference { node, socket } => {
+            assert_eq!(node.index(), 1);
+            assert_eq!(socket, "value");
+        }
+        Value::Literal { .. } => panic!("expected a reference"),
+    }
+    assert!(set.flows().next().is_none());
+}
+
+#[cfg(all(feature = "KHR_physics_rigid_bodies", feature = "names"))]
+#[test]
+fn test_khr_physics_rigid_bodies() {
+    use gltf::khr_implicit_shapes::Kind;
+    use gltf::khr_physics_rigid_bodies::{CombineMode, DriveType, Geometry};
+
+    let gltf = gltf::Gltf::open("tests/khr_physics_rigid_bodies.gltf").unwrap();
+    let mut nodes = gltf.nodes();
+
+    let ground = nodes.next().unwrap().physics().unwrap();
+    assert!(ground.motion().is_none());
+    let ground_collider = ground.collider().unwrap();
+    match ground_collider.geometry() {
+        Geometry::Shape(shape) => {
+            assert_eq!(shape.parameters(), Kind::Box { size: [10.0, 0.5, 10.0] });
+        }
+        Geometry::Mesh { .. } => panic!("expected an implicit shape"),
+    }
+    assert!(ground_collider.material().is_none());
+
+    let ball = nodes.next().unwrap().physics().unwrap();
+    let motion = ball.motion().unwrap();
+    assert!(!motion.is_kinematic());
+    assert_eq!(motion.mass(), Some(2.0));
+    assert_eq!(motion.linear_velocity(), [1.0, 0.0, 0.0]);
+    assert_eq!(motion.gravity_factor(), 1.0);
+    let ball_collider = ball.collider().unwrap();
+    match ball_collider.geometry() {
+        Geometry::Shape(shape) => assert_eq!(shape.parameters(), Kind::Sphere { radius: 0.25 }),
+        Geometry::Mesh { .. } => panic!("expected an implicit shape"),
+    }
+    let material = ball_collider.material().unwrap();
+    assert_eq!(material.static_friction(), 0.9);
+    assert_eq!(material.dynamic_friction(), 0.6);
+    assert_eq!(material.restitution(), 0.5);
+    assert_eq!(material.friction_combine(), Some(CombineMode::Maximum));
+    assert_eq!(material.restitution_combine(), None);
+
+    let ground_filter = ground_collider.collision_filter().unwrap();
+    let ball_filter = ball_collider.collision_filter().unwrap();
+    assert!(ball_filter.collides_with(&ground_filter));
+    assert!(!ball_filter.collides_with(&ball_filter));
+
+    let joint = ball.joint().unwrap();
+    assert_eq!(joint.connected_node().name(), Some("Ground"));
+    assert!(!joint.enable_collision());
+    let joint = joint.joint();
+    assert_eq!(joint.limits()[0].linear_axes, [0, 1, 2]);
+    assert_eq!(joint.drives()[0].type_.unwrap(), DriveType::Angular);
+
+    let zone = nodes.next().unwrap().physics().unwrap();
+    let trigger = zone.trigger().unwrap();
+    assert!(trigger.geometry().is_none());
+    let zone_shape = trigger.nodes().next().unwrap();
+    assert_eq!(zone_shape.name(), Some("ZoneShape"));
+    let trigger = zone_shape.physics().unwrap().trigger().unwrap();
+    match trigger.geometry().unwrap() {
+        Geometry::Shape(shape) => assert_eq!(
+            shape.parameters(),
+            Kind::Capsule { height: 0.5, radius_top: 0.25, radius_bottom: 0.25 }
+        ),
+        Geometry::Mesh { .. } => panic!("expected an implicit shape"),
+    }
+}
+
+#[cfg(all(
+    feature = "import",
+    feature = "extras",
+    feature = "EXT_mesh_features",
+    feature = "EXT_structural_metadata"
+))]
+#[test]
+fn test_ext_structural_metadata() {
+    use gltf::ext_mesh_features::Source;
+    use gltf::ext_structural_metadata::{ComponentType, Type, Value};
+
+    let (document, buffers, _) = gltf::import("tests/ext_structural_metadata.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let mut feature_ids = primitive.feature_ids();
+    let buildings = feature_ids.next().unwrap();
+    assert_eq!(buildings.feature_count(), 3);
+    assert_eq!(buildings.label(), Some("buildings"));
+    assert!(matches!(buildings.source(), Source::Attribute(0)));
+    let implicit = feature_ids.next().unwrap();
+    assert!(matches!(implicit.source(), Source::Implicit));
+    assert_eq!(implicit.null_feature_id(), Some(255));
+    assert!(implicit.property_table().is_none());
+
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    let ids = reader.read_feature_ids(0).unwrap().into_u32().collect::<Vec<_>>();
+    assert_eq!(ids, [0, 1, 2]);
+
+    let table = buildings.property_table().unwrap();
+    assert_eq!(table.name(), Some("Buildings"));
+    assert_eq!(table.count(), 3);
+    let class = table.class().unwrap();
+    assert_eq!(class.name(), Some("Building"));
+    assert_eq!(class.properties().len(), 6);
+
+    let read = |id: &str| {
+        table
+            .property(id)
+            .unwrap()
+            .reader(|buffer| Some(&buffers[buffer.index()]))
+            .unwrap()
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(read("height"), [Value::Float(10.5), Value::Float(20.0), Value::Float(7.25)]);
+    assert_eq!(
+        read("name"),
+        [Value::String("Tower"), Value::String("Hall"), Value::String("Shed")]
+    );
+    assert_eq!(
+        read("occupied"),
+        [Value::Boolean(true), Value::Boolean(false), Value::Boolean(true)]
+    );
+    assert_eq!(
+        read("tags")[2],
+        Value::Array(vec![Value::UInt(3), Value::UInt(4), Value::UInt(5)])
+    );
+    assert_eq!(read("tags")[1], Value::Array(vec![]));
+    assert_eq!(
+        read("position")[1],
+        Value::Array(vec![Value::Int(300), Value::Int(-400)])
+    );
+
+    let kind = table.property("kind").unwrap().class_property().unwrap();
+    assert_eq!(kind.type_(), Type::Enum);
+    assert_eq!(kind.component_type(), Some(ComponentType::U16));
+    let enumeration = kind.enumeration().unwrap();
+    let kinds = read("kind")
+        .into_iter()
+        .map(|value| match value {
+            Value::UInt(value) => enumeration.name_of(value as i64).unwrap(),
+            _ => panic!("expected an enum value"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(kinds, ["Residential", "Industrial", "Commercial"]);
+}
+
+#[cfg(feature = "VRM")]
+#[test]
+fn test_vrm() {
+    use gltf::vrm::{
+        AvatarPermission, ColliderShape, CommercialUsage, ExpressionOverride, FirstPersonType,
+        HumanBoneName, LookAtType, MaterialColorType, OutlineWidthMode,
+    };
+
+    let gltf = gltf::Gltf::open("tests/vrm.gltf").unwrap();
+    let vrm = gltf.vrm().unwrap();
+    assert_eq!(vrm.spec_version(), "1.0");
+
+    let meta = vrm.meta();
+    assert_eq!(meta.name(), "Sample Avatar");
+    assert_eq!(meta.authors(), ["Alice", "Bob"]);
+    assert_eq!(meta.avatar_permission(), AvatarPermission::Everyone);
+    assert_eq!(meta.commercial_usage(), CommercialUsage::PersonalProfit);
+    assert!(meta.allow_redistribution());
+    assert!(!meta.allow_excessively_violent_usage());
+
+    let humanoid = vrm.humanoid();
+    assert_eq!(humanoid.bones().count(), 15);
+    assert_eq!(humanoid.bone(HumanBoneName::Head).unwrap().index(), 2);
+    assert_eq!(humanoid.bone(HumanBoneName::RightHand).unwrap().index(), 14);
+    assert!(humanoid.bone(HumanBoneName::Jaw).is_none());
+
+    let annotation = vrm.mesh_annotations().next().unwrap();
+    assert_eq!(annotation.node.index(), 17);
+    assert_eq!(annotation.kind, FirstPersonType::ThirdPersonOnly);
+
+    let look_at = vrm.look_at().unwrap();
+    assert_eq!(look_at.kind(), LookAtType::Bone);
+    assert_eq!(look_at.range_map_horizontal_inner().unwrap().output_scale, Some(10.0));
+    assert!(look_at.range_map_vertical_up().is_none());
+
+    assert_eq!(vrm.expressions().len(), 3);
+    let happy = vrm.expression("happy").unwrap();
+    assert!(happy.is_preset());
+    assert_eq!(happy.override_blink(), ExpressionOverride::Block);
+    assert_eq!(happy.morph_target_binds().next().unwrap().node.index(), 17);
+    assert!(vrm.expression("blink").unwrap().is_binary());
+    let wink = vrm.expression("wink").unwrap();
+    assert!(!wink.is_preset());
+    let color = wink.material_color_binds().next().unwrap();
+    assert_eq!(color.kind, MaterialColorType::RimColor);
+    assert_eq!(color.material.index(), Some(0));
+    let transform = wink.texture_transform_binds().next().unwrap();
+    assert_eq!(transform.scale, [1.0, 1.0]);
+    assert_eq!(transform.offset, [0.5, 0.0]);
+
+    let spring_bone = gltf.spring_bone().unwrap();
+    let shapes = spring_bone.colliders().map(|collider| collider.shape()).collect::<Vec<_>>();
+    assert_eq!(
+        shapes,
+        [
+            ColliderShape::Sphere { offset: [0.0, 0.1, 0.0], radius: 0.12 },
+            ColliderShape::Capsule { offset: [0.0, 0.0, 0.0], radius: 0.1, tail: [0.0, 0.3, 0.0] },
+        ]
+    );
+    let spring = spring_bone.springs().next().unwrap();
+    assert_eq!(spring.center().unwrap().index(), 0);
+    let group = spring.collider_groups().next().unwrap();
+    assert_eq!(group.colliders().map(|collider| collider.node().index()).collect::<Vec<_>>(), [2, 1]);
+    let joints = spring.joints().collect::<Vec<_>>();
+    assert_eq!(joints[0].stiffness(), 0.8);
+    assert_eq!(joints[1].stiffness(), 1.0);
+    assert_eq!(joints[1].gravity_dir(), [0.0, -1.0, 0.0]);
+    assert_eq!(joints[1].drag_force(), 0.5);
+
+    let mtoon = gltf.materials().next().unwrap().mtoon().unwrap();
+    assert_eq!(mtoon.shade_color_factor(), [0.8, 0.6, 0.6]);
+    assert_eq!(mtoon.outline_width_mode(), OutlineWidthMode::ScreenCoordinates);
+    assert_eq!(mtoon.shading_toony_factor(), 0.9);
+    assert!(mtoon.matcap_texture().is_none());
+}
+
+#[cfg(feature = "CESIUM_RTC")]
+#[test]
+fn test_cesium_rtc() {
+    let gltf = gltf::Gltf::open("tests/cesium_rtc.gltf").unwrap();
+    assert_eq!(gltf.rtc_center(), Some([6378137.25, -1234567.5, 42.125]));
+
+    let scene = gltf.scenes().next().unwrap();
+    let local = scene.world_transforms().collect::<Vec<_>>();
+    assert_eq!(local.iter().map(|(node, _)| node.index()).collect::<Vec<_>>(), [0, 1, 2]);
+    assert_eq!(local[1].1[3], [1.0, 3.0, 0.0, 1.0]);
+
+    let world = scene.rtc_world_transforms().collect::<Vec<_>>();
+    assert_eq!(world[0].1[3], [6378138.25, -1234567.5, 42.125, 1.0]);
+    assert_eq!(world[1].1[3], [6378138.25, -1234564.5, 42.125, 1.0]);
+    assert_eq!(world[1].1[0], [2.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+fn test_document_mut() {
+    use gltf::json::{Index, Node};
+
+    let gltf = gltf::Gltf::open("tests/box_sparse.gltf").unwrap();
+    let mut document = gltf::DocumentMut::from(gltf.document);
+
+    // Removing the morph target accessor shifts the animation accessors down.
+    document.remove_accessor(Index::new(4));
+    let json = document.as_json();
+    assert_eq!(json.accessors.len(), 4);
+    assert!(json.meshes[0].primitives[0].targets.as_ref().unwrap()[0].positions.is_none());
+    assert_eq!(json.animations[0].samplers[0].input.value(), 3);
+
+    // Removing an animation input removes the sampler and its channel.
+    document.remove_accessor(Index::new(3));
+    assert!(document.as_json().animations[0].samplers.is_empty());
+    assert!(document.as_json().animations[0].channels.is_empty());
+
+    let parent = document.add(Node {
+        children: Some(vec![Index::new(0)]),
+        ..Default::default()
+    });
+    document.get_mut::<gltf::json::Scene>(Index::new(0)).unwrap().nodes = vec![parent];
+    document.remove_node(Index::new(0));
+    let json = document.as_json();
+    assert_eq!(json.nodes.len(), 1);
+    assert!(json.nodes[0].children.as_ref().unwrap().is_empty());
+    assert_eq!(json.scenes[0].nodes[0].value(), 0);
+
+    document.remove_mesh(Index::new(0));
+    assert!(document.as_json().meshes.is_empty());
+    let document = document.into_document().unwrap();
+    assert_eq!(document.nodes().count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "skin joint")]
+fn test_document_mut_remove_joint() {
+    use gltf::json::Index;
+
+    let json = gltf::json::deserialize::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "nodes": [{"skin": 0}, {}],
+            "skins": [{"joints": [1]}]
+        }"#,
+    )
+    .unwrap();
+    let mut document = gltf::DocumentMut::from_json(json);
+    document.remove_node(Index::new(1));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_export_glb() {
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let export = gltf::export::Export::new(&document, &buffers);
+    let png = [0x89, b'P', b'N', b'G'];
+    let mut json = document.clone().into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: Some("missing.png".to_owned()),
+        extensions: None,
+        extras: Default::default(),
+    });
+    let glb = gltf::export::Export::from_json(json, &buffers)
+        .image(0, &png, "image/png")
+        .to_vec()
+        .unwrap();
+    assert_eq!(glb.len() % 4, 0);
+    assert_eq!(u32::from_le_bytes([glb[8], glb[9], glb[10], glb[11]]) as usize, glb.len());
+
+    let gltf = gltf::Gltf::from_slice(&glb).unwrap();
+    let blob = gltf.blob.as_ref().unwrap();
+    assert_eq!(blob.len() % 4, 0);
+    assert_eq!(gltf.buffers().count(), 1);
+    let image = gltf.images().next().unwrap();
+    match image.source() {
+        gltf::image::Source::View { view, mime_type } => {
+            assert_eq!(mime_type, "image/png");
+            assert_eq!(&blob[view.offset()..view.offset() + view.length()], &png[..]);
+        }
+        _ => panic!("image was not embedded"),
+    }
+
+    let glb = export.to_vec().unwrap();
+    let (exported, exported_buffers, _) = gltf::import_slice(&glb).unwrap();
+    let positions = |document: &gltf::Document, buffers: &[gltf::buffer::Data]| {
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        reader.read_positions().unwrap().collect::<Vec<_>>()
+    };
+    assert_eq!(positions(&document, &buffers), positions(&exported, &exported_buffers));
+    assert_eq!(exported.animations().count(), document.animations().count());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_export_gltf() {
+    use std::collections::HashMap;
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.clone().into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let jpeg = [0xFF, 0xD8, 0xFF];
+    let mut files = HashMap::new();
+    gltf::export::Export::from_json(json, &buffers)
+        .image(0, &jpeg, "image/jpeg")
+        .to_gltf("box", &mut files)
+        .unwrap();
+    assert_eq!(files.len(), 3);
+    assert_eq!(files["box_0.jpg"], jpeg);
+    let gltf = gltf::Gltf::from_slice(&files["box.gltf"]).unwrap();
+    assert!(matches!(gltf.buffers().next().unwrap().source(), gltf::buffer::Source::Uri("box.bin")));
+    assert!(matches!(gltf.images().next().unwrap().source(), gltf::image::Source::Uri { uri: "box_0.jpg", .. }));
+
+    let directory = std::env::temp_dir().join("gltf_test_export_gltf");
+    fs::create_dir_all(&directory).unwrap();
+    gltf::export::Export::new(&document, &buffers)
+        .to_gltf("box", &mut gltf::export::Directory(directory.clone()))
+        .unwrap();
+    let (exported, exported_buffers, _) = gltf::import(directory.join("box.gltf")).unwrap();
+    let positions = |document: &gltf::Document, buffers: &[gltf::buffer::Data]| {
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+        reader.read_positions().unwrap().collect::<Vec<_>>()
+    };
+    assert_eq!(positions(&document, &buffers), positions(&exported, &exported_buffers));
+    fs::remove_dir_all(&directory).unwrap();
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_export_images() {
+    use gltf::export::{Export, ImageStorage};
+    use std::collections::HashMap;
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let pixels = [255, 0, 0, 255, 0, 255, 0, 128, 0, 0, 255, 255, 255, 255, 255, 0];
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 2, 2, &pixels).unwrap();
+
+    let glb = export.to_vec().unwrap();
+    let (exported, _, images) = gltf::import_slice(&glb).unwrap();
+    let image = exported.images().next().unwrap();
+    assert!(matches!(image.source(), gltf::image::Source::View { mime_type: "image/png", .. }));
+    assert_eq!(images[0].format, gltf::image::Format::R8G8B8A8);
+    assert_eq!((images[0].width, images[0].height), (2, 2));
+    assert_eq!(images[0].pixels, pixels);
+
+    let mut files = HashMap::new();
+    export.image_storage(ImageStorage::External).write_glb("box", &mut files).unwrap();
+    assert_eq!(files.len(), 2);
+    assert!(files["box_0.png"].starts_with(b"\x89PNG"));
+    let gltf = gltf::Gltf::from_slice(&files["box.glb"]).unwrap();
+    let image = gltf.images().next().unwrap();
+    assert!(matches!(image.source(), gltf::image::Source::Uri { uri: "box_0.png", mime_type: Some("image/png") }));
+
+    let jpeg = [0xFF, 0xD8, 0xFF, 0xE0];
+    export.encoded_image(0, &jpeg).unwrap();
+    assert!(matches!(export.encoded_image(0, b"GIF89a"), Err(gltf::Error::UnsupportedImageEncoding)));
+    let mut files = HashMap::new();
+    export.image_storage(ImageStorage::Buffer).to_gltf("box", &mut files).unwrap();
+    assert_eq!(files.len(), 2);
+    let gltf = gltf::Gltf::from_slice(&files["box.gltf"]).unwrap();
+    let image = gltf.images().next().unwrap();
+    assert!(matches!(image.source(), gltf::image::Source::View { mime_type: "image/jpeg", .. }));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_export_embedded_gltf() {
+    use gltf::export::Export;
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let pixels = [255, 0, 0, 255, 0, 255, 0, 128, 0, 0, 255, 255, 255, 255, 255, 0];
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 2, 2, &pixels).unwrap();
+
+    // Data URIs of images are only resolved when importing from a path.
+    let path = std::env::temp_dir().join("gltf_test_export_embedded.gltf");
+    std::fs::write(&path, export.to_embedded_gltf().unwrap()).unwrap();
+    let (exported, exported_buffers, images) = gltf::import(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let buffer = exported.buffers().next().unwrap();
+    assert!(matches!(buffer.source(), gltf::buffer::Source::Uri(uri) if uri.starts_with("data:application/octet-stream;base64,")));
+    assert_eq!(&exported_buffers[0][..buffers[0].len()], &buffers[0][..]);
+    let image = exported.images().next().unwrap();
+    assert!(matches!(image.source(), gltf::image::Source::Uri { uri, .. } if uri.starts_with("data:image/png;base64,")));
+    assert_eq!(images[0].pixels, pixels);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_export_image_encoding() {
+    use gltf::export::{Export, ImageEncoding, ImageFormat, PngCompression};
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let pixels = [255, 0, 0, 255].repeat(8);
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 4, 2, &pixels).unwrap();
+
+    export.image_encoding(ImageEncoding { png_compression: PngCompression::Best, ..Default::default() });
+    let (_, _, images) = gltf::import_slice(export.to_vec().unwrap()).unwrap();
+    assert_eq!(images[0].pixels, pixels);
+
+    export.image_encoding(ImageEncoding {
+        format: Some(ImageFormat::Jpeg),
+        jpeg_quality: 50,
+        max_dimension: Some(2),
+        ..Default::default()
+    });
+    let (exported, _, images) = gltf::import_slice(export.to_vec().unwrap()).unwrap();
+    let image = exported.images().next().unwrap();
+    assert!(matches!(image.source(), gltf::image::Source::View { mime_type: "image/jpeg", .. }));
+    assert_eq!(images[0].format, gltf::image::Format::R8G8B8);
+    assert_eq!((images[0].width, images[0].height), (2, 1));
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_export_meshopt_compression() {
+    use gltf::build::{BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::export::meshopt::{Filter, Options};
+    use gltf::json::accessor::{ComponentType, GenericComponentType};
+    use gltf::json::extensions::buffer::ext_meshopt_compression as ext;
+    use gltf::json::validation::Checked::Valid;
+
+    let positions = (0..64).map(|i| [i as f32, 0.0, 0.0]).collect::<Vec<_>>();
+    let normals = vec![[0.0, 0.0, 1.0]; 64];
+    let indices = (0..62).flat_map(|i| vec![i, i + 1, i + 2]).collect::<Vec<u32>>();
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).normals(&normals).indices(&indices))
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+    let options = Options { normals: Filter::Octahedral(8), ..Default::default() };
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data])
+        .meshopt_compression(options)
+        .to_glb()
+        .unwrap();
+    let bin = glb.bin.unwrap();
+    let json = gltf::json::Root::from_slice(&glb.json).unwrap();
+    assert_eq!(json.extensions_required, ["EXT_meshopt_compression", "KHR_mesh_quantization"]);
+    assert_eq!(json.buffers.len(), 2);
+    assert_eq!(json.buffers[0].byte_length as usize, bin.len());
+    assert!(json.buffers[1].uri.is_none());
+    assert!(json.buffers[1].extensions.as_ref().unwrap().ext_meshopt_compression.as_ref().unwrap().fallback);
+
+    let compression = |view: usize| {
+        let extensions = json.buffer_views[view].extensions.as_ref().unwrap();
+        extensions.ext_meshopt_compression.clone().unwrap()
+    };
+    let positions = compression(0);
+    assert!(matches!((positions.mode, positions.filter), (Valid(ext::Mode::Attributes), Valid(ext::Filter::None))));
+    assert_eq!((positions.count, positions.byte_stride), (64, 12));
+    assert_eq!(bin[positions.byte_offset as usize], 0xa0);
+
+    let normals = compression(1);
+    assert!(matches!((normals.mode, normals.filter), (Valid(ext::Mode::Attributes), Valid(ext::Filter::Octahedral))));
+    assert_eq!((normals.count, normals.byte_stride), (64, 4));
+    assert_eq!(json.buffer_views[1].byte_stride, Some(4));
+    assert_eq!(json.buffer_views[1].byte_length, 256);
+    let accessor = &json.accessors[1];
+    assert!(matches!(accessor.component_type, Valid(GenericComponentType(ComponentType::I8))));
+    assert!(accessor.normalized);
+
+    // Each index differs only slightly from the previous one and thus takes
+    // a single byte.
+    let indices = compression(2);
+    assert!(matches!(indices.mode, Valid(ext::Mode::Indices)));
+    assert_eq!((indices.count, indices.byte_stride), (186, 2));
+    assert_eq!(indices.byte_length, 1 + 186 + 4);
+    assert_eq!(bin[indices.byte_offset as usize], 0xd1);
+    assert_eq!(json.buffer_views[2].buffer.value(), 1);
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_export_meshopt_compressed_document() {
+    use gltf::build::{BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::json::extensions::buffer::ext_meshopt_compression as ext;
+
+    let positions = (0..64).map(|i| [i as f32, 0.0, 0.0]).collect::<Vec<_>>();
+    let indices = (0..62).flat_map(|i| vec![i, i + 1, i + 2]).collect::<Vec<u32>>();
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).indices(&indices))
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data])
+        .meshopt_compression(Default::default())
+        .to_glb()
+        .unwrap();
+    let compressed = glb.bin.unwrap().into_owned();
+    let mut json = gltf::json::Root::from_slice(&glb.json).unwrap();
+
+    // Move the data to the second buffer, after a buffer of 4 bytes.
+    let mut first = json.buffers[0].clone();
+    first.byte_length = 4;
+    json.buffers.insert(0, first);
+    fn compression(view: &mut gltf::json::buffer::View) -> &mut ext::View {
+        let extensions = view.extensions.as_mut().unwrap();
+        extensions.ext_meshopt_compression.as_mut().unwrap()
+    }
+    let mut payloads = Vec::new();
+    for view in &mut json.buffer_views {
+        view.buffer = gltf::json::Index::new(view.buffer.value() as u32 + 1);
+        let extension = compression(view);
+        let start = extension.byte_offset as usize;
+        payloads.push(compressed[start..start + extension.byte_length as usize].to_vec());
+        extension.buffer = gltf::json::Index::new(1);
+    }
+    let buffers = [vec![1, 2, 3, 4], compressed];
+
+    // Compressed data is kept by every way of laying out the merged buffer.
+    let mut export = gltf::export::Export::from_json(json.clone(), &buffers);
+    let mut exports = vec![export.clone()];
+    export.meshopt_compression(Default::default());
+    exports.push(export.clone());
+    #[cfg(feature = "KHR_mesh_quantization")]
+    {
+        export.quantize(gltf::export::quantize::Options { positions: true, ..Default::default() });
+        exports.push(export);
+    }
+    for export in &exports {
+        let glb = export.to_glb().unwrap();
+        let bin = glb.bin.unwrap();
+        let mut output = gltf::Gltf::from_slice(&export.to_vec().unwrap()).unwrap().document.into_json();
+        assert_eq!(output.buffers.len(), 2);
+        assert_eq!(output.buffers[0].byte_length as usize, bin.len());
+        assert!(output.extensions_required.iter().any(|name| name == "EXT_meshopt_compression"));
+        for (view, payload) in output.buffer_views.iter_mut().zip(&payloads) {
+            assert_eq!(view.buffer.value(), 1);
+            let extension = compression(view);
+            assert_eq!(extension.buffer.value(), 0);
+            let start = extension.byte_offset as usize;
+            assert_eq!(&bin[start..start + extension.byte_length as usize], &payload[..]);
+        }
+    }
+
+    // Compressed data cannot be read from a fallback buffer.
+    compression(&mut json.buffer_views[0]).buffer = gltf::json::Index::new(2);
+    let result = gltf::export::Export::from_json(json, &buffers).to_glb();
+    assert!(matches!(result, Err(gltf::Error::Validation(_))));
+}
+
+/// Decoders of `EXT_meshopt_compression` for checking compressed data.
+///
+/// Attribute data is decoded by the reference implementation, which predates
+/// the index sequence codec and the filters in the version available to the
+/// `meshopt` crate. These are decoded as described by the extension instead.
+#[cfg(feature = "EXT_meshopt_compression")]
+mod meshopt_decode {
+    use gltf::json::extensions::buffer::ext_meshopt_compression as ext;
+    use gltf::json::validation::Checked::Valid;
+
+    /// Decodes the data of a compressed buffer view.
+    pub fn view(bin: &[u8], view: &ext::View) -> Vec<u8> {
+        let start = view.byte_offset as usize;
+        let data = &bin[start..start + view.byte_length as usize];
+        let (count, stride) = (view.count as usize, view.byte_stride as usize);
+        let mut decoded = match view.mode {
+            Valid(ext::Mode::Attributes) => attributes(data, count, stride),
+            Valid(ext::Mode::Indices) => indices(data, count, stride),
+            ref mode => panic!("unexpected mode {:?}", mode),
+        };
+        assert_eq!(decoded.len(), count * stride);
+        for element in decoded.chunks_exact_mut(stride) {
+            match view.filter {
+                Valid(ext::Filter::None) => {}
+                Valid(ext::Filter::Octahedral) if stride == 4 => octahedral(element, 1),
+                Valid(ext::Filter::Octahedral) => octahedral(element, 2),
+                Valid(ext::Filter::Quaternion) => quaternion(element),
+                Valid(ext::Filter::Exponential) => exponential(element),
+                ref filter => panic!("unexpected filter {:?}", filter),
+            }
+        }
+        decoded
+    }
+
+    fn attributes(data: &[u8], count: usize, stride: usize) -> Vec<u8> {
+        fn decode<T: Clone + Default + AsRef<[u8]>>(data: &[u8], count: usize) -> Vec<u8> {
+            let vertices = meshopt::decode_vertex_buffer::<T>(data, count).unwrap();
+            vertices.iter().flat_map(|vertex| vertex.as_ref().to_vec()).collect()
+        }
+        match stride {
+            4 => decode::<[u8; 4]>(data, count),
+            8 => decode::<[u8; 8]>(data, count),
+            12 => decode::<[u8; 12]>(data, count),
+            16 => decode::<[u8; 16]>(data, count),
+            _ => panic!("unexpected stride {}", stride),
+        }
+    }
+
+    fn indices(data: &[u8], count: usize, size: usize) -> Vec<u8> {
+        assert_eq!(data[0], 0xd1);
+        let mut data = &data[1..];
+        let mut last = [0u32; 2];
+        let mut decoded = Vec::with_capacity(count * size);
+        for _ in 0..count {
+            let mut value = 0;
+            for shift in (0..35).step_by(7) {
+                let byte = data[0];
+                data = &data[1..];
+                value |= u32::from(byte & 127) << shift;
+                if byte < 128 {
+                    break;
+                }
+            }
+            let current = (value & 1) as usize;
+            let value = value >> 1;
+            let delta = (value >> 1) ^ (value & 1).wrapping_neg();
+            last[current] = last[current].wrapping_add(delta);
+            decoded.extend_from_slice(&last[current].to_le_bytes()[..size]);
+        }
+        assert_eq!(data, [0; 4]);
+        decoded
+    }
+
+    fn octahedral(element: &mut [u8], size: usize) {
+        let read = |i: usize| -> f32 {
+            if size == 1 {
+                f32::from(element[i] as i8)
+            } else {
+                f32::from(i16::from_le_bytes([element[2 * i], element[2 * i + 1]]))
+            }
+        };
+        let one = read(2);
+        let (mut x, mut y) = (read(0) / one, read(1) / one);
+        let z = 1.0 - x.abs() - y.abs();
+        let t = (-z).max(0.0);
+        x -= if x >= 0.0 { t } else { -t };
+        y -= if y >= 0.0 { t } else { -t };
+        let scale = if size == 1 { 127.0 } else { 32767.0 } / (x * x + y * y + z * z).sqrt();
+        for (i, value) in [x, y, z].iter().enumerate() {
+            let value = (value * scale).round();
+            if size == 1 {
+                element[i] = value as i8 as u8;
+            } else {
+                element[2 * i..2 * i + 2].copy_from_slice(&(value as i16).to_le_bytes());
+            }
+        }
+    }
+
+    fn quaternion(element: &mut [u8]) {
+        let read = |i: usize| i16::from_le_bytes([element[2 * i], element[2 * i + 1]]);
+        let one = f32::from(read(3) | 3);
+        let range = std::f32::consts::FRAC_1_SQRT_2;
+        let [x, y, z] = [0, 1, 2].map(|i| f32::from(read(i)) / one * range);
+        let w = (1.0 - x * x - y * y - z * z).max(0.0).sqrt();
+        let largest = (read(3) & 3) as usize;
+        for (i, value) in [w, x, y, z].iter().enumerate() {
+            let j = (largest + i) % 4;
+            element[2 * j..2 * j + 2].copy_from_slice(&((value * 32767.0).round() as i16).to_le_bytes());
+        }
+    }
+
+    fn exponential(element: &mut [u8]) {
+        for bytes in element.chunks_exact_mut(4) {
+            let value = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+            let mantissa = ((value << 8) as i32) >> 8;
+            let exponent = (value as i32) >> 24;
+            let decoded = (f64::from(mantissa) * 2f64.powi(exponent)) as f32;
+            bytes.copy_from_slice(&decoded.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(feature = "EXT_meshopt_compression")]
+#[test]
+fn test_export_meshopt_roundtrip() {
+    use gltf::animation::Interpolation;
+    use gltf::build::{AnimationBuilder, BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::export::meshopt::{Filter, Options};
+    use gltf::json::accessor::{ComponentType, GenericComponentType};
+    use gltf::json::extensions::buffer::ext_meshopt_compression as ext;
+    use gltf::json::mesh::Semantic;
+    use gltf::json::validation::Checked::Valid;
+    use std::collections::HashSet;
+
+    let n = 300;
+    let positions = (0..n)
+        .map(|i| [(i % 20) as f32 * 0.5, (i / 20) as f32 * 0.25, ((i * 7) % 11) as f32 - 5.0])
+        .collect::<Vec<_>>();
+    let normals = (0..n)
+        .map(|i| {
+            let (a, b) = (i as f32 * 0.1, i as f32 * 0.11);
+            [a.cos() * b.sin(), a.sin() * b.sin(), b.cos()]
+        })
+        .collect::<Vec<_>>();
+    // Jumps between distant vertices take several bytes and switch between
+    // the two baselines of the index sequence codec.
+    let mut indices = Vec::new();
+    for i in 0..n as u32 - 2 {
+        indices.extend_from_slice(&[i, i + 1, i + 2]);
+        if i % 10 == 0 {
+            indices.extend_from_slice(&[0, i + 2, n as u32 - 1 - i]);
+        }
+    }
+    // Indices beyond the range of unsigned shorts are stored as integers.
+    let large = vec![[0.0, 0.0, 0.0]; 65540];
+    let large_indices = [65539, 0, 1, 65538, 65537, 2];
+    let times = (0..n).map(|i| i as f32 / 30.0).collect::<Vec<_>>();
+    let rotations = (0..n)
+        .map(|i| {
+            let (sin, cos) = (i as f32 * 0.025).sin_cos();
+            let axis = [1.0 / 14f32.sqrt(), 2.0 / 14f32.sqrt(), 3.0 / 14f32.sqrt()];
+            [axis[0] * sin, axis[1] * sin, axis[2] * sin, cos]
+        })
+        .collect::<Vec<_>>();
+    let translations = (0..n)
+        .map(|i| [i as f32 * 0.01, (i as f32 * 0.1).sin() * 3.0, -2.5])
+        .collect::<Vec<_>>();
+
+    let mut document = gltf::DocumentMut::new();
+    let node = document.add(gltf::json::Node::default());
+    let mut buffer = BufferBuilder::new(&mut document);
+    MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).normals(&normals).indices(&indices))
+        .primitive(PrimitiveBuilder::new(&large).indices(&large_indices))
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    AnimationBuilder::new()
+        .translations(node, &times, &translations, Interpolation::Linear)
+        .rotations(node, &times, &rotations, Interpolation::Linear)
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let buffers = [buffer.finish(&mut document)];
+    let json = document.into_json();
+
+    // The decoded values of each accessor, the source values, the tolerance
+    // relative to the largest component of each element, and whether the
+    // sign of elements may be flipped.
+    let mesh = &json.meshes[0];
+    let attribute = |primitive: usize, semantic| mesh.primitives[primitive].attributes[&Valid(semantic)].value();
+    let index_values = |indices: &[u32]| indices.iter().map(|&i| vec![i as f32]).collect::<Vec<_>>();
+    let expected = |options: &Options| {
+        let tolerance = |filter: Filter| match filter {
+            Filter::None => 0.0,
+            Filter::Octahedral(bits) | Filter::Quaternion(bits) => 4.0 / (1 << (bits - 1)) as f32,
+            Filter::Exponential(bits) => 2.0 / (1 << (bits - 1)) as f32,
+        };
+        let sampler = |channel: usize| json.animations[0].samplers[channel].output.value();
+        let to_vec = |values: &[[f32; 3]]| values.iter().map(|value| value.to_vec()).collect::<Vec<_>>();
+        vec![
+            (attribute(0, Semantic::Positions), to_vec(&positions), tolerance(options.other), false),
+            (attribute(0, Semantic::Normals), to_vec(&normals), tolerance(options.normals), false),
+            (mesh.primitives[0].indices.unwrap().value(), index_values(&indices), 0.0, false),
+            (attribute(1, Semantic::Positions), to_vec(&large), tolerance(options.other), false),
+            (mesh.primitives[1].indices.unwrap().value(), index_values(&large_indices), 0.0, false),
+            (json.animations[0].samplers[0].input.value(), times.iter().map(|&t| vec![t]).collect(), 0.0, false),
+            (sampler(0), to_vec(&translations), tolerance(options.other), false),
+            (sampler(1), rotations.iter().map(|value| value.to_vec()).collect(), tolerance(options.rotations), true),
+        ]
+    };
+
+    let cases = [
+        (
+            Options::default(),
+            vec![(ext::Mode::Attributes, ext::Filter::None), (ext::Mode::Indices, ext::Filter::None)],
+        ),
+        (
+            Options {
+                normals: Filter::Octahedral(8),
+                rotations: Filter::Quaternion(12),
+                other: Filter::Exponential(16),
+            },
+            vec![
+                (ext::Mode::Attributes, ext::Filter::Octahedral),
+                (ext::Mode::Attributes, ext::Filter::Quaternion),
+                (ext::Mode::Attributes, ext::Filter::Exponential),
+                (ext::Mode::Indices, ext::Filter::None),
+            ],
+        ),
+        (
+            Options {
+                normals: Filter::Octahedral(12),
+                rotations: Filter::Exponential(20),
+                other: Filter::None,
+            },
+            vec![
+                (ext::Mode::Attributes, ext::Filter::None),
+                (ext::Mode::Attributes, ext::Filter::Octahedral),
+                (ext::Mode::Attributes, ext::Filter::Exponential),
+                (ext::Mode::Indices, ext::Filter::None),
+            ],
+        ),
+    ];
+    for (options, modes) in &cases {
+        let glb = gltf::export::Export::from_json(json.clone(), &buffers)
+            .meshopt_compression(*options)
+            .to_glb()
+            .unwrap();
+        let bin = glb.bin.unwrap();
+        let exported = gltf::json::Root::from_slice(&glb.json).unwrap();
+
+        // Decode the compressed views into the fallback buffer.
+        let mut fallback = vec![0; exported.buffers[1].byte_length as usize];
+        let mut found = HashSet::new();
+        let mut sizes = HashSet::new();
+        for view in &exported.buffer_views {
+            let extension = match view.extensions.as_ref().and_then(|e| e.ext_meshopt_compression.as_ref()) {
+                Some(extension) => extension,
+                None => continue,
+            };
+            assert_eq!(view.buffer.value(), 1);
+            let decoded = meshopt_decode::view(&bin, extension);
+            assert_eq!(decoded.len(), view.byte_length as usize);
+            let start = view.byte_offset.unwrap_or(0) as usize;
+            fallback[start..start + decoded.len()].copy_from_slice(&decoded);
+            if let (Valid(mode), Valid(filter)) = (extension.mode, extension.filter) {
+                found.insert((mode, filter));
+                if mode == ext::Mode::Indices {
+                    sizes.insert(extension.byte_stride);
+                }
+            }
+        }
+        for mode in modes {
+            assert!(found.contains(mode), "{:?}: {:?} not found", options, mode);
+        }
+        assert_eq!(sizes, [2, 4].iter().copied().collect());
+
+        for (accessor, source, tolerance, signed) in expected(options) {
+            let accessor = &exported.accessors[accessor];
+            let view = &exported.buffer_views[accessor.buffer_view.unwrap().value()];
+            let buffer = if view.buffer.value() == 0 { &bin[..] } else { &fallback[..] };
+            let component = match accessor.component_type {
+                Valid(GenericComponentType(component)) => component,
+                _ => unreachable!(),
+            };
+            let size = component.size();
+            let components = source[0].len();
+            let stride = view.byte_stride.map_or(size * components, |stride| stride as usize);
+            let start = view.byte_offset.unwrap_or(0) as usize + accessor.byte_offset as usize;
+            for (i, expected) in source.iter().enumerate() {
+                let element = &buffer[start + i * stride..];
+                let actual = (0..components)
+                    .map(|c| {
+                        let bytes = &element[c * size..];
+                        match component {
+                            ComponentType::I8 => (f32::from(bytes[0] as i8) / 127.0).max(-1.0),
+                            ComponentType::I16 => {
+                                (f32::from(i16::from_le_bytes([bytes[0], bytes[1]])) / 32767.0).max(-1.0)
+                            }
+                            ComponentType::U16 => f32::from(u16::from_le_bytes([bytes[0], bytes[1]])),
+                            ComponentType::U32 => u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as f32,
+                            ComponentType::F32 => f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+                            _ => unreachable!(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let scale = expected.iter().fold(1f32, |scale, value| scale.max(value.abs()));
+                let close = |sign: f32| {
+                    expected
+                        .iter()
+                        .zip(&actual)
+                        .all(|(expected, actual)| (expected * sign - actual).abs() <= tolerance * scale)
+                };
+                assert!(close(1.0) || signed && close(-1.0), "{:?}: {:?} != {:?}", options, actual, expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_export_view_offset_overflow() {
+    let mut json = gltf::Gltf::open("tests/box_sparse.gltf").unwrap().document.into_json();
+    let mut first = json.buffers[0].clone();
+    first.byte_length = 4;
+    first.uri = None;
+    json.buffers.insert(0, first);
+    for view in &mut json.buffer_views {
+        view.buffer = gltf::json::Index::new(view.buffer.value() as u32 + 1);
+    }
+    json.buffer_views[0].byte_offset = Some(u32::MAX - 1);
+    let length = json.buffers[1].byte_length as usize;
+    let buffers = [vec![0; 4], vec![0; length]];
+    let result = gltf::export::Export::from_json(json, &buffers).to_glb();
+    assert!(matches!(result, Err(gltf::Error::Io(ref error)) if error.kind() == std::io::ErrorKind::InvalidInput));
+}
+
+#[cfg(feature = "KHR_mesh_quantization")]
+#[test]
+fn test_export_quantize() {
+    use gltf::build::{BufferBuilder, MeshBuilder, NodeBuilder, PrimitiveBuilder, SceneBuilder};
+    use gltf::export::quantize::Options;
+    use gltf::json::accessor::{ComponentType, GenericComponentType};
+    use gltf::json::validation::Checked::Valid;
+    use gltf::json::Value;
+
+    let positions = [[-1.0, 0.0, 0.0], [3.0, 1.0, 0.0], [1.0, 2.0, 0.5f32]];
+    let normals = [[0.0, 0.0, 1.0]; 3];
+    let tex_coords = [[0.0, 0.0], [1.0, 0.0], [0.5, 1.0f32]];
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    let mesh = MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).normals(&normals).tex_coords(&tex_coords))
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+    let node = NodeBuilder::new().mesh(mesh).build(&mut document);
+    SceneBuilder::new().node(node).build(&mut document);
+    let options = Options { positions: true, normals: true, tex_coords: true };
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data])
+        .quantize(options)
+        .to_glb()
+        .unwrap();
+    let bin = glb.bin.unwrap();
+    let json = gltf::json::Root::from_slice(&glb.json).unwrap();
+    assert_eq!(json.extensions_required, ["KHR_mesh_quantization"]);
+    assert_eq!(json.buffers[0].byte_length as usize, bin.len());
+
+    // The positions are mapped back by a node inserted below the original.
+    assert!(json.nodes[0].mesh.is_none());
+    assert_eq!(json.nodes[0].children.as_ref().unwrap()[0].value(), 1);
+    assert_eq!(json.nodes[1].mesh.unwrap().value(), mesh.value());
+    assert_eq!(json.nodes[1].translation, Some([-1.0, 0.0, 0.0]));
+    assert_eq!(json.nodes[1].scale, Some([4.0; 3]));
+
+    let component_type = |accessor: usize| match json.accessors[accessor].component_type {
+        Valid(GenericComponentType(component_type)) => component_type,
+        _ => unreachable!(),
+    };
+    let accessor = &json.accessors[0];
+    assert_eq!(component_type(0), ComponentType::U16);
+    assert!(accessor.normalized);
+    assert_eq!(accessor.min, Some(Value::from(vec![0, 0, 0])));
+    assert_eq!(accessor.max, Some(Value::from(vec![65535, 32768, 8192])));
+    let view = &json.buffer_views[accessor.buffer_view.unwrap().value()];
+    assert_eq!((view.byte_stride, view.byte_length), (Some(8), 24));
+    let start = view.byte_offset.unwrap() as usize + 8;
+    assert_eq!(&bin[start..start + 8], &[255, 255, 0, 64, 0, 0, 0, 0]);
+
+    assert_eq!(component_type(1), ComponentType::I8);
+    assert!(json.accessors[1].normalized);
+    let view = &json.buffer_views[json.accessors[1].buffer_view.unwrap().value()];
+    assert_eq!((view.byte_stride, view.byte_length), (Some(4), 12));
+    assert_eq!(component_type(2), ComponentType::U16);
+    assert!(json.accessors[2].normalized);
+}
+
+#[test]
+fn test_export_json_format() {
+    use gltf::build::{BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::export::{Export, JsonFormat};
+    use std::collections::HashMap;
+
+    let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0f32]];
+    let normals = [[0.0, 0.0, 1.0]; 3];
+    let tex_coords = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0f32]];
+    let colors = [[1.0; 4]; 3];
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    MeshBuilder::new()
+        .primitive(
+            PrimitiveBuilder::new(&positions)
+                .normals(&normals)
+                .tex_coords(&tex_coords)
+                .colors(&colors),
+        )
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = [buffer.finish(&mut document)];
+    let json = document.into_json();
+
+    // Attributes are written sorted by name, however they are stored.
+    let glb = Export::from_json(json.clone(), &data).to_glb().unwrap();
+    let text = std::str::from_utf8(&glb.json).unwrap();
+    assert!(!text.contains('\n'));
+    assert!(text.contains(r#""attributes":{"COLOR_0":3,"NORMAL":1,"POSITION":0,"TEXCOORD_0":2}"#));
+    for _ in 0..8 {
+        let mut copy = json.clone();
+        let attributes = &mut copy.meshes[0].primitives[0].attributes;
+        *attributes = std::mem::take(attributes).into_iter().collect();
+        assert_eq!(Export::from_json(copy, &data).to_vec().unwrap(), glb.to_vec().unwrap());
+    }
+
+    let glb = Export::from_json(json.clone(), &data)
+        .json_format(JsonFormat::Pretty)
+        .to_glb()
+        .unwrap();
+    assert!(glb.json.starts_with(b"{\n  \""));
+
+    let (mut pretty, mut minified) = (HashMap::new(), HashMap::new());
+    Export::from_json(json.clone(), &data).to_gltf("model", &mut pretty).unwrap();
+    Export::from_json(json, &data)
+        .json_format(JsonFormat::Minified)
+        .to_gltf("model", &mut minified)
+        .unwrap();
+    assert!(pretty["model.gltf"].contains(&b'\n'));
+    assert!(!minified["model.gltf"].contains(&b'\n'));
+    let pretty = gltf::json::Root::from_slice(&pretty["model.gltf"]).unwrap();
+    let minified = gltf::json::Root::from_slice(&minified["model.gltf"]).unwrap();
+    assert_eq!(pretty.to_vec().unwrap(), minified.to_vec().unwrap());
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_export_strip_extensions() {
+    use gltf::export::Export;
+
+    let json = gltf::json::Root::from_str(
+        r#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_materials_variants", "VENDOR_custom"],
+            "extensionsRequired": ["VENDOR_custom"],
+            "extensions": {"KHR_materials_variants": {"variants": [{"name": "red"}, {"name": "blue"}]}},
+            "materials": [{}, {}, {}],
+            "meshes": [{
+                "primitives": [{
+                    "attributes": {},
+                    "material": 0,
+                    "extensions": {"KHR_materials_variants": {"mappings": [
+                        {"material": 1, "variants": [0]},
+                        {"material": 2, "variants": [1]}
+                    ]}}
+                }]
+            }]
+        }"#,
+    )
+    .unwrap();
+    let buffers: [&[u8]; 0] = [];
+
+    let glb = Export::from_json(json.clone(), &buffers).to_glb().unwrap();
+    let text = std::str::from_utf8(&glb.json).unwrap();
+    assert!(text.contains("VENDOR_custom"));
+
+    let glb = Export::from_json(json.clone(), &buffers)
+        .bake_material_variant(1)
+        .strip_unsupported_extensions()
+        .to_glb()
+        .unwrap();
+    let text = std::str::from_utf8(&glb.json).unwrap();
+    assert!(!text.contains("extensions"));
+    let exported = gltf::json::Root::from_slice(&glb.json).unwrap();
+    assert_eq!(exported.meshes[0].primitives[0].material.unwrap().value(), 2);
+    assert_eq!(exported.materials.len(), 3);
+}
+
+#[test]
+fn test_export_glb_writer() {
+    use gltf::export::GlbWriter;
+    use gltf::json::buffer::Target;
+
+    let mut document = gltf::DocumentMut::new();
+    let mut writer = GlbWriter::new(&mut document);
+    let bytes = [1, 2, 3];
+    let view = writer.push_reader(&mut document, bytes.len(), &bytes[..], None);
+    let colors = vec![[255u8, 0, 0], [0, 255, 0]];
+    let accessor = writer.push_iter(&mut document, colors, Some(Target::ArrayBuffer));
+    let mut glb = Vec::new();
+    writer.write(&document, &mut glb).unwrap();
+
+    let gltf = gltf::Gltf::from_slice(&glb).unwrap();
+    assert_eq!(glb.len(), gltf::binary::Glb::from_slice(&glb).unwrap().header.length as usize);
+    let json = document.as_json();
+    assert_eq!(json.buffers[0].byte_length, 12);
+    assert_eq!(json.buffer_views[view.value()].byte_offset, Some(0));
+    let colors = &json.buffer_views[json.accessors[accessor.value()].buffer_view.unwrap().value()];
+    assert_eq!((colors.byte_offset, colors.byte_length, colors.byte_stride), (Some(4), 8, Some(4)));
+    assert_eq!(gltf.blob.unwrap(), [1, 2, 3, 0, 255, 0, 0, 0, 0, 255, 0, 0]);
+
+    let mut document = gltf::DocumentMut::new();
+    let mut writer = GlbWriter::new(&mut document);
+    writer.push_reader(&mut document, 4, &bytes[..], None);
+    assert!(matches!(writer.write(&document, Vec::new()), Err(gltf::Error::Io(_))));
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_export_sanitize_names() {
+    use gltf::export::{names::Options, Export};
+
+    let mut json = gltf::json::Root::default();
+    for name in &["Cube", " Cube ", "Cube.001", "Cube", "\u{7f}Lamp\u{e9}", "  "] {
+        json.nodes.push(gltf::json::Node { name: Some(name.to_string()), ..Default::default() });
+    }
+    json.meshes.push(gltf::json::Mesh { name: Some("Cube".to_owned()), ..Default::default() });
+    let buffers: [&[u8]; 0] = [];
+    let options = Options { trim: true, replace_control: true, ascii_only: true, deduplicate: true };
+    let glb = Export::from_json(json, &buffers).sanitize_names(options).to_glb().unwrap();
+    let exported = gltf::json::Root::from_slice(&glb.json).unwrap();
+    let names = exported.nodes.iter().map(|node| node.name.as_deref()).collect::<Vec<_>>();
+    assert_eq!(names, [Some("Cube"), Some("Cube.002"), Some("Cube.001"), Some("Cube.003"), Some("_Lamp_"), None]);
+    assert_eq!(exported.meshes[0].name.as_deref(), Some("Cube"));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_buffer_builder() {
+    use gltf::build::BufferBuilder;
+    use gltf::json::{buffer::Target, Value};
+
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    let positions = [[0.5, -1.0, 0.0], [1.0, 0.0, 0.25], [0.0, 1.0, -0.1f32]];
+    let colors = [[255, 0, 0], [0, 255, 0], [0, 0, 255u8]];
+    let indices = [0, 1, 2u16];
+    let positions = buffer.push(&mut document, &positions, Some(Target::ArrayBuffer));
+    let colors = buffer.push_normalized(&mut document, &colors, Some(Target::ArrayBuffer));
+    let indices = buffer.push(&mut document, &indices, Some(Target::ElementArrayBuffer));
+    let data = buffer.finish(&mut document);
+
+    let json = document.as_json();
+    assert_eq!(json.buffers[0].byte_length as usize, data.len());
+    let accessor = &json.accessors[positions.value()];
+    assert_eq!(accessor.min, Some(Value::from(vec![0.0, -1.0, -0.1])));
+    assert_eq!(accessor.max, Some(Value::from(vec![1.0, 1.0, 0.25])));
+    let view = &json.buffer_views[json.accessors[colors.value()].buffer_view.unwrap().value()];
+    assert_eq!((view.byte_offset, view.byte_stride, view.byte_length), (Some(36), Some(4), 12));
+    let view = &json.buffer_views[json.accessors[indices.value()].buffer_view.unwrap().value()];
+    assert_eq!((view.byte_offset, view.byte_stride, view.byte_length), (Some(48), None, 6));
+
+    let mut mesh = gltf::json::Mesh::default();
+    mesh.primitives.push(gltf::json::mesh::Primitive {
+        attributes: vec![
+            (gltf::json::validation::Checked::Valid(gltf::Semantic::Positions), positions),
+            (gltf::json::validation::Checked::Valid(gltf::Semantic::Colors(0)), colors),
+        ]
+        .into_iter()
+        .collect(),
+        extensions: None,
+        extras: Default::default(),
+        indices: Some(indices),
+        material: None,
+        mode: gltf::json::validation::Checked::Valid(gltf::mesh::Mode::Triangles),
+        targets: None,
+    });
+    document.add(mesh);
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data]).to_vec().unwrap();
+    let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    assert_eq!(reader.read_positions().unwrap().nth(2), Some([0.0, 1.0, -0.1]));
+    assert_eq!(
+        reader.read_colors(0).unwrap().into_rgb_u8().collect::<Vec<_>>(),
+        vec![[255, 0, 0], [0, 255, 0], [0, 0, 255]],
+    );
+    assert_eq!(reader.read_indices().unwrap().into_u32().collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_mesh_builder() {
+    use gltf::build::{BufferBuilder, Error, MeshBuilder, PrimitiveBuilder};
+
+    let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]];
+    let normals = [[0.0, 0.0, 1.0]; 3];
+    let tex_coords = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    let result = MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).normals(&normals[..2]))
+        .build(&mut document, &mut buffer);
+    assert_eq!(
+        result.unwrap_err(),
+        Error::AttributeCount {
+            semantic: gltf::Semantic::Normals,
+            expected: 3,
+            actual: 2,
+        },
+    );
+    let result = MeshBuilder::new()
+        .primitive(PrimitiveBuilder::new(&positions).indices(&[0, 1, 3]))
+        .build(&mut document, &mut buffer);
+    assert_eq!(result.unwrap_err(), Error::IndexOutOfBounds { index: 3, count: 3 });
+    assert!(document.as_json().accessors.is_empty());
+
+    let mesh = MeshBuilder::new()
+        .primitive(
+            PrimitiveBuilder::new(&positions)
+                .normals(&normals)
+                .tex_coords(&tex_coords)
+                .indices(&[0, 1, 2]),
+        )
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+    assert_eq!(mesh.value(), 0);
+
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data]).to_vec().unwrap();
+    let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    assert_eq!(primitive.bounding_box().max, [1.0, 1.0, 0.0]);
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()]));
+    assert_eq!(reader.read_normals().unwrap().collect::<Vec<_>>(), normals);
+    assert_eq!(reader.read_tex_coords(0).unwrap().into_f32().collect::<Vec<_>>(), tex_coords);
+    assert_eq!(reader.read_indices().unwrap().into_u32().collect::<Vec<_>>(), vec![0, 1, 2]);
+}
+
+#[cfg(feature = "KHR_materials_emissive_strength")]
+#[test]
+fn test_material_builder() {
+    use gltf::build::MaterialBuilder;
+    use gltf::json::extensions::material::{EmissiveStrength, EmissiveStrengthFactor, Material};
+    use gltf::json::Index;
+
+    let mut document = gltf::DocumentMut::new();
+    let material = MaterialBuilder::new()
+        .base_color_factor([1.0, 0.5, 0.25, 1.0])
+        .base_color_texture(Index::new(0), 1)
+        .metallic_factor(0.0)
+        .roughness_factor(0.5)
+        .normal_texture(Index::new(1), 0, 0.5)
+        .emissive_factor([1.0, 1.0, 1.0])
+        .alpha_cutoff(0.25)
+        .double_sided(true)
+        .extensions(Material {
+            emissive_strength: Some(EmissiveStrength {
+                emissive_strength: EmissiveStrengthFactor(5.0),
+                extras: Default::default(),
+            }),
+            ..Default::default()
+        })
+        .build(&mut document);
+    let json = document.as_json();
+    assert_eq!(json.extensions_used, vec!["KHR_materials_emissive_strength"]);
+    let pbr = &json.materials[0].pbr_metallic_roughness;
+    assert_eq!(pbr.base_color_texture.as_ref().unwrap().tex_coord, 1);
+    assert_eq!(json.materials[0].normal_texture.as_ref().unwrap().scale, 0.5);
+
+    let document = document.into_document_without_validation();
+    let material = document.materials().nth(material.value()).unwrap();
+    let pbr = material.pbr_metallic_roughness();
+    assert_eq!(pbr.base_color_factor(), [1.0, 0.5, 0.25, 1.0]);
+    assert_eq!(pbr.metallic_factor(), 0.0);
+    assert_eq!(pbr.roughness_factor(), 0.5);
+    assert_eq!(material.alpha_mode(), gltf::material::AlphaMode::Mask);
+    assert_eq!(material.alpha_cutoff(), Some(0.25));
+    assert!(material.double_sided());
+    assert_eq!(material.emissive_strength(), Some(5.0));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_animation_builder() {
+    use gltf::animation::util::ReadOutputs;
+    use gltf::animation::{Interpolation, Property};
+    use gltf::build::{AnimationBuilder, BufferBuilder, Error};
+
+    let mut document = gltf::DocumentMut::new();
+    let node = document.add(gltf::json::Node::default());
+    let mut buffer = BufferBuilder::new(&mut document);
+
+    let times = [0.0, 0.5, 1.0];
+    let translations = [[0.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]];
+    let rotations = [[0.0, 0.0, 0.0, 1.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]];
+    let result = AnimationBuilder::new()
+        .translations(node, &times, &translations, Interpolation::CubicSpline)
+        .build(&mut document, &mut buffer);
+    assert_eq!(result.unwrap_err(), Error::KeyframeCount { expected: 9, actual: 3 });
+    let result = AnimationBuilder::new()
+        .translations(node, &[0.0, 1.0, 0.5], &translations, Interpolation::Linear)
+        .build(&mut document, &mut buffer);
+    assert_eq!(result.unwrap_err(), Error::KeyframeTimes);
+
+    AnimationBuilder::new()
+        .translations(node, &times, &translations, Interpolation::Linear)
+        .rotations(node, &times, &rotations, Interpolation::Step)
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+    assert_eq!(document.as_json().accessors.len(), 3);
+
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data]).to_vec().unwrap();
+    let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+    let animation = document.animations().next().unwrap();
+    let channels = animation.channels().collect::<Vec<_>>();
+    assert_eq!(channels.len(), 2);
+    assert_eq!(channels[0].target().property(), Property::Translation);
+    #[cfg(not(feature = "KHR_animation_pointer"))]
+    assert_eq!(channels[0].target().node().index(), 0);
+    #[cfg(feature = "KHR_animation_pointer")]
+    assert_eq!(channels[0].target().node().unwrap().index(), 0);
+    assert_eq!(channels[1].sampler().interpolation(), Interpolation::Step);
+    let reader = channels[1].reader(|buffer| Some(&buffers[buffer.index()]));
+    assert_eq!(reader.read_inputs().unwrap().collect::<Vec<_>>(), times);
+    match reader.read_outputs().unwrap() {
+        ReadOutputs::Rotations(rotations) => {
+            assert_eq!(rotations.into_f32().nth(1), Some([0.0, 1.0, 0.0, 0.0]))
+        }
+        _ => panic!("unexpected outputs"),
+    }
+}
+
+#[test]
+fn test_scene_builder() {
+    use gltf::build::{NodeBuilder, SceneBuilder};
+    use gltf::scene::Transform;
+
+    let mut document = gltf::DocumentMut::new();
+    let mesh = document.add(gltf::json::Mesh::default());
+    let leaf = NodeBuilder::new().translation([1.0, 2.0, 3.0]).mesh(mesh).build(&mut document);
+    let matrix = [
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 2.0, 0.0, 0.0],
+        [0.0, 0.0, 2.0, 0.0],
+        [0.0, 0.0, 5.0, 1.0],
+    ];
+    let root = NodeBuilder::new().matrix(matrix).child(leaf).build(&mut document);
+    let sibling = NodeBuilder::new()
+        .transform(Transform::Decomposed {
+            translation: [0.0, 1.0, 0.0],
+            rotation: [0.0, 0.0, 0.0, 1.0],
+            scale: [1.0, 1.0, 1.0],
+        })
+        .parent(root)
+        .build(&mut document);
+    let scene = SceneBuilder::new().node(root).build(&mut document);
+    assert_eq!((leaf.value(), root.value(), sibling.value()), (0, 1, 2));
+
+    let document = document.into_document().unwrap();
+    assert_eq!(document.default_scene().unwrap().index(), scene.value());
+    let root = document.scenes().next().unwrap().nodes().next().unwrap();
+    assert_eq!(root.transform().matrix(), matrix);
+    let children = root.children().collect::<Vec<_>>();
+    assert_eq!(children.len(), 2);
+    assert_eq!(children[0].mesh().unwrap().index(), mesh.value());
+    assert_eq!(children[0].transform().decomposed().0, [1.0, 2.0, 3.0]);
+    assert_eq!(children[1].index(), 2);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_skin_builder() {
+    use gltf::build::{BufferBuilder, Error, SkinBuilder};
+
+    let mut document = gltf::DocumentMut::new();
+    let root = document.add(gltf::json::Node::default());
+    let joint = document.add(gltf::json::Node::default());
+    let mut buffer = BufferBuilder::new(&mut document);
+
+    let result = SkinBuilder::new()
+        .joint(root, [[1.0, 0.0, 0.0, 0.0]; 4])
+        .build(&mut document, &mut buffer);
+    assert_eq!(result.unwrap_err(), Error::SingularBindPose { joint: 0 });
+
+    let translation = [
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+        [0.0, 0.0, 1.0, 0.0],
+        [0.0, 2.0, 0.0, 1.0],
+    ];
+    let scale = [
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 4.0, 0.0, 0.0],
+        [0.0, 0.0, 0.5, 0.0],
+        [0.0, 0.0, 0.0, 1.0],
+    ];
+    SkinBuilder::new()
+        .joint(root, translation)
+        .joint(joint, scale)
+        .skeleton(root)
+        .build(&mut document, &mut buffer)
+        .unwrap();
+    let data = buffer.finish(&mut document);
+
+    let glb = gltf::export::Export::from_json(document.into_json(), &[data]).to_vec().unwrap();
+    let (document, buffers, _) = gltf::import_slice(&glb).unwrap();
+    let skin = document.skins().next().unwrap();
+    assert_eq!(skin.joints().map(|joint| joint.index()).collect::<Vec<_>>(), vec![0, 1]);
+    assert_eq!(skin.skeleton().unwrap().index(), 0);
+    let reader = skin.reader(|buffer| Some(&buffers[buffer.index()]));
+    let matrices = reader.read_inverse_bind_matrices().unwrap().collect::<Vec<_>>();
+    assert_eq!(matrices[0][3], [0.0, -2.0, 0.0, 1.0]);
+    assert_eq!(matrices[1][0][0], 0.5);
+    assert_eq!(matrices[1][1][1], 0.25);
+    assert_eq!(matrices[1][2][2], 2.0);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_async() {
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+
+    struct Noop;
+
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(Noop));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    fn assert_send<T: Send>(value: T) -> T {
+        value
+    }
+
+    /// Serves files, recording the URIs requested.
+    struct Files(Mutex<Vec<String>>);
+
+    impl gltf::AsyncResolver for Files {
+        fn resolve<'a>(&'a self, uri: &'a str) -> Pin<Box<dyn Future<Output = gltf::Result<Vec<u8>>> + Send + 'a>> {
+            self.0.lock().unwrap().push(uri.to_owned());
+            Box::pin(async move { Ok(std::fs::read(uri)?) })
+        }
+    }
+
+    let files = Files(Mutex::new(Vec::new()));
+    let (document, buffers, _) = block_on(assert_send(gltf::import_async("tests/box_sparse.gltf", &files))).unwrap();
+    assert_eq!(*files.0.lock().unwrap(), ["tests/box_sparse.gltf", "tests/box_sparse.bin"]);
+    let (expected, expected_buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    assert_eq!(document.accessors().count(), expected.accessors().count());
+    assert_eq!(buffers[0].0, expected_buffers[0].0);
+
+    let glb = std::fs::read("tests/box_sparse.glb").unwrap();
+    let files = Files(Mutex::new(Vec::new()));
+    let (_, buffers, _) = block_on(gltf::import_slice_async(&glb, &files)).unwrap();
+    assert!(files.0.lock().unwrap().is_empty());
+    assert_eq!(buffers.len(), 1);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_async_with_options() {
+    use gltf::image::Imported;
+    use gltf::{CancellationToken, ImageMode, ImportOptions, Limit, Limits, Progress};
+    use std::collections::HashMap;
+    use std::future::Future;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll, Wake, Waker};
+    use std::thread::{self, Thread};
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = Box::pin(future);
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut context = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut context) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// Serves files from memory, recording the URIs requested.
+    struct Files(HashMap<String, Vec<u8>>, Mutex<Vec<String>>);
+
+    impl gltf::AsyncResolver for Files {
+        fn resolve<'a>(&'a self, uri: &'a str) -> gltf::ResolveFuture<'a> {
+            self.1.lock().unwrap().push(uri.to_owned());
+            Box::pin(async move { self.0.get(uri).cloned().ok_or(gltf::Error::UnsupportedScheme) })
+        }
+    }
+
+    // Encode an image to be served as a file.
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let pixels = [0, 255, 0, 255].repeat(6);
+    let mut export = gltf::export::Export::from_json(json.clone(), &buffers);
+    export.image_rgba(0, 3, 2, &pixels).unwrap();
+    let mut options = ImportOptions::new();
+    options.images(ImageMode::Encoded);
+    let (_, _, images) = gltf::import_slice_with_options(export.to_vec().unwrap(), &options).unwrap();
+    let png = match &images[0] {
+        Imported::Encoded(image) => image.data().to_vec(),
+        _ => panic!("image is not encoded"),
+    };
+
+    json.images[0].uri = Some("image.png".to_owned());
+    let mut files = HashMap::new();
+    files.insert("models/box.gltf".to_owned(), json.to_vec().unwrap());
+    files.insert("models/box_sparse.bin".to_owned(), fs::read("tests/box_sparse.bin").unwrap());
+    files.insert("models/image.png".to_owned(), png.clone());
+    let files = Files(files, Mutex::new(Vec::new()));
+    let import = |options: &ImportOptions| {
+        files.1.lock().unwrap().clear();
+        let result = block_on(gltf::import_async_with_options("models/box.gltf", &files, options));
+        (result, files.1.lock().unwrap().clone())
+    };
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let reported = progress.clone();
+    let mut options = ImportOptions::new();
+    options.progress(move |progress| reported.lock().unwrap().push(progress));
+    let (result, requested) = import(&options);
+    let (_, buffers, images) = result.unwrap();
+    assert_eq!(requested, ["models/box.gltf", "models/box_sparse.bin", "models/image.png"]);
+    assert_eq!(buffers.len(), 1);
+    assert!(matches!(&images[0], Imported::Decoded(image) if image.pixels == pixels));
+    assert_eq!(
+        *progress.lock().unwrap(),
+        [
+            Progress::Json,
+            Progress::Buffer { index: 0, count: 1 },
+            Progress::Image { index: 0, count: 1 },
+        ]
+    );
+
+    options.images(ImageMode::Encoded);
+    let (result, _) = import(&options);
+    assert!(matches!(&result.unwrap().2[0], Imported::Encoded(image) if image.data() == &png[..]));
+
+    options.images(ImageMode::Skip);
+    let (result, requested) = import(&options);
+    assert!(matches!(result.unwrap().2[..], [Imported::Skipped]));
+    assert_eq!(requested, ["models/box.gltf", "models/box_sparse.bin"]);
+
+    // Resources exceeding the limits are not fetched, or are rejected.
+    options.images(ImageMode::Decode);
+    options.limits(Limits { max_buffer_size: Some(16), ..Default::default() });
+    let (result, requested) = import(&options);
+    assert!(matches!(result, Err(gltf::Error::LimitExceeded(Limit::BufferSize))));
+    assert_eq!(requested, ["models/box.gltf"]);
+    options.limits(Limits { max_image_size: Some(png.len() - 1), ..Default::default() });
+    let (result, _) = import(&options);
+    assert!(matches!(result, Err(gltf::Error::LimitExceeded(Limit::ImageSize))));
+    options.limits(Limits::default());
+
+    let token = CancellationToken::new();
+    token.cancel();
+    options.cancellation(token);
+    let (result, requested) = import(&options);
+    assert!(matches!(result, Err(gltf::Error::Cancelled)));
+    assert!(requested.is_empty());
+
+    let glb = fs::read("tests/box_sparse.glb").unwrap();
+    let options = ImportOptions::new();
+    let result = block_on(gltf::import_slice_async_with_options(&glb, &files, &options));
+    assert_eq!(result.unwrap().1.len(), 1);
+
+    #[cfg(feature = "futures-io")]
+    {
+        let gltf = block_on(gltf::Gltf::from_reader_async(&glb[..])).unwrap();
+        assert_eq!(gltf.blob.unwrap().len(), gltf.document.buffers().next().unwrap().length());
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_with_resolver() {
+    use std::cell::RefCell;
+    use std::collections::HashMap;
+
+    let mut files = HashMap::new();
+    files.insert("models/box_sparse.gltf", std::fs::read("tests/box_sparse.gltf").unwrap());
+    files.insert("models/box_sparse.bin", std::fs::read("tests/box_sparse.bin").unwrap());
+    let requested = RefCell::new(Vec::new());
+    let resolver = |uri: &str| {
+        requested.borrow_mut().push(uri.to_owned());
+        files.get(uri).cloned().ok_or(gltf::Error::UnsupportedScheme)
+    };
+
+    let (_, buffers, _) = gltf::import_with_resolver("models/box_sparse.gltf", &resolver).unwrap();
+    let (_, expected, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    assert_eq!(*requested.borrow(), ["models/box_sparse.gltf", "models/box_sparse.bin"]);
+    assert_eq!(buffers.len(), expected.len());
+    assert_eq!(buffers[0].0, expected[0].0);
+
+    let gltf = &files["models/box_sparse.gltf"];
+    assert!(gltf::import_slice_with_resolver(gltf, &resolver).is_err());
+    assert_eq!(requested.borrow().last().unwrap(), "box_sparse.bin");
+}
+
+#[cfg(feature = "http")]
+#[test]
+fn test_import_http() {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            let mut stream = stream.unwrap();
+            let mut request = String::new();
+            BufReader::new(&stream).read_line(&mut request).unwrap();
+            let path = request.split(' ').nth(1).unwrap();
+            let response = match fs::read(format!("tests{}", path)) {
+                Ok(data) => {
+                    let header = format!("HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n", data.len());
+                    [header.into_bytes(), data].concat()
+                }
+                Err(_) => b"HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec(),
+            };
+            let _ = stream.write_all(&response);
+        }
+    });
+
+    let mut resolver = gltf::HttpResolver::new();
+    let uri = format!("http://{}/box_sparse.gltf", address);
+    let (_, buffers, _) = gltf::import_with_resolver(&uri, &resolver).unwrap();
+    let (_, expected, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    assert_eq!(buffers[0].0, expected[0].0);
+
+    let missing = format!("http://{}/missing.gltf", address);
+    assert!(matches!(gltf::import_with_resolver(&missing, &resolver), Err(gltf::Error::Http(_))));
+    resolver.max_size(100);
+    assert!(matches!(gltf::import_with_resolver(&uri, &resolver), Err(gltf::Error::Io(_))));
+}
+
+#[cfg(feature = "mmap")]
+#[test]
+fn test_import_mapped() {
+    for path in &["tests/box_sparse.gltf", "tests/box_sparse.glb", "examples/Box.gltf"] {
+        let (document, buffers, _) = gltf::import_mapped(path).unwrap();
+        let (_, expected, _) = gltf::import(path).unwrap();
+        assert_eq!(buffers.len(), expected.len());
+        for (buffer, (data, expected)) in document.buffers().zip(buffers.iter().zip(&expected)) {
+            assert!(data.is_mapped());
+            assert_eq!(data[..buffer.length()], expected[..buffer.length()]);
+        }
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_lazy() {
+    for path in &["tests/box_sparse.gltf", "tests/box_sparse.glb", "examples/Box.glb"] {
+        let (document, buffers) = gltf::import_lazy(path).unwrap();
+        let (_, expected, _) = gltf::import(path).unwrap();
+        assert_eq!(buffers.len(), expected.len());
+        assert!((0..buffers.len()).all(|index| !buffers.is_loaded(index)));
+
+        for mesh in document.meshes() {
+            for primitive in mesh.primitives() {
+                let reader = primitive.reader(|buffer| buffers.get(buffer));
+                let expected = primitive.reader(|buffer| Some(&expected[buffer.index()]));
+                assert_eq!(
+                    reader.read_positions().unwrap().collect::<Vec<_>>(),
+                    expected.read_positions().unwrap().collect::<Vec<_>>(),
+                );
+            }
+        }
+        for (index, expected) in expected.iter().enumerate() {
+            assert!(buffers.is_loaded(index));
+            assert_eq!(buffers.load(index).unwrap().0, expected.0);
+        }
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_with_options() {
+    use gltf::export::Export;
+    use gltf::image::Imported;
+    use gltf::{ImageMode, ImportOptions};
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let pixels = [0, 255, 0, 255].repeat(6);
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 3, 2, &pixels).unwrap();
+    let glb = export.to_vec().unwrap();
+
+    let mut options = ImportOptions::new();
+    let (_, _, images) = gltf::import_slice_with_options(&glb, &options).unwrap();
+    assert!(matches!(&images[0], Imported::Decoded(image) if image.pixels == pixels));
+
+    options.images(ImageMode::Encoded);
+    let (_, _, images) = gltf::import_slice_with_options(&glb, &options).unwrap();
+    let image = match &images[0] {
+        Imported::Encoded(image) => image,
+        _ => panic!("image is not encoded"),
+    };
+    assert_eq!(image.mime_type(), "image/png");
+    assert!(image.data().starts_with(b"\x89PNG"));
+    assert_eq!(image.decode().unwrap().pixels, pixels);
+
+    options.images(ImageMode::Skip);
+    let (_, buffers, images) = gltf::import_slice_with_options(&glb, &options).unwrap();
+    assert_eq!(buffers.len(), 1);
+    assert!(matches!(images[..], [Imported::Skipped]));
+}
+
+#[test]
+fn test_glb_reader() {
+    use gltf::binary::{ChunkType, GlbReader};
+
+    /// Yields at most one byte per read, as a slow network stream might.
+    struct Trickle<'a>(&'a [u8]);
+
+    impl<'a> Read for Trickle<'a> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let length = buf.len().min(self.0.len()).min(1);
+            buf[..length].copy_from_slice(&self.0[..length]);
+            self.0 = &self.0[length..];
+            Ok(length)
+        }
+    }
+
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let expected = gltf::Glb::from_slice(&data).unwrap();
+    let mut glb = GlbReader::new(Trickle(&data)).unwrap();
+    assert_eq!(glb.header().length, expected.header.length);
+    let json = glb.next_chunk().unwrap().unwrap();
+    assert!(matches!(json.ty(), ChunkType::Json));
+    assert_eq!(json.to_vec().unwrap(), &expected.json[..]);
+    let mut bin = glb.next_chunk().unwrap().unwrap();
+    assert!(matches!(bin.ty(), ChunkType::Bin));
+    let mut data = Vec::new();
+    bin.read_to_end(&mut data).unwrap();
+    assert_eq!(data, &expected.bin.as_ref().unwrap()[..]);
+    assert!(glb.next_chunk().unwrap().is_none());
+
+    // Unread chunks are skipped.
+    let data = fs::read("tests/box_sparse.glb").unwrap();
+    let mut glb = GlbReader::new(&data[..]).unwrap();
+    glb.next_chunk().unwrap();
+    assert_eq!(glb.next_chunk().unwrap().unwrap().to_vec().unwrap(), &expected.bin.as_ref().unwrap()[..]);
+
+    // Truncated streams are detected as the chunks are read.
+    let mut glb = GlbReader::new(&data[..data.len() - 4]).unwrap();
+    glb.next_chunk().unwrap();
+    assert!(glb.next_chunk().unwrap().unwrap().to_vec().is_err());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_progress() {
+    use gltf::{CancellationToken, ImportOptions, Progress};
+    use std::sync::{Arc, Mutex};
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let mut options = ImportOptions::new();
+    let sink = reports.clone();
+    options.progress(move |progress| sink.lock().unwrap().push(progress));
+    gltf::import_with_options("tests/box_sparse.gltf", &options).unwrap();
+    assert_eq!(*reports.lock().unwrap(), [Progress::Json, Progress::Buffer { index: 0, count: 1 }]);
+
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    options.progress(move |progress| {
+        if let Progress::Json = progress {
+            cancel.cancel();
+        }
+    });
+    options.cancellation(token.clone());
+    let result = gltf::import_with_options("tests/box_sparse.gltf", &options);
+    assert!(matches!(result, Err(gltf::Error::Cancelled)));
+    assert!(token.is_cancelled());
+}
+
+#[cfg(all(feature = "rayon", feature = "utils"))]
+#[test]
+fn test_import_parallel() {
+    use gltf::export::Export;
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    for _ in 0..8 {
+        json.images.push(gltf::json::Image {
+            buffer_view: None,
+            mime_type: None,
+            #[cfg(feature = "names")]
+            name: None,
+            uri: None,
+            extensions: None,
+            extras: Default::default(),
+        });
+    }
+    let mut export = Export::from_json(json, &buffers);
+    for index in 0..8 {
+        export.image_rgba(index, 2, 2, &[index as u8; 16]).unwrap();
+    }
+    let glb = export.to_vec().unwrap();
+
+    // The resolver is not used, but keeps the import sequential.
+    let resolver = |_: &str| Err(gltf::Error::UnsupportedScheme);
+    let (_, _, expected) = gltf::import_slice_with_resolver(&glb, &resolver).unwrap();
+    let (_, _, images) = gltf::import_slice(&glb).unwrap();
+    assert_eq!(images.len(), 8);
+    for (index, (image, expected)) in images.iter().zip(&expected).enumerate() {
+        assert_eq!(image.pixels, expected.pixels);
+        assert_eq!(image.pixels, [index as u8; 16]);
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_import_limits() {
+    use gltf::export::Export;
+    use gltf::{ImportOptions, Limit, Limits};
+
+    fn exceeded(result: gltf::Result<impl Sized>) -> Option<Limit> {
+        match result {
+            Err(gltf::Error::LimitExceeded(limit)) => Some(limit),
+            _ => None,
+        }
+    }
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 16, 4, &[255; 256]).unwrap();
+    let glb = export.to_vec().unwrap();
+
+    let mut options = ImportOptions::new();
+    let limits = Limits {
+        max_json_size: Some(4096),
+        max_buffer_size: Some(1 << 20),
+        max_image_size: Some(1 << 16),
+        max_image_dimension: Some(16),
+        max_nodes: Some(1),
+    };
+    options.limits(limits);
+    assert!(gltf::import_slice_with_options(&glb, &options).is_ok());
+    assert!(gltf::import_with_options("tests/box_sparse.glb", &options).is_ok());
+
+    options.limits(Limits { max_json_size: Some(100), ..limits });
+    assert_eq!(exceeded(gltf::import_slice_with_options(&glb, &options)), Some(Limit::JsonSize));
+    assert_eq!(exceeded(gltf::import_with_options("tests/box_sparse.gltf", &options)), Some(Limit::JsonSize));
+    assert_eq!(exceeded(gltf::import_with_options("tests/box_sparse.glb", &options)), Some(Limit::JsonSize));
+
+    options.limits(Limits { max_buffer_size: Some(100), ..limits });
+    assert_eq!(exceeded(gltf::import_slice_with_options(&glb, &options)), Some(Limit::BufferSize));
+    assert_eq!(exceeded(gltf::import_with_options("tests/box_sparse.gltf", &options)), Some(Limit::BufferSize));
+
+    options.limits(Limits { max_image_dimension: Some(8), ..limits });
+    assert_eq!(exceeded(gltf::import_slice_with_options(&glb, &options)), Some(Limit::ImageDimension));
+
+    options.limits(Limits { max_image_size: Some(16), ..limits });
+    assert_eq!(exceeded(gltf::import_slice_with_options(&glb, &options)), Some(Limit::ImageSize));
+
+    options.limits(Limits { max_nodes: Some(0), ..limits });
+    assert_eq!(exceeded(gltf::import_slice_with_options(&glb, &options)), Some(Limit::Nodes));
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_limits_external_files() {
+    use gltf::{ImageMode, ImportOptions, Limit, Limits};
+
+    // The files are far larger than declared, but sparse, so that only the
+    // bytes read by the importer are ever allocated.
+    let dir = std::env::temp_dir().join("gltf_test_import_limits_external_files");
+    fs::create_dir_all(&dir).unwrap();
+    fs::File::create(dir.join("big.bin")).unwrap().set_len(1 << 33).unwrap();
+    fs::File::create(dir.join("big.png")).unwrap().set_len(1 << 33).unwrap();
+    fs::write(dir.join("small.bin"), [1, 2, 3, 4]).unwrap();
+    let write = |name: &str, json: &str| {
+        let path = dir.join(name);
+        fs::write(&path, format!(r#"{{"asset":{{"version":"2.0"}},{}}}"#, json)).unwrap();
+        path
+    };
+    let big_buffer = write("big_buffer.gltf", r#""buffers":[{"byteLength":4,"uri":"big.bin"}]"#);
+    let small_buffers = write(
+        "small_buffers.gltf",
+        r#""buffers":[{"byteLength":4,"uri":"small.bin"},{"byteLength":4,"uri":"small.bin"}]"#,
+    );
+    let big_image = write("big_image.gltf", r#""images":[{"uri":"big.png"}]"#);
+
+    let exceeded = |path: &std::path::Path, limits: Limits| {
+        let mut options = ImportOptions::new();
+        options.images(ImageMode::Encoded).limits(limits);
+        match gltf::import_with_options(path, &options) {
+            Err(gltf::Error::LimitExceeded(limit)) => Some(limit),
+            Err(error) => panic!("{}", error),
+            Ok(_) => None,
+        }
+    };
+    let limits = Limits { max_buffer_size: Some(16), max_image_size: Some(16), ..Default::default() };
+    assert_eq!(exceeded(&big_buffer, limits), Some(Limit::BufferSize));
+    assert_eq!(exceeded(&small_buffers, limits), None);
+    assert_eq!(exceeded(&small_buffers, Limits { max_buffer_size: Some(7), ..limits }), Some(Limit::BufferSize));
+    assert_eq!(exceeded(&big_image, limits), Some(Limit::ImageSize));
+
+    // Limits at the largest size must not overflow.
+    let max = Some(usize::MAX);
+    let unbounded = Limits { max_json_size: max, max_buffer_size: max, max_image_size: max, ..Default::default() };
+    assert_eq!(exceeded(&small_buffers, unbounded), None);
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_data_uri_buffers() {
+    for length in 0..12 {
+        let data = (0..length as u8).map(|byte| byte.wrapping_mul(37)).collect::<Vec<_>>();
+        let gltf = format!(
+            r#"{{"asset":{{"version":"2.0"}},"buffers":[{{"byteLength":{},"uri":"data:application/octet-stream;base64,{}"}}]}}"#,
+            length,
+            base64::encode(&data),
+        );
+        let (_, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+        assert_eq!(buffers[0].len() % 4, 0);
+        assert_eq!(buffers[0][..length], data[..]);
+        assert!(buffers[0][length..].iter().all(|&byte| byte == 0));
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_encoded_ktx2() {
+    use gltf::image::Imported;
+    use gltf::{ImageMode, ImportOptions};
+
+    let mut ktx2 = vec![0xAB, b'K', b'T', b'X', b' ', b'2', b'0', 0xBB, b'\r', b'\n', 0x1A, b'\n'];
+    ktx2.extend_from_slice(&[1, 2, 3, 4]);
+    let gltf = format!(
+        r#"{{"asset":{{"version":"2.0"}},"images":[{{"uri":"data:image/ktx2;base64,{0}"}},{{"uri":"data:;base64,{0}"}}]}}"#,
+        base64::encode(&ktx2),
+    );
+    let path = std::env::temp_dir().join("gltf_test_import_encoded_ktx2.gltf");
+    fs::write(&path, gltf).unwrap();
+    assert!(gltf::import(&path).is_err());
+
+    let mut options = ImportOptions::new();
+    options.images(ImageMode::Encoded);
+    let (_, _, images) = gltf::import_with_options(&path, &options).unwrap();
+    fs::remove_file(&path).unwrap();
+    for image in &images {
+        let image = match image {
+            Imported::Encoded(image) => image,
+            _ => panic!("image is not encoded"),
+        };
+        assert_eq!(image.data(), &ktx2[..]);
+        assert_eq!(image.mime_type(), "image/ktx2");
+        assert!(!image.is_decodable());
+        assert!(matches!(image.decode(), Err(gltf::Error::UnsupportedImageEncoding)));
+    }
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_import_cached() {
+    use std::sync::Arc;
+
+    let cache = gltf::Cache::new();
+    let (_, first, _) = gltf::import_cached("examples/Box.gltf", &cache).unwrap();
+    let (_, second, _) = gltf::import_cached("examples/../examples/Box.gltf", &cache).unwrap();
+    assert!(Arc::ptr_eq(&first[0], &second[0]));
+    assert_eq!(cache.buffer_count(), 1);
+
+    let (_, glb, _) = gltf::import_cached("examples/Box.glb", &cache).unwrap();
+    assert_eq!(glb[0][..first[0].len()], first[0][..]);
+    assert_eq!(cache.buffer_count(), 1);
+
+    cache.evict_unused();
+    assert_eq!(cache.buffer_count(), 1);
+    drop((first, second));
+    cache.evict_unused();
+    assert_eq!(cache.buffer_count(), 0);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_iter_errors() {
+    use gltf::accessor::{Error, Iter};
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let positions = document
+        .meshes()
+        .flat_map(|mesh| mesh.primitives())
+        .find_map(|primitive| primitive.get(&gltf::Semantic::Positions))
+        .unwrap();
+    let view = positions.view().unwrap();
+    let data = &buffers[0][..];
+    assert_eq!(Iter::<[f32; 3]>::new(positions.clone(), |_| Some(data)).unwrap().len(), positions.count());
+
+    let missing = Iter::<[f32; 3]>::new(positions.clone(), |_| None).unwrap_err();
+    assert_eq!(missing, Error::MissingBufferData { buffer: 0 });
+
+    let mismatch = Iter::<f32>::new(positions.clone(), |_| Some(data)).unwrap_err();
+    assert_eq!(mismatch, Error::SizeMismatch { expected: 12, actual: 4 });
+
+    let truncated = &data[..view.offset() + 1];
+    let out_of_bounds = Iter::<[f32; 3]>::new(positions, |_| Some(truncated)).unwrap_err();
+    assert_eq!(
+        out_of_bounds,
+        Error::ViewOutOfBounds { view: view.index(), end: view.offset() + view.length(), length: truncated.len() },
+    );
+    assert!(out_of_bounds.to_string().contains("buffer view"));
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_read_dyn() {
+    use gltf::accessor::{DynElement, Element, Iter};
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+
+    let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    let elements = positions.read_dyn(get_buffer_data).unwrap();
+    assert_eq!(elements.len(), positions.count());
+    let expected = Iter::<[f32; 3]>::new(positions, get_buffer_data).unwrap();
+    for (element, position) in elements.zip(expected) {
+        assert_eq!(element, DynElement::F32(Element::Vec3(position)));
+    }
+
+    let indices = primitive.indices().unwrap();
+    let mut elements = indices.read_dyn(get_buffer_data).unwrap();
+    assert!(matches!(elements.next(), Some(DynElement::U16(Element::Scalar(_)))));
+    assert_eq!(elements.len(), indices.count() - 1);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_read_normalized() {
+    let mut data = vec![255u8, 0, 51, 255, 0x80, 0x7f, 0, 0];
+    data.extend_from_slice(&[0xff, 0xff, 0, 0, 0, 0, 0, 0]);
+    data.extend_from_slice(&[0; 12]);
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 28, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 28}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5121, "normalized": true, "count": 1, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 4, "componentType": 5120, "normalized": true, "count": 1, "type": "VEC2"}},
+                {{"bufferView": 0, "byteOffset": 8, "componentType": 5123, "normalized": true, "count": 1, "type": "VEC4"}},
+                {{"bufferView": 0, "componentType": 5121, "count": 1, "type": "VEC4"}},
+                {{"bufferView": 0, "byteOffset": 16, "componentType": 5126, "count": 1, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 4, "COLOR_0": 0}}}}]}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let accessors = document.accessors().collect::<Vec<_>>();
+
+    let colors = accessors[0].read_normalized::<4, _>(get_buffer_data).unwrap();
+    assert_eq!(colors.collect::<Vec<_>>(), [[1.0, 0.0, 0.2, 1.0]]);
+    let signed = accessors[1].read_normalized::<2, _>(get_buffer_data).unwrap();
+    assert_eq!(signed.collect::<Vec<_>>(), [[-1.0, 1.0]]);
+    let weights = accessors[2].read_normalized::<4, _>(get_buffer_data).unwrap();
+    assert_eq!(weights.collect::<Vec<_>>(), [[1.0, 0.0, 0.0, 0.0]]);
+    let unnormalized = accessors[3].read_normalized::<4, _>(get_buffer_data).unwrap();
+    assert_eq!(unnormalized.collect::<Vec<_>>(), [[255.0, 0.0, 51.0, 255.0]]);
+    assert!(accessors[0].read_normalized::<3, _>(get_buffer_data).is_err());
+
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let colors = primitive.reader(get_buffer_data).read_colors(0).unwrap().into_rgba_f32();
+    assert_eq!(colors.collect::<Vec<_>>(), [[1.0, 0.0, 0.2, 1.0]]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_indices_u32() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    let expected = reader.read_indices().unwrap().into_u32().collect::<Vec<_>>();
+    let indices = reader.read_indices_u32().unwrap();
+    assert_eq!(indices.len(), expected.len());
+    assert_eq!(indices.collect::<Vec<_>>(), expected);
+
+    let mut json = document.into_json();
+    json.meshes[0].primitives[0].indices = None;
+    let document = gltf::Document::from_json_without_validation(json);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let indices = primitive.reader(get_buffer_data).read_indices_u32().unwrap();
+    assert_eq!(indices.collect::<Vec<_>>(), (0..24).collect::<Vec<_>>());
+}
+
+#[cfg(all(feature = "import", feature = "utils", feature = "bytemuck"))]
+#[test]
+fn test_accessor_as_slice() {
+    use gltf::accessor::Iter;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    let slice = positions.as_slice::<[f32; 3], _>(get_buffer_data).unwrap();
+    let expected = Iter::<[f32; 3]>::new(positions.clone(), get_buffer_data).unwrap();
+    assert_eq!(slice, &expected.collect::<Vec<_>>()[..]);
+    assert!(positions.as_slice::<[f32; 4], _>(get_buffer_data).is_none());
+    assert!(positions.as_slice::<[f32; 3], _>(|_| None).is_none());
+
+    let index = positions.index();
+    let mut json = document.into_json();
+    let view = json.accessors[index].buffer_view.unwrap().value();
+    json.buffer_views[view].byte_stride = Some(24);
+    let document = gltf::Document::from_json_without_validation(json);
+    let positions = document.accessors().nth(index).unwrap();
+    assert!(positions.as_slice::<[f32; 3], _>(get_buffer_data).is_none());
+}
+
+/// Returns glTF with two sparse `f32` accessors, whose elements are
+/// `[10.0, 2.0, 30.0]` and `[0.0, 5.0, 0.0, 6.0]`.
+#[cfg(all(feature = "import", feature = "utils"))]
+fn sparse_accessors_gltf() -> String {
+    let mut data = Vec::new();
+    for value in [1.0f32, 2.0, 3.0] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[0, 2, 0, 0]);
+    for value in [10.0f32, 30.0] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[1, 0, 3, 0]);
+    for value in [5.0f32, 6.0] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 36, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 12}},
+                {{"buffer": 0, "byteOffset": 12, "byteLength": 2}},
+                {{"buffer": 0, "byteOffset": 16, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 24, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 28, "byteLength": 8}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "SCALAR", "sparse": {{
+                    "count": 2,
+                    "indices": {{"bufferView": 1, "componentType": 5121}},
+                    "values": {{"bufferView": 2}}
+                }}}},
+                {{"componentType": 5126, "count": 4, "type": "SCALAR", "sparse": {{
+                    "count": 2,
+                    "indices": {{"bufferView": 3, "componentType": 5123}},
+                    "values": {{"bufferView": 4}}
+                }}}}
+            ]
+        }}"#,
+        base64::encode(&data),
+    )
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_get() {
+    use gltf::accessor::{Get, Iter};
+
+    let gltf = sparse_accessors_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let expected = [vec![10.0, 2.0, 30.0], vec![0.0, 5.0, 0.0, 6.0]];
+    for (accessor, expected) in document.accessors().zip(expected) {
+        let get = Get::<f32>::new(accessor, get_buffer_data).unwrap();
+        assert_eq!(get.len(), expected.len());
+        let values = (0..get.len()).rev().map(|index| get.get(index).unwrap()).rev().collect::<Vec<_>>();
+        assert_eq!(values, expected);
+        assert_eq!(get.get(expected.len()), None);
+    }
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let positions = document.meshes().next().unwrap().primitives().next().unwrap().get(&gltf::Semantic::Positions).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let get = Get::<[f32; 3]>::new(positions.clone(), get_buffer_data).unwrap();
+    for (index, position) in Iter::<[f32; 3]>::new(positions, get_buffer_data).unwrap().enumerate() {
+        assert_eq!(get.get(index), Some(position));
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_iter_double_ended() {
+    use gltf::accessor::Iter;
+
+    let gltf = sparse_accessors_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let expected = [vec![10.0, 2.0, 30.0], vec![0.0, 5.0, 0.0, 6.0]];
+    for (accessor, expected) in document.accessors().zip(expected) {
+        let iter = Iter::<f32>::new(accessor, get_buffer_data).unwrap();
+        assert_eq!(iter.len(), expected.len());
+        assert_eq!(iter.clone().collect::<Vec<_>>(), expected);
+        assert_eq!(iter.clone().rev().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+        assert_eq!(iter.clone().last(), expected.last().copied());
+        for n in 0..=expected.len() {
+            let mut front = iter.clone();
+            assert_eq!(front.nth(n), expected.get(n).copied());
+            assert_eq!(front.collect::<Vec<_>>(), expected.get(n + 1..).unwrap_or_default());
+            let mut back = iter.clone();
+            assert_eq!(back.nth_back(n), expected.len().checked_sub(n + 1).map(|i| expected[i]));
+            assert_eq!(back.len(), expected.len().saturating_sub(n + 1));
+        }
+        let mut both = iter.clone();
+        assert_eq!(both.next_back(), expected.last().copied());
+        assert_eq!(both.next(), expected.first().copied());
+        assert_eq!(both.len(), expected.len() - 2);
+    }
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let positions = document.meshes().next().unwrap().primitives().next().unwrap().get(&gltf::Semantic::Positions).unwrap();
+    let iter = Iter::<[f32; 3]>::new(positions, get_buffer_data).unwrap();
+    let expected = iter.clone().collect::<Vec<_>>();
+    assert_eq!(iter.clone().rev().collect::<Vec<_>>(), expected.iter().rev().copied().collect::<Vec<_>>());
+    let mut iter = iter;
+    assert_eq!(iter.nth(10), Some(expected[10]));
+    assert_eq!(iter.nth_back(10), Some(expected[expected.len() - 11]));
+    assert_eq!(iter.collect::<Vec<_>>(), expected[11..expected.len() - 11]);
+}
+
+/// Returns glTF with a primitive with positions `[[0.0, 1.0, 2.0], [3.0, 4.0,
+/// 5.0], [6.0, 7.0, 8.0]]` and a morph target displacing them by `[[1.0, 1.0,
+/// 1.0], [5.0, 5.0, 5.0], [1.0, 1.0, 1.0]]`, which also displaces normals. The
+/// displacements are stored in sparse accessors.
+#[cfg(all(feature = "import", feature = "utils"))]
+fn morph_target_gltf() -> String {
+    let floats = |values: &[f32]| values.iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<_>>();
+    let mut data = floats(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+    data.extend(floats(&[1.0; 9]));
+    data.extend_from_slice(&[1, 0, 0, 0]);
+    data.extend(floats(&[5.0, 5.0, 5.0]));
+    data.extend_from_slice(&[2, 0, 0, 0]);
+    data.extend(floats(&[0.0, 0.0, 1.0]));
+    format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 104, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 36}},
+                {{"buffer": 0, "byteOffset": 36, "byteLength": 36}},
+                {{"buffer": 0, "byteOffset": 72, "byteLength": 1}},
+                {{"buffer": 0, "byteOffset": 76, "byteLength": 12}},
+                {{"buffer": 0, "byteOffset": 88, "byteLength": 1}},
+                {{"buffer": 0, "byteOffset": 92, "byteLength": 12}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 1, 2], "max": [6, 7, 8]}},
+                {{"bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "sparse": {{
+                    "count": 1,
+                    "indices": {{"bufferView": 2, "componentType": 5121}},
+                    "values": {{"bufferView": 3}}
+                }}}},
+                {{"componentType": 5126, "count": 3, "type": "VEC3", "sparse": {{
+                    "count": 1,
+                    "indices": {{"bufferView": 4, "componentType": 5121}},
+                    "values": {{"bufferView": 5}}
+                }}}}
+            ],
+            "meshes": [{{"primitives": [{{
+                "attributes": {{"POSITION": 0}},
+                "targets": [{{"POSITION": 1, "NORMAL": 2}}]
+            }}]}}]
+        }}"#,
+        base64::encode(&data),
+    )
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_sparse_morph_targets() {
+    let gltf = morph_target_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let (positions, normals, tangents) = reader.read_morph_targets().next().unwrap();
+    assert_eq!(
+        positions.unwrap().collect::<Vec<_>>(),
+        [[1.0, 1.0, 1.0], [5.0, 5.0, 5.0], [1.0, 1.0, 1.0]],
+    );
+    assert_eq!(
+        normals.unwrap().collect::<Vec<_>>(),
+        [[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 1.0]],
+    );
+    assert!(tangents.is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_accessor_without_buffer_view() {
+    use gltf::accessor::{Get, Iter};
+
+    let gltf = r#"{
+        "asset": {"version": "2.0"},
+        "accessors": [
+            {"componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [0, 0, 0]},
+            {"componentType": 5123, "count": 3, "type": "SCALAR"}
+        ],
+        "meshes": [{"primitives": [{"attributes": {"POSITION": 0}, "indices": 1}]}]
+    }"#;
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    assert!(buffers.is_empty());
+    let get_buffer_data = |_: gltf::Buffer| -> Option<&[u8]> { unreachable!() };
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    assert_eq!(reader.read_positions().unwrap().collect::<Vec<_>>(), [[0.0; 3]; 3]);
+    assert_eq!(reader.read_indices_u32().unwrap().collect::<Vec<_>>(), [0; 3]);
+
+    let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    let iter = Iter::<[f32; 3]>::new(positions.clone(), get_buffer_data).unwrap();
+    assert_eq!(iter.rev().len(), 3);
+    let get = Get::<[f32; 3]>::new(positions, get_buffer_data).unwrap();
+    assert_eq!(get.get(2), Some([0.0; 3]));
+    assert_eq!(get.get(3), None);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_blended_positions() {
+    let gltf = morph_target_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let base = reader.read_positions().unwrap().collect::<Vec<_>>();
+
+    let blended = reader.read_blended_positions(&[0.5]).unwrap();
+    assert_eq!(blended.len(), 3);
+    assert_eq!(
+        blended.collect::<Vec<_>>(),
+        [[0.5, 1.5, 2.5], [5.5, 6.5, 7.5], [6.5, 7.5, 8.5]],
+    );
+    let mut blended = reader.read_blended_positions(&[2.0]).unwrap();
+    assert_eq!(blended.nth(1), Some([13.0, 14.0, 15.0]));
+    assert_eq!(blended.next(), Some([8.0, 9.0, 10.0]));
+
+    assert_eq!(reader.read_blended_positions(&[0.0]).unwrap().collect::<Vec<_>>(), base);
+    assert_eq!(reader.read_blended_positions(&[]).unwrap().collect::<Vec<_>>(), base);
+    assert!(reader.read_blended_normals(&[1.0]).is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_triangles() {
+    use gltf::json::validation::Checked;
+    use gltf::mesh::Mode;
+
+    let mut data = (0..8).flat_map(|i| [i as f32, 0.0, 0.0]).flat_map(f32::to_le_bytes).collect::<Vec<_>>();
+    data.extend_from_slice(&[0, 1, 2, 3, 3, 4, 4, 5, 6, 7]);
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 106, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 96}},
+                {{"buffer": 0, "byteOffset": 96, "byteLength": 10}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 8, "type": "VEC3", "min": [0, 0, 0], "max": [7, 0, 0]}},
+                {{"bufferView": 1, "componentType": 5121, "count": 10, "type": "SCALAR"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0}}, "indices": 1, "mode": 5}}]}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    // The degenerate triangles joining the two strips are skipped.
+    assert_eq!(
+        reader.read_triangles().unwrap().collect::<Vec<_>>(),
+        [[0, 1, 2], [1, 3, 2], [4, 5, 6], [5, 7, 6]],
+    );
+    let positions = reader.read_triangle_positions().unwrap().collect::<Vec<_>>();
+    assert_eq!(positions.len(), 4);
+    assert_eq!(positions[1], [[1.0, 0.0, 0.0], [3.0, 0.0, 0.0], [2.0, 0.0, 0.0]]);
+
+    let read = |mode: Mode, indexed: bool| {
+        let mut json = document.clone().into_json();
+        json.meshes[0].primitives[0].mode = Checked::Valid(mode);
+        if !indexed {
+            json.meshes[0].primitives[0].indices = None;
+        }
+        let document = gltf::Document::from_json_without_validation(json);
+        let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+        let triangles = primitive.reader(get_buffer_data).read_triangles();
+        triangles.map(|triangles| triangles.collect::<Vec<_>>())
+    };
+    assert_eq!(read(Mode::Triangles, true).unwrap(), [[0, 1, 2], [3, 3, 4], [4, 5, 6]]);
+    assert_eq!(read(Mode::Triangles, false).unwrap(), [[0, 1, 2], [3, 4, 5]]);
+    assert_eq!(
+        read(Mode::TriangleStrip, false).unwrap(),
+        [[0, 1, 2], [1, 3, 2], [2, 3, 4], [3, 5, 4], [4, 5, 6], [5, 7, 6]],
+    );
+    assert_eq!(
+        read(Mode::TriangleFan, false).unwrap(),
+        [[1, 2, 0], [2, 3, 0], [3, 4, 0], [4, 5, 0], [5, 6, 0], [6, 7, 0]],
+    );
+    assert_eq!(
+        read(Mode::TriangleFan, true).unwrap(),
+        [[1, 2, 0], [2, 3, 0], [3, 4, 0], [4, 5, 0], [5, 6, 0], [6, 7, 0]],
+    );
+    assert!(read(Mode::Lines, false).is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_lines_and_points() {
+    use gltf::json::validation::Checked;
+    use gltf::mesh::Mode;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let with_mode = |mode: Mode, indexed: bool| {
+        let mut json = document.clone().into_json();
+        json.meshes[0].primitives[0].mode = Checked::Valid(mode);
+        if !indexed {
+            json.meshes[0].primitives[0].indices = None;
+        }
+        gltf::Document::from_json_without_validation(json)
+    };
+
+    let document = with_mode(Mode::Lines, true);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    let indices = reader.read_indices_u32().unwrap().collect::<Vec<_>>();
+    let lines = reader.read_lines().unwrap();
+    assert_eq!(lines.len(), 18);
+    assert!(lines.zip(indices.chunks(2)).all(|(line, pair)| line == pair));
+    assert!(reader.read_triangles().is_none());
+    assert!(reader.read_points().is_none());
+
+    let document = with_mode(Mode::LineStrip, false);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let lines = primitive.reader(get_buffer_data).read_lines().unwrap();
+    assert_eq!(lines.len(), 23);
+    assert_eq!(lines.clone().next(), Some([0, 1]));
+    assert_eq!(lines.last(), Some([22, 23]));
+
+    let document = with_mode(Mode::LineLoop, false);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    let mut lines = reader.read_lines().unwrap();
+    assert_eq!(lines.len(), 24);
+    assert_eq!(lines.nth(22), Some([22, 23]));
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines.next(), Some([23, 0]));
+    assert_eq!(lines.next(), None);
+    let positions = reader.read_positions().unwrap().collect::<Vec<_>>();
+    let segments = reader.read_line_positions().unwrap().collect::<Vec<_>>();
+    assert_eq!(segments.len(), 24);
+    assert_eq!(segments[23], [positions[23], positions[0]]);
+
+    let document = with_mode(Mode::Points, false);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(get_buffer_data);
+    assert_eq!(reader.read_points().unwrap().collect::<Vec<_>>(), (0..24).collect::<Vec<_>>());
+    assert_eq!(reader.read_point_positions().unwrap().collect::<Vec<_>>(), positions);
+    assert!(reader.read_lines().is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_world_positions_and_normals() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let local = reader.read_positions().unwrap().collect::<Vec<_>>();
+    let normals = reader.read_normals().unwrap().collect::<Vec<_>>();
+
+    // Mirrors along X, scales along Y, shears Z into X, and translates.
+    let transform = [
+        [-1.0, 0.0, 0.0, 0.0],
+        [0.0, 3.0, 0.0, 0.0],
+        [0.5, 0.0, 1.0, 0.0],
+        [1.0, 2.0, 3.0, 1.0],
+    ];
+    let positions = reader.read_world_positions(transform).unwrap();
+    assert_eq!(positions.len(), local.len());
+    let positions = positions.collect::<Vec<_>>();
+    for (world, [x, y, z]) in positions.iter().zip(&local) {
+        assert_eq!(*world, [1.0 - x + 0.5 * z, 2.0 + 3.0 * y, 3.0 + z]);
+    }
+
+    let world_normals = reader.read_world_normals(transform).unwrap().collect::<Vec<_>>();
+    let dot = |a: [f32; 3], b: [f32; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+    let sub = |a: [f32; 3], b: [f32; 3]| [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+    for [a, b, c] in reader.read_triangles().unwrap() {
+        let [a, b, c] = [a as usize, b as usize, c as usize];
+        let normal = world_normals[a];
+        assert!((dot(normal, normal) - 1.0).abs() < 1e-6);
+        assert!(dot(normal, sub(positions[b], positions[a])).abs() < 1e-6);
+        assert!(dot(normal, sub(positions[c], positions[a])).abs() < 1e-6);
+    }
+    // Normals keep facing outwards under the mirroring transform.
+    let x = normals.iter().position(|normal| *normal == [1.0, 0.0, 0.0]).unwrap();
+    assert!(world_normals[x][0] < 0.0);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_colors_srgb() {
+    use gltf::mesh::util::colors::{linear_to_srgb, srgb_to_linear};
+
+    let data = [[0.0f32, 0.0, 0.0], [1.0, 1.0, 1.0]]
+        .iter()
+        .flatten()
+        .chain(&[0.0, 0.5, 1.0, 0.5, 0.002, 0.2, 0.8, 1.0])
+        .flat_map(|value| value.to_le_bytes())
+        .collect::<Vec<_>>();
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 56, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 24}},
+                {{"buffer": 0, "byteOffset": 24, "byteLength": 32}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}},
+                {{"bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC4"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "COLOR_0": 1}}}}]}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let colors = || reader.read_colors(0).unwrap();
+
+    assert_eq!(colors().into_srgba_u8().next(), Some([0, 188, 255, 127]));
+    assert_eq!(colors().into_srgb_u8().last(), Some([7, 124, 231]));
+    assert_eq!(colors().into_rgba_u8().next(), Some([0, 127, 255, 127]));
+    for (encoded, linear) in colors().into_srgba_f32().zip(colors().into_rgba_f32()) {
+        assert_eq!(encoded[3], linear[3]);
+        for (encoded, linear) in encoded.iter().zip(&linear).take(3) {
+            assert_eq!(*encoded, linear_to_srgb(*linear));
+            assert!((srgb_to_linear(*encoded) - linear).abs() < 1e-6);
+        }
+    }
+    assert_eq!(linear_to_srgb(-1.0), 0.0);
+    assert_eq!(srgb_to_linear(2.0), 1.0);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_tex_coords_flipped_v() {
+    let mut data = [0.0f32, 0.0, 0.0, 1.0, 1.0, 1.0].iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<_>>();
+    data.extend_from_slice(&[0, 0, 255, 51]);
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 28, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 24}},
+                {{"buffer": 0, "byteOffset": 24, "byteLength": 4}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}},
+                {{"bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC2"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "TEXCOORD_0": 1}}}}]}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let tex_coords = reader.read_tex_coords(0).unwrap().into_f32_flipped_v();
+    assert_eq!(tex_coords.len(), 2);
+    assert_eq!(tex_coords.collect::<Vec<_>>(), [[0.0, 1.0], [1.0, 0.8]]);
+}
+
+#[cfg(feature = "KHR_texture_transform")]
+#[test]
+fn test_texture_transform_matrix_flipped_v() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "extensionsUsed": ["KHR_texture_transform"],
+            "images": [{"uri": "image.png"}],
+            "textures": [{"source": 0}],
+            "materials": [{"pbrMetallicRoughness": {"baseColorTexture": {
+                "index": 0,
+                "extensions": {"KHR_texture_transform": {"offset": [0.25, 0.5], "rotation": 0.5, "scale": [2, 3]}}
+            }}}]
+        }"#,
+    )
+    .unwrap();
+    let material = gltf.materials().next().unwrap();
+    let info = material.pbr_metallic_roughness().base_color_texture().unwrap();
+    let transform = info.texture_transform().unwrap();
+    let apply = |m: [[f32; 3]; 3], [u, v]: [f32; 2]| {
+        [m[0][0] * u + m[1][0] * v + m[2][0], m[0][1] * u + m[1][1] * v + m[2][1]]
+    };
+    let flip = |[u, v]: [f32; 2]| [u, 1.0 - v];
+
+    let m = transform.matrix();
+    assert_eq!(apply(m, [0.0, 0.0]), [0.25, 0.5]);
+    let [u, v] = apply(m, [1.0, 0.0]);
+    assert!((u - (0.25 + 2.0 * 0.5f32.cos())).abs() < 1e-6);
+    assert!((v - (0.5 - 2.0 * 0.5f32.sin())).abs() < 1e-6);
+
+    let flipped = transform.matrix_flipped_v();
+    for uv in [[0.0, 0.0], [1.0, 0.0], [0.3, 0.7], [-2.0, 5.0]] {
+        let expected = flip(apply(m, uv));
+        let actual = apply(flipped, flip(uv));
+        assert!((expected[0] - actual[0]).abs() < 1e-5 && (expected[1] - actual[1]).abs() < 1e-5);
+    }
+}
+
+#[cfg(feature = "utils")]
+#[test]
+fn test_generate_tangents() {
+    use gltf::build::{BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::mesh::generate;
+
+    let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+    let normals = [[0.0, 0.0, 1.0]; 4];
+    let indices = [0, 1, 2, 0, 2, 3];
+
+    // The texture origin is at the top-left corner, as in glTF.
+    let tex_coords = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+    let tangents = generate::tangents(&positions, &normals, &tex_coords, &indices);
+    assert_eq!(tangents, [[1.0, 0.0, 0.0, 1.0]; 4]);
+
+    // Mirroring the texture horizontally flips the tangents and handedness.
+    let mirrored = tex_coords.map(|[u, v]| [1.0 - u, v]);
+    let tangents = generate::tangents(&positions, &normals, &mirrored, &indices);
+    assert_eq!(tangents, [[-1.0, 0.0, 0.0, -1.0]; 4]);
+
+    // Vertices without texture derivatives get any perpendicular tangent.
+    let tangents = generate::tangents(&positions, &normals, &[[0.5, 0.5]; 4], &indices);
+    for tangent in tangents {
+        assert_eq!(tangent[2], 0.0);
+        assert!((tangent[0] * tangent[0] + tangent[1] * tangent[1] - 1.0).abs() < 1e-6);
+        assert_eq!(tangent[3], 1.0);
+    }
+
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    let primitive = PrimitiveBuilder::new(&positions).normals(&normals).tex_coords(&mirrored).indices(&indices);
+    MeshBuilder::new().primitive(primitive).build(&mut document, &mut buffer).unwrap();
+    let data = buffer.finish(&mut document);
+    let document = gltf::Document::from_json(document.into_json()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+    assert_eq!(reader.generate_tangents(0).unwrap(), [[-1.0, 0.0, 0.0, -1.0]; 4]);
+    assert!(reader.generate_tangents(1).is_none());
+}
+
+#[cfg(all(feature = "mikktspace", feature = "utils"))]
+#[test]
+fn test_generate_mikktspace_tangents() {
+    use gltf::build::{BufferBuilder, MeshBuilder, PrimitiveBuilder};
+    use gltf::mesh::generate;
+
+    let close = |a: &[[f32; 4]], b: &[[f32; 4]]| {
+        a.len() == b.len() && a.iter().flatten().zip(b.iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-5)
+    };
+    let positions = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+    let normals = [[0.0, 0.0, 1.0]; 4];
+    let indices = [0, 1, 2, 0, 2, 3];
+
+    // The texture origin is at the top-left corner, as in glTF.
+    let tex_coords = [[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]];
+    let tangents = generate::mikktspace_tangents(&positions, &normals, &tex_coords, &indices).unwrap();
+    assert!(close(&tangents, &[[1.0, 0.0, 0.0, 1.0]; 6]));
+    let mirrored = tex_coords.map(|[u, v]| [1.0 - u, v]);
+    let tangents = generate::mikktspace_tangents(&positions, &normals, &mirrored, &indices).unwrap();
+    assert!(close(&tangents, &[[-1.0, 0.0, 0.0, -1.0]; 6]));
+
+    // Two quads sharing the edge from vertex 1 to vertex 4, with the texture
+    // mirrored across it. The vertices of the edge get the tangents of either
+    // side, rather than a single tangent.
+    let positions = [
+        [0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0],
+        [2.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        [1.0, 1.0, 0.0],
+        [2.0, 1.0, 0.0],
+    ];
+    let normals = [[0.0, 0.0, 1.0]; 6];
+    let tex_coords = [[0.0, 1.0], [1.0, 1.0], [0.0, 1.0], [0.0, 0.0], [1.0, 0.0], [0.0, 0.0]];
+    let indices = [0, 1, 4, 0, 4, 3, 1, 2, 5, 1, 5, 4];
+    let tangents = generate::mikktspace_tangents(&positions, &normals, &tex_coords, &indices).unwrap();
+    assert!(close(&tangents[..6], &[[1.0, 0.0, 0.0, 1.0]; 6]));
+    assert!(close(&tangents[6..], &[[-1.0, 0.0, 0.0, -1.0]; 6]));
+
+    let mut document = gltf::DocumentMut::new();
+    let mut buffer = BufferBuilder::new(&mut document);
+    let primitive = PrimitiveBuilder::new(&positions).normals(&normals).tex_coords(&tex_coords).indices(&indices);
+    MeshBuilder::new().primitive(primitive).build(&mut document, &mut buffer).unwrap();
+    let data = buffer.finish(&mut document);
+    let document = gltf::Document::from_json(document.into_json()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|_| Some(&data[..]));
+    assert!(close(&reader.generate_mikktspace_tangents(0).unwrap(), &tangents));
+    assert!(reader.generate_mikktspace_tangents(1).is_none());
+    assert!(generate::mikktspace_tangents(&positions, &normals, &tex_coords, &[]).is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_generate_normals() {
+    use gltf::mesh::generate;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let positions = reader.read_positions().unwrap().collect::<Vec<_>>();
+    let normals = reader.read_normals().unwrap().collect::<Vec<_>>();
+    let indices = reader.read_triangles().unwrap().flatten().collect::<Vec<_>>();
+    let expected = indices.iter().map(|&index| normals[index as usize]).collect::<Vec<_>>();
+    let close = |a: &[[f32; 3]], b: &[[f32; 3]]| {
+        a.len() == b.len() && a.iter().flatten().zip(b.iter().flatten()).all(|(a, b)| (a - b).abs() < 1e-6)
+    };
+
+    assert!(close(&reader.generate_flat_normals().unwrap(), &expected));
+    // The faces of the box meet at right angles and are thus not smoothed
+    // together below that.
+    assert!(close(&reader.generate_smooth_normals(1.5).unwrap(), &expected));
+    // Beyond, the corners point away from the center of the box.
+    let smooth = reader.generate_smooth_normals(std::f32::consts::PI).unwrap();
+    for (normal, &index) in smooth.iter().zip(&indices) {
+        let expected = positions[index as usize].map(|x| x.signum() / 3f32.sqrt());
+        assert!(close(&[*normal], &[expected]));
+    }
+
+    // Coplanar triangles are smoothed together even with no crease angle.
+    let quad = [[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [0.0, 1.0, 0.0]];
+    let quad_indices = [0, 1, 2, 0, 2, 3];
+    assert_eq!(generate::flat_normals(&quad, &quad_indices), [[0.0, 0.0, 1.0]; 6]);
+    assert_eq!(generate::smooth_normals(&quad, &quad_indices, 0.0), [[0.0, 0.0, 1.0]; 6]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_weld() {
+    use gltf::mesh::generate;
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let positions = reader.read_positions().unwrap().collect::<Vec<_>>();
+    let normals = reader.read_normals().unwrap().collect::<Vec<_>>();
+    let indices = reader.read_triangles().unwrap().flatten().collect::<Vec<_>>();
+
+    // The faces of the box only share positions.
+    let weld = generate::weld(&positions, 0.0);
+    assert_eq!(weld.vertices.len(), 8);
+    assert_eq!(weld.indices.len(), 24);
+    for (&welded, position) in weld.indices.iter().zip(&positions) {
+        assert_eq!(weld.remap(&positions)[welded as usize], *position);
+    }
+    let reindexed = weld.reindex(&indices);
+    assert_eq!(reindexed.len(), 36);
+    assert!(reindexed.iter().all(|&index| index < 8));
+
+    let vertices = positions
+        .iter()
+        .zip(&normals)
+        .map(|(p, n)| [p[0], p[1], p[2], n[0], n[1], n[2]])
+        .collect::<Vec<_>>();
+    assert_eq!(generate::weld(&vertices, 0.0).vertices.len(), 24);
+
+    // Nearby positions are welded within the tolerance only, whereas those
+    // jittered alike are still exact duplicates.
+    let jittered = positions
+        .iter()
+        .enumerate()
+        .map(|(i, p)| p.map(|x| x + if i % 2 == 0 { 1e-4 } else { -1e-4 }))
+        .collect::<Vec<_>>();
+    assert_eq!(generate::weld(&jittered, 1e-3).vertices.len(), 8);
+    assert_eq!(generate::weld(&jittered, 1e-5).vertices.len(), 16);
+    assert_eq!(generate::weld(&jittered, 0.0).vertices.len(), 16);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_primitive_compute_bounds() {
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let (bounds, sphere) = primitive.compute_bounds(get_buffer_data, &[]).unwrap();
+    assert_eq!(bounds, primitive.bounding_box());
+    assert!(sphere.center.iter().all(|x| x.abs() < 1e-6));
+    assert!((sphere.radius - 0.75f32.sqrt()).abs() < 1e-6);
+
+    let gltf = morph_target_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let (bounds, _) = primitive.compute_bounds(get_buffer_data, &[]).unwrap();
+    assert_eq!((bounds.min, bounds.max), ([0.0, 1.0, 2.0], [6.0, 7.0, 8.0]));
+    let (bounds, sphere) = primitive.compute_bounds(get_buffer_data, &[1.0]).unwrap();
+    assert_eq!((bounds.min, bounds.max), ([1.0, 2.0, 3.0], [8.0, 9.0, 10.0]));
+    for position in [[1.0f32, 2.0, 3.0], [8.0, 9.0, 10.0], [7.0, 8.0, 9.0]] {
+        let distance = position.iter().zip(&sphere.center).map(|(a, b)| (a - b).powi(2)).sum::<f32>().sqrt();
+        assert!(distance <= sphere.radius);
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_analyze() {
+    let data = [1.0, f32::NAN, f32::INFINITY, 1e-40, -2.0, 3.0, 3.0, 3.0]
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect::<Vec<_>>();
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 32, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 32}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 5, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 20, "componentType": 5126, "count": 3, "type": "SCALAR"}},
+                {{"bufferView": 0, "byteOffset": 20, "componentType": 5126, "count": 0, "type": "SCALAR"}}
+            ]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let mut accessors = document.accessors();
+
+    let analysis = accessors.next().unwrap().analyze(get_buffer_data).unwrap();
+    assert_eq!(analysis.count, 5);
+    assert_eq!((analysis.min.clone(), analysis.max.clone()), (vec![-2.0], vec![f64::INFINITY]));
+    assert_eq!((analysis.nan, analysis.infinite, analysis.subnormal), (1, 1, 1));
+    assert!(!analysis.constant);
+    assert!(analysis.has_invalid_values());
+
+    let analysis = accessors.next().unwrap().analyze(get_buffer_data).unwrap();
+    assert_eq!((analysis.min.clone(), analysis.max.clone()), (vec![3.0], vec![3.0]));
+    assert!(analysis.constant && !analysis.has_invalid_values());
+
+    let analysis = accessors.next().unwrap().analyze(get_buffer_data).unwrap();
+    assert_eq!(analysis.count, 0);
+    assert!(analysis.min.is_empty() && analysis.constant);
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let positions = primitive.get(&gltf::Semantic::Positions).unwrap();
+    let analysis = positions.analyze(|buffer| Some(&buffers[buffer.index()][..])).unwrap();
+    assert_eq!(analysis.min, [-0.5; 3]);
+    assert_eq!(analysis.max, [0.5; 3]);
+    assert!(!analysis.constant);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_read_influences() {
+    let floats = |values: &[f32]| values.iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<_>>();
+    let mut data = floats(&[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+    data.extend_from_slice(&[1, 2, 3, 4, 0, 0, 0, 0]);
+    data.extend(floats(&[0.4, 0.3, 0.1, 0.0, 0.0, 0.0, 0.0, 0.0]));
+    data.extend_from_slice(&[5, 6, 7, 8, 0, 0, 0, 0]);
+    data.extend_from_slice(&[51, 0, 0, 0, 0, 0, 0, 0]);
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 80, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 24}},
+                {{"buffer": 0, "byteOffset": 24, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 32, "byteLength": 32}},
+                {{"buffer": 0, "byteOffset": 64, "byteLength": 8}},
+                {{"buffer": 0, "byteOffset": 72, "byteLength": 8}}
+            ],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 2, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1]}},
+                {{"bufferView": 1, "componentType": 5121, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 2, "componentType": 5126, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 3, "componentType": 5121, "count": 2, "type": "VEC4"}},
+                {{"bufferView": 4, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC4"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{
+                "POSITION": 0, "JOINTS_0": 1, "WEIGHTS_0": 2, "JOINTS_1": 3, "WEIGHTS_1": 4
+            }}}}]}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    let close = |a: &[f32], b: &[f32]| a.iter().zip(b).all(|(a, b)| (a - b).abs() < 1e-6);
+
+    let mut influences = reader.read_influences::<4>().unwrap();
+    assert_eq!(influences.len(), 2);
+    let (joints, weights) = influences.next().unwrap();
+    assert_eq!(joints, [1, 2, 5, 3]);
+    assert!(close(&weights, &[0.4, 0.3, 0.2, 0.1]));
+    assert_eq!(influences.next(), Some(([0; 4], [0.0; 4])));
+    assert_eq!(influences.next(), None);
+
+    // The least influential joints are dropped and the others renormalized.
+    let (joints, weights) = reader.read_influences::<2>().unwrap().next().unwrap();
+    assert_eq!(joints, [1, 2]);
+    assert!(close(&weights, &[4.0 / 7.0, 3.0 / 7.0]));
+
+    let (joints, weights) = reader.read_influences::<6>().unwrap().next().unwrap();
+    assert_eq!(joints, [1, 2, 5, 3, 0, 0]);
+    assert!(close(&weights, &[0.4, 0.3, 0.2, 0.1, 0.0, 0.0]));
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    assert!(primitive.reader(|buffer| Some(&buffers[buffer.index()][..])).read_influences::<4>().is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils", feature = "bytemuck"))]
+#[test]
+fn test_read_vertices() {
+    use gltf::accessor::{DataType, Dimensions};
+    use gltf::mesh::{Vertex, VertexAttribute};
+    use gltf::Semantic;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Full {
+        position: [f32; 3],
+        normal: [f32; 3],
+        uv: [f32; 2],
+    }
+    unsafe impl bytemuck::Zeroable for Full {}
+    unsafe impl bytemuck::Pod for Full {}
+    impl Vertex for Full {
+        const ATTRIBUTES: &'static [VertexAttribute] = &[
+            VertexAttribute { semantic: Semantic::Positions, offset: 0, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+            VertexAttribute { semantic: Semantic::Normals, offset: 12, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+            VertexAttribute { semantic: Semantic::TexCoords(0), offset: 24, data_type: DataType::F32, dimensions: Dimensions::Vec2 },
+        ];
+    }
+
+    // The same layout with the normals and texture co-ordinates swapped.
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct Swapped([f32; 8]);
+    unsafe impl bytemuck::Zeroable for Swapped {}
+    unsafe impl bytemuck::Pod for Swapped {}
+    impl Vertex for Swapped {
+        const ATTRIBUTES: &'static [VertexAttribute] = &[
+            VertexAttribute { semantic: Semantic::Positions, offset: 0, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+            VertexAttribute { semantic: Semantic::TexCoords(0), offset: 12, data_type: DataType::F32, dimensions: Dimensions::Vec2 },
+            VertexAttribute { semantic: Semantic::Normals, offset: 20, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+        ];
+    }
+
+    // A vertex without texture co-ordinates, which is smaller than the stride.
+    #[derive(Clone, Copy)]
+    #[repr(C)]
+    struct PositionNormal([f32; 6]);
+    unsafe impl bytemuck::Zeroable for PositionNormal {}
+    unsafe impl bytemuck::Pod for PositionNormal {}
+    impl Vertex for PositionNormal {
+        const ATTRIBUTES: &'static [VertexAttribute] = &[
+            VertexAttribute { semantic: Semantic::Positions, offset: 0, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+            VertexAttribute { semantic: Semantic::Normals, offset: 12, data_type: DataType::F32, dimensions: Dimensions::Vec3 },
+        ];
+    }
+
+    let vertices = (0..3)
+        .map(|i| Full { position: [i as f32, 0.0, 0.0], normal: [0.0, 0.0, 1.0], uv: [i as f32 / 2.0, 0.0] })
+        .collect::<Vec<_>>();
+    let data = bytemuck::cast_slice::<_, u8>(&vertices);
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 96, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 96, "byteStride": 32}}],
+            "accessors": [
+                {{"bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [2, 0, 0]}},
+                {{"bufferView": 0, "byteOffset": 12, "componentType": 5126, "count": 3, "type": "VEC3"}},
+                {{"bufferView": 0, "byteOffset": 24, "componentType": 5126, "count": 3, "type": "VEC2"}}
+            ],
+            "meshes": [{{"primitives": [{{"attributes": {{"POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2}}}}]}}]
+        }}"#,
+        base64::encode(data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    assert_eq!(reader.read_vertices::<Full>().unwrap(), &vertices[..]);
+    assert!(reader.read_vertices::<Swapped>().is_none());
+    assert!(reader.read_vertices::<PositionNormal>().is_none());
+
+    // Box.gltf stores its attributes one after the other.
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let primitive = document.meshes().next().unwrap().primitives().next().unwrap();
+    let reader = primitive.reader(|buffer| Some(&buffers[buffer.index()][..]));
+    assert!(reader.read_vertices::<PositionNormal>().is_none());
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_read_into() {
+    use gltf::accessor::{Error, Iter};
+
+    let gltf = sparse_accessors_gltf();
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let expected = [vec![10.0, 2.0, 30.0], vec![0.0, 5.0, 0.0, 6.0]];
+    for (accessor, expected) in document.accessors().zip(expected) {
+        assert_eq!(accessor.read_into_vec::<f32, _>(get_buffer_data).unwrap(), expected);
+
+        // Only the front of a shorter output is written.
+        let mut out = [-1.0; 2];
+        assert_eq!(accessor.read_into(get_buffer_data, &mut out).unwrap(), 2);
+        assert_eq!(out, expected[..2]);
+
+        // Elements past the end of the accessor are left as they are.
+        let mut out = [-1.0; 5];
+        assert_eq!(accessor.read_into(get_buffer_data, &mut out).unwrap(), expected.len());
+        assert_eq!(out[..expected.len()], expected[..]);
+        assert!(out[expected.len()..].iter().all(|&value| value == -1.0));
+    }
+
+    // Strided elements.
+    let data = [1u16, 0xffff, 2, 0xffff, 3].iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<_>>();
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 10, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [{{"buffer": 0, "byteLength": 10, "byteStride": 4}}],
+            "accessors": [{{"bufferView": 0, "componentType": 5123, "count": 3, "type": "SCALAR"}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let accessor = document.accessors().next().unwrap();
+    assert_eq!(accessor.read_into_vec::<u16, _>(get_buffer_data).unwrap(), [1, 2, 3]);
+    assert!(matches!(
+        accessor.read_into_vec::<u32, _>(get_buffer_data),
+        Err(Error::SizeMismatch { expected: 2, actual: 4 }),
+    ));
+
+    let (document, buffers, _) = gltf::import("examples/Box.gltf").unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    for accessor in document.accessors().filter(|accessor| accessor.dimensions() == gltf::accessor::Dimensions::Vec3) {
+        let expected = Iter::<[f32; 3]>::new(accessor.clone(), get_buffer_data).unwrap().collect::<Vec<_>>();
+        assert_eq!(accessor.read_into_vec::<[f32; 3], _>(get_buffer_data).unwrap(), expected);
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_accessor_custom_item() {
+    use gltf::accessor::{Get, Item, Iter};
+
+    // Signed 8.8 fixed-point numbers, which are smaller when encoded.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Fixed(f32);
+
+    impl Item for Fixed {
+        const SIZE: usize = 2;
+
+        fn from_slice(slice: &[u8]) -> Self {
+            Fixed(i16::from_le_bytes([slice[0], slice[1]]) as f32 / 256.0)
+        }
+
+        fn zero() -> Self {
+            Fixed(0.0)
+        }
+    }
+
+    let mut data = [256i16, -128, 512, 64, 768, 0].iter().flat_map(|value| value.to_le_bytes()).collect::<Vec<_>>();
+    data.extend_from_slice(&[1, 0, 0, 0]);
+    data.extend([-256i16, -512].iter().flat_map(|value| value.to_le_bytes()));
+    let gltf = format!(
+        r#"{{
+            "asset": {{"version": "2.0"}},
+            "buffers": [{{"byteLength": 20, "uri": "data:application/octet-stream;base64,{}"}}],
+            "bufferViews": [
+                {{"buffer": 0, "byteLength": 12}},
+                {{"buffer": 0, "byteOffset": 12, "byteLength": 4}},
+                {{"buffer": 0, "byteOffset": 16, "byteLength": 4}}
+            ],
+            "accessors": [{{"bufferView": 0, "componentType": 5122, "count": 3, "type": "VEC2", "sparse": {{
+                "count": 1,
+                "indices": {{"bufferView": 1, "componentType": 5125}},
+                "values": {{"bufferView": 2}}
+            }}}}]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let get_buffer_data = |buffer: gltf::Buffer| Some(&buffers[buffer.index()][..]);
+    let accessor = document.accessors().next().unwrap();
+    let expected = vec![[Fixed(1.0), Fixed(-0.5)], [Fixed(-1.0), Fixed(-2.0)], [Fixed(3.0), Fixed(0.0)]];
+
+    let iter = Iter::<[Fixed; 2]>::new(accessor.clone(), get_buffer_data).unwrap();
+    assert_eq!(iter.collect::<Vec<_>>(), expected);
+    let get = Get::<[Fixed; 2]>::new(accessor.clone(), get_buffer_data).unwrap();
+    assert_eq!(get.get(1), Some(expected[1]));
+    assert_eq!(accessor.read_into_vec::<[Fixed; 2], _>(get_buffer_data).unwrap(), expected);
+
+    // The size of the encoded elements must match.
+    assert!(Iter::<Fixed>::new(accessor, get_buffer_data).is_err());
+}
+
+#[test]
+fn test_world_transform() {
+    use gltf::scene::TransformCache;
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0]}],
+            "nodes": [
+                {"children": [1], "matrix": [2, 0, 0, 0, 0, 2, 0, 0, 0, 0, 2, 0, 1, 2, 3, 1]},
+                {"children": [2], "translation": [1, 0, 0], "rotation": [0, 0, 1, 0]},
+                {"scale": [1, 1, 3]},
+                {"translation": [5, 5, 5]}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let cache = TransformCache::new(&scene);
+
+    // The child is rotated by half a turn around z and translated along the
+    // scaled x axis of its parent.
+    let expected = [
+        [[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [1.0, 2.0, 3.0, 1.0]],
+        [[-2.0, 0.0, 0.0, 0.0], [0.0, -2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [3.0, 2.0, 3.0, 1.0]],
+        [[-2.0, 0.0, 0.0, 0.0], [0.0, -2.0, 0.0, 0.0], [0.0, 0.0, 6.0, 0.0], [3.0, 2.0, 3.0, 1.0]],
+    ];
+    for (node, expected) in nodes.iter().zip(expected) {
+        let transform = cache.get(node).unwrap();
+        for (column, expected) in transform.iter().zip(expected) {
+            for (value, expected) in column.iter().zip(expected) {
+                assert!((value - expected).abs() < 1e-6, "{:?}", transform);
+            }
+        }
+        assert_eq!(node.world_transform(&scene), Some(transform));
+    }
+
+    // Nodes outside of the scene have no world transform.
+    assert!(cache.get(&nodes[3]).is_none());
+    assert!(nodes[3].world_transform(&scene).is_none());
+}
+
+#[test]
+fn test_world_transforms_cycle() {
+    use gltf::scene::TransformCache;
+
+    // Cycles are not valid glTF, but must not make the traversal endless.
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0]}],
+            "nodes": [{"children": [1]}, {"children": [0]}]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let nodes = scene.world_transforms().map(|(node, _)| node.index()).collect::<Vec<_>>();
+    assert_eq!(nodes, [0, 1]);
+
+    let cache = TransformCache::new(&scene);
+    for node in gltf.nodes() {
+        assert!(cache.get(&node).is_some());
+        assert!(node.world_transform(&scene).is_some());
+    }
+    assert!(scene.draw_list().is_empty());
+}
+
+#[test]
+fn test_scene_traverse() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0, 3]}],
+            "nodes": [
+                {"children": [1, 2], "translation": [1, 0, 0]},
+                {"children": [4], "scale": [2, 2, 2]},
+                {"translation": [0, 1, 0]},
+                {"translation": [0, 0, 1]},
+                {"translation": [1, 1, 1]}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+
+    let visited = scene
+        .depth_first()
+        .map(|(node, depth, transform)| (node.index(), depth, transform[3]))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        visited,
+        [
+            (0, 0, [1.0, 0.0, 0.0, 1.0]),
+            (1, 1, [1.0, 0.0, 0.0, 1.0]),
+            (4, 2, [3.0, 2.0, 2.0, 1.0]),
+            (2, 1, [1.0, 1.0, 0.0, 1.0]),
+            (3, 0, [0.0, 0.0, 1.0, 1.0]),
+        ]
+    );
+
+    // The descendants of node 1 are skipped.
+    let mut visited = Vec::new();
+    scene.traverse(|node, depth, _| {
+        visited.push((node.index(), depth));
+        node.index() != 1
+    });
+    assert_eq!(visited, [(0, 0), (1, 1), (2, 1), (3, 0)]);
+
+    let mut nodes = scene.depth_first();
+    assert_eq!(nodes.next().unwrap().0.index(), 0);
+    nodes.skip_children();
+    assert_eq!(nodes.map(|(node, _, _)| node.index()).collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn test_scene_traverse_cycle() {
+    // Node 1 is its own child and node 2 loops back to the root.
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0]}],
+            "nodes": [{"children": [1, 2]}, {"children": [1]}, {"children": [0]}]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+
+    let visited = scene.depth_first().map(|(node, depth, _)| (node.index(), depth)).collect::<Vec<_>>();
+    assert_eq!(visited, [(0, 0), (1, 1), (2, 1)]);
+
+    let mut visited = Vec::new();
+    scene.traverse(|node, depth, _| {
+        visited.push((node.index(), depth));
+        true
+    });
+    assert_eq!(visited, [(0, 0), (1, 1), (2, 1)]);
+}
+
+#[test]
+fn test_parent_index() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0, 3]}],
+            "nodes": [
+                {"children": [1, 2]},
+                {"children": [4]},
+                {},
+                {},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let parents = gltf.build_parent_index();
+    let nodes = gltf.nodes().collect::<Vec<_>>();
+    let parent = |node| parents.parent(&nodes[node]).map(|parent| parent.index());
+    assert_eq!((0..5).map(parent).collect::<Vec<_>>(), [None, Some(0), Some(0), None, Some(1)]);
+    let ancestors = parents.ancestors(&nodes[4]).map(|node| node.index()).collect::<Vec<_>>();
+    assert_eq!(ancestors, [1, 0]);
+    assert_eq!(parents.ancestors(&nodes[3]).count(), 0);
+}
+
+#[cfg(feature = "names")]
+#[test]
+fn test_find_nodes_by_name() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0, 4]}],
+            "nodes": [
+                {"name": "Root", "children": [1, 3]},
+                {"name": "Arm", "children": [2]},
+                {"name": "Hand"},
+                {"name": "Arm", "children": [5]},
+                {"name": "Prop"},
+                {"name": "Hand"}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let index = |node: Option<gltf::Node>| node.map(|node| node.index());
+
+    assert_eq!(index(gltf.node_by_name("Hand")), Some(2));
+    assert_eq!(index(gltf.node_by_name("Foot")), None);
+
+    let names = gltf.build_name_index();
+    assert_eq!(index(names.get("Arm")), Some(1));
+    assert_eq!(names.get_all("Hand").iter().map(|node| node.index()).collect::<Vec<_>>(), [2, 5]);
+    assert!(names.get_all("Foot").is_empty());
+
+    let scene = gltf.scenes().next().unwrap();
+    assert_eq!(index(scene.find("Root/Arm/Hand")), Some(2));
+    assert_eq!(index(scene.find("Root/Arm")), Some(1));
+    assert_eq!(index(scene.find("Prop")), Some(4));
+    assert_eq!(index(scene.find("Arm/Hand")), None);
+    assert_eq!(index(scene.find("Root/Hand")), None);
+    assert_eq!(index(scene.find("")), None);
+}
+
+#[cfg(feature = "import")]
+#[test]
+fn test_scene_draw_list() {
+    let (document, _, _) = gltf::import("examples/Box.gltf").unwrap();
+    let scene = document.default_scene().unwrap();
+    let draws = scene.draw_list();
+    assert_eq!(draws.len(), 1);
+    let draw = &draws[0];
+    assert_eq!(draw.node.index(), 1);
+    assert_eq!(draw.material.index(), draw.primitive.material().index());
+    assert_eq!(
+        draw.transform,
+        [[1.0, 0.0, 0.0, 0.0], [0.0, 0.0, -1.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]]
+    );
+    assert!(draw.skin.is_none());
+    assert!(draw.instance.is_none());
+}
+
+#[cfg(all(feature = "import", feature = "EXT_mesh_gpu_instancing"))]
+#[test]
+fn test_scene_instanced_draw_list() {
+    let (document, buffers, _) = gltf::import("tests/ext_mesh_gpu_instancing.gltf").unwrap();
+    let scene = document.default_scene().unwrap();
+    let draws = scene.draw_list();
+    assert_eq!(draws.iter().map(|draw| (draw.node.index(), draw.instance)).collect::<Vec<_>>(), [(0, None), (1, None)]);
+
+    let draws = scene.instanced_draw_list(|buffer| Some(&buffers[buffer.index()][..]));
+    assert_eq!(
+        draws.iter().map(|draw| (draw.node.index(), draw.instance)).collect::<Vec<_>>(),
+        [(0, Some(0)), (0, Some(1)), (1, None)]
+    );
+    assert_eq!(draws[0].transform[3], [1.0, 2.0, 3.0, 1.0]);
+    assert_eq!(draws[1].transform[0], [-2.0, 0.0, 0.0, 0.0]);
+    assert_eq!(draws[1].transform[3], [-4.0, 0.0, 0.0, 1.0]);
+    assert_eq!(draws[2].transform[3], [0.0, 0.0, 0.0, 1.0]);
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_scene_bounds() {
+    use gltf::mesh::Bounds;
+
+    let gltf = morph_target_gltf().replace(
+        r#""meshes": ["#,
+        r#""scenes": [{"nodes": [0, 1]}, {"nodes": []}],
+        "nodes": [
+            {"mesh": 0, "translation": [10, 0, 0]},
+            {"mesh": 0, "scale": [2, 1, 1], "weights": [1]}
+        ],
+        "meshes": ["#,
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let mut scenes = document.scenes();
+    let scene = scenes.next().unwrap();
+
+    // The accessor bounds do not include the morph target.
+    assert_eq!(scene.bounding_box(), Some(Bounds { min: [0.0, 1.0, 2.0], max: [16.0, 7.0, 8.0] }));
+    assert_eq!(
+        scene.compute_bounds(|buffer| Some(&buffers[buffer.index()][..])),
+        Some(Bounds { min: [2.0, 1.0, 2.0], max: [16.0, 9.0, 10.0] })
+    );
+
+    let empty = scenes.next().unwrap();
+    assert_eq!(empty.bounding_box(), None);
+    assert_eq!(empty.compute_bounds(|buffer| Some(&buffers[buffer.index()][..])), None);
+}
+
+#[test]
+fn test_camera_projection_matrices() {
+    use gltf::camera::Projection;
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "cameras": [
+                {"type": "perspective", "perspective": {"yfov": 1.5707964, "aspectRatio": 2, "znear": 1, "zfar": 3}},
+                {"type": "perspective", "perspective": {"yfov": 1.5707964, "znear": 0.5}},
+                {"type": "orthographic", "orthographic": {"xmag": 2, "ymag": 4, "znear": 1, "zfar": 5}}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let cameras = gltf.cameras().collect::<Vec<_>>();
+    let projections = cameras.iter().map(|camera| camera.projection()).collect::<Vec<_>>();
+    let assert_near = |actual: [[f32; 4]; 4], expected: [[f32; 4]; 4]| {
+        for (actual, expected) in actual.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((actual - expected).abs() < 1e-6, "{:?} != {:?}", actual, expected);
+        }
+    };
+    match (&projections[0], &projections[1], &projections[2]) {
+        (Projection::Perspective(finite), Projection::Perspective(infinite), Projection::Orthographic(orthographic)) => {
+            // The aspect ratio of the camera takes precedence.
+            assert_near(
+                finite.matrix(1.0),
+                [[0.5, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, -2.0, -1.0], [0.0, 0.0, -3.0, 0.0]],
+            );
+            assert_near(
+                infinite.matrix(4.0),
+                [[0.25, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, -1.0, -1.0], [0.0, 0.0, -1.0, 0.0]],
+            );
+            assert_near(
+                orthographic.matrix(),
+                [[0.5, 0.0, 0.0, 0.0], [0.0, 0.25, 0.0, 0.0], [0.0, 0.0, -0.5, 0.0], [0.0, 0.0, -1.5, 1.0]],
+            );
+        }
+        _ => panic!("unexpected projections"),
+    }
+}
+
+#[test]
+fn test_camera_view_matrix() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [0, 2]}],
+            "cameras": [{"type": "perspective", "perspective": {"yfov": 1, "znear": 0.1}}],
+            "nodes": [
+                {"children": [1], "translation": [0, 0, 10], "scale": [2, 2, 2]},
+                {"camera": 0, "translation": [1, 0, 0], "rotation": [0, 0.70710677, 0, 0.70710677]},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let scene = gltf.scenes().next().unwrap();
+    let cameras = scene.cameras().collect::<Vec<_>>();
+    assert_eq!(cameras.len(), 1);
+    let (camera, node, transform) = &cameras[0];
+    assert_eq!((camera.index(), node.index()), (0, 1));
+    assert_eq!(transform[3], [2.0, 0.0, 10.0, 1.0]);
+
+    // The camera at (2, 0, 10) looks towards -X, ignoring the scale of its
+    // parent.
+    let view = camera.view_matrix(*transform);
+    let apply = |[x, y, z]: [f32; 3]| {
+        let mut p = [0.0; 3];
+        for (row, value) in p.iter_mut().enumerate() {
+            *value = view[0][row] * x + view[1][row] * y + view[2][row] * z + view[3][row];
+        }
+        p
+    };
+    for (point, expected) in [
+        ([2.0, 0.0, 10.0], [0.0, 0.0, 0.0]),
+        ([-3.0, 0.0, 10.0], [0.0, 0.0, -5.0]),
+        ([2.0, 1.0, 10.0], [0.0, 1.0, 0.0]),
+    ] {
+        let actual = apply(point);
+        for (actual, expected) in actual.iter().zip(expected) {
+            assert!((actual - expected).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    }
+    assert_eq!(view[0][3], 0.0);
+    assert_eq!(view[3][3], 1.0);
+}
+
+#[test]
+fn test_camera_frustum() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "cameras": [
+                {"type": "perspective", "perspective": {"yfov": 1.5707964, "aspectRatio": 1, "znear": 1, "zfar": 10}},
+                {"type": "orthographic", "orthographic": {"xmag": 2, "ymag": 1, "znear": 1, "zfar": 5}},
+                {"type": "perspective", "perspective": {"yfov": 1.5707964, "znear": 1}}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let cameras = gltf.cameras().collect::<Vec<_>>();
+    let assert_near = |actual: [[f32; 4]; 6], expected: [[f32; 4]; 6]| {
+        for (actual, expected) in actual.iter().flatten().zip(expected.iter().flatten()) {
+            assert!((actual - expected).abs() < 1e-5, "{:?} != {:?}", actual, expected);
+        }
+    };
+    let identity = [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]];
+    let h = std::f32::consts::FRAC_1_SQRT_2;
+
+    assert_near(
+        cameras[0].frustum(identity, 1.0),
+        [
+            [h, 0.0, -h, 0.0],
+            [-h, 0.0, -h, 0.0],
+            [0.0, h, -h, 0.0],
+            [0.0, -h, -h, 0.0],
+            [0.0, 0.0, -1.0, -1.0],
+            [0.0, 0.0, 1.0, 10.0],
+        ],
+    );
+
+    // Moving the camera moves the planes.
+    let mut moved = identity;
+    moved[3] = [0.0, 0.0, 5.0, 1.0];
+    assert_near(
+        cameras[0].frustum(moved, 1.0),
+        [
+            [h, 0.0, -h, 5.0 * h],
+            [-h, 0.0, -h, 5.0 * h],
+            [0.0, h, -h, 5.0 * h],
+            [0.0, -h, -h, 5.0 * h],
+            [0.0, 0.0, -1.0, 4.0],
+            [0.0, 0.0, 1.0, 5.0],
+        ],
+    );
+
+    assert_near(
+        cameras[1].frustum(identity, 1.0),
+        [
+            [1.0, 0.0, 0.0, 2.0],
+            [-1.0, 0.0, 0.0, 2.0],
+            [0.0, 1.0, 0.0, 1.0],
+            [0.0, -1.0, 0.0, 1.0],
+            [0.0, 0.0, -1.0, -1.0],
+            [0.0, 0.0, 1.0, 5.0],
+        ],
+    );
+
+    // Every point is inside the far plane at infinity.
+    let far = cameras[2].frustum(identity, 1.0)[5];
+    assert_eq!(&far[..3], &[0.0, 0.0, 0.0]);
+    assert!(far[3] > 0.0);
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn test_node_graph() {
+    use petgraph::algo::toposort;
+    use petgraph::graph::NodeIndex;
+    use petgraph::visit::Dfs;
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": {"version": "2.0"},
+            "scenes": [{"nodes": [3]}],
+            "nodes": [
+                {"children": [2]},
+                {},
+                {},
+                {"children": [0, 1]},
+                {"children": [5]},
+                {}
+            ]
+        }"#,
+    )
+    .unwrap();
+
+    let graph = gltf.node_graph();
+    assert_eq!(graph.node_count(), 6);
+    assert_eq!(graph.edge_count(), 4);
+    assert!((0..6).all(|index| graph[NodeIndex::new(index)] == index));
+    let order = toposort(&graph, None).unwrap().into_iter().map(|index| graph[index]).collect::<Vec<_>>();
+    let position = |node: usize| order.iter().position(|&index| index == node).unwrap();
+    assert!(position(3) < position(0) && position(0) < position(2) && position(4) < position(5));
+
+    let scene = gltf.scenes().next().unwrap();
+    let graph = scene.node_graph();
+    assert_eq!(graph.node_indices().map(|index| graph[index]).collect::<Vec<_>>(), [3, 0, 2, 1]);
+    assert_eq!(graph.edge_count(), 3);
+    let mut subtree = Vec::new();
+    let mut dfs = Dfs::new(&graph, NodeIndex::new(1));
+    while let Some(index) = dfs.next(&graph) {
+        subtree.push(graph[index]);
+    }
+    assert_eq!(subtree, [0, 2]);
+}
+
+#[cfg(all(feature = "import", feature = "EXT_mesh_gpu_instancing"))]
+#[test]
+fn test_ext_mesh_gpu_instancing_world_matrices() {
+    let (document, buffers, _) = gltf::import("tests/ext_mesh_gpu_instancing.gltf").unwrap();
+    let instancing = document.nodes().next().unwrap().instancing().unwrap();
+    let reader = instancing.reader(|buffer| Some(&buffers[buffer.index()]));
+
+    // The node is translated along x and scaled by two.
+    let world = [[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [10.0, 0.0, 0.0, 1.0]];
+    let matrices = reader.read_world_matrices(world).unwrap();
+    assert_eq!(matrices.len(), 2);
+    assert_eq!(
+        matrices.collect::<Vec<_>>(),
+        [
+            [[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 2.0, 0.0], [12.0, 4.0, 6.0, 1.0]],
+            [[-4.0, 0.0, 0.0, 0.0], [0.0, -4.0, 0.0, 0.0], [0.0, 0.0, 4.0, 0.0], [2.0, 0.0, 0.0, 1.0]],
+        ]
+    );
+}
+
+#[test]
+fn test_users_of() {
+    use gltf::users::{Resource, User};
+
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "accessors": [
+                { "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1] },
+                { "componentType": 5123, "count": 3, "type": "SCALAR" },
+                { "componentType": 5126, "count": 2, "type": "SCALAR", "min": [0], "max": [1] },
+                { "componentType": 5126, "count": 2, "type": "VEC3" },
+                { "componentType": 5126, "count": 1, "type": "MAT4" }
+            ],
+            "images": [{ "uri": "image.png" }],
+            "textures": [{ "source": 0 }, { "source": 0 }],
+            "materials": [
+                { "pbrMetallicRoughness": { "baseColorTexture": { "index": 0 } } },
+                { "normalTexture": { "index": 0 }, "emissiveTexture": { "index": 1 } }
+            ],
+            "meshes": [
+                {
+                    "primitives": [
+                        { "attributes": { "POSITION": 0 }, "indices": 1, "material": 0 },
+                        { "attributes": { "POSITION": 0 } }
+                    ]
+                },
+                {
+                    "primitives": [
+                        { "attributes": { "POSITION": 0 }, "material": 1, "targets": [{ "POSITION": 3 }] }
+                    ]
+                }
+            ],
+            "nodes": [{ "mesh": 0 }, { "mesh": 0 }, { "mesh": 1 }],
+            "skins": [{ "inverseBindMatrices": 4, "joints": [1] }],
+            "animations": [
+                {
+                    "channels": [{ "sampler": 0, "target": { "node": 1, "path": "translation" } }],
+                    "samplers": [{ "input": 2, "output": 3 }]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let describe = |users: Vec<User>| {
+        users
+            .into_iter()
+            .map(|user| match user {
+                User::Animation(animation) => ("animation", animation.index()),
+                User::Material(material) => ("material", material.index().unwrap()),
+                User::Node(node) => ("node", node.index()),
+                User::Primitive(primitive) => ("primitive", primitive.index()),
+                User::Skin(skin) => ("skin", skin.index()),
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let accessor = |index| gltf.accessors().nth(index).unwrap();
+    assert_eq!(
+        describe(gltf.users_of(&accessor(0))),
+        [("primitive", 0), ("primitive", 1), ("primitive", 0)]
+    );
+    assert_eq!(describe(gltf.users_of(&accessor(1))), [("primitive", 0)]);
+    assert_eq!(describe(gltf.users_of(&accessor(2))), [("animation", 0)]);
+    assert_eq!(describe(gltf.users_of(&accessor(3))), [("primitive", 0), ("animation", 0)]);
+    assert_eq!(describe(gltf.users_of(&accessor(4))), [("skin", 0)]);
+
+    let mesh = gltf.meshes().next().unwrap();
+    let default = mesh.primitives().nth(1).unwrap().material();
+    assert_eq!(describe(gltf.users_of(&default)), [("primitive", 1)]);
+    assert_eq!(describe(gltf.users_of(Resource::Material(Some(1)))), [("primitive", 0)]);
+    assert_eq!(describe(gltf.users_of(&mesh)), [("node", 0), ("node", 1)]);
+
+    let texture = |index| gltf.textures().nth(index).unwrap();
+    assert_eq!(describe(gltf.users_of(&texture(0))), [("material", 0), ("material", 1)]);
+    assert_eq!(describe(gltf.users_of(&texture(1))), [("material", 1)]);
+}
+
+#[cfg(feature = "KHR_materials_variants")]
+#[test]
+fn test_users_of_variant_materials() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": ["KHR_materials_variants"],
+            "extensions": { "KHR_materials_variants": { "variants": [{ "name": "red" }, { "name": "blue" }] } },
+            "accessors": [{ "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1] }],
+            "materials": [{}, {}, {}],
+            "meshes": [
+                {
+                    "primitives": [
+                        {
+                            "attributes": { "POSITION": 0 },
+                            "material": 0,
+                            "extensions": {
+                                "KHR_materials_variants": { "mappings": [{ "material": 1, "variants": [0] }] }
+                            }
+                        },
+                        { "attributes": { "POSITION": 0 } }
+                    ]
+                }
+            ]
+        }"#,
+    )
+    .unwrap();
+    let users = |index| {
+        let material = gltf.materials().nth(index).unwrap();
+        gltf.users_of(&material).len()
+    };
+    assert_eq!(users(0), 1);
+    assert_eq!(users(1), 1);
+    assert_eq!(users(2), 0);
+    assert_eq!(gltf.users_of(gltf::users::Resource::Material(None)).len(), 1);
+}
+
+#[test]
+fn test_document_statistics() {
+    let gltf = gltf::Gltf::from_slice(
+        br#"{
+            "asset": { "version": "2.0" },
+            "extensionsUsed": ["KHR_materials_unlit"],
+            "buffers": [{ "byteLength": 120, "uri": "buffer.bin" }],
+            "bufferViews": [
+                { "buffer": 0, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 36, "byteLength": 12 },
+                { "buffer": 0, "byteOffset": 48, "byteLength": 72 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [0, 0, 0], "max": [1, 1, 1] },
+                { "bufferView": 1, "componentType": 5123, "count": 6, "type": "SCALAR" }
+            ],
+            "images": [{ "bufferView": 2, "mimeType": "image/png" }, { "uri": "image.jpg" }],
+            "textures": [{ "source": 0 }, { "source": 0 }, { "source": 1 }],
+            "meshes": [
+                {
+                    "primitives": [
+                        { "attributes": { "POSITION": 0 }, "indices": 1 },
+                        { "attributes": { "POSITION": 0 }, "mode": 5 },
+                        { "attributes": { "POSITION": 0 }, "mode": 2 }
+                    ]
+                }
+            ],
+            "nodes": [{ "mesh": 0, "children": [1] }, {}],
+            "scenes": [{ "nodes": [0] }]
+        }"#,
+    )
+    .unwrap();
+    let statistics = gltf.statistics();
+    assert_eq!((statistics.scenes, statistics.nodes, statistics.meshes), (1, 2, 1));
+    assert_eq!((statistics.primitives, statistics.vertices), (3, 9));
+    assert_eq!((statistics.triangles, statistics.lines, statistics.points), (3, 3, 0));
+    assert_eq!(statistics.buffer_byte_lengths, [120]);
+    assert_eq!(statistics.total_buffer_byte_length(), 120);
+    assert_eq!(statistics.buffer_view_byte_lengths, [36, 12, 72]);
+    assert_eq!(statistics.accessor_byte_lengths, [36, 12]);
+    assert_eq!(statistics.extensions_used, ["KHR_materials_unlit"]);
+    assert!(statistics.extensions_required.is_empty());
+
+    let images = &statistics.images;
+    assert_eq!(images.len(), 2);
+    assert_eq!(images[0].mime_type.as_deref(), Some("image/png"));
+    assert_eq!((images[0].byte_length, images[0].textures), (Some(72), 2));
+    assert_eq!((images[1].mime_type.as_deref(), images[1].byte_length, images[1].textures), (None, None, 1));
+    assert_eq!((images[1].width, images[1].height), (None, None));
+
+    #[cfg(feature = "serde")]
+    {
+        let json = gltf::json::serialize::to_value(&statistics).unwrap();
+        assert_eq!(json["triangles"], 3);
+        assert_eq!(json["images"][0]["mime_type"], "image/png");
+    }
+}
+
+#[cfg(all(feature = "import", feature = "utils"))]
+#[test]
+fn test_document_statistics_with_image_data() {
+    use gltf::export::Export;
+
+    let (document, buffers, _) = gltf::import("tests/box_sparse.gltf").unwrap();
+    let mut json = document.into_json();
+    json.images.push(gltf::json::Image {
+        buffer_view: None,
+        mime_type: None,
+        #[cfg(feature = "names")]
+        name: None,
+        uri: None,
+        extensions: None,
+        extras: Default::default(),
+    });
+    let mut export = Export::from_json(json, &buffers);
+    export.image_rgba(0, 4, 2, &[255; 32]).unwrap();
+
+    let (document, _, images) = gltf::import_slice(export.to_vec().unwrap()).unwrap();
+    let statistics = document.statistics();
+    assert_eq!((statistics.images[0].width, statistics.images[0].height), (None, None));
+    let statistics = statistics.with_image_data(&images);
+    assert_eq!((statistics.images[0].width, statistics.images[0].height), (Some(4), Some(2)));
+    assert_eq!(statistics.images[0].mime_type.as_deref(), Some("image/png"));
+}
+
+#[cfg(all(feature = "import", feature = "EXT_mesh_gpu_instancing"))]
+#[test]
+fn test_ext_mesh_gpu_instancing_quantized() {
+    let mut data = Vec::new();
+    for value in [10i16, 20, 30, -5, 0, 5] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(&[255, 255, 255, 51, 102, 255]);
+    let gltf = format!(
+        r#"{{
+            "asset": {{ "version": "2.0" }},
+            "extensionsUsed": ["EXT_mesh_gpu_instancing", "KHR_mesh_quantization"],
+            "buffers": [{{ "byteLength": 18, "uri": "data:application/octet-stream;base64,{}" }}],
+            "bufferViews": [{{ "buffer": 0, "byteLength": 12 }}, {{ "buffer": 0, "byteOffset": 12, "byteLength": 6 }}],
+            "accessors": [
+                {{ "bufferView": 0, "componentType": 5122, "count": 2, "type": "VEC3" }},
+                {{ "bufferView": 1, "componentType": 5121, "normalized": true, "count": 2, "type": "VEC3" }}
+            ],
+            "nodes": [
+                {{
+                    "extensions": {{
+                        "EXT_mesh_gpu_instancing": {{ "attributes": {{ "TRANSLATION": 0, "SCALE": 1 }} }}
+                    }}
+                }}
+            ]
+        }}"#,
+        base64::encode(&data),
+    );
+    let (document, buffers, _) = gltf::import_slice(gltf.as_bytes()).unwrap();
+    let instancing = document.nodes().next().unwrap().instancing().unwrap();
+    let reader = instancing.reader(|buffer| Some(&buffers[buffer.index()]));
+    assert!(reader.read_translations().is_none());
+
+    // Quantized attributes must not be mistaken for identity transforms.
+    #[cfg(not(feature = "KHR_mesh_quantization"))]
+    assert!(reader.read_matrices().is_none());
+    #[cfg(feature = "KHR_mesh_quantization")]
+    assert_eq!(
+        reader.read_matrices().unwrap().collect::<Vec<_>>(),
+        [
+            [[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [10.0, 20.0, 30.0, 1.0]],
+            [[0.2, 0.0, 0.0, 0.0], [0.0, 0.4, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [-5.0, 0.0, 5.0, 1.0]],
+        ]
+    );
+}
+
+#[cfg(feature = "extensions")]
+#[test]
+fn test_unknown_extensions_roundtrip() {
+    let json = br#"{
+        "asset": { "version": "2.0" },
+        "extensionsUsed": ["ACME_root", "ACME_scene", "ACME_node", "ACME_material", "KHR_materials_unlit"],
+        "extensions": { "ACME_root": { "id": 7 } },
+        "scenes": [{ "nodes": [0], "extensions": { "ACME_scene": { "layer": "main" } } }],
+        "nodes": [{ "extensions": { "ACME_node": [1, 2, 3] } }],
+        "materials": [{
+            "extensions": {
+                "ACME_material": { "glow": 0.5 },
+                "KHR_materials_unlit": {}
+            }
+        }]
+    }"#;
+    let mut root = gltf::json::Root::from_slice(json).unwrap();
+    let extensions = root.nodes[0].extensions.as_mut().unwrap();
+    extensions.others.insert("ACME_added".to_owned(), gltf::json::Value::Bool(true));
+
+    let glb = gltf::export::Export::from_json(root, &[] as &[Vec<u8>]).to_glb().unwrap();
+    let root = gltf::json::Root::from_slice(&glb.json).unwrap();
+    let others = |extensions| gltf::json::serialize::to_value(extensions).unwrap();
+    let value = |json: &str| gltf::json::deserialize::from_str::<gltf::json::Value>(json).unwrap();
+    assert_eq!(
+        others(&root.extensions.as_ref().unwrap().others),
+        value(r#"{"ACME_root": {"id": 7}}"#)
+    );
+    assert_eq!(
+        others(&root.scenes[0].extensions.as_ref().unwrap().others),
+        value(r#"{"ACME_scene": {"layer": "main"}}"#)
+    );
+    assert_eq!(
+        others(&root.nodes[0].extensions.as_ref().unwrap().others),
+        value(r#"{"ACME_node": [1, 2, 3], "ACME_added": true}"#)
+    );
+
+    // Supported extensions are still parsed into their fields and are only
+    // kept as raw JSON when their feature is disabled.
+    let material = root.materials[0].extensions.as_ref().unwrap();
+    #[cfg(feature = "KHR_materials_unlit")]
+    {
+        assert!(material.unlit.is_some());
+        assert_eq!(others(&material.others), value(r#"{"ACME_material": {"glow": 0.5}}"#));
+    }
+    #[cfg(not(feature = "KHR_materials_unlit"))]
+    assert_eq!(
+        others(&material.others),
+        value(r#"{"ACME_material": {"glow": 0.5}, "KHR_materials_unlit": {}}"#)
+    );
+
+    // Stripping an unknown extension removes the data kept for it as well.
+    let mut export = gltf::export::Export::from_json(root, &[] as &[Vec<u8>]);
+    export.strip_extension("ACME_node");
+    let root = gltf::json::Root::from_slice(&export.to_glb().unwrap().json).unwrap();
+    assert!(root.nodes[0].extensions.as_ref().unwrap().others.get("ACME_node").is_none());
+    assert!(!root.extensions_used.iter().any(|name| name == "ACME_node"));
+}